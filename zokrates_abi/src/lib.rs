@@ -5,7 +5,7 @@ pub enum Inputs<T> {
     Abi(Values<T>),
 }
 
-impl<T: From<usize>> Encode<T> for Inputs<T> {
+impl<T: Field> Encode<T> for Inputs<T> {
     fn encode(self) -> Vec<T> {
         match self {
             Inputs::Raw(v) => v,
@@ -15,7 +15,9 @@ impl<T: From<usize>> Encode<T> for Inputs<T> {
 }
 
 use std::fmt;
-use zokrates_core::typed_absy::types::{ConcreteType, UBitwidth};
+use zokrates_core::typed_absy::types::{
+    ConcreteEnumType, ConcreteEnumVariant, ConcreteType, UBitwidth,
+};
 
 use zokrates_field::Field;
 
@@ -42,10 +44,49 @@ pub enum Value<T> {
     U16(u16),
     U32(u32),
     U64(u64),
+    U128(u128),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
     Field(T),
     Boolean(bool),
     Array(Vec<Value<T>>),
     Struct(Vec<(String, Value<T>)>),
+    Tuple(Vec<Value<T>>),
+    Enum(EnumValue<T>),
+}
+
+/// A variant of an enum, along with the number of primitive values taken by the payloads of
+/// the other variants, which are zeroed when encoding
+#[derive(PartialEq, Debug)]
+pub struct EnumValue<T> {
+    pub variant: String,
+    pub tag: usize,
+    pub payload: Vec<Value<T>>,
+    pub padding: (usize, usize),
+}
+
+impl<T> EnumValue<T> {
+    pub fn new(ty: &ConcreteEnumType, tag: usize, payload: Vec<Value<T>>) -> Self {
+        EnumValue {
+            variant: ty.variants[tag].id.clone(),
+            tag,
+            payload,
+            padding: (
+                payload_count(&ty.variants[..tag]),
+                payload_count(&ty.variants[tag + 1..]),
+            ),
+        }
+    }
+}
+
+fn payload_count(variants: &[ConcreteEnumVariant]) -> usize {
+    variants
+        .iter()
+        .flat_map(|v| v.payload.iter())
+        .map(|ty| ty.get_primitive_count())
+        .sum()
 }
 
 #[derive(PartialEq, Debug)]
@@ -59,6 +100,11 @@ impl<T: Field> fmt::Display for Value<T> {
             Value::U16(v) => write!(f, "{:#06x}", v),
             Value::U32(v) => write!(f, "{:#010x}", v),
             Value::U64(v) => write!(f, "{:#018x}", v),
+            Value::U128(v) => write!(f, "{:#034x}", v),
+            Value::I8(v) => write!(f, "{}", v),
+            Value::I16(v) => write!(f, "{}", v),
+            Value::I32(v) => write!(f, "{}", v),
+            Value::I64(v) => write!(f, "{}", v),
             Value::Boolean(v) => write!(f, "{}", v),
             Value::Array(v) => write!(
                 f,
@@ -76,6 +122,30 @@ impl<T: Field> fmt::Display for Value<T> {
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
+            Value::Tuple(v) => match v.len() {
+                1 => write!(f, "({},)", v[0]),
+                _ => write!(
+                    f,
+                    "({})",
+                    v.iter()
+                        .map(|v| format!("{}", v))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+            Value::Enum(e) => match e.payload.len() {
+                0 => write!(f, "{}", e.variant),
+                _ => write!(
+                    f,
+                    "{}({})",
+                    e.variant,
+                    e.payload
+                        .iter()
+                        .map(|v| format!("{}", v))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
         }
     }
 }
@@ -90,7 +160,7 @@ pub trait Decode<T> {
     fn decode(raw: Vec<T>, expected: Self::Expected) -> Self;
 }
 
-impl<T: From<usize>> Encode<T> for Value<T> {
+impl<T: Field> Encode<T> for Value<T> {
     fn encode(self) -> Vec<T> {
         match self {
             Value::Field(t) => vec![t],
@@ -98,9 +168,20 @@ impl<T: From<usize>> Encode<T> for Value<T> {
             Value::U16(t) => vec![T::from(t as usize)],
             Value::U32(t) => vec![T::from(t as usize)],
             Value::U64(t) => vec![T::from(t as usize)],
+            Value::U128(t) => vec![T::from(t)],
+            Value::I8(t) => vec![T::from(t as u8 as usize)],
+            Value::I16(t) => vec![T::from(t as u16 as usize)],
+            Value::I32(t) => vec![T::from(t as u32 as usize)],
+            Value::I64(t) => vec![T::from(t as u64 as usize)],
             Value::Boolean(b) => vec![if b { 1.into() } else { 0.into() }],
             Value::Array(a) => a.into_iter().flat_map(|v| v.encode()).collect(),
             Value::Struct(s) => s.into_iter().flat_map(|(_, v)| v.encode()).collect(),
+            Value::Tuple(t) => t.into_iter().flat_map(|v| v.encode()).collect(),
+            Value::Enum(e) => std::iter::once(T::from(e.tag))
+                .chain((0..e.padding.0).map(|_| T::from(0)))
+                .chain(e.payload.into_iter().flat_map(|v| v.encode()))
+                .chain((0..e.padding.1).map(|_| T::from(0)))
+                .collect(),
         }
     }
 }
@@ -144,6 +225,21 @@ impl<T: Field> Decode<T> for Value<T> {
             ConcreteType::Uint(UBitwidth::B64) => {
                 Value::U64(raw.pop().unwrap().to_dec_string().parse().unwrap())
             }
+            ConcreteType::Uint(UBitwidth::B128) => {
+                Value::U128(raw.pop().unwrap().to_dec_string().parse().unwrap())
+            }
+            ConcreteType::Uint(UBitwidth::I8) => {
+                Value::I8(raw.pop().unwrap().to_dec_string().parse::<u8>().unwrap() as i8)
+            }
+            ConcreteType::Uint(UBitwidth::I16) => {
+                Value::I16(raw.pop().unwrap().to_dec_string().parse::<u16>().unwrap() as i16)
+            }
+            ConcreteType::Uint(UBitwidth::I32) => {
+                Value::I32(raw.pop().unwrap().to_dec_string().parse::<u32>().unwrap() as i32)
+            }
+            ConcreteType::Uint(UBitwidth::I64) => {
+                Value::I64(raw.pop().unwrap().to_dec_string().parse::<u64>().unwrap() as i64)
+            }
             ConcreteType::Boolean => {
                 let v = raw.pop().unwrap();
                 Value::Boolean(if v == 0.into() {
@@ -170,11 +266,41 @@ impl<T: Field> Decode<T> for Value<T> {
                     })
                     .collect(),
             ),
+            ConcreteType::Tuple(tuple_type) => Value::Tuple(
+                tuple_type
+                    .elements
+                    .into_iter()
+                    .scan(0, |state, element| {
+                        let new_state = *state + element.get_primitive_count();
+                        let res = Value::decode(raw[*state..new_state].to_vec(), element);
+                        *state = new_state;
+                        Some(res)
+                    })
+                    .collect(),
+            ),
+            ConcreteType::Enum(enum_type) => {
+                let tag: usize = raw[0].to_dec_string().parse().unwrap();
+
+                let offset = 1 + payload_count(&enum_type.variants[..tag]);
+
+                let payload = enum_type.variants[tag]
+                    .payload
+                    .iter()
+                    .scan(offset, |state, ty| {
+                        let new_state = *state + ty.get_primitive_count();
+                        let res = Value::decode(raw[*state..new_state].to_vec(), ty.clone());
+                        *state = new_state;
+                        Some(res)
+                    })
+                    .collect();
+
+                Value::Enum(EnumValue::new(&enum_type, tag, payload))
+            }
         }
     }
 }
 
-impl<T: From<usize>> Encode<T> for Values<T> {
+impl<T: Field> Encode<T> for Values<T> {
     fn encode(self) -> Vec<T> {
         self.0.into_iter().flat_map(|v| v.encode()).collect()
     }
@@ -188,6 +314,11 @@ impl<T: Field> Value<T> {
             Value::U16(u) => serde_json::Value::String(format!("{:#06x}", u)),
             Value::U32(u) => serde_json::Value::String(format!("{:#010x}", u)),
             Value::U64(u) => serde_json::Value::String(format!("{:#018x}", u)),
+            Value::U128(u) => serde_json::Value::String(format!("{:#034x}", u)),
+            Value::I8(i) => serde_json::Value::String(i.to_string()),
+            Value::I16(i) => serde_json::Value::String(i.to_string()),
+            Value::I32(i) => serde_json::Value::String(i.to_string()),
+            Value::I64(i) => serde_json::Value::String(i.to_string()),
             Value::Boolean(b) => serde_json::Value::Bool(b),
             Value::Array(a) => {
                 serde_json::Value::Array(a.into_iter().map(|e| e.into_serde_json()).collect())
@@ -197,6 +328,21 @@ impl<T: Field> Value<T> {
                     .map(|(k, v)| (k, v.into_serde_json()))
                     .collect(),
             ),
+            Value::Tuple(t) => {
+                serde_json::Value::Array(t.into_iter().map(|e| e.into_serde_json()).collect())
+            }
+            Value::Enum(e) => match e.payload.len() {
+                0 => serde_json::Value::String(e.variant),
+                _ => serde_json::Value::Object(
+                    std::iter::once((
+                        e.variant,
+                        serde_json::Value::Array(
+                            e.payload.into_iter().map(|v| v.into_serde_json()).collect(),
+                        ),
+                    ))
+                    .collect(),
+                ),
+            },
         }
     }
 }
@@ -242,6 +388,44 @@ fn parse_value<T: Field>(
             .or_else(|_| u64::from_str_radix(s.as_str().trim_start_matches("0x"), 16))
             .map(Value::U64)
             .map_err(|_| Error::Type(format!("Could not parse `{}` to u64 type", s))),
+        (ConcreteType::Uint(UBitwidth::B128), serde_json::Value::String(s)) => s
+            .as_str()
+            .parse::<u128>()
+            .or_else(|_| u128::from_str_radix(s.as_str().trim_start_matches("0x"), 16))
+            .map(Value::U128)
+            .map_err(|_| Error::Type(format!("Could not parse `{}` to u128 type", s))),
+        (ConcreteType::Uint(UBitwidth::I8), serde_json::Value::String(s)) => s
+            .as_str()
+            .parse::<i8>()
+            .or_else(|_| {
+                u8::from_str_radix(s.as_str().trim_start_matches("0x"), 16).map(|v| v as i8)
+            })
+            .map(Value::I8)
+            .map_err(|_| Error::Type(format!("Could not parse `{}` to i8 type", s))),
+        (ConcreteType::Uint(UBitwidth::I16), serde_json::Value::String(s)) => s
+            .as_str()
+            .parse::<i16>()
+            .or_else(|_| {
+                u16::from_str_radix(s.as_str().trim_start_matches("0x"), 16).map(|v| v as i16)
+            })
+            .map(Value::I16)
+            .map_err(|_| Error::Type(format!("Could not parse `{}` to i16 type", s))),
+        (ConcreteType::Uint(UBitwidth::I32), serde_json::Value::String(s)) => s
+            .as_str()
+            .parse::<i32>()
+            .or_else(|_| {
+                u32::from_str_radix(s.as_str().trim_start_matches("0x"), 16).map(|v| v as i32)
+            })
+            .map(Value::I32)
+            .map_err(|_| Error::Type(format!("Could not parse `{}` to i32 type", s))),
+        (ConcreteType::Uint(UBitwidth::I64), serde_json::Value::String(s)) => s
+            .as_str()
+            .parse::<i64>()
+            .or_else(|_| {
+                u64::from_str_radix(s.as_str().trim_start_matches("0x"), 16).map(|v| v as i64)
+            })
+            .map(Value::I64)
+            .map_err(|_| Error::Type(format!("Could not parse `{}` to i64 type", s))),
         (ConcreteType::Boolean, serde_json::Value::Bool(b)) => Ok(Value::Boolean(b)),
         (ConcreteType::Array(array_type), serde_json::Value::Array(a)) => {
             let size = array_type.size;
@@ -283,6 +467,69 @@ fn parse_value<T: Field>(
                 ))
             }
         }
+        (ConcreteType::Tuple(tuple_type), serde_json::Value::Array(a)) => {
+            if a.len() != tuple_type.elements.len() {
+                Err(Error::Type(format!(
+                    "Expected tuple of size {}, found array of size {}",
+                    tuple_type.elements.len(),
+                    a.len()
+                )))
+            } else {
+                a.into_iter()
+                    .zip(tuple_type.elements.iter())
+                    .map(|(v, ty)| parse_value(v, ty.clone()))
+                    .collect::<Result<_, _>>()
+                    .map(Value::Tuple)
+            }
+        }
+        // a variant without payload is represented by its name, `"Idle"`
+        (ConcreteType::Enum(enum_type), serde_json::Value::String(s)) => {
+            let tag = enum_type.variant_index(&s).ok_or_else(|| {
+                Error::Type(format!("Variant `{}` not found in enum {}", s, enum_type))
+            })?;
+
+            match enum_type.variants[tag].payload.len() {
+                0 => Ok(Value::Enum(EnumValue::new(enum_type, tag, vec![]))),
+                n => Err(Error::Type(format!(
+                    "Variant `{}` expects a payload of size {}",
+                    s, n
+                ))),
+            }
+        }
+        // a variant with a payload is represented as an object with a single key, `{"Active": ["42"]}`
+        (ConcreteType::Enum(enum_type), serde_json::Value::Object(o)) => {
+            if o.len() != 1 {
+                return Err(Error::Type(format!(
+                    "Expected a single variant, found {} variant(s)",
+                    o.len()
+                )));
+            }
+
+            let (variant, payload) = o.into_iter().next().unwrap();
+
+            let tag = enum_type.variant_index(&variant).ok_or_else(|| {
+                Error::Type(format!(
+                    "Variant `{}` not found in enum {}",
+                    variant, enum_type
+                ))
+            })?;
+
+            let expected = &enum_type.variants[tag].payload;
+
+            match payload {
+                serde_json::Value::Array(a) if a.len() == expected.len() => a
+                    .into_iter()
+                    .zip(expected.iter())
+                    .map(|(v, ty)| parse_value(v, ty.clone()))
+                    .collect::<Result<_, _>>()
+                    .map(|payload| Value::Enum(EnumValue::new(enum_type, tag, payload))),
+                _ => Err(Error::Type(format!(
+                    "Variant `{}` expects a payload of size {}",
+                    variant,
+                    expected.len()
+                ))),
+            }
+        }
         (_, serde_json::Value::Number(n)) => Err(Error::Conversion(format!(
             "Value `{}` isn't allowed, did you mean `\"{}\"`?",
             n, n
@@ -332,7 +579,8 @@ pub fn parse_strict_json<T: Field>(
 mod tests {
     use super::*;
     use zokrates_core::typed_absy::types::{
-        ConcreteStructMember, ConcreteStructType, ConcreteType,
+        ConcreteEnumType, ConcreteEnumVariant, ConcreteStructMember, ConcreteStructType,
+        ConcreteTupleType, ConcreteType,
     };
     use zokrates_field::Bn128Field;
 
@@ -399,6 +647,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn u128() {
+        let s =
+            r#"["340282366920938463463374607431768211455", "0x0123456789abcdef0123456789abcdef"]"#;
+        let values = parse_strict::<Bn128Field>(
+            s,
+            vec![
+                ConcreteType::Uint(UBitwidth::B128),
+                ConcreteType::Uint(UBitwidth::B128),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            values,
+            Values(vec![
+                Value::U128(u128::MAX),
+                Value::U128(0x0123456789abcdef0123456789abcdefu128)
+            ])
+        );
+
+        assert_eq!(
+            values.encode(),
+            vec![
+                Bn128Field::try_from_dec_str("340282366920938463463374607431768211455").unwrap(),
+                Bn128Field::from(0x0123456789abcdef0123456789abcdefu128)
+            ]
+        );
+    }
+
+    #[test]
+    fn ints() {
+        let s = r#"["-1", "0x1234", "2147483647", "-9223372036854775808"]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(
+                s,
+                vec![
+                    ConcreteType::Uint(UBitwidth::I8),
+                    ConcreteType::Uint(UBitwidth::I16),
+                    ConcreteType::Uint(UBitwidth::I32),
+                    ConcreteType::Uint(UBitwidth::I64)
+                ]
+            )
+            .unwrap(),
+            Values(vec![
+                Value::I8(-1i8),
+                Value::I16(4660i16),
+                Value::I32(2147483647i32),
+                Value::I64(-9223372036854775808i64)
+            ])
+        );
+
+        let s = r#"["0xff"]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(s, vec![ConcreteType::Uint(UBitwidth::I8)]).unwrap(),
+            Values(vec![Value::I8(-1i8)])
+        );
+
+        let s = r#"["128"]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(s, vec![ConcreteType::Uint(UBitwidth::I8)]).unwrap_err(),
+            Error::Type("Could not parse `128` to i8 type".into())
+        );
+    }
+
     #[test]
     fn bools() {
         let s = "[true, false]";
@@ -501,6 +814,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tuple() {
+        let s = r#"[["42", true]]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(
+                s,
+                vec![ConcreteType::tuple(ConcreteTupleType::new(vec![
+                    ConcreteType::FieldElement,
+                    ConcreteType::Boolean
+                ]))]
+            )
+            .unwrap(),
+            Values(vec![Value::Tuple(vec![
+                Value::Field(42.into()),
+                Value::Boolean(true)
+            ])])
+        );
+
+        let s = r#"[["42"]]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(
+                s,
+                vec![ConcreteType::tuple(ConcreteTupleType::new(vec![
+                    ConcreteType::FieldElement,
+                    ConcreteType::Boolean
+                ]))]
+            )
+            .unwrap_err(),
+            Error::Type("Expected tuple of size 2, found array of size 1".into())
+        );
+    }
+
+    #[test]
+    fn enumeration() {
+        // enum State { Idle, Active(field, bool), Done(u8) }
+        let ty = ConcreteEnumType::new(
+            "".into(),
+            "State".into(),
+            vec![
+                ConcreteEnumVariant::new("Idle".into(), vec![]),
+                ConcreteEnumVariant::new(
+                    "Active".into(),
+                    vec![ConcreteType::FieldElement, ConcreteType::Boolean],
+                ),
+                ConcreteEnumVariant::new("Done".into(), vec![ConcreteType::Uint(UBitwidth::B8)]),
+            ],
+        );
+
+        let s = r#"["Idle", {"Active": ["42", true]}]"#;
+        let values = parse_strict::<Bn128Field>(
+            s,
+            vec![
+                ConcreteType::enumeration(ty.clone()),
+                ConcreteType::enumeration(ty.clone()),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            values,
+            Values(vec![
+                Value::Enum(EnumValue::new(&ty, 0, vec![])),
+                Value::Enum(EnumValue::new(
+                    &ty,
+                    1,
+                    vec![Value::Field(42.into()), Value::Boolean(true)]
+                ))
+            ])
+        );
+
+        // the tag comes first, followed by the payloads of all variants, zeroed when inactive
+        let encoded = values.encode();
+        assert_eq!(
+            encoded,
+            vec![0, 0, 0, 0, 1, 42, 1, 0]
+                .into_iter()
+                .map(Bn128Field::from)
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            Value::decode(encoded[4..].to_vec(), ConcreteType::enumeration(ty.clone())),
+            Value::Enum(EnumValue::new(
+                &ty,
+                1,
+                vec![Value::Field(42.into()), Value::Boolean(true)]
+            ))
+        );
+
+        let s = r#"[{"Active": ["42"]}]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(s, vec![ConcreteType::enumeration(ty.clone())]).unwrap_err(),
+            Error::Type("Variant `Active` expects a payload of size 2".into())
+        );
+
+        let s = r#"["Paused"]"#;
+        assert_eq!(
+            parse_strict::<Bn128Field>(s, vec![ConcreteType::enumeration(ty)]).unwrap_err(),
+            Error::Type("Variant `Paused` not found in enum State".into())
+        );
+    }
+
     #[test]
     fn into_serde() {
         let values = Values::<Bn128Field>(vec![
@@ -515,6 +930,7 @@ mod tests {
                 ("a".to_string(), Value::Field(1.into())),
                 ("b".to_string(), Value::Field(2.into())),
             ]),
+            Value::Tuple(vec![Value::Field(1.into()), Value::Boolean(false)]),
         ]);
 
         let serde_value = values.into_serde_json();
@@ -532,7 +948,8 @@ mod tests {
                     serde_json::Value::String("1".into()),
                     serde_json::Value::String("2".into())
                 ]),
-                serde_json::json!({ "a": "1", "b": "2" })
+                serde_json::json!({ "a": "1", "b": "2" }),
+                serde_json::json!(["1", false])
             ])
         )
     }
@@ -552,6 +969,12 @@ mod tests {
             assert_eq!(v.encode(), vec![1, 2]);
         }
 
+        #[test]
+        fn i8s() {
+            let v = Values::<usize>(vec![Value::I8(1), Value::I8(-1)]);
+            assert_eq!(v.encode(), vec![1, 255]);
+        }
+
         #[test]
         fn bools() {
             let v: Values<usize> = Values(vec![Value::Boolean(true), Value::Boolean(false)]);
@@ -576,5 +999,14 @@ mod tests {
             )]);
             assert_eq!(v.encode(), vec![42]);
         }
+
+        #[test]
+        fn tuple() {
+            let v: Values<usize> = Values(vec![Value::Tuple(vec![
+                Value::Field(42),
+                Value::Boolean(true),
+            ])]);
+            assert_eq!(v.encode(), vec![42, 1]);
+        }
     }
 }
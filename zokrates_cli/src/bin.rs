@@ -44,16 +44,27 @@ fn cli() -> Result<(), String> {
         .subcommands(vec![
             compile::subcommand(),
             check::subcommand(),
+            check_uniqueness::subcommand(),
             compute_witness::subcommand(),
+            convert_witness::subcommand(),
             #[cfg(feature = "ark")]
             universal_setup::subcommand(),
             #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
             setup::subcommand(),
+            equiv::subcommand(),
+            export_r1cs::subcommand(),
             export_verifier::subcommand(),
+            export_witness_generator::subcommand(),
+            fmt::subcommand(),
             #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
             generate_proof::subcommand(),
             generate_smtlib2::subcommand(),
+            inspect::subcommand(),
+            #[cfg(feature = "bellman")]
+            mpc::subcommand(),
             print_proof::subcommand(),
+            profile::subcommand(),
+            test::subcommand(),
             #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
             verify::subcommand()])
         .get_matches();
@@ -61,16 +72,29 @@ fn cli() -> Result<(), String> {
     match matches.subcommand() {
         ("compile", Some(sub_matches)) => compile::exec(sub_matches),
         ("check", Some(sub_matches)) => check::exec(sub_matches),
+        ("check-uniqueness", Some(sub_matches)) => check_uniqueness::exec(sub_matches),
         ("compute-witness", Some(sub_matches)) => compute_witness::exec(sub_matches),
+        ("convert-witness", Some(sub_matches)) => convert_witness::exec(sub_matches),
         #[cfg(feature = "ark")]
         ("universal-setup", Some(sub_matches)) => universal_setup::exec(sub_matches),
         #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
         ("setup", Some(sub_matches)) => setup::exec(sub_matches),
+        ("equiv", Some(sub_matches)) => equiv::exec(sub_matches),
+        ("export-r1cs", Some(sub_matches)) => export_r1cs::exec(sub_matches),
         ("export-verifier", Some(sub_matches)) => export_verifier::exec(sub_matches),
+        ("export-witness-generator", Some(sub_matches)) => {
+            export_witness_generator::exec(sub_matches)
+        }
+        ("fmt", Some(sub_matches)) => fmt::exec(sub_matches),
         #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
         ("generate-proof", Some(sub_matches)) => generate_proof::exec(sub_matches),
         ("generate-smtlib2", Some(sub_matches)) => generate_smtlib2::exec(sub_matches),
+        ("inspect", Some(sub_matches)) => inspect::exec(sub_matches),
+        #[cfg(feature = "bellman")]
+        ("mpc", Some(sub_matches)) => mpc::exec(sub_matches),
         ("print-proof", Some(sub_matches)) => print_proof::exec(sub_matches),
+        ("profile", Some(sub_matches)) => profile::exec(sub_matches),
+        ("test", Some(sub_matches)) => test::exec(sub_matches),
         #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
         ("verify", Some(sub_matches)) => verify::exec(sub_matches),
         _ => unreachable!(),
@@ -116,6 +140,7 @@ mod tests {
     use std::io::{BufReader, Read};
     use std::string::String;
     use zokrates_core::compile::{compile, CompilationArtifacts, CompileConfig};
+    use zokrates_core::flat_absy::RuntimeError;
     use zokrates_core::ir;
     use zokrates_field::Bn128Field;
     use zokrates_fs_resolver::FileSystemResolver;
@@ -168,6 +193,89 @@ mod tests {
             .unwrap();
     }
 
+    // removes the line and the column from an assertion message of the form
+    // `Assertion failed at {file}:{line}:{column}`, followed by the optional user message
+    fn strip_position(message: &str) -> String {
+        let (location, rest) = message.split_at(message.find(": \"").unwrap_or(message.len()));
+        let file = location.rsplitn(3, ':').last().unwrap();
+        format!("{}{}", file, rest)
+    }
+
+    #[test]
+    fn format_examples() {
+        let builder = std::thread::Builder::new().stack_size(8388608);
+
+        builder
+            .spawn(|| {
+                for p in glob("./examples/**/*.zok").expect("Failed to read glob pattern") {
+                    let path = match p {
+                        Ok(x) => x,
+                        Err(why) => panic!("Error: {:?}", why),
+                    };
+
+                    if path.to_str().unwrap().contains("compile_errors") {
+                        continue;
+                    }
+
+                    println!("Testing {:?}", path);
+
+                    let file = File::open(path.clone()).unwrap();
+
+                    let mut reader = BufReader::new(file);
+                    let mut source = String::new();
+                    reader.read_to_string(&mut source).unwrap();
+
+                    let formatted = zokrates_pest_ast::format(&source).unwrap();
+
+                    // formatting is idempotent
+                    assert_eq!(zokrates_pest_ast::format(&formatted).unwrap(), formatted);
+
+                    // formatting does not change the compiled program
+                    let stdlib = std::fs::canonicalize("../zokrates_stdlib/stdlib").unwrap();
+                    let resolver = FileSystemResolver::with_stdlib_root(stdlib.to_str().unwrap());
+
+                    let serialize = |source: String| {
+                        let artifacts: CompilationArtifacts<Bn128Field> = compile(
+                            source,
+                            path.clone(),
+                            Some(&resolver),
+                            &CompileConfig::default(),
+                        )
+                        .unwrap();
+
+                        // assertion messages contain the position of the assertion, which
+                        // formatting may change
+                        let mut program = artifacts.prog().clone();
+                        program.statements = program
+                            .statements
+                            .into_iter()
+                            .map(|s| match s {
+                                ir::Statement::Constraint(
+                                    quad,
+                                    lin,
+                                    Some(RuntimeError::SourceAssertion(message)),
+                                ) => ir::Statement::Constraint(
+                                    quad,
+                                    lin,
+                                    Some(RuntimeError::SourceAssertion(strip_position(&message))),
+                                ),
+                                s => s,
+                            })
+                            .collect();
+
+                        let mut buffer = vec![];
+                        program.serialize(&mut buffer);
+                        buffer
+                    };
+
+                    assert_eq!(serialize(source), serialize(formatted));
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
     #[test]
     fn execute_examples_ok() {
         //these examples should compile and run
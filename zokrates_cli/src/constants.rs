@@ -8,6 +8,13 @@ pub const JSON_PROOF_PATH: &str = "proof.json";
 pub const UNIVERSAL_SETUP_DEFAULT_PATH: &str = "universal_setup.dat";
 pub const UNIVERSAL_SETUP_DEFAULT_SIZE: &str = "10";
 pub const SMTLIB2_DEFAULT_PATH: &str = "out.smt2";
+pub const R1CS_DEFAULT_PATH: &str = "out.r1cs";
+pub const PROFILE_DEFAULT_PATH: &str = "profile.folded";
+pub const WITNESS_GENERATOR_DEFAULT_PATH: &str = "witness_generator";
+pub const MPC_DEFAULT_PATH: &str = "mpc.params";
+pub const MPC_BEACON_DEFAULT_ITERATIONS: &str = "10";
+
+pub const SEED_WARNING: &str = "WARNING: You are using a seed for the randomness. Anyone who knows the seed can recompute the secret values of the setup or the proof, only do this for testing.";
 
 pub const BELLMAN: &str = "bellman";
 pub const LIBSNARK: &str = "libsnark";
@@ -52,6 +59,8 @@ pub const BLS12_381: &str = "bls12_381";
 pub const BLS12_377: &str = "bls12_377";
 pub const BW6_761: &str = "bw6_761";
 pub const CURVES: &[&str] = &[BN128, BLS12_381, BLS12_377, BW6_761];
+/// The curves supported by the multi-party computation of the Groth16 setup
+pub const MPC_CURVES: &[&str] = &[BN128, BLS12_381];
 
 pub const G16: &str = "g16";
 pub const PGHR13: &str = "pghr13";
@@ -1,5 +1,23 @@
 use crate::constants::*;
+use clap::ArgMatches;
 use std::convert::TryFrom;
+#[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
+use std::fs::File;
+#[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
+use std::io::Read;
+use std::io::Write;
+#[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use zokrates_core::compile::{CompileConfig, CompileWarning, Lint, LintLevel};
+use zokrates_core::ir::smtlib2::SmtSolver;
+#[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
+use zokrates_core::ir::Fingerprint;
+use zokrates_core::passes::{check_config, OptLevel};
+#[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
+use zokrates_core::proof_system::check_proving_key;
+use zokrates_core::proof_system::Seed;
 
 #[derive(Debug)]
 pub enum CurveParameter {
@@ -93,6 +111,12 @@ impl TryFrom<(&str, &str, &str)> for Parameters {
             #[cfg(feature = "bellman")]
             (BackendParameter::Bellman, CurveParameter::Bls12_381, SchemeParameter::G16) => Ok(()),
             #[cfg(feature = "ark")]
+            (BackendParameter::Ark, CurveParameter::Bls12_377, SchemeParameter::G16) => Ok(()),
+            #[cfg(feature = "ark")]
+            (BackendParameter::Ark, CurveParameter::Bw6_761, SchemeParameter::G16) => Ok(()),
+            #[cfg(feature = "ark")]
+            (BackendParameter::Ark, CurveParameter::Bn128, SchemeParameter::G16) => Ok(()),
+            #[cfg(feature = "ark")]
             (BackendParameter::Ark, CurveParameter::Bls12_377, SchemeParameter::GM17) => Ok(()),
             #[cfg(feature = "ark")]
             (BackendParameter::Ark, CurveParameter::Bw6_761, SchemeParameter::GM17) => Ok(()),
@@ -115,3 +139,163 @@ impl TryFrom<(&str, &str, &str)> for Parameters {
         }.map(|_: ()| Parameters(backend, curve, proving_scheme))
     }
 }
+
+#[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
+/// Parses 32 bytes in hex encoding, optionally prefixed with `0x`
+pub fn parse_bytes32(s: &str) -> Option<[u8; 32]> {
+    let hex = s.trim_start_matches("0x");
+
+    if hex.len() != 64 {
+        return None;
+    }
+
+    let mut res = [0u8; 32];
+    for (i, byte) in res.iter_mut().enumerate() {
+        *byte = hex
+            .get(2 * i..2 * i + 2)
+            .and_then(|b| u8::from_str_radix(b, 16).ok())?;
+    }
+
+    Some(res)
+}
+
+#[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
+/// Reads the optional `seed` argument, a 32-byte hex string, and warns the user if it is set
+pub fn seed(sub_matches: &ArgMatches) -> Result<Option<Seed>, String> {
+    let seed = match sub_matches.value_of("seed") {
+        Some(seed) => seed,
+        None => return Ok(None),
+    };
+
+    let res = parse_bytes32(seed)
+        .ok_or_else(|| format!("Invalid seed {}: expected 32 bytes in hex encoding", seed))?;
+
+    println!("{}", SEED_WARNING);
+
+    Ok(Some(res))
+}
+
+#[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
+/// Warns if the proving key at `pk_path`, if any, was made for a program other than the one with
+/// fingerprint `fingerprint`
+pub fn warn_overwritten_key(pk_path: &Path, fingerprint: Fingerprint) {
+    // only read the magic number and the fingerprint
+    let mut header = vec![];
+    let read = File::open(pk_path).and_then(|f| f.take(36).read_to_end(&mut header));
+
+    // the comparison is the one `generate-proof` fails on, so that both report the same mismatch
+    if let (Ok(_), Err(e)) = (read, check_proving_key(header, fingerprint)) {
+        println!(
+            "Warning: overwriting the proving key {}: {}",
+            pk_path.display(),
+            e
+        );
+    }
+}
+
+/// Applies the `allow` and `deny` arguments to `config`. Each of them takes lint names, or
+/// `warnings` which stands for all lints and is overridden by the lints given by name
+pub fn lint_levels(
+    mut config: CompileConfig,
+    sub_matches: &ArgMatches,
+) -> Result<CompileConfig, String> {
+    let levels: Vec<_> = [("allow", LintLevel::Allow), ("deny", LintLevel::Deny)]
+        .iter()
+        .flat_map(|(arg, level)| {
+            sub_matches
+                .values_of(arg)
+                .into_iter()
+                .flatten()
+                .map(move |name| (name, *level))
+        })
+        .collect();
+
+    for (_, level) in levels.iter().filter(|(name, _)| *name == "warnings") {
+        for lint in Lint::ALL.iter() {
+            config = config.lint(*lint, *level);
+        }
+    }
+
+    for (name, level) in levels.iter().filter(|(name, _)| *name != "warnings") {
+        config = config.lint(Lint::from_str(name)?, *level);
+    }
+
+    Ok(config)
+}
+
+/// Applies the `opt-level`, `disable-pass` and `print-after` arguments to `config`
+pub fn passes(config: CompileConfig, sub_matches: &ArgMatches) -> Result<CompileConfig, String> {
+    let config = match sub_matches.value_of("opt-level") {
+        Some(level) => config.opt_level(OptLevel::from_str(level)?),
+        None => config,
+    };
+
+    let config = sub_matches
+        .values_of("disable-pass")
+        .into_iter()
+        .flatten()
+        .fold(config, |config, name| config.disable_pass(name));
+
+    let config = sub_matches
+        .values_of("print-after")
+        .into_iter()
+        .flatten()
+        .fold(config, |config, name| config.print_after(name));
+
+    check_config(&config, &[])?;
+
+    Ok(config)
+}
+
+/// Formats a warning, its file being relative to the current directory
+pub fn format_warning(w: &CompileWarning) -> String {
+    let file = w.file().canonicalize().unwrap();
+    format!(
+        "Warning: {}:{}",
+        file.strip_prefix(std::env::current_dir().unwrap())
+            .unwrap_or_else(|_| file.as_path())
+            .display(),
+        w.value()
+    )
+}
+
+/// An SMT solver run as an external command, which reads a query on its standard input
+pub struct ExternalSmtSolver {
+    command: String,
+    args: Vec<String>,
+}
+
+impl ExternalSmtSolver {
+    /// Reads the optional `smt-solver` argument, a command line such as `z3 -in`
+    pub fn from_matches(sub_matches: &ArgMatches) -> Option<Self> {
+        let mut words = sub_matches.value_of("smt-solver")?.split_whitespace();
+        Some(ExternalSmtSolver {
+            command: words.next()?.to_string(),
+            args: words.map(|w| w.to_string()).collect(),
+        })
+    }
+}
+
+impl SmtSolver for ExternalSmtSolver {
+    fn run(&self, query: &str) -> Result<String, String> {
+        let mut child = Command::new(&self.command)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|why| format!("Could not run {}: {}", self.command, why))?;
+
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(query.as_bytes())
+            .map_err(|why| format!("Could not write to {}: {}", self.command, why))?;
+
+        let output = child
+            .wait_with_output()
+            .map_err(|why| format!("Could not run {}: {}", self.command, why))?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::constants;
-use crate::helpers::CurveParameter;
+use crate::helpers::{format_warning, lint_levels, CurveParameter};
 use clap::{App, Arg, ArgMatches, SubCommand};
 use std::convert::TryFrom;
 use std::fs::File;
@@ -46,6 +46,26 @@ pub fn subcommand() -> App<'static, 'static> {
             .help("Isolate the execution of branches: a panic in a branch only makes the program panic if this branch is being logically executed")
             .required(false)
         )
+        .arg(
+            Arg::with_name("allow")
+                .long("allow")
+                .help("Do not report a lint, or all of them with `warnings`")
+                .value_name("LINT")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name("deny")
+                .long("deny")
+                .help("Report a lint as an error, or all of them with `warnings`")
+                .value_name("LINT")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .required(false),
+        )
 }
 
 pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
@@ -91,9 +111,10 @@ fn cli_check<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
 
     let config =
         CompileConfig::default().isolate_branches(sub_matches.is_present("isolate-branches"));
+    let config = lint_levels(config, sub_matches)?;
 
     let resolver = FileSystemResolver::with_stdlib_root(stdlib_path);
-    let _ = check::<T, _>(source, path, Some(&resolver), &config).map_err(|e| {
+    let warnings = check::<T, _>(source, path, Some(&resolver), &config).map_err(|e| {
         format!(
             "Check failed:\n\n{}",
             e.0.iter()
@@ -103,6 +124,10 @@ fn cli_check<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
         )
     })?;
 
+    for warning in &warnings.0 {
+        println!("{}\n", format_warning(warning));
+    }
+
     println!("Program checked, no errors found.");
 
     Ok(())
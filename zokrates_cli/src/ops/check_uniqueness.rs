@@ -0,0 +1,97 @@
+use crate::constants::FLATTENED_CODE_DEFAULT_PATH;
+use crate::helpers::ExternalSmtSolver;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use zokrates_core::ir::smtlib2::SmtSolver;
+use zokrates_core::ir::{self, ProgEnum, SourceMap};
+use zokrates_field::Field;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("check-uniqueness")
+        .about("Checks that the witness of a compiled program is uniquely determined by its arguments")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the binary")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(FLATTENED_CODE_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("smt-solver")
+                .long("smt-solver")
+                .help("Command running an SMT solver which reads SMTLib2 queries on its standard input, used for the variables the analysis cannot prove unique on its own, for example `z3 -in`")
+                .value_name("COMMAND")
+                .takes_value(true)
+                .required(false),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    // read compiled program
+    let path = Path::new(sub_matches.value_of("input").unwrap());
+    let file =
+        File::open(&path).map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
+
+    let mut reader = BufReader::new(file);
+
+    match ProgEnum::deserialize(&mut reader)? {
+        ProgEnum::Bn128Program(p) => cli_check_uniqueness(p, sub_matches),
+        ProgEnum::Bls12_377Program(p) => cli_check_uniqueness(p, sub_matches),
+        ProgEnum::Bls12_381Program(p) => cli_check_uniqueness(p, sub_matches),
+        ProgEnum::Bw6_761Program(p) => cli_check_uniqueness(p, sub_matches),
+    }
+}
+
+fn cli_check_uniqueness<T: Field>(
+    ir_prog: ir::Prog<T>,
+    sub_matches: &ArgMatches,
+) -> Result<(), String> {
+    // the source map is optional, as it only helps locating the variables
+    let source_map_path = Path::new(sub_matches.value_of("input").unwrap()).with_extension("map");
+    let source_map = File::open(&source_map_path)
+        .ok()
+        .and_then(|file| SourceMap::read(BufReader::new(file)).ok())
+        .unwrap_or_default();
+
+    let solver = ExternalSmtSolver::from_matches(sub_matches);
+
+    println!("Checking uniqueness...");
+
+    let under_constrained =
+        ir_prog.under_constrained(solver.as_ref().map(|s| s as &dyn SmtSolver))?;
+
+    for u in &under_constrained {
+        let origin = source_map
+            .span(u.index)
+            .map(|span| format!(" at {}", span))
+            .unwrap_or_default();
+
+        println!(
+            "{} (output of {}{}) {}",
+            u.variable,
+            u.solver,
+            origin,
+            match u.not_unique {
+                true => "is not uniquely determined",
+                false => "could not be proven to be uniquely determined",
+            }
+        );
+    }
+
+    match under_constrained.len() {
+        0 => {
+            println!("The witness is uniquely determined by the arguments");
+            Ok(())
+        }
+        count => Err(format!(
+            "Found {} variable{} which may be under-constrained",
+            count,
+            if count == 1 { "" } else { "s" }
+        )),
+    }
+}
@@ -1,5 +1,5 @@
 use crate::constants;
-use crate::helpers::CurveParameter;
+use crate::helpers::{format_warning, lint_levels, passes, CurveParameter};
 use clap::{App, Arg, ArgMatches, SubCommand};
 use serde_json::to_writer_pretty;
 use std::convert::TryFrom;
@@ -60,6 +60,57 @@ pub fn subcommand() -> App<'static, 'static> {
         .long("isolate-branches")
         .help("Isolate the execution of branches: a panic in a branch only makes the program panic if this branch is being logically executed")
         .required(false)
+    ).arg(Arg::with_name("eliminate-linear-constraints")
+        .long("eliminate-linear-constraints")
+        .help("Remove linear constraints by substitution, without growing any constraint past the given number of terms")
+        .value_name("MAX_DENSITY")
+        .takes_value(true)
+        .required(false)
+    ).arg(Arg::with_name("opt-level")
+        .short("O")
+        .long("opt-level")
+        .help("Optimization level: 0 only runs the required passes, 3 also eliminates linear constraints")
+        .value_name("LEVEL")
+        .takes_value(true)
+        .possible_values(&["0", "1", "2", "3"])
+        .required(false)
+        .default_value("2")
+    ).arg(Arg::with_name("disable-pass")
+        .long("disable-pass")
+        .help("Do not run an optional pass, regardless of the optimization level")
+        .value_name("PASS")
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1)
+        .required(false)
+    ).arg(Arg::with_name("print-after")
+        .long("print-after")
+        .help("Print the program after a pass")
+        .value_name("PASS")
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1)
+        .required(false)
+    ).arg(Arg::with_name("time-passes")
+        .long("time-passes")
+        .help("Report the time taken by each pass and how it changed the number of constraints")
+        .required(false)
+    ).arg(Arg::with_name("allow")
+        .long("allow")
+        .help("Do not report a lint, or all of them with `warnings`")
+        .value_name("LINT")
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1)
+        .required(false)
+    ).arg(Arg::with_name("deny")
+        .long("deny")
+        .help("Report a lint as an error, or all of them with `warnings`")
+        .value_name("LINT")
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1)
+        .required(false)
     ).arg(Arg::with_name("ztf")
         .long("ztf")
         .help("Write human readable output (ztf)")
@@ -98,6 +149,7 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
     let bin_output_path = Path::new(sub_matches.value_of("output").unwrap());
     let abi_spec_path = Path::new(sub_matches.value_of("abi-spec").unwrap());
     let hr_output_path = bin_output_path.to_path_buf().with_extension("ztf");
+    let source_map_path = bin_output_path.to_path_buf().with_extension("map");
 
     log::debug!("Load entry point file {}", path.display());
 
@@ -130,7 +182,16 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
 
     let config = CompileConfig::default()
         .allow_unconstrained_variables(sub_matches.is_present("allow-unconstrained-variables"))
-        .isolate_branches(sub_matches.is_present("isolate-branches"));
+        .isolate_branches(sub_matches.is_present("isolate-branches"))
+        .linear_elimination(
+            sub_matches
+                .value_of("eliminate-linear-constraints")
+                .map(|d| d.parse::<usize>())
+                .transpose()
+                .map_err(|_| "Invalid maximum density".to_string())?,
+        );
+    let config = lint_levels(config, sub_matches)?;
+    let config = passes(config, sub_matches)?;
 
     let resolver = FileSystemResolver::with_stdlib_root(stdlib_path);
 
@@ -147,6 +208,23 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
             )
         })?;
 
+    for warning in &artifacts.warnings().0 {
+        println!("{}\n", format_warning(warning));
+    }
+
+    for report in artifacts.pass_reports() {
+        if let Some(output) = &report.output {
+            println!("Program after {}:\n{}\n", report.name, output);
+        }
+    }
+
+    if sub_matches.is_present("time-passes") {
+        for report in artifacts.pass_reports() {
+            println!("{}", report);
+        }
+        println!();
+    }
+
     let program_flattened = artifacts.prog();
 
     // number of constraints the flattened program will translate to.
@@ -159,7 +237,9 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
 
     let mut writer = BufWriter::new(bin_output_file);
 
-    program_flattened.serialize(&mut writer);
+    program_flattened
+        .serialize_with_metadata(&mut writer, artifacts.metadata())
+        .map_err(|why| format!("Could not write {}: {}", bin_output_path.display(), why))?;
 
     // serialize ABI spec and write to JSON file
     log::debug!("Serialize ABI");
@@ -171,6 +251,16 @@ fn cli_compile<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
     let mut writer = BufWriter::new(abi_spec_file);
     to_writer_pretty(&mut writer, &abi).map_err(|_| "Unable to write data to file.".to_string())?;
 
+    // serialize source map and write it next to the binary
+    log::debug!("Serialize source map");
+    let source_map_file = File::create(&source_map_path)
+        .map_err(|why| format!("Could not create {}: {}", source_map_path.display(), why))?;
+
+    artifacts
+        .source_map()
+        .write(BufWriter::new(source_map_file))
+        .map_err(|why| format!("Unable to write source map: {}", why))?;
+
     if sub_matches.is_present("verbose") {
         // debugging output
         println!("Compiled program:\n{}", program_flattened);
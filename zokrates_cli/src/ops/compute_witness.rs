@@ -38,6 +38,16 @@ pub fn subcommand() -> App<'static, 'static> {
         .takes_value(true)
         .required(false)
         .default_value(WITNESS_DEFAULT_PATH)
+    ).arg(Arg::with_name("binary")
+        .long("binary")
+        .help("Write the witness in the binary format instead of the text format")
+        .required(false)
+    ).arg(Arg::with_name("wtns")
+        .long("wtns")
+        .help("Path of an additional output file holding the witness in the iden3 `.wtns` format")
+        .value_name("FILE")
+        .takes_value(true)
+        .required(false)
     ).arg(Arg::with_name("arguments")
         .short("a")
         .long("arguments")
@@ -86,6 +96,19 @@ fn cli_compute<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Resu
         println!("{}", ir_prog);
     }
 
+    // attach source spans to the program if a source map was written next to it at compile time
+    let source_map_path = Path::new(sub_matches.value_of("input").unwrap()).with_extension("map");
+    let ir_prog = match source_map_path.exists() {
+        true => {
+            let file = File::open(&source_map_path)
+                .map_err(|why| format!("Could not open {}: {}", source_map_path.display(), why))?;
+            let source_map = ir::SourceMap::read(BufReader::new(file))
+                .map_err(|why| format!("Could not read source map: {}", why))?;
+            ir_prog.with_source_map(&source_map)
+        }
+        false => ir_prog,
+    };
+
     let is_stdin = sub_matches.is_present("stdin");
     let is_abi = sub_matches.is_present("abi");
 
@@ -162,7 +185,7 @@ fn cli_compute<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Resu
     let interpreter = ir::Interpreter::default();
 
     let witness = interpreter
-        .execute(&ir_prog, &arguments.encode())
+        .execute_with_log_stream(&ir_prog, &arguments.encode(), &mut std::io::stdout())
         .map_err(|e| format!("Execution failed: {}", e))?;
 
     use zokrates_abi::Decode;
@@ -181,10 +204,25 @@ fn cli_compute<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Resu
 
     let writer = BufWriter::new(output_file);
 
-    witness
-        .write(writer)
-        .map_err(|why| format!("Could not save witness: {:?}", why))?;
+    match sub_matches.is_present("binary") {
+        true => witness.write_binary(&ir_prog.wires(), writer),
+        false => witness.write(writer),
+    }
+    .map_err(|why| format!("Could not save witness: {:?}", why))?;
 
     println!("Witness file written to '{}'", output_path.display());
+
+    if let Some(wtns_path) = sub_matches.value_of("wtns") {
+        let wtns_path = Path::new(wtns_path);
+        let wtns_file = File::create(&wtns_path)
+            .map_err(|why| format!("Could not create {}: {}", wtns_path.display(), why))?;
+
+        witness
+            .write_wtns(&ir_prog.wires(), BufWriter::new(wtns_file))
+            .map_err(|why| format!("Could not save witness: {:?}", why))?;
+
+        println!("Witness file written to '{}'", wtns_path.display());
+    }
+
     Ok(())
 }
@@ -0,0 +1,96 @@
+use crate::constants;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use zokrates_core::ir;
+use zokrates_core::ir::{is_binary_witness, ProgEnum, Witness};
+use zokrates_field::Field;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("convert-witness")
+        .about("Converts a witness between the text and the binary formats")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the witness, in either format")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::WITNESS_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .help("Path of the converted witness, in the other format")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("program")
+                .short("p")
+                .long("program")
+                .help("Path of the program the witness was computed for")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::FLATTENED_CODE_DEFAULT_PATH),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    // read compiled program
+    let path = Path::new(sub_matches.value_of("program").unwrap());
+    let file =
+        File::open(&path).map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
+
+    let mut reader = BufReader::new(file);
+
+    match ProgEnum::deserialize(&mut reader)? {
+        ProgEnum::Bn128Program(p) => cli_convert_witness(p, sub_matches),
+        ProgEnum::Bls12_377Program(p) => cli_convert_witness(p, sub_matches),
+        ProgEnum::Bls12_381Program(p) => cli_convert_witness(p, sub_matches),
+        ProgEnum::Bw6_761Program(p) => cli_convert_witness(p, sub_matches),
+    }
+}
+
+fn cli_convert_witness<T: Field>(
+    program: ir::Prog<T>,
+    sub_matches: &ArgMatches,
+) -> Result<(), String> {
+    let input_path = Path::new(sub_matches.value_of("input").unwrap());
+    let output_path = Path::new(sub_matches.value_of("output").unwrap());
+
+    let mut header = vec![];
+    File::open(&input_path)
+        .and_then(|file| file.take(4).read_to_end(&mut header))
+        .map_err(|why| format!("Could not read {}: {}", input_path.display(), why))?;
+
+    let input = BufReader::new(
+        File::open(&input_path)
+            .map_err(|why| format!("Could not open {}: {}", input_path.display(), why))?,
+    );
+
+    let wires = program.wires();
+
+    let witness: Witness<T> =
+        Witness::read(input, &wires).map_err(|why| format!("Could not load witness: {}", why))?;
+
+    let mut output = BufWriter::new(
+        File::create(&output_path)
+            .map_err(|why| format!("Could not create {}: {}", output_path.display(), why))?,
+    );
+
+    match is_binary_witness(&header) {
+        true => witness.write(&mut output).and_then(|_| output.flush()),
+        false => witness.write_binary(&wires, output),
+    }
+    .map_err(|why| format!("Could not save witness: {}", why))?;
+
+    println!("Witness file written to '{}'", output_path.display());
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use crate::helpers::ExternalSmtSolver;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use zokrates_core::ir::smtlib2::SmtSolver;
+use zokrates_core::ir::{self, Counterexample, Equivalence, ProgEnum};
+use zokrates_field::Field;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("equiv")
+        .about("Checks that two compiled programs accept the same public arguments and return values")
+        .arg(
+            Arg::with_name("left")
+                .help("Path of the first binary")
+                .value_name("FILE")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::with_name("right")
+                .help("Path of the second binary")
+                .value_name("FILE")
+                .required(true)
+                .index(2),
+        )
+        .arg(
+            Arg::with_name("smt-solver")
+                .long("smt-solver")
+                .help("Command running an SMT solver which reads SMTLib2 queries on its standard input, for example `z3 -in`")
+                .value_name("COMMAND")
+                .takes_value(true)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name("runs")
+                .long("runs")
+                .help("Number of random executions to compare if the SMT solver is not provided or cannot decide")
+                .value_name("RUNS")
+                .takes_value(true)
+                .required(false)
+                .default_value("1000"),
+        )
+}
+
+fn read(path: &Path) -> Result<ProgEnum, String> {
+    let file =
+        File::open(&path).map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
+
+    let mut reader = BufReader::new(file);
+
+    ProgEnum::deserialize(&mut reader).map_err(String::from)
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let left = read(Path::new(sub_matches.value_of("left").unwrap()))?;
+    let right = read(Path::new(sub_matches.value_of("right").unwrap()))?;
+
+    match (left, right) {
+        (ProgEnum::Bn128Program(l), ProgEnum::Bn128Program(r)) => cli_equiv(l, r, sub_matches),
+        (ProgEnum::Bls12_377Program(l), ProgEnum::Bls12_377Program(r)) => {
+            cli_equiv(l, r, sub_matches)
+        }
+        (ProgEnum::Bls12_381Program(l), ProgEnum::Bls12_381Program(r)) => {
+            cli_equiv(l, r, sub_matches)
+        }
+        (ProgEnum::Bw6_761Program(l), ProgEnum::Bw6_761Program(r)) => cli_equiv(l, r, sub_matches),
+        _ => Err("The programs were compiled for different curves".to_string()),
+    }
+}
+
+fn format_values<T: Field>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_result<T: Field>(result: &Result<Vec<T>, String>) -> String {
+    match result {
+        Ok(values) => format!("returns [{}]", format_values(values)),
+        Err(e) => format!("fails: {}", e),
+    }
+}
+
+fn cli_equiv<T: Field>(
+    left: ir::Prog<T>,
+    right: ir::Prog<T>,
+    sub_matches: &ArgMatches,
+) -> Result<(), String> {
+    let runs = sub_matches
+        .value_of("runs")
+        .unwrap()
+        .parse::<usize>()
+        .map_err(|_| "Invalid number of runs".to_string())?;
+
+    let solver = ExternalSmtSolver::from_matches(sub_matches);
+    let (left_path, right_path) = (
+        sub_matches.value_of("left").unwrap(),
+        sub_matches.value_of("right").unwrap(),
+    );
+
+    println!("Checking equivalence...");
+
+    match left.equivalence(&right, solver.as_ref().map(|s| s as &dyn SmtSolver), runs, None)? {
+        Equivalence::Proven => {
+            println!("The programs are equivalent");
+            Ok(())
+        }
+        Equivalence::Unknown => {
+            println!(
+                "No counterexample found in {} random run{}, but the equivalence could not be proven",
+                runs,
+                if runs == 1 { "" } else { "s" }
+            );
+            Ok(())
+        }
+        Equivalence::Counterexample(Counterexample::Relation {
+            public_arguments,
+            returns,
+        }) => Err(format!(
+            "The programs are not equivalent: exactly one of them accepts the public arguments [{}] with the return values [{}]",
+            format_values(&public_arguments),
+            format_values(&returns)
+        )),
+        Equivalence::Counterexample(Counterexample::Execution {
+            arguments,
+            left,
+            right,
+        }) => Err(format!(
+            "The programs are not equivalent: on the arguments [{}], {} {} and {} {}",
+            format_values(&arguments),
+            left_path,
+            format_result(&left),
+            right_path,
+            format_result(&right)
+        )),
+    }
+}
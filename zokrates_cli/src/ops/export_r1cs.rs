@@ -0,0 +1,66 @@
+use crate::constants::{FLATTENED_CODE_DEFAULT_PATH, R1CS_DEFAULT_PATH};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use zokrates_core::ir;
+use zokrates_core::ir::ProgEnum;
+use zokrates_field::Field;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("export-r1cs")
+        .about(
+            "Outputs the constraint system in the iden3 `.r1cs` format used by circom and snarkjs",
+        )
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the binary")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(FLATTENED_CODE_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .help("Path of the output file")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(R1CS_DEFAULT_PATH),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    // read compiled program
+    let path = Path::new(sub_matches.value_of("input").unwrap());
+    let file =
+        File::open(&path).map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
+
+    let mut reader = BufReader::new(file);
+
+    match ProgEnum::deserialize(&mut reader)? {
+        ProgEnum::Bn128Program(p) => cli_export_r1cs(p, sub_matches),
+        ProgEnum::Bls12_377Program(p) => cli_export_r1cs(p, sub_matches),
+        ProgEnum::Bls12_381Program(p) => cli_export_r1cs(p, sub_matches),
+        ProgEnum::Bw6_761Program(p) => cli_export_r1cs(p, sub_matches),
+    }
+}
+
+fn cli_export_r1cs<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Result<(), String> {
+    println!("Exporting r1cs...");
+
+    let output_path = Path::new(sub_matches.value_of("output").unwrap());
+    let output_file = File::create(&output_path)
+        .map_err(|why| format!("Could not create {}: {}", output_path.display(), why))?;
+
+    ir_prog
+        .write_r1cs(BufWriter::new(output_file))
+        .map_err(|why| format!("Could not save r1cs: {:?}", why))?;
+
+    println!("R1CS file written to '{}'", output_path.display());
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use crate::constants::{FLATTENED_CODE_DEFAULT_PATH, WITNESS_GENERATOR_DEFAULT_PATH};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::fs::{self, File};
+use std::io::{BufReader, Write};
+use std::path::{Path, PathBuf};
+use zokrates_core::ir;
+use zokrates_core::ir::native::{Dependency, NativeWitnessGenerator};
+use zokrates_core::ir::ProgEnum;
+use zokrates_field::Field;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("export-witness-generator")
+        .about("Outputs a Rust crate computing the witness of a program")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the binary")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(FLATTENED_CODE_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .help("Path of the directory of the crate")
+                .value_name("DIR")
+                .takes_value(true)
+                .required(false)
+                .default_value(WITNESS_GENERATOR_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("zokrates-path")
+                .long("zokrates-path")
+                .help("Path of the ZoKrates repository the crate depends on")
+                .value_name("PATH")
+                .takes_value(true)
+                .required_unless("zokrates-rev")
+                .conflicts_with("zokrates-rev"),
+        )
+        .arg(
+            Arg::with_name("zokrates-rev")
+                .long("zokrates-rev")
+                .help("Git revision of ZoKrates the crate depends on, which should be the one this program was compiled with")
+                .value_name("REV")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("zokrates-git")
+                .long("zokrates-git")
+                .help("Git repository of ZoKrates the crate depends on")
+                .value_name("URL")
+                .takes_value(true)
+                .default_value("https://github.com/Zokrates/ZoKrates"),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    // read compiled program
+    let path = Path::new(sub_matches.value_of("input").unwrap());
+    let file =
+        File::open(&path).map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
+
+    let mut reader = BufReader::new(file);
+
+    match ProgEnum::deserialize(&mut reader)? {
+        ProgEnum::Bn128Program(p) => cli_export_witness_generator(p, sub_matches),
+        ProgEnum::Bls12_377Program(p) => cli_export_witness_generator(p, sub_matches),
+        ProgEnum::Bls12_381Program(p) => cli_export_witness_generator(p, sub_matches),
+        ProgEnum::Bw6_761Program(p) => cli_export_witness_generator(p, sub_matches),
+    }
+}
+
+fn cli_export_witness_generator<T: Field>(
+    ir_prog: ir::Prog<T>,
+    sub_matches: &ArgMatches,
+) -> Result<(), String> {
+    println!("Exporting witness generator...");
+
+    let output_path = PathBuf::from(sub_matches.value_of("output").unwrap());
+
+    let dependency = match sub_matches.value_of("zokrates-path") {
+        Some(path) => Dependency::Path(
+            fs::canonicalize(path).map_err(|why| format!("Could not find {}: {}", path, why))?,
+        ),
+        None => Dependency::Git {
+            url: sub_matches.value_of("zokrates-git").unwrap().to_string(),
+            rev: sub_matches.value_of("zokrates-rev").unwrap().to_string(),
+        },
+    };
+
+    // the name of the crate is the name of its directory, restricted to valid characters
+    let name: String = output_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c,
+            false => '-',
+        })
+        .collect();
+
+    let generator = NativeWitnessGenerator {
+        prog: &ir_prog,
+        name: match name.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() => name,
+            _ => format!("witness-{}", name),
+        },
+        dependency,
+    };
+
+    for (path, content) in generator.files()? {
+        let path = output_path.join(path);
+
+        fs::create_dir_all(path.parent().unwrap())
+            .map_err(|why| format!("Could not create {}: {}", path.display(), why))?;
+
+        File::create(&path)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+            .map_err(|why| format!("Could not write {}: {}", path.display(), why))?;
+    }
+
+    println!("Witness generator written to '{}'", output_path.display());
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::fs::File;
+use std::io::{BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("fmt")
+        .about("Formats source files")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Paths of the source files to format")
+                .value_name("FILE")
+                .takes_value(true)
+                .multiple(true)
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("check")
+                .long("check")
+                .help("Check that the files are formatted without modifying them")
+                .required(false),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let check = sub_matches.is_present("check");
+
+    let mut unformatted = vec![];
+
+    for input in sub_matches.values_of("input").unwrap() {
+        let path = PathBuf::from(input);
+        let source = read(&path)?;
+
+        let formatted = zokrates_pest_ast::format(&source)
+            .map_err(|e| format!("Could not format {}:\n{}", path.display(), e))?;
+
+        if formatted == source {
+            continue;
+        }
+
+        match check {
+            true => {
+                println!("{} is not formatted", path.display());
+                unformatted.push(path);
+            }
+            false => {
+                let mut file = File::create(&path)
+                    .map_err(|why| format!("Could not create {}: {}", path.display(), why))?;
+                file.write_all(formatted.as_bytes())
+                    .map_err(|why| format!("Could not write to {}: {}", path.display(), why))?;
+
+                println!("Formatted {}", path.display());
+            }
+        }
+    }
+
+    match unformatted.len() {
+        0 => Ok(()),
+        count => Err(format!(
+            "{} file{} not formatted, run `zokrates fmt` to format",
+            count,
+            if count == 1 { " is" } else { "s are" }
+        )),
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    let file =
+        File::open(path).map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
+
+    let mut reader = BufReader::new(file);
+    let mut source = String::new();
+    reader
+        .read_to_string(&mut source)
+        .map_err(|why| format!("Could not read {}: {}", path.display(), why))?;
+
+    Ok(source)
+}
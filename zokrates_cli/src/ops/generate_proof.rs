@@ -81,6 +81,14 @@ pub fn subcommand() -> App<'static, 'static> {
                 .possible_values(constants::SCHEMES)
                 .default_value(constants::G16),
         )
+        .arg(
+            Arg::with_name("seed")
+                .long("seed")
+                .help("Seed for the randomness as a 32-byte hex string. Only use this for testing, as it makes the proof reproducible")
+                .value_name("SEED")
+                .takes_value(true)
+                .required(false),
+        )
 }
 
 pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
@@ -102,6 +110,13 @@ pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
         sub_matches.value_of("proving-scheme").unwrap(),
     ))?;
 
+    #[cfg(feature = "libsnark")]
+    if let Parameters(BackendParameter::Libsnark, _, _) = parameters {
+        if sub_matches.is_present("seed") {
+            return Err("The libsnark backend does not support seeding".to_string());
+        }
+    }
+
     match parameters {
         #[cfg(feature = "bellman")]
         Parameters(BackendParameter::Bellman, _, SchemeParameter::G16) => match prog {
@@ -110,6 +125,13 @@ pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
             _ => unreachable!(),
         },
         #[cfg(feature = "ark")]
+        Parameters(BackendParameter::Ark, _, SchemeParameter::G16) => match prog {
+            ProgEnum::Bls12_377Program(p) => cli_generate_proof::<_, G16, Ark>(p, sub_matches),
+            ProgEnum::Bw6_761Program(p) => cli_generate_proof::<_, G16, Ark>(p, sub_matches),
+            ProgEnum::Bn128Program(p) => cli_generate_proof::<_, G16, Ark>(p, sub_matches),
+            _ => unreachable!(),
+        },
+        #[cfg(feature = "ark")]
         Parameters(BackendParameter::Ark, _, SchemeParameter::GM17) => match prog {
             ProgEnum::Bls12_377Program(p) => cli_generate_proof::<_, GM17, Ark>(p, sub_matches),
             ProgEnum::Bw6_761Program(p) => cli_generate_proof::<_, GM17, Ark>(p, sub_matches),
@@ -156,7 +178,7 @@ fn cli_generate_proof<T: Field, S: Scheme<T>, B: Backend<T, S>>(
     let witness_file = File::open(&witness_path)
         .map_err(|why| format!("Could not open {}: {}", witness_path.display(), why))?;
 
-    let witness = ir::Witness::read(witness_file)
+    let witness = ir::Witness::read(witness_file, &program.wires())
         .map_err(|why| format!("Could not load witness: {:?}", why))?;
 
     let pk_path = Path::new(sub_matches.value_of("proving-key-path").unwrap());
@@ -171,7 +193,13 @@ fn cli_generate_proof<T: Field, S: Scheme<T>, B: Backend<T, S>>(
         .read_to_end(&mut pk)
         .map_err(|why| format!("Could not read {}: {}", pk_path.display(), why))?;
 
-    let proof = B::generate_proof(program, witness, pk);
+    let fingerprint = program.fingerprint();
+    let pk = check_proving_key(pk, fingerprint)?;
+
+    let seed = seed(sub_matches)?;
+
+    let mut proof = B::generate_proof(program, witness, pk, seed);
+    proof.fingerprint = Some(fingerprint);
     let mut proof_file = File::create(proof_path).unwrap();
 
     let proof = serde_json::to_string_pretty(&proof).unwrap();
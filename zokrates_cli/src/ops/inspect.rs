@@ -0,0 +1,103 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use serde_json::Value;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use zokrates_core::ir::{self, DeserializeError, Fingerprint, ProgEnum};
+use zokrates_core::proof_system::unbind_proving_key;
+use zokrates_field::Field;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("inspect")
+        .about("Shows which program compiled programs, keys and proofs belong to")
+        .arg(
+            Arg::with_name("files")
+                .help("Paths of compiled programs, proving keys, verification keys or proofs")
+                .value_name("FILE")
+                .multiple(true)
+                .required(true),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let mut fingerprints = vec![];
+
+    for path in sub_matches.values_of("files").unwrap() {
+        let path = Path::new(path);
+        let fingerprint = inspect(path)?;
+
+        match fingerprint {
+            Some(fingerprint) => {
+                println!("  fingerprint: {}", fingerprint);
+                fingerprints.push(fingerprint);
+            }
+            None => println!("  fingerprint: unknown, made before fingerprints were recorded"),
+        }
+    }
+
+    if fingerprints.iter().any(|f| *f != fingerprints[0]) {
+        return Err("The files belong to different programs".to_string());
+    }
+
+    Ok(())
+}
+
+/// Prints what the file at `path` is, returning the fingerprint of the program it belongs to if
+/// it is known
+fn inspect(path: &Path) -> Result<Option<Fingerprint>, String> {
+    let open =
+        || File::open(path).map_err(|why| format!("Could not open {}: {}", path.display(), why));
+
+    match ProgEnum::deserialize_with_metadata(BufReader::new(open()?)) {
+        Ok((prog, metadata)) => {
+            let (curve, constraint_count, fingerprint) = match prog {
+                ProgEnum::Bn128Program(p) => describe(p),
+                ProgEnum::Bls12_381Program(p) => describe(p),
+                ProgEnum::Bls12_377Program(p) => describe(p),
+                ProgEnum::Bw6_761Program(p) => describe(p),
+            };
+
+            println!("{}: program", path.display());
+            println!("  curve: {}", curve);
+            println!("  constraints: {}", constraint_count);
+            if let Some(metadata) = metadata {
+                println!("  compiler version: {}", metadata.compiler_version);
+                if let Some(source_hash) = metadata.source_hash {
+                    println!("  source hash: {}", source_hash);
+                }
+            }
+
+            return Ok(Some(fingerprint));
+        }
+        Err(DeserializeError::WrongMagicNumber) => {}
+        Err(e) => return Err(format!("Could not read {}: {}", path.display(), e)),
+    }
+
+    let mut content = vec![];
+    open()?
+        .read_to_end(&mut content)
+        .map_err(|why| format!("Could not read {}: {}", path.display(), why))?;
+
+    // keys and proofs written as JSON record the fingerprint in a `fingerprint` field
+    if let Ok(Value::Object(object)) = serde_json::from_slice(&content) {
+        let kind = match object.contains_key("proof") {
+            true => "proof",
+            false => "verification key",
+        };
+        println!("{}: {}", path.display(), kind);
+
+        return object
+            .get("fingerprint")
+            .map(|f| serde_json::from_value(f.clone()))
+            .transpose()
+            .map_err(|why| format!("Invalid fingerprint in {}: {}", path.display(), why));
+    }
+
+    println!("{}: proving key", path.display());
+
+    Ok(unbind_proving_key(content).0)
+}
+
+fn describe<T: Field>(prog: ir::Prog<T>) -> (&'static str, usize, Fingerprint) {
+    (T::name(), prog.constraint_count(), prog.fingerprint())
+}
@@ -1,13 +1,24 @@
 pub mod check;
+pub mod check_uniqueness;
 pub mod compile;
 pub mod compute_witness;
+pub mod convert_witness;
+pub mod equiv;
+pub mod export_r1cs;
 pub mod export_verifier;
+pub mod export_witness_generator;
+pub mod fmt;
 #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
 pub mod generate_proof;
 pub mod generate_smtlib2;
+pub mod inspect;
+#[cfg(feature = "bellman")]
+pub mod mpc;
 pub mod print_proof;
+pub mod profile;
 #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
 pub mod setup;
+pub mod test;
 #[cfg(feature = "ark")]
 pub mod universal_setup;
 #[cfg(any(feature = "bellman", feature = "ark", feature = "libsnark"))]
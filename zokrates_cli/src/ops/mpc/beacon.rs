@@ -0,0 +1,91 @@
+use crate::constants;
+use crate::helpers::parse_bytes32;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::path::Path;
+use zokrates_core::proof_system::beacon_seed;
+use zokrates_field::{Bls12_381Field, Bn128Field};
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("beacon")
+        .about("Makes the final contribution to the parameters of the ceremony from a public random beacon")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the parameters to contribute to")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::MPC_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .help("Path of the parameters after the contribution")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::MPC_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("hash")
+                .long("hash")
+                .help("Value of the beacon as a 32-byte hex string, for example the hash of a future block")
+                .value_name("HASH")
+                .takes_value(true)
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("iterations")
+                .short("n")
+                .long("iterations")
+                .help("Number of times the beacon is hashed passed as an exponent. For example, 10 for 2**10")
+                .value_name("EXPONENT")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::MPC_BEACON_DEFAULT_ITERATIONS),
+        )
+        .arg(
+            Arg::with_name("curve")
+                .short("c")
+                .long("curve")
+                .help("Curve of the program")
+                .takes_value(true)
+                .required(false)
+                .possible_values(constants::MPC_CURVES)
+                .default_value(constants::BN128),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let input = Path::new(sub_matches.value_of("input").unwrap());
+    let output = Path::new(sub_matches.value_of("output").unwrap());
+
+    let hash = sub_matches.value_of("hash").unwrap();
+    let beacon = parse_bytes32(hash)
+        .ok_or_else(|| format!("Invalid beacon {}: expected 32 bytes in hex encoding", hash))?;
+
+    let iterations = sub_matches.value_of("iterations").unwrap();
+    let exponent = iterations
+        .parse::<u32>()
+        .ok()
+        .filter(|e| *e < 64)
+        .ok_or_else(|| {
+            format!(
+                "Invalid number of iterations {}: expected an exponent below 64",
+                iterations
+            )
+        })?;
+
+    println!("Hashing the beacon 2^{} times...", exponent);
+    let seed = beacon_seed(&beacon, exponent);
+
+    println!("Contributing to '{}'...", input.display());
+
+    match sub_matches.value_of("curve").unwrap() {
+        constants::BN128 => super::contribute::<Bn128Field>(input, output, seed),
+        constants::BLS12_381 => super::contribute::<Bls12_381Field>(input, output, seed),
+        _ => unreachable!(),
+    }
+}
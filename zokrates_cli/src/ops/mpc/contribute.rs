@@ -0,0 +1,64 @@
+use crate::constants;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::path::Path;
+use zokrates_core::proof_system::contribution_seed;
+use zokrates_field::{Bls12_381Field, Bn128Field};
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("contribute")
+        .about("Contributes randomness to the parameters of the ceremony")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the parameters to contribute to")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::MPC_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .help("Path of the parameters after the contribution")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::MPC_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("entropy")
+                .short("e")
+                .long("entropy")
+                .help("Random text, which is mixed with randomness from the system")
+                .value_name("TEXT")
+                .takes_value(true)
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("curve")
+                .short("c")
+                .long("curve")
+                .help("Curve of the program")
+                .takes_value(true)
+                .required(false)
+                .possible_values(constants::MPC_CURVES)
+                .default_value(constants::BN128),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let input = Path::new(sub_matches.value_of("input").unwrap());
+    let output = Path::new(sub_matches.value_of("output").unwrap());
+
+    let seed = contribution_seed(sub_matches.value_of("entropy").unwrap().as_bytes());
+
+    println!("Contributing to '{}'...", input.display());
+
+    match sub_matches.value_of("curve").unwrap() {
+        constants::BN128 => super::contribute::<Bn128Field>(input, output, seed),
+        constants::BLS12_381 => super::contribute::<Bls12_381Field>(input, output, seed),
+        _ => unreachable!(),
+    }
+}
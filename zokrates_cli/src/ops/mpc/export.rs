@@ -0,0 +1,112 @@
+use super::open;
+use crate::constants;
+use crate::helpers::warn_overwritten_key;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use zokrates_core::ir::{self, ProgEnum};
+use zokrates_core::proof_system::bellman::Bellman;
+use zokrates_core::proof_system::{MpcBackend, G16};
+use zokrates_field::Field;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("export")
+        .about("Exports the proving and verification keys from the parameters of the ceremony")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the parameters")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::MPC_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("circuit")
+                .long("circuit")
+                .help("Path of the binary")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::FLATTENED_CODE_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("proving-key-path")
+                .short("p")
+                .long("proving-key-path")
+                .help("Path of the generated proving key file")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::PROVING_KEY_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("verification-key-path")
+                .short("v")
+                .long("verification-key-path")
+                .help("Path of the generated verification key file")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::VERIFICATION_KEY_DEFAULT_PATH),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let path = Path::new(sub_matches.value_of("circuit").unwrap());
+    let prog = ProgEnum::deserialize(open(path)?)?;
+
+    match prog {
+        ProgEnum::Bn128Program(p) => cli_mpc_export(p, sub_matches),
+        ProgEnum::Bls12_381Program(p) => cli_mpc_export(p, sub_matches),
+        _ => Err(format!(
+            "The MPC setup is only supported on the curves {}",
+            constants::MPC_CURVES.join(", ")
+        )),
+    }
+}
+
+fn cli_mpc_export<T: Field>(program: ir::Prog<T>, sub_matches: &ArgMatches) -> Result<(), String>
+where
+    Bellman: MpcBackend<T, G16>,
+{
+    println!("Exporting keys...");
+
+    let params_path = Path::new(sub_matches.value_of("input").unwrap());
+    let pk_path = Path::new(sub_matches.value_of("proving-key-path").unwrap());
+    let vk_path = Path::new(sub_matches.value_of("verification-key-path").unwrap());
+
+    // warn before overwriting a key made for another program
+    let fingerprint = program.fingerprint();
+    warn_overwritten_key(pk_path, fingerprint);
+
+    let keypair =
+        <Bellman as MpcBackend<T, G16>>::export_keypair(&mut open(params_path)?, program)?
+            .bind(fingerprint);
+
+    // write verification key
+    let mut vk_file = File::create(vk_path)
+        .map_err(|why| format!("Could not create {}: {}", vk_path.display(), why))?;
+    vk_file
+        .write_all(
+            serde_json::to_string_pretty(&keypair.vk)
+                .unwrap()
+                .as_bytes(),
+        )
+        .map_err(|why| format!("Could not write to {}: {}", vk_path.display(), why))?;
+
+    println!("Verification key written to '{}'", vk_path.display());
+
+    // write proving key
+    let mut pk_file = File::create(pk_path)
+        .map_err(|why| format!("Could not create {}: {}", pk_path.display(), why))?;
+    pk_file
+        .write_all(keypair.pk.as_ref())
+        .map_err(|why| format!("Could not write to {}: {}", pk_path.display(), why))?;
+
+    println!("Proving key written to '{}'", pk_path.display());
+
+    Ok(())
+}
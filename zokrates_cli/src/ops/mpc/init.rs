@@ -0,0 +1,87 @@
+use super::{create, open, phase1};
+use crate::constants;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::io::Write;
+use std::path::Path;
+use zokrates_core::ir::{self, ProgEnum};
+use zokrates_core::proof_system::bellman::Bellman;
+use zokrates_core::proof_system::{MpcBackend, G16};
+use zokrates_field::Field;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("init")
+        .about("Initializes the parameters of the ceremony from phase 1 parameters")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the binary")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::FLATTENED_CODE_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .help("Path of the initial parameters")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::MPC_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("radix-path")
+                .short("r")
+                .long("radix-path")
+                .help("Path of the phase 1 parameters for the size of the program, for example `phase1radix2m10` for up to 2^10 constraints")
+                .value_name("FILE")
+                .takes_value(true)
+                .required_unless("ptau"),
+        )
+        .arg(
+            Arg::with_name("ptau")
+                .long("ptau")
+                .help("Path of the output of a powers of tau ceremony in the `.ptau` format, to compute the phase 1 parameters from")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .conflicts_with("radix-path"),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let path = Path::new(sub_matches.value_of("input").unwrap());
+    let prog = ProgEnum::deserialize(open(path)?)?;
+
+    match prog {
+        ProgEnum::Bn128Program(p) => cli_mpc_init(p, sub_matches),
+        ProgEnum::Bls12_381Program(p) => cli_mpc_init(p, sub_matches),
+        _ => Err(format!(
+            "The MPC setup is only supported on the curves {}",
+            constants::MPC_CURVES.join(", ")
+        )),
+    }
+}
+
+fn cli_mpc_init<T: Field>(program: ir::Prog<T>, sub_matches: &ArgMatches) -> Result<(), String>
+where
+    Bellman: MpcBackend<T, G16>,
+{
+    println!("Initializing MPC...");
+
+    let output_path = Path::new(sub_matches.value_of("output").unwrap());
+
+    let mut radix = phase1(&program, sub_matches)?;
+    let mut writer = create(output_path)?;
+
+    <Bellman as MpcBackend<T, G16>>::initialize(program, &mut radix, &mut writer)?;
+    writer
+        .flush()
+        .map_err(|why| format!("Could not write to {}: {}", output_path.display(), why))?;
+
+    println!("Parameters written to '{}'", output_path.display());
+
+    Ok(())
+}
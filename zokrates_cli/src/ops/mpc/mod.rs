@@ -0,0 +1,117 @@
+//! Commands running the circuit specific part of the Groth16 setup (phase 2) as a multi-party
+//! computation, so that the keys are secure as long as one participant was honest
+
+use clap::{App, AppSettings, ArgMatches, SubCommand};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Cursor, Read, Write};
+use std::path::Path;
+use zokrates_core::ir;
+use zokrates_core::proof_system::bellman::Bellman;
+use zokrates_core::proof_system::{ContributionHash, MpcBackend, Seed, G16};
+use zokrates_field::Field;
+
+pub mod beacon;
+pub mod contribute;
+pub mod export;
+pub mod init;
+pub mod verify;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("mpc")
+        .about("Runs the circuit specific part of the Groth16 setup as a multi-party computation")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommands(vec![
+            init::subcommand(),
+            contribute::subcommand(),
+            beacon::subcommand(),
+            verify::subcommand(),
+            export::subcommand(),
+        ])
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    match sub_matches.subcommand() {
+        ("init", Some(sub_matches)) => init::exec(sub_matches),
+        ("contribute", Some(sub_matches)) => contribute::exec(sub_matches),
+        ("beacon", Some(sub_matches)) => beacon::exec(sub_matches),
+        ("verify", Some(sub_matches)) => verify::exec(sub_matches),
+        ("export", Some(sub_matches)) => export::exec(sub_matches),
+        _ => unreachable!(),
+    }
+}
+
+fn open(path: &Path) -> Result<BufReader<File>, String> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|why| format!("Could not open {}: {}", path.display(), why))
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>, String> {
+    File::create(path)
+        .map(BufWriter::new)
+        .map_err(|why| format!("Could not create {}: {}", path.display(), why))
+}
+
+/// Opens the phase 1 parameters for `program`, either in the radix format or computed from the
+/// output of a powers of tau ceremony
+fn phase1<T: Field>(
+    program: &ir::Prog<T>,
+    sub_matches: &ArgMatches,
+) -> Result<Box<dyn Read>, String>
+where
+    Bellman: MpcBackend<T, G16>,
+{
+    match sub_matches.value_of("ptau") {
+        Some(path) => {
+            let path = Path::new(path);
+            println!("Computing phase 1 parameters from '{}'...", path.display());
+
+            let mut res = vec![];
+            <Bellman as MpcBackend<T, G16>>::prepare_phase1(
+                program.clone(),
+                &mut open(path)?,
+                &mut res,
+            )?;
+            Ok(Box::new(Cursor::new(res)))
+        }
+        None => {
+            let path = Path::new(sub_matches.value_of("radix-path").unwrap());
+            Ok(Box::new(open(path)?))
+        }
+    }
+}
+
+fn format_hash(hash: &ContributionHash) -> String {
+    // split in lines of 16 bytes to make the hash easier to compare by eye
+    hash.chunks(16)
+        .map(|line| {
+            line.iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Contributes to the parameters at `input` with `seed`, writing the result to `output`
+fn contribute<T: Field>(input: &Path, output: &Path, seed: Seed) -> Result<(), String>
+where
+    Bellman: MpcBackend<T, G16>,
+{
+    // read the parameters entirely first, so that the output may be the input
+    let mut params = vec![];
+    open(input)?
+        .read_to_end(&mut params)
+        .map_err(|why| format!("Could not read {}: {}", input.display(), why))?;
+
+    let mut writer = create(output)?;
+    let hash = <Bellman as MpcBackend<T, G16>>::contribute(&mut &params[..], seed, &mut writer)?;
+    writer
+        .flush()
+        .map_err(|why| format!("Could not write to {}: {}", output.display(), why))?;
+
+    println!("Contribution written to '{}'", output.display());
+    println!("The hash of the contribution is:\n{}", format_hash(&hash));
+
+    Ok(())
+}
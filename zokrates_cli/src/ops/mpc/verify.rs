@@ -0,0 +1,88 @@
+use super::{format_hash, open, phase1};
+use crate::constants;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::path::Path;
+use zokrates_core::ir::{self, ProgEnum};
+use zokrates_core::proof_system::bellman::Bellman;
+use zokrates_core::proof_system::{MpcBackend, G16};
+use zokrates_field::Field;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("verify")
+        .about("Verifies the contributions to the parameters of the ceremony")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the parameters")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::MPC_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("circuit")
+                .long("circuit")
+                .help("Path of the binary")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(constants::FLATTENED_CODE_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("radix-path")
+                .short("r")
+                .long("radix-path")
+                .help("Path of the phase 1 parameters the ceremony was initialized with")
+                .value_name("FILE")
+                .takes_value(true)
+                .required_unless("ptau"),
+        )
+        .arg(
+            Arg::with_name("ptau")
+                .long("ptau")
+                .help("Path of the output of a powers of tau ceremony in the `.ptau` format, to compute the phase 1 parameters from")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .conflicts_with("radix-path"),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let path = Path::new(sub_matches.value_of("circuit").unwrap());
+    let prog = ProgEnum::deserialize(open(path)?)?;
+
+    match prog {
+        ProgEnum::Bn128Program(p) => cli_mpc_verify(p, sub_matches),
+        ProgEnum::Bls12_381Program(p) => cli_mpc_verify(p, sub_matches),
+        _ => Err(format!(
+            "The MPC setup is only supported on the curves {}",
+            constants::MPC_CURVES.join(", ")
+        )),
+    }
+}
+
+fn cli_mpc_verify<T: Field>(program: ir::Prog<T>, sub_matches: &ArgMatches) -> Result<(), String>
+where
+    Bellman: MpcBackend<T, G16>,
+{
+    println!("Verifying contributions...");
+
+    let params_path = Path::new(sub_matches.value_of("input").unwrap());
+    let mut radix = phase1(&program, sub_matches)?;
+
+    let hashes = <Bellman as MpcBackend<T, G16>>::verify_contributions(
+        &mut open(params_path)?,
+        program,
+        &mut radix,
+    )?;
+
+    for (index, hash) in hashes.iter().enumerate() {
+        println!("\nContribution {}:\n{}", index + 1, format_hash(hash));
+    }
+
+    println!("\nThe {} contributions are valid", hashes.len());
+
+    Ok(())
+}
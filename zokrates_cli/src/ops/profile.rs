@@ -0,0 +1,183 @@
+use crate::constants::{self, FLATTENED_CODE_DEFAULT_PATH, PROFILE_DEFAULT_PATH};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use zokrates_core::ir::{self, Cost, Costs, Profile, ProgEnum, SourceMap};
+use zokrates_field::Field;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("profile")
+        .about("Reports the number of constraints and directives coming from each function of a compiled program")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the binary")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(FLATTENED_CODE_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .help("Path of the folded stack file, which can be read by flamegraph tools")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .default_value(PROFILE_DEFAULT_PATH),
+        )
+        .arg(
+            Arg::with_name("stdlib-path")
+                .long("stdlib-path")
+                .help("Path to the standard library the program was compiled with")
+                .value_name("PATH")
+                .takes_value(true)
+                .required(false)
+                .env("ZOKRATES_STDLIB")
+                .default_value(constants::DEFAULT_STDLIB_PATH.as_str()),
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    // read compiled program
+    let path = Path::new(sub_matches.value_of("input").unwrap());
+    let file =
+        File::open(&path).map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
+
+    let mut reader = BufReader::new(file);
+
+    match ProgEnum::deserialize(&mut reader)? {
+        ProgEnum::Bn128Program(p) => cli_profile(p, sub_matches),
+        ProgEnum::Bls12_377Program(p) => cli_profile(p, sub_matches),
+        ProgEnum::Bls12_381Program(p) => cli_profile(p, sub_matches),
+        ProgEnum::Bw6_761Program(p) => cli_profile(p, sub_matches),
+    }
+}
+
+fn cli_profile<T: Field>(ir_prog: ir::Prog<T>, sub_matches: &ArgMatches) -> Result<(), String> {
+    // read the source map written at compile time
+    let source_map_path = Path::new(sub_matches.value_of("input").unwrap()).with_extension("map");
+    let file = File::open(&source_map_path).map_err(|why| {
+        format!(
+            "Could not open source map {}: {}. Please recompile the program.",
+            source_map_path.display(),
+            why
+        )
+    })?;
+    let source_map = SourceMap::read(BufReader::new(file))
+        .map_err(|why| format!("Could not read source map: {}", why))?;
+
+    let profile = Profile::new(&ir_prog, &source_map);
+    let stdlib_path = sub_matches.value_of("stdlib-path").unwrap();
+
+    let total = profile.total();
+    println!(
+        "Total: {} constraints, {} directives",
+        total.constraints, total.directives
+    );
+
+    print_costs(
+        "Call paths",
+        profile
+            .paths()
+            .into_iter()
+            .map(|(path, costs)| {
+                let path = std::iter::once("main".to_string())
+                    .chain(path.iter().map(|frame| frame.to_string()))
+                    .collect::<Vec<_>>()
+                    .join(" > ");
+                (path, costs)
+            })
+            .collect(),
+    );
+
+    let (embeds, functions): (Vec<_>, Vec<_>) = profile
+        .functions()
+        .into_iter()
+        .partition(|(frame, _)| frame.is_embed());
+
+    print_costs(
+        "Functions",
+        functions
+            .into_iter()
+            .map(|(frame, costs)| (frame.to_string(), costs))
+            .collect(),
+    );
+
+    print_cost(
+        "Embeds",
+        embeds
+            .into_iter()
+            .map(|(frame, costs)| (frame.function.clone(), costs.inclusive))
+            .collect(),
+    );
+
+    print_cost(
+        "Standard library imports",
+        profile
+            .entry_points(|frame| frame.module.starts_with(stdlib_path))
+            .into_iter()
+            .map(|(frame, cost)| {
+                let module = Path::new(&frame.module)
+                    .strip_prefix(stdlib_path)
+                    .map(|m| m.display().to_string())
+                    .unwrap_or_else(|_| frame.module.clone());
+                (format!("{} ({})", frame.function, module), cost)
+            })
+            .collect(),
+    );
+
+    // write the folded stacks
+    let output_path = Path::new(sub_matches.value_of("output").unwrap());
+    let output_file = File::create(&output_path)
+        .map_err(|why| format!("Could not create {}: {}", output_path.display(), why))?;
+
+    profile
+        .write_folded(BufWriter::new(output_file))
+        .map_err(|why| format!("Could not save profile: {}", why))?;
+
+    println!("\nFolded stacks written to '{}'", output_path.display());
+    Ok(())
+}
+
+fn print_costs(title: &str, mut rows: Vec<(String, Costs)>) {
+    if rows.is_empty() {
+        return;
+    }
+
+    rows.sort_by(|(_, a), (_, b)| b.inclusive.constraints.cmp(&a.inclusive.constraints));
+
+    println!("\n{}:", title);
+    println!("{:>24} {:>24}", "inclusive", "exclusive");
+    println!(
+        "{:>12}{:>12} {:>12}{:>12}  name",
+        "constraints", "directives", "constraints", "directives"
+    );
+    for (name, costs) in rows {
+        println!(
+            "{:>12}{:>12} {:>12}{:>12}  {}",
+            costs.inclusive.constraints,
+            costs.inclusive.directives,
+            costs.exclusive.constraints,
+            costs.exclusive.directives,
+            name
+        );
+    }
+}
+
+fn print_cost(title: &str, mut rows: Vec<(String, Cost)>) {
+    if rows.is_empty() {
+        return;
+    }
+
+    rows.sort_by(|(_, a), (_, b)| b.constraints.cmp(&a.constraints));
+
+    println!("\n{}:", title);
+    println!("{:>12}{:>12}  name", "constraints", "directives");
+    for (name, cost) in rows {
+        println!("{:>12}{:>12}  {}", cost.constraints, cost.directives, name);
+    }
+}
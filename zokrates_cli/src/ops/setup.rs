@@ -80,6 +80,14 @@ pub fn subcommand() -> App<'static, 'static> {
                 .required(false)
                 .default_value(constants::UNIVERSAL_SETUP_DEFAULT_PATH),
         )
+        .arg(
+            Arg::with_name("seed")
+                .long("seed")
+                .help("Seed for the randomness as a 32-byte hex string. Only use this for testing, as it makes the setup reproducible")
+                .value_name("SEED")
+                .takes_value(true)
+                .required(false),
+        )
 }
 
 pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
@@ -102,6 +110,13 @@ pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
         sub_matches.value_of("proving-scheme").unwrap(),
     ))?;
 
+    #[cfg(feature = "libsnark")]
+    if let Parameters(BackendParameter::Libsnark, _, _) = parameters {
+        if sub_matches.is_present("seed") {
+            return Err("The libsnark backend does not support seeding".to_string());
+        }
+    }
+
     match parameters {
         #[cfg(feature = "bellman")]
         Parameters(BackendParameter::Bellman, _, SchemeParameter::G16) => match prog {
@@ -112,6 +127,13 @@ pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
             _ => unreachable!(),
         },
         #[cfg(feature = "ark")]
+        Parameters(BackendParameter::Ark, _, SchemeParameter::G16) => match prog {
+            ProgEnum::Bls12_377Program(p) => cli_setup_non_universal::<_, G16, Ark>(p, sub_matches),
+            ProgEnum::Bw6_761Program(p) => cli_setup_non_universal::<_, G16, Ark>(p, sub_matches),
+            ProgEnum::Bn128Program(p) => cli_setup_non_universal::<_, G16, Ark>(p, sub_matches),
+            _ => unreachable!(),
+        },
+        #[cfg(feature = "ark")]
         Parameters(BackendParameter::Ark, _, SchemeParameter::GM17) => match prog {
             ProgEnum::Bls12_377Program(p) => {
                 cli_setup_non_universal::<_, GM17, Ark>(p, sub_matches)
@@ -185,8 +207,14 @@ fn cli_setup_non_universal<T: Field, S: NonUniversalScheme<T>, B: NonUniversalBa
     let pk_path = Path::new(sub_matches.value_of("proving-key-path").unwrap());
     let vk_path = Path::new(sub_matches.value_of("verification-key-path").unwrap());
 
+    let seed = seed(sub_matches)?;
+
+    // warn before overwriting a key made for another program
+    let fingerprint = program.fingerprint();
+    warn_overwritten_key(pk_path, fingerprint);
+
     // run setup phase
-    let keypair = B::setup(program);
+    let keypair = B::setup(program, seed).bind(fingerprint);
 
     // write verification key
     let mut vk_file = File::create(vk_path)
@@ -230,8 +258,12 @@ fn cli_setup_universal<T: Field, S: UniversalScheme<T>, B: UniversalBackend<T, S
     let pk_path = Path::new(sub_matches.value_of("proving-key-path").unwrap());
     let vk_path = Path::new(sub_matches.value_of("verification-key-path").unwrap());
 
+    // warn before overwriting a key made for another program
+    let fingerprint = program.fingerprint();
+    warn_overwritten_key(pk_path, fingerprint);
+
     // run setup phase
-    let keypair = B::setup(srs, program)?;
+    let keypair = B::setup(srs, program)?.bind(fingerprint);
 
     // write verification key
     let mut vk_file = File::create(vk_path)
@@ -0,0 +1,208 @@
+use crate::constants;
+use crate::helpers::CurveParameter;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+use zokrates_core::compile::{
+    compile, tests, CompilationArtifacts, CompileConfig, CompileError, TestFunction,
+};
+use zokrates_core::flat_absy::RuntimeError;
+use zokrates_core::ir;
+use zokrates_field::{Bls12_377Field, Bls12_381Field, Bn128Field, Bw6_761Field, Field};
+use zokrates_fs_resolver::FileSystemResolver;
+
+pub fn subcommand() -> App<'static, 'static> {
+    SubCommand::with_name("test")
+        .about("Runs the functions marked with #[test] in a source file")
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .help("Path of the source code")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(true),
+        )
+        .arg(
+            Arg::with_name("filter")
+                .help("Only run the tests whose name contains this string")
+                .value_name("FILTER")
+                .takes_value(true)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name("stdlib-path")
+                .long("stdlib-path")
+                .help("Path to the standard library")
+                .value_name("PATH")
+                .takes_value(true)
+                .required(false)
+                .env("ZOKRATES_STDLIB")
+                .default_value(constants::DEFAULT_STDLIB_PATH.as_str()),
+        )
+        .arg(
+            Arg::with_name("curve")
+                .short("c")
+                .long("curve")
+                .help("Curve to be used in the compilation")
+                .takes_value(true)
+                .required(false)
+                .possible_values(constants::CURVES)
+                .default_value(constants::BN128),
+        )
+        .arg(
+            Arg::with_name("allow-unconstrained-variables")
+                .long("allow-unconstrained-variables")
+                .help("Allow unconstrained variables by inserting dummy constraints")
+                .required(false),
+        )
+        .arg(Arg::with_name("isolate-branches")
+            .long("isolate-branches")
+            .help("Isolate the execution of branches: a panic in a branch only makes the program panic if this branch is being logically executed")
+            .required(false)
+        )
+}
+
+pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
+    let curve = CurveParameter::try_from(sub_matches.value_of("curve").unwrap())?;
+    match curve {
+        CurveParameter::Bn128 => cli_test::<Bn128Field>(sub_matches),
+        CurveParameter::Bls12_377 => cli_test::<Bls12_377Field>(sub_matches),
+        CurveParameter::Bls12_381 => cli_test::<Bls12_381Field>(sub_matches),
+        CurveParameter::Bw6_761 => cli_test::<Bw6_761Field>(sub_matches),
+    }
+}
+
+fn cli_test<T: Field>(sub_matches: &ArgMatches) -> Result<(), String> {
+    let path = PathBuf::from(sub_matches.value_of("input").unwrap());
+
+    let file = File::open(path.clone())
+        .map_err(|why| format!("Could not open {}: {}", path.display(), why))?;
+
+    let mut reader = BufReader::new(file);
+    let mut source = String::new();
+    reader.read_to_string(&mut source).unwrap();
+
+    let fmt_errors = |errors: Vec<CompileError>| {
+        errors
+            .iter()
+            .map(|e| {
+                let file = e.file().canonicalize().unwrap();
+                format!(
+                    "{}:{}",
+                    file.strip_prefix(std::env::current_dir().unwrap())
+                        .unwrap_or_else(|_| file.as_path())
+                        .display(),
+                    e.value()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+
+    let stdlib_path = sub_matches.value_of("stdlib-path").unwrap();
+    match Path::new(stdlib_path).exists() {
+        true => Ok(()),
+        _ => Err(format!(
+            "Invalid standard library source path: {}",
+            stdlib_path
+        )),
+    }?;
+
+    let filter = sub_matches.value_of("filter").unwrap_or("");
+    let tests: Vec<_> = tests::<T>(&source, &path)
+        .map_err(|e| format!("Compilation failed:\n\n{}", fmt_errors(e.0)))?
+        .into_iter()
+        .filter(|t| t.id.contains(filter))
+        .collect();
+
+    println!(
+        "Running {} test{} in {}\n",
+        tests.len(),
+        if tests.len() == 1 { "" } else { "s" },
+        path.display()
+    );
+
+    let resolver = FileSystemResolver::with_stdlib_root(stdlib_path);
+    let mut failed = vec![];
+
+    for test in &tests {
+        log::debug!("Compile test {}", test.id);
+
+        let config = CompileConfig::default()
+            .allow_unconstrained_variables(sub_matches.is_present("allow-unconstrained-variables"))
+            .isolate_branches(sub_matches.is_present("isolate-branches"))
+            .entry_point(test.id.clone());
+
+        let result = compile::<T, _>(source.clone(), path.clone(), Some(&resolver), &config)
+            .map_err(|e| format!("Compilation failed:\n\n{}", fmt_errors(e.0)))
+            .and_then(|artifacts| run_test(test, artifacts));
+
+        match result {
+            Ok(constraint_count) => {
+                println!("test {} ... ok ({} constraints)", test.id, constraint_count)
+            }
+            Err(e) => {
+                println!("test {} ... FAILED", test.id);
+                failed.push((test.id.clone(), e));
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        println!("\nfailures:");
+        for (id, e) in &failed {
+            println!("\n---- {} ----\n{}", id, e);
+        }
+    }
+
+    println!(
+        "\ntest result: {}. {} passed; {} failed",
+        if failed.is_empty() { "ok" } else { "FAILED" },
+        tests.len() - failed.len(),
+        failed.len()
+    );
+
+    match failed.is_empty() {
+        true => Ok(()),
+        false => Err(format!("{} test(s) failed", failed.len())),
+    }
+}
+
+/// Runs the compiled test, returning its number of constraints if it behaved as expected
+fn run_test<T: Field>(
+    test: &TestFunction,
+    artifacts: CompilationArtifacts<T>,
+) -> Result<usize, String> {
+    let program = artifacts.prog().clone();
+
+    if program.arguments_count() != 0 {
+        return Err("Test functions cannot take arguments".into());
+    }
+
+    let constraint_count = program.constraint_count();
+    let program = program.with_source_map(artifacts.source_map());
+
+    let interpreter = ir::Interpreter::default();
+
+    match (
+        interpreter.execute_with_log_stream(&program, &[], &mut std::io::stdout()),
+        test.should_fail,
+    ) {
+        (Ok(_), false) => Ok(constraint_count),
+        // only a failing assertion is an expected failure, other errors are reported
+        (
+            Err(ir::Error::UnsatisfiedConstraint {
+                error: Some(RuntimeError::SourceAssertion(_)),
+                ..
+            }),
+            true,
+        ) => Ok(constraint_count),
+        (Ok(_), true) => {
+            Err("Execution succeeded but the test is marked with #[should_fail]".into())
+        }
+        (Err(e), _) => Err(format!("Execution failed: {}", e)),
+    }
+}
@@ -3,7 +3,7 @@ use crate::helpers::*;
 use clap::{App, Arg, ArgMatches, SubCommand};
 use std::convert::TryFrom;
 use std::fs::File;
-use std::io::Write;
+use std::io::{BufReader, Write};
 use std::path::Path;
 #[cfg(feature = "ark")]
 use zokrates_core::proof_system::ark::Ark;
@@ -53,6 +53,23 @@ pub fn subcommand() -> App<'static, 'static> {
                 .required(false)
                 .default_value(constants::UNIVERSAL_SETUP_DEFAULT_SIZE),
         )
+        .arg(
+            Arg::with_name("ptau")
+                .long("ptau")
+                .help("Path of the output of a powers of tau ceremony in the `.ptau` format, to use instead of generating the setup locally")
+                .value_name("FILE")
+                .takes_value(true)
+                .required(false)
+                .conflicts_with("seed"),
+        )
+        .arg(
+            Arg::with_name("seed")
+                .long("seed")
+                .help("Seed for the randomness as a 32-byte hex string. Only use this for testing, as it makes the setup reproducible")
+                .value_name("SEED")
+                .takes_value(true)
+                .required(false),
+        )
 }
 
 pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
@@ -93,8 +110,17 @@ fn cli_universal_setup<T: Field, S: UniversalScheme<T>, B: UniversalBackend<T, S
         .parse::<u32>()
         .map_err(|_| format!("Universal setup size {} is invalid", size))?;
 
-    // run universal setup phase
-    let setup = B::universal_setup(size);
+    // run universal setup phase, or derive it from a powers of tau ceremony
+    let setup = match sub_matches.value_of("ptau") {
+        Some(ptau_path) => {
+            let ptau_path = Path::new(ptau_path);
+            let ptau_file = File::open(ptau_path)
+                .map_err(|why| format!("Could not open {}: {}", ptau_path.display(), why))?;
+
+            B::universal_setup_from_ptau(size, &mut BufReader::new(ptau_file))?
+        }
+        None => B::universal_setup(size, seed(sub_matches)?),
+    };
 
     // write proving key
     let mut u_file = File::create(u_path)
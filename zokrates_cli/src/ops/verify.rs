@@ -78,6 +78,18 @@ pub fn exec(sub_matches: &ArgMatches) -> Result<(), String> {
             cli_verify::<Bls12_381Field, G16, Bellman>(sub_matches)
         }
         #[cfg(feature = "ark")]
+        Parameters(BackendParameter::Ark, CurveParameter::Bls12_377, SchemeParameter::G16) => {
+            cli_verify::<Bls12_377Field, G16, Ark>(sub_matches)
+        }
+        #[cfg(feature = "ark")]
+        Parameters(BackendParameter::Ark, CurveParameter::Bw6_761, SchemeParameter::G16) => {
+            cli_verify::<Bw6_761Field, G16, Ark>(sub_matches)
+        }
+        #[cfg(feature = "ark")]
+        Parameters(BackendParameter::Ark, CurveParameter::Bn128, SchemeParameter::G16) => {
+            cli_verify::<Bn128Field, G16, Ark>(sub_matches)
+        }
+        #[cfg(feature = "ark")]
         Parameters(BackendParameter::Ark, CurveParameter::Bls12_377, SchemeParameter::GM17) => {
             cli_verify::<Bls12_377Field, GM17, Ark>(sub_matches)
         }
@@ -121,7 +133,7 @@ fn cli_verify<T: Field, S: Scheme<T>, B: Backend<T, S>>(
         .map_err(|why| format!("Could not open {}: {}", vk_path.display(), why))?;
 
     let vk_reader = BufReader::new(vk_file);
-    let vk = serde_json::from_reader(vk_reader)
+    let vk: BoundVerificationKey<S::VerificationKey> = serde_json::from_reader(vk_reader)
         .map_err(|why| format!("Could not deserialize verification key: {}", why))?;
 
     let proof_path = Path::new(sub_matches.value_of("proof-path").unwrap());
@@ -129,13 +141,15 @@ fn cli_verify<T: Field, S: Scheme<T>, B: Backend<T, S>>(
         .map_err(|why| format!("Could not open {}: {}", proof_path.display(), why))?;
 
     let proof_reader = BufReader::new(proof_file);
-    let proof = serde_json::from_reader(proof_reader)
+    let proof: Proof<S::ProofPoints> = serde_json::from_reader(proof_reader)
         .map_err(|why| format!("Could not deserialize proof: {}", why))?;
 
+    check_proof(&vk, &proof)?;
+
     println!("Performing verification...");
     println!(
         "{}",
-        match B::verify(vk, proof) {
+        match B::verify(vk.vk, proof) {
             true => "PASSED",
             false => "FAILED",
         }
@@ -215,13 +215,13 @@ mod integration {
         let backends = map! {
             "bellman" => vec!["g16"],
             "libsnark" => vec!["pghr13"],
-            "ark" => vec!["gm17", "marlin"]
+            "ark" => vec!["g16", "gm17", "marlin"]
         };
 
         #[cfg(not(feature = "libsnark"))]
         let backends = map! {
             "bellman" => vec!["g16"],
-            "ark" => vec!["gm17", "marlin"]
+            "ark" => vec!["g16", "gm17", "marlin"]
         };
 
         // GENERATE A UNIVERSAL SETUP
@@ -295,6 +295,18 @@ mod integration {
                     .succeeds()
                     .unwrap();
 
+                    // INSPECT
+                    assert_cli::Assert::command(&[
+                        "../target/release/zokrates",
+                        "inspect",
+                        flattened_path.to_str().unwrap(),
+                        proving_key_path.to_str().unwrap(),
+                        verification_key_path.to_str().unwrap(),
+                        proof_path.to_str().unwrap(),
+                    ])
+                    .succeeds()
+                    .unwrap();
+
                     if scheme != &"marlin" {
                         // EXPORT-VERIFIER
                         assert_cli::Assert::command(&[
@@ -11,6 +11,7 @@ impl<'ast> From<pest::File<'ast>> for absy::Module<'ast> {
             pest::SymbolDeclaration::Import(i) => import_directive_to_symbol_vec(i),
             pest::SymbolDeclaration::Constant(c) => vec![c.into()],
             pest::SymbolDeclaration::Struct(s) => vec![s.into()],
+            pest::SymbolDeclaration::Enum(e) => vec![e.into()],
             pest::SymbolDeclaration::Type(t) => vec![t.into()],
             pest::SymbolDeclaration::Function(f) => vec![f.into()],
         }))
@@ -116,6 +117,49 @@ impl<'ast> From<pest::StructField<'ast>> for absy::StructDefinitionFieldNode<'as
     }
 }
 
+impl<'ast> From<pest::EnumDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
+    fn from(definition: pest::EnumDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
+        use crate::absy::NodeValue;
+
+        let span = definition.span;
+
+        let id = definition.id.span.as_str();
+
+        let ty = absy::EnumDefinition {
+            variants: definition
+                .variants
+                .into_iter()
+                .map(absy::EnumVariantNode::from)
+                .collect(),
+        }
+        .span(span.clone());
+
+        absy::SymbolDeclaration {
+            id,
+            symbol: absy::Symbol::Here(absy::SymbolDefinition::Enum(ty)),
+        }
+        .span(span)
+    }
+}
+
+impl<'ast> From<pest::EnumVariant<'ast>> for absy::EnumVariantNode<'ast> {
+    fn from(variant: pest::EnumVariant<'ast>) -> absy::EnumVariantNode<'ast> {
+        use crate::absy::NodeValue;
+
+        let span = variant.span;
+
+        let id = variant.id.span.as_str();
+
+        let payload = variant
+            .payload
+            .into_iter()
+            .map(absy::UnresolvedTypeNode::from)
+            .collect();
+
+        absy::EnumVariant { id, payload }.span(span)
+    }
+}
+
 impl<'ast> From<pest::ConstantDefinition<'ast>> for absy::SymbolDeclarationNode<'ast> {
     fn from(definition: pest::ConstantDefinition<'ast>) -> absy::SymbolDeclarationNode<'ast> {
         use crate::absy::NodeValue;
@@ -254,8 +298,10 @@ fn statements_from_statement(statement: pest::Statement) -> Vec<absy::StatementN
     match statement {
         pest::Statement::Definition(s) => statements_from_definition(s),
         pest::Statement::Iteration(s) => vec![absy::StatementNode::from(s)],
+        pest::Statement::IfElse(s) => vec![absy::StatementNode::from(s)],
         pest::Statement::Assertion(s) => vec![absy::StatementNode::from(s)],
         pest::Statement::Return(s) => vec![absy::StatementNode::from(s)],
+        pest::Statement::Log(s) => vec![absy::StatementNode::from(s)],
     }
 }
 
@@ -381,6 +427,22 @@ impl<'ast> From<pest::AssertionStatement<'ast>> for absy::StatementNode<'ast> {
     }
 }
 
+impl<'ast> From<pest::LogStatement<'ast>> for absy::StatementNode<'ast> {
+    fn from(statement: pest::LogStatement<'ast>) -> absy::StatementNode<'ast> {
+        use crate::absy::NodeValue;
+
+        absy::Statement::Log(
+            statement.format_string.value,
+            statement
+                .expressions
+                .into_iter()
+                .map(absy::ExpressionNode::from)
+                .collect(),
+        )
+        .span(statement.span)
+    }
+}
+
 impl<'ast> From<pest::IterationStatement<'ast>> for absy::StatementNode<'ast> {
     fn from(statement: pest::IterationStatement<'ast>) -> absy::StatementNode<'ast> {
         use crate::absy::NodeValue;
@@ -400,6 +462,31 @@ impl<'ast> From<pest::IterationStatement<'ast>> for absy::StatementNode<'ast> {
     }
 }
 
+impl<'ast> From<pest::IfElseStatement<'ast>> for absy::StatementNode<'ast> {
+    fn from(statement: pest::IfElseStatement<'ast>) -> absy::StatementNode<'ast> {
+        use crate::absy::NodeValue;
+        let condition = absy::ExpressionNode::from(statement.condition);
+        let consequence = statements_from_block(statement.consequence);
+
+        // `else if` is sugar for an `else` block containing a single if/else statement
+        let alternative = match statement.alternative {
+            Some(pest::ElseClause::IfElse(box s)) => vec![absy::StatementNode::from(s)],
+            Some(pest::ElseClause::Block(b)) => statements_from_block(b),
+            None => vec![],
+        };
+
+        absy::Statement::IfElse(condition, consequence, alternative).span(statement.span)
+    }
+}
+
+fn statements_from_block(block: pest::Block) -> Vec<absy::StatementNode> {
+    block
+        .statements
+        .into_iter()
+        .flat_map(statements_from_statement)
+        .collect()
+}
+
 impl<'ast> From<pest::Expression<'ast>> for absy::ExpressionNode<'ast> {
     fn from(expression: pest::Expression<'ast>) -> absy::ExpressionNode<'ast> {
         match expression {
@@ -411,6 +498,9 @@ impl<'ast> From<pest::Expression<'ast>> for absy::ExpressionNode<'ast> {
             pest::Expression::Postfix(e) => absy::ExpressionNode::from(e),
             pest::Expression::InlineArray(e) => absy::ExpressionNode::from(e),
             pest::Expression::InlineStruct(e) => absy::ExpressionNode::from(e),
+            pest::Expression::InlineTuple(e) => absy::ExpressionNode::from(e),
+            pest::Expression::InlineEnum(e) => absy::ExpressionNode::from(e),
+            pest::Expression::Match(e) => absy::ExpressionNode::from(e),
             pest::Expression::ArrayInitializer(e) => absy::ExpressionNode::from(e),
             pest::Expression::Unary(e) => absy::ExpressionNode::from(e),
         }
@@ -615,6 +705,73 @@ impl<'ast> From<pest::InlineStructExpression<'ast>> for absy::ExpressionNode<'as
     }
 }
 
+impl<'ast> From<pest::InlineTupleExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(tuple: pest::InlineTupleExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use crate::absy::NodeValue;
+        absy::Expression::InlineTuple(
+            tuple
+                .elements
+                .into_iter()
+                .map(absy::ExpressionNode::from)
+                .collect(),
+        )
+        .span(tuple.span)
+    }
+}
+
+impl<'ast> From<pest::InlineEnumExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(e: pest::InlineEnumExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use crate::absy::NodeValue;
+        absy::Expression::InlineEnum(
+            e.ty.span.as_str().to_string(),
+            e.variant.span.as_str(),
+            e.arguments
+                .into_iter()
+                .map(absy::ExpressionNode::from)
+                .collect(),
+        )
+        .span(e.span)
+    }
+}
+
+impl<'ast> From<pest::MatchExpression<'ast>> for absy::ExpressionNode<'ast> {
+    fn from(e: pest::MatchExpression<'ast>) -> absy::ExpressionNode<'ast> {
+        use crate::absy::NodeValue;
+        absy::Expression::Match(
+            box absy::ExpressionNode::from(*e.expression),
+            e.arms.into_iter().map(absy::MatchArmNode::from).collect(),
+        )
+        .span(e.span)
+    }
+}
+
+impl<'ast> From<pest::MatchArm<'ast>> for absy::MatchArmNode<'ast> {
+    fn from(arm: pest::MatchArm<'ast>) -> absy::MatchArmNode<'ast> {
+        use crate::absy::NodeValue;
+
+        let pattern = match arm.pattern {
+            pest::MatchPattern::Wildcard(_) => absy::MatchPattern::Wildcard,
+            pest::MatchPattern::Variant(p) => absy::MatchPattern::Variant(
+                p.ty.span.as_str().to_string(),
+                p.variant.span.as_str(),
+                p.bindings
+                    .into_iter()
+                    .map(|b| match b {
+                        pest::BindingPattern::Wildcard(_) => None,
+                        pest::BindingPattern::Identifier(i) => Some(i.span.as_str()),
+                    })
+                    .collect(),
+            ),
+        };
+
+        absy::MatchArm {
+            pattern,
+            expression: absy::ExpressionNode::from(arm.expression),
+        }
+        .span(arm.span)
+    }
+}
+
 impl<'ast> From<pest::ArrayInitializerExpression<'ast>> for absy::ExpressionNode<'ast> {
     fn from(initializer: pest::ArrayInitializerExpression<'ast>) -> absy::ExpressionNode<'ast> {
         use crate::absy::NodeValue;
@@ -685,6 +842,10 @@ impl<'ast> From<pest::PostfixExpression<'ast>> for absy::ExpressionNode<'ast> {
                 pest::Access::Member(m) => {
                     absy::Expression::Member(box acc, box m.id.span.as_str()).span(m.span)
                 }
+                pest::Access::Element(e) => {
+                    absy::Expression::Element(box acc, e.index.span.as_str().parse().unwrap())
+                        .span(e.span)
+                }
             })
     }
 }
@@ -698,6 +859,9 @@ impl<'ast> From<pest::DecimalLiteralExpression<'ast>> for absy::ExpressionNode<'
                 pest::DecimalSuffix::Field(_) => absy::Expression::FieldConstant(
                     BigUint::parse_bytes(expression.value.span.as_str().as_bytes(), 10).unwrap(),
                 ),
+                pest::DecimalSuffix::U128(_) => {
+                    absy::Expression::U128Constant(expression.value.span.as_str().parse().unwrap())
+                }
                 pest::DecimalSuffix::U64(_) => {
                     absy::Expression::U64Constant(expression.value.span.as_str().parse().unwrap())
                 }
@@ -710,6 +874,18 @@ impl<'ast> From<pest::DecimalLiteralExpression<'ast>> for absy::ExpressionNode<'
                 pest::DecimalSuffix::U8(_) => {
                     absy::Expression::U8Constant(expression.value.span.as_str().parse().unwrap())
                 }
+                pest::DecimalSuffix::I64(_) => absy::Expression::I64Constant(
+                    BigUint::parse_bytes(expression.value.span.as_str().as_bytes(), 10).unwrap(),
+                ),
+                pest::DecimalSuffix::I32(_) => absy::Expression::I32Constant(
+                    BigUint::parse_bytes(expression.value.span.as_str().as_bytes(), 10).unwrap(),
+                ),
+                pest::DecimalSuffix::I16(_) => absy::Expression::I16Constant(
+                    BigUint::parse_bytes(expression.value.span.as_str().as_bytes(), 10).unwrap(),
+                ),
+                pest::DecimalSuffix::I8(_) => absy::Expression::I8Constant(
+                    BigUint::parse_bytes(expression.value.span.as_str().as_bytes(), 10).unwrap(),
+                ),
             }
             .span(expression.span),
             None => absy::Expression::IntConstant(
@@ -725,6 +901,9 @@ impl<'ast> From<pest::HexLiteralExpression<'ast>> for absy::ExpressionNode<'ast>
         use crate::absy::NodeValue;
 
         match expression.value {
+            pest::HexNumberExpression::U128(e) => {
+                absy::Expression::U128Constant(u128::from_str_radix(e.span.as_str(), 16).unwrap())
+            }
             pest::HexNumberExpression::U64(e) => {
                 absy::Expression::U64Constant(u64::from_str_radix(e.span.as_str(), 16).unwrap())
             }
@@ -786,6 +965,9 @@ impl<'ast> From<pest::Assignee<'ast>> for absy::AssigneeNode<'ast> {
                 pest::AssigneeAccess::Member(m) => {
                     absy::Assignee::Member(box acc, box m.id.span.as_str())
                 }
+                pest::AssigneeAccess::Element(e) => {
+                    absy::Assignee::Element(box acc, e.index.span.as_str().parse().unwrap())
+                }
             }
             .span(span.clone())
         })
@@ -805,18 +987,28 @@ impl<'ast> From<pest::Type<'ast>> for absy::UnresolvedTypeNode<'ast> {
                 pest::BasicType::U16(t) => UnresolvedType::Uint(16).span(t.span),
                 pest::BasicType::U32(t) => UnresolvedType::Uint(32).span(t.span),
                 pest::BasicType::U64(t) => UnresolvedType::Uint(64).span(t.span),
+                pest::BasicType::U128(t) => UnresolvedType::Uint(128).span(t.span),
+                pest::BasicType::I8(t) => UnresolvedType::Int(8).span(t.span),
+                pest::BasicType::I16(t) => UnresolvedType::Int(16).span(t.span),
+                pest::BasicType::I32(t) => UnresolvedType::Int(32).span(t.span),
+                pest::BasicType::I64(t) => UnresolvedType::Int(64).span(t.span),
             },
             pest::Type::Array(t) => {
                 let inner_type = match t.ty {
-                    pest::BasicOrStructType::Basic(t) => match t {
+                    pest::BasicOrStructOrTupleType::Basic(t) => match t {
                         pest::BasicType::Field(t) => UnresolvedType::FieldElement.span(t.span),
                         pest::BasicType::Boolean(t) => UnresolvedType::Boolean.span(t.span),
                         pest::BasicType::U8(t) => UnresolvedType::Uint(8).span(t.span),
                         pest::BasicType::U16(t) => UnresolvedType::Uint(16).span(t.span),
                         pest::BasicType::U32(t) => UnresolvedType::Uint(32).span(t.span),
                         pest::BasicType::U64(t) => UnresolvedType::Uint(64).span(t.span),
+                        pest::BasicType::U128(t) => UnresolvedType::Uint(128).span(t.span),
+                        pest::BasicType::I8(t) => UnresolvedType::Int(8).span(t.span),
+                        pest::BasicType::I16(t) => UnresolvedType::Int(16).span(t.span),
+                        pest::BasicType::I32(t) => UnresolvedType::Int(32).span(t.span),
+                        pest::BasicType::I64(t) => UnresolvedType::Int(64).span(t.span),
                     },
-                    pest::BasicOrStructType::Struct(t) => UnresolvedType::User(
+                    pest::BasicOrStructOrTupleType::Struct(t) => UnresolvedType::User(
                         t.id.span.as_str().to_string(),
                         t.explicit_generics.map(|explicit_generics| {
                             explicit_generics
@@ -835,6 +1027,7 @@ impl<'ast> From<pest::Type<'ast>> for absy::UnresolvedTypeNode<'ast> {
                         }),
                     )
                     .span(t.span),
+                    pest::BasicOrStructOrTupleType::Tuple(t) => absy::UnresolvedTypeNode::from(t),
                 };
 
                 let span = t.span;
@@ -869,10 +1062,26 @@ impl<'ast> From<pest::Type<'ast>> for absy::UnresolvedTypeNode<'ast> {
                 }),
             )
             .span(s.span),
+            pest::Type::Tuple(t) => absy::UnresolvedTypeNode::from(t),
         }
     }
 }
 
+impl<'ast> From<pest::TupleType<'ast>> for absy::UnresolvedTypeNode<'ast> {
+    fn from(t: pest::TupleType<'ast>) -> absy::UnresolvedTypeNode<'ast> {
+        use crate::absy::types::UnresolvedType;
+        use crate::absy::NodeValue;
+
+        UnresolvedType::Tuple(
+            t.elements
+                .into_iter()
+                .map(absy::UnresolvedTypeNode::from)
+                .collect(),
+        )
+        .span(t.span)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,6 +132,7 @@ pub struct SymbolDeclaration<'ast> {
 pub enum SymbolDefinition<'ast> {
     Import(CanonicalImportNode<'ast>),
     Struct(StructDefinitionNode<'ast>),
+    Enum(EnumDefinitionNode<'ast>),
     Constant(ConstantDefinitionNode<'ast>),
     Type(TypeDefinitionNode<'ast>),
     Function(FunctionNode<'ast>),
@@ -155,6 +156,7 @@ impl<'ast> fmt::Display for SymbolDeclaration<'ast> {
                     i.value.id
                 ),
                 SymbolDefinition::Struct(ref s) => write!(f, "struct {}{}", self.id, s),
+                SymbolDefinition::Enum(ref e) => write!(f, "enum {}{}", self.id, e),
                 SymbolDefinition::Constant(ref c) => write!(
                     f,
                     "const {} {} = {}",
@@ -258,6 +260,51 @@ impl<'ast> fmt::Display for StructDefinitionField<'ast> {
 
 type StructDefinitionFieldNode<'ast> = Node<StructDefinitionField<'ast>>;
 
+/// An enum type definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDefinition<'ast> {
+    pub variants: Vec<EnumVariantNode<'ast>>,
+}
+
+impl<'ast> fmt::Display for EnumDefinition<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, " {{")?;
+        for variant in &self.variants {
+            writeln!(f, "  {}", variant)?;
+        }
+        write!(f, "}}",)
+    }
+}
+
+pub type EnumDefinitionNode<'ast> = Node<EnumDefinition<'ast>>;
+
+/// A variant of an enum type definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumVariant<'ast> {
+    pub id: Identifier<'ast>,
+    pub payload: Vec<UnresolvedTypeNode<'ast>>,
+}
+
+impl<'ast> fmt::Display for EnumVariant<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.id)?;
+        if !self.payload.is_empty() {
+            write!(
+                f,
+                "({})",
+                self.payload
+                    .iter()
+                    .map(|ty| ty.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub type EnumVariantNode<'ast> = Node<EnumVariant<'ast>>;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ConstantDefinition<'ast> {
     pub ty: UnresolvedTypeNode<'ast>,
@@ -363,6 +410,7 @@ pub enum Assignee<'ast> {
     Identifier(Identifier<'ast>),
     Select(Box<AssigneeNode<'ast>>, Box<RangeOrExpression<'ast>>),
     Member(Box<AssigneeNode<'ast>>, Box<Identifier<'ast>>),
+    Element(Box<AssigneeNode<'ast>>, u32),
 }
 
 pub type AssigneeNode<'ast> = Node<Assignee<'ast>>;
@@ -373,6 +421,7 @@ impl<'ast> fmt::Display for Assignee<'ast> {
             Assignee::Identifier(ref s) => write!(f, "{}", s),
             Assignee::Select(ref a, ref e) => write!(f, "{}[{}]", a, e),
             Assignee::Member(ref s, ref m) => write!(f, "{}.{}", s, m),
+            Assignee::Element(ref s, index) => write!(f, "{}.{}", s, index),
         }
     }
 }
@@ -391,7 +440,13 @@ pub enum Statement<'ast> {
         ExpressionNode<'ast>,
         Vec<StatementNode<'ast>>,
     ),
+    IfElse(
+        ExpressionNode<'ast>,
+        Vec<StatementNode<'ast>>,
+        Vec<StatementNode<'ast>>,
+    ),
     MultipleDefinition(Vec<AssigneeNode<'ast>>, ExpressionNode<'ast>),
+    Log(String, Vec<ExpressionNode<'ast>>),
 }
 
 pub type StatementNode<'ast> = Node<Statement<'ast>>;
@@ -416,6 +471,17 @@ impl<'ast> fmt::Display for Statement<'ast> {
                 }
                 write!(f, "\tendfor")
             }
+            Statement::IfElse(ref condition, ref consequence, ref alternative) => {
+                writeln!(f, "if {} {{", condition)?;
+                for s in consequence {
+                    writeln!(f, "\t\t{}", s)?;
+                }
+                writeln!(f, "\t}} else {{")?;
+                for s in alternative {
+                    writeln!(f, "\t\t{}", s)?;
+                }
+                write!(f, "\t}}")
+            }
             Statement::MultipleDefinition(ref ids, ref rhs) => {
                 for (i, id) in ids.iter().enumerate() {
                     write!(f, "{}", id)?;
@@ -425,6 +491,13 @@ impl<'ast> fmt::Display for Statement<'ast> {
                 }
                 write!(f, " = {}", rhs)
             }
+            Statement::Log(ref format_string, ref expressions) => {
+                write!(f, "log(\"{}\"", format_string)?;
+                for e in expressions {
+                    write!(f, ", {}", e)?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -513,6 +586,51 @@ pub enum ConditionalKind {
     Ternary,
 }
 
+/// A pattern in an arm of a match expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchPattern<'ast> {
+    Wildcard,
+    Variant(UserTypeId, Identifier<'ast>, Vec<Option<Identifier<'ast>>>),
+}
+
+impl<'ast> fmt::Display for MatchPattern<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchPattern::Wildcard => write!(f, "_"),
+            MatchPattern::Variant(ty, variant, bindings) => {
+                write!(f, "{}::{}", ty, variant)?;
+                if !bindings.is_empty() {
+                    write!(
+                        f,
+                        "({})",
+                        bindings
+                            .iter()
+                            .map(|b| b.map(|b| b.to_string()).unwrap_or_else(|| "_".into()))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// An arm of a match expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm<'ast> {
+    pub pattern: MatchPattern<'ast>,
+    pub expression: ExpressionNode<'ast>,
+}
+
+impl<'ast> fmt::Display for MatchArm<'ast> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.expression)
+    }
+}
+
+pub type MatchArmNode<'ast> = Node<MatchArm<'ast>>;
+
 /// An expression
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expression<'ast> {
@@ -523,6 +641,12 @@ pub enum Expression<'ast> {
     U16Constant(u16),
     U32Constant(u32),
     U64Constant(u64),
+    U128Constant(u128),
+    // signed literals are unsigned, `-128i8` is the negation of `128i8`
+    I8Constant(BigUint),
+    I16Constant(BigUint),
+    I32Constant(BigUint),
+    I64Constant(BigUint),
     Identifier(Identifier<'ast>),
     Add(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     Sub(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
@@ -553,8 +677,12 @@ pub enum Expression<'ast> {
     InlineArray(Vec<SpreadOrExpression<'ast>>),
     ArrayInitializer(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     InlineStruct(UserTypeId, Vec<(Identifier<'ast>, ExpressionNode<'ast>)>),
+    InlineTuple(Vec<ExpressionNode<'ast>>),
+    InlineEnum(UserTypeId, Identifier<'ast>, Vec<ExpressionNode<'ast>>),
+    Match(Box<ExpressionNode<'ast>>, Vec<MatchArmNode<'ast>>),
     Select(Box<ExpressionNode<'ast>>, Box<RangeOrExpression<'ast>>),
     Member(Box<ExpressionNode<'ast>>, Box<Identifier<'ast>>),
+    Element(Box<ExpressionNode<'ast>>, u32),
     Or(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     BitXor(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
     BitAnd(Box<ExpressionNode<'ast>>, Box<ExpressionNode<'ast>>),
@@ -573,6 +701,11 @@ impl<'ast> fmt::Display for Expression<'ast> {
             Expression::U16Constant(ref i) => write!(f, "{}", i),
             Expression::U32Constant(ref i) => write!(f, "{}", i),
             Expression::U64Constant(ref i) => write!(f, "{}", i),
+            Expression::U128Constant(ref i) => write!(f, "{}", i),
+            Expression::I8Constant(ref i) => write!(f, "{}", i),
+            Expression::I16Constant(ref i) => write!(f, "{}", i),
+            Expression::I32Constant(ref i) => write!(f, "{}", i),
+            Expression::I64Constant(ref i) => write!(f, "{}", i),
             Expression::IntConstant(ref i) => write!(f, "{}", i),
             Expression::Identifier(ref var) => write!(f, "{}", var),
             Expression::Add(ref lhs, ref rhs) => write!(f, "({} + {})", lhs, rhs),
@@ -647,8 +780,48 @@ impl<'ast> fmt::Display for Expression<'ast> {
                 }
                 write!(f, "}}")
             }
+            Expression::InlineTuple(ref exprs) => {
+                write!(f, "(")?;
+                for (i, e) in exprs.iter().enumerate() {
+                    write!(f, "{}", e)?;
+                    if i < exprs.len() - 1 || exprs.len() == 1 {
+                        write!(f, ",")?;
+                    }
+                    if i < exprs.len() - 1 {
+                        write!(f, " ")?;
+                    }
+                }
+                write!(f, ")")
+            }
+            Expression::InlineEnum(ref ty, ref variant, ref arguments) => {
+                write!(f, "{}::{}", ty, variant)?;
+                if !arguments.is_empty() {
+                    write!(
+                        f,
+                        "({})",
+                        arguments
+                            .iter()
+                            .map(|a| a.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+            Expression::Match(ref e, ref arms) => {
+                write!(
+                    f,
+                    "match {} {{ {} }}",
+                    e,
+                    arms.iter()
+                        .map(|a| a.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
             Expression::Select(ref array, ref index) => write!(f, "{}[{}]", array, index),
             Expression::Member(ref struc, ref id) => write!(f, "{}.{}", struc, id),
+            Expression::Element(ref tuple, index) => write!(f, "{}.{}", tuple, index),
             Expression::Or(ref lhs, ref rhs) => write!(f, "({} || {})", lhs, rhs),
             Expression::BitXor(ref lhs, ref rhs) => write!(f, "({} ^ {})", lhs, rhs),
             Expression::BitAnd(ref lhs, ref rhs) => write!(f, "({} & {})", lhs, rhs),
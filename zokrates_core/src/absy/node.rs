@@ -92,6 +92,9 @@ impl<'ast> NodeValue for SymbolDeclaration<'ast> {}
 impl<'ast> NodeValue for UnresolvedType<'ast> {}
 impl<'ast> NodeValue for StructDefinition<'ast> {}
 impl<'ast> NodeValue for StructDefinitionField<'ast> {}
+impl<'ast> NodeValue for EnumDefinition<'ast> {}
+impl<'ast> NodeValue for EnumVariant<'ast> {}
+impl<'ast> NodeValue for MatchArm<'ast> {}
 impl<'ast> NodeValue for ConstantDefinition<'ast> {}
 impl<'ast> NodeValue for TypeDefinition<'ast> {}
 impl<'ast> NodeValue for Function<'ast> {}
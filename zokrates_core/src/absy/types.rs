@@ -11,8 +11,10 @@ pub enum UnresolvedType<'ast> {
     FieldElement,
     Boolean,
     Uint(usize),
+    Int(usize),
     Array(Box<UnresolvedTypeNode<'ast>>, ExpressionNode<'ast>),
     User(UserTypeId, Option<Vec<Option<ExpressionNode<'ast>>>>),
+    Tuple(Vec<UnresolvedTypeNode<'ast>>),
 }
 
 impl<'ast> fmt::Display for UnresolvedType<'ast> {
@@ -21,6 +23,7 @@ impl<'ast> fmt::Display for UnresolvedType<'ast> {
             UnresolvedType::FieldElement => write!(f, "field"),
             UnresolvedType::Boolean => write!(f, "bool"),
             UnresolvedType::Uint(bitwidth) => write!(f, "u{}", bitwidth),
+            UnresolvedType::Int(bitwidth) => write!(f, "i{}", bitwidth),
             UnresolvedType::Array(ref ty, ref size) => write!(f, "{}[{}]", ty, size),
             UnresolvedType::User(ref id, ref generics) => {
                 write!(
@@ -46,6 +49,19 @@ impl<'ast> fmt::Display for UnresolvedType<'ast> {
                         .unwrap_or_default()
                 )
             }
+            UnresolvedType::Tuple(ref elements) => {
+                write!(f, "(")?;
+                for (i, e) in elements.iter().enumerate() {
+                    write!(f, "{}", e)?;
+                    if i < elements.len() - 1 || elements.len() == 1 {
+                        write!(f, ",")?;
+                    }
+                    if i < elements.len() - 1 {
+                        write!(f, " ")?;
+                    }
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -4,18 +4,24 @@
 //! @author Thibaut Schaeffer <thibaut@schaeff.fr>
 //! @date 2018
 use crate::absy::{Module, OwnedModuleId, Program};
+use crate::flat_absy::FlatProg;
 use crate::flatten::Flattener;
 use crate::imports::{self, Importer};
 use crate::ir;
 use crate::macros;
+use crate::passes::{self, CustomPass, OptLevel, PassManager, PassReport};
 use crate::semantics::{self, Checker};
 use crate::static_analysis;
 use crate::static_analysis::Analyse;
 use crate::typed_absy::abi::Abi;
+use crate::typed_absy::folder::{fold_declaration_function_key, Folder};
+use crate::typed_absy::{DeclarationFunctionKey, OwnedTypedModuleId, TypedProgram};
 use crate::zir::ZirProgram;
 use macros::process_macros;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
 use std::fmt;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -24,10 +30,16 @@ use zokrates_common::Resolver;
 use zokrates_field::Field;
 use zokrates_pest_ast as pest;
 
+pub use crate::semantics::Lint;
+
 #[derive(Debug)]
 pub struct CompilationArtifacts<T: Field> {
     prog: ir::Prog<T>,
     abi: Abi,
+    source_map: ir::SourceMap,
+    warnings: CompileWarnings,
+    pass_reports: Vec<PassReport>,
+    metadata: ir::ProgMetadata,
 }
 
 impl<T: Field> CompilationArtifacts<T> {
@@ -38,6 +50,24 @@ impl<T: Field> CompilationArtifacts<T> {
     pub fn abi(&self) -> &Abi {
         &self.abi
     }
+
+    pub fn source_map(&self) -> &ir::SourceMap {
+        &self.source_map
+    }
+
+    pub fn warnings(&self) -> &CompileWarnings {
+        &self.warnings
+    }
+
+    /// Returns what each pass did, in the order in which the passes ran
+    pub fn pass_reports(&self) -> &[PassReport] {
+        &self.pass_reports
+    }
+
+    /// Returns the metadata to serialize along with the program
+    pub fn metadata(&self) -> &ir::ProgMetadata {
+        &self.metadata
+    }
 }
 
 #[derive(Debug)]
@@ -57,6 +87,8 @@ pub enum CompileErrorInner {
     SemanticError(semantics::ErrorInner),
     ReadError(io::Error),
     AnalysisError(static_analysis::Error),
+    DeniedWarning(semantics::WarningInner),
+    ConfigError(String),
 }
 
 impl CompileErrorInner {
@@ -158,16 +190,80 @@ impl fmt::Display for CompileErrorInner {
                 write!(f, "{}\n\t{}", location, e.message())
             }
             CompileErrorInner::AnalysisError(ref e) => write!(f, "\n\t{}", e),
+            CompileErrorInner::DeniedWarning(ref e) => write!(f, "{}", e),
+            CompileErrorInner::ConfigError(ref e) => write!(f, "\n\t{}", e),
         }
     }
 }
 
-#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default)]
+pub struct CompileWarnings(pub Vec<CompileWarning>);
+
+#[derive(Debug)]
+pub struct CompileWarning {
+    file: PathBuf,
+    value: semantics::WarningInner,
+}
+
+impl CompileWarning {
+    pub fn file(&self) -> &PathBuf {
+        &self.file
+    }
+
+    pub fn value(&self) -> &semantics::WarningInner {
+        &self.value
+    }
+}
+
+impl From<semantics::Warning> for CompileWarning {
+    fn from(warning: semantics::Warning) -> Self {
+        CompileWarning {
+            value: warning.inner,
+            file: warning.module_id,
+        }
+    }
+}
+
+/// How a lint is reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl Default for LintLevel {
+    fn default() -> Self {
+        LintLevel::Warn
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CompileConfig {
     #[serde(default)]
     pub allow_unconstrained_variables: bool,
     #[serde(default)]
     pub isolate_branches: bool,
+    /// The function of the main module to compile instead of `main`
+    #[serde(default)]
+    pub entry_point: Option<String>,
+    /// The level of the lints which are not reported as warnings
+    #[serde(default)]
+    pub lints: BTreeMap<Lint, LintLevel>,
+    /// The maximum number of terms of the constraints produced when eliminating linear
+    /// constraints, which is only done if this is set
+    #[serde(default)]
+    pub linear_elimination: Option<usize>,
+    /// The optimization level, which decides which optional passes are run
+    #[serde(default)]
+    pub opt_level: OptLevel,
+    /// The passes which are not run, regardless of the optimization level
+    #[serde(default)]
+    pub disabled_passes: Vec<String>,
+    /// The passes after which the program is recorded in the pass reports
+    #[serde(default)]
+    pub print_after: Vec<String>,
 }
 
 impl CompileConfig {
@@ -179,29 +275,114 @@ impl CompileConfig {
         self.isolate_branches = flag;
         self
     }
+    pub fn entry_point<S: Into<String>>(mut self, id: S) -> Self {
+        self.entry_point = Some(id.into());
+        self
+    }
+    pub fn lint(mut self, lint: Lint, level: LintLevel) -> Self {
+        self.lints.insert(lint, level);
+        self
+    }
+    pub fn linear_elimination(mut self, max_density: Option<usize>) -> Self {
+        self.linear_elimination = max_density;
+        self
+    }
+    pub fn opt_level(mut self, level: OptLevel) -> Self {
+        self.opt_level = level;
+        self
+    }
+    pub fn disable_pass<S: Into<String>>(mut self, name: S) -> Self {
+        self.disabled_passes.push(name.into());
+        self
+    }
+    pub fn print_after<S: Into<String>>(mut self, name: S) -> Self {
+        self.print_after.push(name.into());
+        self
+    }
+
+    pub fn lint_level(&self, lint: Lint) -> LintLevel {
+        self.lints.get(&lint).cloned().unwrap_or_default()
+    }
+}
+
+/// A function marked with `#[test]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestFunction {
+    pub id: String,
+    /// Whether the function is expected to fail, as marked with `#[should_fail]`
+    pub should_fail: bool,
 }
 
 type FilePath = PathBuf;
 
+/// The maximum density of the constraints produced by linear elimination at `-O3`, if none is set
+pub const DEFAULT_MAX_DENSITY: usize = 100;
+
 pub fn compile<T: Field, E: Into<imports::Error>>(
     source: String,
     location: FilePath,
     resolver: Option<&dyn Resolver<E>>,
     config: &CompileConfig,
 ) -> Result<CompilationArtifacts<T>, CompileErrors> {
+    compile_with_passes(source, location, resolver, config, vec![])
+}
+
+/// Compiles the program like `compile`, running the `custom` passes in order on the ir program
+/// after the optimizations of the compiler. They can be disabled and printed through the
+/// configuration like the passes of the compiler
+pub fn compile_with_passes<T: Field, E: Into<imports::Error>>(
+    source: String,
+    location: FilePath,
+    resolver: Option<&dyn Resolver<E>>,
+    config: &CompileConfig,
+    custom: Vec<CustomPass<T>>,
+) -> Result<CompilationArtifacts<T>, CompileErrors> {
+    passes::check_config(config, &custom.iter().map(|p| p.info).collect::<Vec<_>>())
+        .map_err(|e| CompileErrorInner::ConfigError(e).in_file(location.as_path()))?;
+
     let arena = Arena::new();
 
-    let (typed_ast, abi) = check_with_arena(source, location.clone(), resolver, config, &arena)?;
+    let mut pass_reports = vec![];
+
+    let (typed_ast, abi, warnings) = check_with_arena(
+        source,
+        location.clone(),
+        resolver,
+        config,
+        &arena,
+        &mut pass_reports,
+    )?;
 
     // flatten input program
     log::debug!("Flatten");
     let program_flattened = Flattener::flatten(typed_ast, config);
     log::trace!("\n{}", program_flattened);
 
+    // all sources are now resolved, hash them in an order which does not depend on the imports
+    let mut sources = arena.into_vec();
+    sources.sort();
+    let mut hasher = Sha256::new();
+    for source in sources {
+        hasher.update(&(source.len() as u64).to_le_bytes());
+        hasher.update(&source);
+    }
+
+    let metadata = ir::ProgMetadata {
+        source_hash: Some(hex::encode(hasher.finalize())),
+        config: Some(config.clone()),
+        abi_hash: Some(hex::encode(Sha256::digest(
+            serde_json::to_string(&abi).unwrap().as_bytes(),
+        ))),
+        ..ir::ProgMetadata::default()
+    };
+
     // constant propagation after call resolution
-    log::debug!("Propagate flat program");
-    let program_flattened = program_flattened.propagate();
-    log::trace!("\n{}", program_flattened);
+    let program_flattened = PassManager::new(config)
+        .pass("flat-propagation", |p: FlatProg<T>| {
+            Ok::<_, Infallible>(p.propagate())
+        })
+        .run(program_flattened, &mut pass_reports)
+        .unwrap_or_else(|e| match e {});
 
     // convert to ir
     log::debug!("Convert to IR");
@@ -210,7 +391,19 @@ pub fn compile<T: Field, E: Into<imports::Error>>(
 
     // optimize
     log::debug!("Optimise IR");
-    let optimized_ir_prog = ir_prog.optimize();
+    let optimized_ir_prog = ir_prog.optimize(config, &mut pass_reports);
+
+    let optimized_ir_prog = custom
+        .into_iter()
+        .fold(
+            PassManager::<ir::Prog<T>, Infallible>::new(config),
+            |manager, pass| {
+                let info = pass.info;
+                manager.custom(info, move |p| Ok(pass.run(p)))
+            },
+        )
+        .run(optimized_ir_prog, &mut pass_reports)
+        .unwrap_or_else(|e| match e {});
 
     // analyse ir (check constraints)
     log::debug!("Analyse IR");
@@ -218,9 +411,35 @@ pub fn compile<T: Field, E: Into<imports::Error>>(
         .analyse()
         .map_err(|e| CompileErrorInner::from(e).in_file(location.as_path()))?;
 
+    // eliminating linear constraints leaves the substituted variables out of the constraints,
+    // so this happens after checking for unconstrained variables
+    let max_density = config
+        .linear_elimination
+        .or_else(|| match config.opt_level {
+            OptLevel::O3 => Some(DEFAULT_MAX_DENSITY),
+            _ => None,
+        });
+    let optimized_ir_prog = PassManager::new(config)
+        .pass_if(
+            "linear-elimination",
+            max_density.is_some(),
+            |p: ir::Prog<T>| {
+                Ok::<_, Infallible>(p.eliminate_linear_constraints(max_density.unwrap()))
+            },
+        )
+        .run(optimized_ir_prog, &mut pass_reports)
+        .unwrap_or_else(|e| match e {});
+
+    // move source spans out of the program
+    let (optimized_ir_prog, source_map) = optimized_ir_prog.extract_source_map();
+
     Ok(CompilationArtifacts {
         prog: optimized_ir_prog,
         abi,
+        source_map,
+        warnings,
+        pass_reports,
+        metadata,
     })
 }
 
@@ -229,10 +448,14 @@ pub fn check<T: Field, E: Into<imports::Error>>(
     location: FilePath,
     resolver: Option<&dyn Resolver<E>>,
     config: &CompileConfig,
-) -> Result<(), CompileErrors> {
+) -> Result<CompileWarnings, CompileErrors> {
+    passes::check_config(config, &[])
+        .map_err(|e| CompileErrorInner::ConfigError(e).in_file(location.as_path()))?;
+
     let arena = Arena::new();
 
-    check_with_arena::<T, _>(source, location, resolver, config, &arena).map(|_| ())
+    check_with_arena::<T, _>(source, location, resolver, config, &arena, &mut vec![])
+        .map(|(_, _, warnings)| warnings)
 }
 
 fn check_with_arena<'ast, T: Field, E: Into<imports::Error>>(
@@ -241,7 +464,8 @@ fn check_with_arena<'ast, T: Field, E: Into<imports::Error>>(
     resolver: Option<&dyn Resolver<E>>,
     config: &CompileConfig,
     arena: &'ast Arena<String>,
-) -> Result<(ZirProgram<'ast, T>, Abi), CompileErrors> {
+    pass_reports: &mut Vec<PassReport>,
+) -> Result<(ZirProgram<'ast, T>, Abi, CompileWarnings), CompileErrors> {
     let source = arena.alloc(source);
 
     log::debug!("Parse program with entry file {}", location.display());
@@ -251,19 +475,126 @@ fn check_with_arena<'ast, T: Field, E: Into<imports::Error>>(
     log::debug!("Check semantics");
 
     // check semantics
-    let typed_ast = Checker::check(compiled)
-        .map_err(|errors| CompileErrors(errors.into_iter().map(CompileError::from).collect()))?;
+    let (typed_ast, warnings) = match &config.entry_point {
+        Some(id) => {
+            log::debug!("Use {} as entry point", id);
+            Checker::check_with_entry_point(compiled, id)
+                .map(|(typed_ast, warnings)| (with_entry_point(typed_ast, id), warnings))
+        }
+        None => Checker::check_with_warnings(compiled),
+    }
+    .map_err(|errors| CompileErrors(errors.into_iter().map(CompileError::from).collect()))?;
 
     log::trace!("\n{}", typed_ast);
 
+    // apply the configured lint levels
+    let (denied, warnings): (Vec<_>, Vec<_>) = warnings
+        .into_iter()
+        .filter(|w| config.lint_level(w.inner.lint()) != LintLevel::Allow)
+        .partition(|w| config.lint_level(w.inner.lint()) == LintLevel::Deny);
+
+    if !denied.is_empty() {
+        return Err(CompileErrors(
+            denied
+                .into_iter()
+                .map(|w| CompileErrorInner::DeniedWarning(w.inner).in_file(&w.module_id))
+                .collect(),
+        ));
+    }
+
+    let warnings = CompileWarnings(warnings.into_iter().map(CompileWarning::from).collect());
+
     let main_module = typed_ast.main.clone();
 
     log::debug!("Run static analysis");
 
     // analyse (unroll and constant propagation)
-    typed_ast
-        .analyse(config)
-        .map_err(|e| CompileErrors(vec![CompileErrorInner::from(e).in_file(&main_module)]))
+    let (zir, abi) = typed_ast
+        .analyse(config, pass_reports)
+        .map_err(|e| CompileErrors(vec![CompileErrorInner::from(e).in_file(&main_module)]))?;
+
+    Ok((zir, abi, warnings))
+}
+
+/// Parses the program and checks its semantics, returning the typed program before any static analysis
+pub fn check_types<'ast, T: Field, E: Into<imports::Error>>(
+    source: &'ast str,
+    location: FilePath,
+    resolver: Option<&dyn Resolver<E>>,
+    arena: &'ast Arena<String>,
+) -> Result<TypedProgram<'ast, T>, CompileErrors> {
+    let compiled = parse_program::<T, E>(source, location, resolver, arena)?;
+
+    Checker::check(compiled)
+        .map_err(|errors| CompileErrors(errors.into_iter().map(CompileError::from).collect()))
+}
+
+/// Makes the function `id` of the main module its `main` function. The original `main` function,
+/// if any, is renamed to an identifier which cannot be written in source code, so that the new
+/// `main` function can still call it
+fn with_entry_point<'ast, T: Field>(
+    program: TypedProgram<'ast, T>,
+    id: &str,
+) -> TypedProgram<'ast, T> {
+    EntryPointRenamer {
+        module: program.main.clone(),
+        id,
+    }
+    .fold_program(program)
+}
+
+struct EntryPointRenamer<'a> {
+    module: OwnedTypedModuleId,
+    id: &'a str,
+}
+
+impl<'ast, 'a, T: Field> Folder<'ast, T> for EntryPointRenamer<'a> {
+    fn fold_declaration_function_key(
+        &mut self,
+        key: DeclarationFunctionKey<'ast, T>,
+    ) -> DeclarationFunctionKey<'ast, T> {
+        let key = fold_declaration_function_key(self, key);
+
+        if key.module != self.module {
+            return key;
+        }
+
+        match key.id {
+            "main" => key.id("_main"),
+            id if id == self.id => key.id("main"),
+            _ => key,
+        }
+    }
+}
+
+/// Returns the functions of the module in `source` which are marked with `#[test]`
+pub fn tests<T: Field>(source: &str, location: &Path) -> Result<Vec<TestFunction>, CompileErrors> {
+    let ast = pest::generate_ast(source)
+        .map_err(|e| CompileErrors::from(CompileErrorInner::from(e).in_file(location)))?;
+
+    let ast = process_macros::<T>(ast)
+        .map_err(|e| CompileErrors::from(CompileErrorInner::from(e).in_file(location)))?;
+
+    Ok(ast
+        .declarations
+        .into_iter()
+        .filter_map(|d| match d {
+            pest::SymbolDeclaration::Function(f) => Some(f),
+            _ => None,
+        })
+        .filter(|f| {
+            f.attributes
+                .iter()
+                .any(|a| a.id.span.as_str() == macros::TEST_ATTRIBUTE)
+        })
+        .map(|f| TestFunction {
+            should_fail: f
+                .attributes
+                .iter()
+                .any(|a| a.id.span.as_str() == macros::SHOULD_FAIL_ATTRIBUTE),
+            id: f.id.span.as_str().to_string(),
+        })
+        .collect())
 }
 
 pub fn parse_program<'ast, T: Field, E: Into<imports::Error>>(
@@ -357,6 +688,292 @@ mod test {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn entry_point() {
+        let source = r#"
+			#[test]
+			def foo() -> field:
+			   return 2
+
+			#[test]
+			#[should_fail]
+			def bar():
+			   assert(1 == 2)
+			   return
+
+			def main() -> field:
+			   return 1
+		"#
+        .to_string();
+
+        assert_eq!(
+            tests::<Bn128Field>(&source, Path::new("./path/to/file")).unwrap(),
+            vec![
+                TestFunction {
+                    id: "foo".into(),
+                    should_fail: false
+                },
+                TestFunction {
+                    id: "bar".into(),
+                    should_fail: true
+                }
+            ]
+        );
+
+        let artifacts: CompilationArtifacts<Bn128Field> = compile(
+            source.clone(),
+            "./path/to/file".into(),
+            None::<&dyn Resolver<io::Error>>,
+            &CompileConfig::default().entry_point("foo"),
+        )
+        .unwrap();
+        assert_eq!(
+            ir::Interpreter::default()
+                .execute(artifacts.prog(), &[])
+                .unwrap()
+                .return_values(),
+            vec![Bn128Field::from(2)]
+        );
+
+        let res: Result<CompilationArtifacts<Bn128Field>, CompileErrors> = compile(
+            source,
+            "./path/to/file".into(),
+            None::<&dyn Resolver<io::Error>>,
+            &CompileConfig::default().entry_point("baz"),
+        );
+        assert!(res.unwrap_err().0[0]
+            .value()
+            .to_string()
+            .contains(&"No baz function found"));
+    }
+
+    #[test]
+    fn entry_point_calling_main() {
+        // the test function becomes the entry point but can still call the original `main`
+        let source = r#"
+			#[test]
+			def foo() -> field:
+			   return main(2) + 1
+
+			def main(field a) -> field:
+			   return a * 2
+		"#
+        .to_string();
+
+        let artifacts: CompilationArtifacts<Bn128Field> = compile(
+            source,
+            "./path/to/file".into(),
+            None::<&dyn Resolver<io::Error>>,
+            &CompileConfig::default().entry_point("foo"),
+        )
+        .unwrap();
+        assert_eq!(
+            ir::Interpreter::default()
+                .execute(artifacts.prog(), &[])
+                .unwrap()
+                .return_values(),
+            vec![Bn128Field::from(5)]
+        );
+    }
+
+    #[test]
+    fn entry_point_without_main() {
+        let source = r#"
+			#[test]
+			def foo() -> field:
+			   return 2
+		"#
+        .to_string();
+
+        let artifacts: CompilationArtifacts<Bn128Field> = compile(
+            source,
+            "./path/to/file".into(),
+            None::<&dyn Resolver<io::Error>>,
+            &CompileConfig::default().entry_point("foo"),
+        )
+        .unwrap();
+        assert_eq!(
+            ir::Interpreter::default()
+                .execute(artifacts.prog(), &[])
+                .unwrap()
+                .return_values(),
+            vec![Bn128Field::from(2)]
+        );
+    }
+
+    #[test]
+    fn custom_passes() {
+        let source = r#"
+			def main(field a) -> field:
+			   return a * a
+		"#
+        .to_string();
+
+        let info = passes::PassInfo {
+            name: "constraint-duplication",
+            description: "Duplicate the first constraint",
+            level: None,
+        };
+
+        let duplicate_constraint = CustomPass::new(info, |mut p: ir::Prog<Bn128Field>| {
+            p.statements.push(p.statements[0].clone());
+            p
+        });
+
+        let artifacts: CompilationArtifacts<Bn128Field> = compile_with_passes(
+            source.clone(),
+            "./path/to/file".into(),
+            None::<&dyn Resolver<io::Error>>,
+            &CompileConfig::default().print_after("constraint-duplication"),
+            vec![duplicate_constraint],
+        )
+        .unwrap();
+
+        let report = artifacts
+            .pass_reports()
+            .iter()
+            .find(|r| r.name == "constraint-duplication")
+            .unwrap();
+        let (before, after) = report.constraints.unwrap();
+        assert_eq!(after, before + 1);
+        assert!(report.output.is_some());
+
+        // custom passes are only known to the compilation they are passed to
+        let res: Result<CompilationArtifacts<Bn128Field>, CompileErrors> = compile(
+            source,
+            "./path/to/file".into(),
+            None::<&dyn Resolver<io::Error>>,
+            &CompileConfig::default().print_after("constraint-duplication"),
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn lints() {
+        let source = r#"
+			from "EMBED" import unpack
+
+			const field UNUSED = 1
+			const field USED = 2
+
+			def foo(field a, field b) -> field:
+			   field c = a
+			   return USED
+
+			def main() -> field:
+			   u32 i = 0
+			   field e = foo(3, 4)
+			   for u32 i in 0..2 do
+			      field d = 1
+			   endfor
+			   return foo(1, 2)
+		"#
+        .to_string();
+
+        let warnings = check::<Bn128Field, io::Error>(
+            source.clone(),
+            "./path/to/file".into(),
+            None,
+            &CompileConfig::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            warnings
+                .0
+                .iter()
+                .map(|w| (w.value().lint(), w.value().message()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Lint::UnusedParameters, "Parameter `b` is never used"),
+                (Lint::UnusedVariables, "Variable `c` is never used"),
+                (
+                    Lint::Shadowing,
+                    "Loop variable `i` shadows a variable or a constant with the same name"
+                ),
+                (Lint::UnusedVariables, "Variable `i` is never used"),
+                (Lint::UnusedVariables, "Variable `d` is never used"),
+                (Lint::UnusedVariables, "Variable `i` is never used"),
+                (
+                    Lint::UnusedReturnValues,
+                    "Return value assigned to `e` is never used"
+                ),
+                (Lint::UnusedImports, "Import `unpack` is never used"),
+                (Lint::UnusedConstants, "Constant `UNUSED` is never used"),
+            ]
+        );
+
+        // allowed lints are not reported
+        let config = Lint::ALL
+            .iter()
+            .fold(CompileConfig::default(), |config, lint| {
+                config.lint(*lint, LintLevel::Allow)
+            })
+            .lint(Lint::UnusedConstants, LintLevel::Warn);
+
+        let warnings =
+            check::<Bn128Field, io::Error>(source.clone(), "./path/to/file".into(), None, &config)
+                .unwrap();
+        assert_eq!(warnings.0.len(), 1);
+
+        // denied lints are errors
+        let errors = check::<Bn128Field, io::Error>(
+            source,
+            "./path/to/file".into(),
+            None,
+            &config.lint(Lint::UnusedConstants, LintLevel::Deny),
+        )
+        .unwrap_err();
+        assert_eq!(errors.0.len(), 1);
+        assert!(errors.0[0]
+            .value()
+            .to_string()
+            .contains("Constant `UNUSED` is never used (`unused_constants`)"));
+    }
+
+    #[test]
+    fn lints_of_imported_modules() {
+        // only the main module is linted
+        struct CustomResolver;
+
+        impl<E> Resolver<E> for CustomResolver {
+            fn resolve(
+                &self,
+                _: PathBuf,
+                import_location: PathBuf,
+            ) -> Result<(String, PathBuf), E> {
+                assert_eq!(import_location.display().to_string(), "foo");
+                Ok((
+                    r#"
+from "EMBED" import unpack
+
+def foo(field a) -> field:
+    field b = 1
+    return 1
+"#
+                    .into(),
+                    "foo".into(),
+                ))
+            }
+        }
+
+        let source = r#"
+from "foo" import foo
+
+def main() -> field:
+    return foo(1)
+"#;
+
+        let warnings = check::<Bn128Field, io::Error>(
+            source.to_string(),
+            "main".into(),
+            Some(&CustomResolver),
+            &CompileConfig::default(),
+        )
+        .unwrap();
+        assert!(warnings.0.is_empty());
+    }
+
     mod abi {
         use super::*;
         use crate::typed_absy::abi::*;
@@ -9,7 +9,7 @@ use crate::flat_absy::{
 use crate::solvers::Solver;
 use crate::typed_absy::types::{
     ConcreteGenericsAssignment, DeclarationConstant, DeclarationSignature, DeclarationType,
-    GenericIdentifier,
+    GenericIdentifier, UBitwidth,
 };
 use std::collections::HashMap;
 use zokrates_field::Field;
@@ -38,10 +38,20 @@ pub enum FlatEmbed {
     U16ToBits,
     U32ToBits,
     U64ToBits,
+    U128ToBits,
     U8FromBits,
     U16FromBits,
     U32FromBits,
     U64FromBits,
+    U128FromBits,
+    I8ToBits,
+    I16ToBits,
+    I32ToBits,
+    I64ToBits,
+    I8FromBits,
+    I16FromBits,
+    I32FromBits,
+    I64FromBits,
     #[cfg(feature = "bellman")]
     Sha256Round,
     #[cfg(feature = "ark")]
@@ -102,6 +112,13 @@ impl FlatEmbed {
                     Expression::U32Constant(64).into(),
                 )
                 .into()]),
+            FlatEmbed::U128ToBits => UnresolvedSignature::new()
+                .inputs(vec![UnresolvedType::Uint(128).into()])
+                .outputs(vec![UnresolvedType::array(
+                    UnresolvedType::Boolean.into(),
+                    Expression::U32Constant(128).into(),
+                )
+                .into()]),
             FlatEmbed::U8FromBits => UnresolvedSignature::new()
                 .outputs(vec![UnresolvedType::Uint(8).into()])
                 .inputs(vec![UnresolvedType::array(
@@ -130,6 +147,69 @@ impl FlatEmbed {
                     Expression::U32Constant(64).into(),
                 )
                 .into()]),
+            FlatEmbed::U128FromBits => UnresolvedSignature::new()
+                .outputs(vec![UnresolvedType::Uint(128).into()])
+                .inputs(vec![UnresolvedType::array(
+                    UnresolvedType::Boolean.into(),
+                    Expression::U32Constant(128).into(),
+                )
+                .into()]),
+            FlatEmbed::I8ToBits => UnresolvedSignature::new()
+                .inputs(vec![UnresolvedType::Int(8).into()])
+                .outputs(vec![UnresolvedType::array(
+                    UnresolvedType::Boolean.into(),
+                    Expression::U32Constant(8).into(),
+                )
+                .into()]),
+            FlatEmbed::I16ToBits => UnresolvedSignature::new()
+                .inputs(vec![UnresolvedType::Int(16).into()])
+                .outputs(vec![UnresolvedType::array(
+                    UnresolvedType::Boolean.into(),
+                    Expression::U32Constant(16).into(),
+                )
+                .into()]),
+            FlatEmbed::I32ToBits => UnresolvedSignature::new()
+                .inputs(vec![UnresolvedType::Int(32).into()])
+                .outputs(vec![UnresolvedType::array(
+                    UnresolvedType::Boolean.into(),
+                    Expression::U32Constant(32).into(),
+                )
+                .into()]),
+            FlatEmbed::I64ToBits => UnresolvedSignature::new()
+                .inputs(vec![UnresolvedType::Int(64).into()])
+                .outputs(vec![UnresolvedType::array(
+                    UnresolvedType::Boolean.into(),
+                    Expression::U32Constant(64).into(),
+                )
+                .into()]),
+            FlatEmbed::I8FromBits => UnresolvedSignature::new()
+                .outputs(vec![UnresolvedType::Int(8).into()])
+                .inputs(vec![UnresolvedType::array(
+                    UnresolvedType::Boolean.into(),
+                    Expression::U32Constant(8).into(),
+                )
+                .into()]),
+            FlatEmbed::I16FromBits => UnresolvedSignature::new()
+                .outputs(vec![UnresolvedType::Int(16).into()])
+                .inputs(vec![UnresolvedType::array(
+                    UnresolvedType::Boolean.into(),
+                    Expression::U32Constant(16).into(),
+                )
+                .into()]),
+            FlatEmbed::I32FromBits => UnresolvedSignature::new()
+                .outputs(vec![UnresolvedType::Int(32).into()])
+                .inputs(vec![UnresolvedType::array(
+                    UnresolvedType::Boolean.into(),
+                    Expression::U32Constant(32).into(),
+                )
+                .into()]),
+            FlatEmbed::I64FromBits => UnresolvedSignature::new()
+                .outputs(vec![UnresolvedType::Int(64).into()])
+                .inputs(vec![UnresolvedType::array(
+                    UnresolvedType::Boolean.into(),
+                    Expression::U32Constant(64).into(),
+                )
+                .into()]),
             #[cfg(feature = "bellman")]
             FlatEmbed::Sha256Round => UnresolvedSignature::new()
                 .inputs(vec![
@@ -223,6 +303,12 @@ impl FlatEmbed {
                     DeclarationType::Boolean,
                     64u32,
                 ))]),
+            FlatEmbed::U128ToBits => DeclarationSignature::new()
+                .inputs(vec![DeclarationType::uint(128)])
+                .outputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    128u32,
+                ))]),
             FlatEmbed::U8FromBits => DeclarationSignature::new()
                 .outputs(vec![DeclarationType::uint(8)])
                 .inputs(vec![DeclarationType::array((
@@ -247,6 +333,60 @@ impl FlatEmbed {
                     DeclarationType::Boolean,
                     64u32,
                 ))]),
+            FlatEmbed::U128FromBits => DeclarationSignature::new()
+                .outputs(vec![DeclarationType::uint(128)])
+                .inputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    128u32,
+                ))]),
+            FlatEmbed::I8ToBits => DeclarationSignature::new()
+                .inputs(vec![DeclarationType::uint(UBitwidth::I8)])
+                .outputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    8u32,
+                ))]),
+            FlatEmbed::I16ToBits => DeclarationSignature::new()
+                .inputs(vec![DeclarationType::uint(UBitwidth::I16)])
+                .outputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    16u32,
+                ))]),
+            FlatEmbed::I32ToBits => DeclarationSignature::new()
+                .inputs(vec![DeclarationType::uint(UBitwidth::I32)])
+                .outputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    32u32,
+                ))]),
+            FlatEmbed::I64ToBits => DeclarationSignature::new()
+                .inputs(vec![DeclarationType::uint(UBitwidth::I64)])
+                .outputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    64u32,
+                ))]),
+            FlatEmbed::I8FromBits => DeclarationSignature::new()
+                .outputs(vec![DeclarationType::uint(UBitwidth::I8)])
+                .inputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    8u32,
+                ))]),
+            FlatEmbed::I16FromBits => DeclarationSignature::new()
+                .outputs(vec![DeclarationType::uint(UBitwidth::I16)])
+                .inputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    16u32,
+                ))]),
+            FlatEmbed::I32FromBits => DeclarationSignature::new()
+                .outputs(vec![DeclarationType::uint(UBitwidth::I32)])
+                .inputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    32u32,
+                ))]),
+            FlatEmbed::I64FromBits => DeclarationSignature::new()
+                .outputs(vec![DeclarationType::uint(UBitwidth::I64)])
+                .inputs(vec![DeclarationType::array((
+                    DeclarationType::Boolean,
+                    64u32,
+                ))]),
             #[cfg(feature = "bellman")]
             FlatEmbed::Sha256Round => DeclarationSignature::new()
                 .inputs(vec![
@@ -302,10 +442,20 @@ impl FlatEmbed {
             FlatEmbed::U16ToBits => "_U16_TO_BITS",
             FlatEmbed::U32ToBits => "_U32_TO_BITS",
             FlatEmbed::U64ToBits => "_U64_TO_BITS",
+            FlatEmbed::U128ToBits => "_U128_TO_BITS",
             FlatEmbed::U8FromBits => "_U8_FROM_BITS",
             FlatEmbed::U16FromBits => "_U16_FROM_BITS",
             FlatEmbed::U32FromBits => "_U32_FROM_BITS",
             FlatEmbed::U64FromBits => "_U64_FROM_BITS",
+            FlatEmbed::U128FromBits => "_U128_FROM_BITS",
+            FlatEmbed::I8ToBits => "_I8_TO_BITS",
+            FlatEmbed::I16ToBits => "_I16_TO_BITS",
+            FlatEmbed::I32ToBits => "_I32_TO_BITS",
+            FlatEmbed::I64ToBits => "_I64_TO_BITS",
+            FlatEmbed::I8FromBits => "_I8_FROM_BITS",
+            FlatEmbed::I16FromBits => "_I16_FROM_BITS",
+            FlatEmbed::I32FromBits => "_I32_FROM_BITS",
+            FlatEmbed::I64FromBits => "_I64_FROM_BITS",
             #[cfg(feature = "bellman")]
             FlatEmbed::Sha256Round => "_SHA256_ROUND",
             #[cfg(feature = "ark")]
@@ -313,6 +463,22 @@ impl FlatEmbed {
         }
     }
 
+    /// Signed integers share the representation of unsigned ones, so converting them to and from bits is
+    /// done by the unsigned embed of the same bitwidth
+    pub fn to_unsigned(self) -> Self {
+        match self {
+            FlatEmbed::I8ToBits => FlatEmbed::U8ToBits,
+            FlatEmbed::I16ToBits => FlatEmbed::U16ToBits,
+            FlatEmbed::I32ToBits => FlatEmbed::U32ToBits,
+            FlatEmbed::I64ToBits => FlatEmbed::U64ToBits,
+            FlatEmbed::I8FromBits => FlatEmbed::U8FromBits,
+            FlatEmbed::I16FromBits => FlatEmbed::U16FromBits,
+            FlatEmbed::I32FromBits => FlatEmbed::U32FromBits,
+            FlatEmbed::I64FromBits => FlatEmbed::U64FromBits,
+            e => e,
+        }
+    }
+
     /// Actually get the `FlatFunction` that this `FlatEmbed` represents
     pub fn synthetize<T: Field>(&self, generics: &[u32]) -> FlatFunction<T> {
         match self {
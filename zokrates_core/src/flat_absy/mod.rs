@@ -7,13 +7,16 @@
 
 pub mod flat_parameter;
 pub mod flat_variable;
+pub mod source_span;
 
 pub use self::flat_parameter::FlatParameter;
 pub use self::flat_variable::FlatVariable;
+pub use self::source_span::{CallFrame, Position, SourceSpan};
 
 use serde::{Deserialize, Serialize};
 
 use crate::solvers::Solver;
+use crate::typed_absy::{ConcreteType, FormatString};
 use std::collections::HashMap;
 use std::fmt;
 use zokrates_field::Field;
@@ -47,6 +50,7 @@ pub enum RuntimeError {
     SourceAssertion(String),
     ArgumentBitness,
     SelectRangeCheck,
+    EnumTagRangeCheck,
 }
 
 impl RuntimeError {
@@ -55,7 +59,12 @@ impl RuntimeError {
 
         !matches!(
             self,
-            SourceAssertion(_) | Inverse | LtSum | SelectRangeCheck | ArgumentBitness
+            SourceAssertion(_)
+                | Inverse
+                | LtSum
+                | SelectRangeCheck
+                | ArgumentBitness
+                | EnumTagRangeCheck
         )
     }
 }
@@ -92,6 +101,7 @@ impl fmt::Display for RuntimeError {
             SourceAssertion(m) => m.as_str(),
             ArgumentBitness => "Argument bitness check failed",
             SelectRangeCheck => "Out of bounds array access",
+            EnumTagRangeCheck => "Invalid enum variant",
         };
 
         write!(f, "{}", msg)
@@ -174,6 +184,13 @@ pub enum FlatStatement<T: Field> {
     Condition(FlatExpression<T>, FlatExpression<T>, RuntimeError),
     Definition(FlatVariable, FlatExpression<T>),
     Directive(FlatDirective<T>),
+    /// Logs the values, if all the conditions of the branches containing the statement hold
+    Log(
+        FormatString,
+        Vec<(ConcreteType, Vec<FlatExpression<T>>)>,
+        Vec<FlatExpression<T>>,
+    ),
+    Span(SourceSpan),
 }
 
 impl<T: Field> fmt::Display for FlatStatement<T> {
@@ -185,6 +202,29 @@ impl<T: Field> fmt::Display for FlatStatement<T> {
                 write!(f, "{} == {} // {}", lhs, rhs, message)
             }
             FlatStatement::Directive(ref d) => write!(f, "{}", d),
+            FlatStatement::Log(ref format_string, ref expressions, ref conditions) => {
+                write!(
+                    f,
+                    "log(\"{}\", {})",
+                    format_string,
+                    expressions
+                        .iter()
+                        .map(|(_, e)| format!(
+                            "[{}]",
+                            e.iter()
+                                .map(|e| e.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )?;
+                for condition in conditions {
+                    write!(f, " if {} == 1", condition)?;
+                }
+                Ok(())
+            }
+            FlatStatement::Span(ref span) => write!(f, "// {}", span),
         }
     }
 }
@@ -198,6 +238,12 @@ impl<T: Field> fmt::Debug for FlatStatement<T> {
                 write!(f, "FlatCondition({:?}, {:?}, {:?})", lhs, rhs, error)
             }
             FlatStatement::Directive(ref d) => write!(f, "{:?}", d),
+            FlatStatement::Log(ref format_string, ref expressions, ref conditions) => write!(
+                f,
+                "FlatLog({:?}, {:?}, {:?})",
+                format_string, expressions, conditions
+            ),
+            FlatStatement::Span(ref span) => write!(f, "FlatSpan({:?})", span),
         }
     }
 }
@@ -236,6 +282,25 @@ impl<T: Field> FlatStatement<T> {
                     ..d
                 })
             }
+            FlatStatement::Log(format_string, expressions, conditions) => FlatStatement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .map(|(t, e)| {
+                        (
+                            t,
+                            e.into_iter()
+                                .map(|e| e.apply_substitution(substitution))
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+                conditions
+                    .into_iter()
+                    .map(|c| c.apply_substitution(substitution))
+                    .collect(),
+            ),
+            FlatStatement::Span(span) => FlatStatement::Span(span),
         }
     }
 }
@@ -0,0 +1,61 @@
+pub use crate::parser::Position;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The region of the source code a statement was compiled from
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SourceSpan {
+    pub file: String,
+    pub start: Position,
+    pub end: Position,
+    /// The functions which were inlined to reach this statement, outermost first. Empty for statements of the entry point.
+    pub call_stack: Vec<CallFrame>,
+}
+
+/// A function on the call stack of a statement
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct CallFrame {
+    pub module: String,
+    pub function: String,
+}
+
+impl CallFrame {
+    pub fn new<S: Into<String>, U: Into<String>>(module: S, function: U) -> Self {
+        CallFrame {
+            module: module.into(),
+            function: function.into(),
+        }
+    }
+
+    /// The frame of a call to an embedded function, which are imported from the `EMBED` module
+    pub fn embed(id: &str) -> Self {
+        CallFrame::new("EMBED", id)
+    }
+
+    pub fn is_embed(&self) -> bool {
+        self.module == "EMBED"
+    }
+}
+
+impl fmt::Display for CallFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.function, self.module)
+    }
+}
+
+impl SourceSpan {
+    pub fn new(file: String, start: Position, end: Position) -> Self {
+        SourceSpan {
+            file,
+            start,
+            end,
+            call_stack: vec![],
+        }
+    }
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.start)
+    }
+}
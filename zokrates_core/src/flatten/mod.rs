@@ -35,6 +35,8 @@ pub struct Flattener<'ast, T: Field> {
     bits_cache: HashMap<FlatExpression<T>, Vec<FlatExpression<T>>>,
     /// Cached flattened conditions for branches
     condition_cache: HashMap<BooleanExpression<'ast, T>, FlatVariable>,
+    /// Source span of the statement being flattened
+    span: Option<SourceSpan>,
 }
 
 trait FlattenOutput<T: Field>: Sized {
@@ -151,6 +153,7 @@ impl From<crate::zir::RuntimeError> for RuntimeError {
         match error {
             crate::zir::RuntimeError::SourceAssertion(s) => RuntimeError::SourceAssertion(s),
             crate::zir::RuntimeError::SelectRangeCheck => RuntimeError::SelectRangeCheck,
+            crate::zir::RuntimeError::EnumTagRangeCheck => RuntimeError::EnumTagRangeCheck,
         }
     }
 }
@@ -169,6 +172,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
             layout: HashMap::new(),
             bits_cache: HashMap::new(),
             condition_cache: HashMap::new(),
+            span: None,
         }
     }
 
@@ -431,6 +435,10 @@ impl<'ast, T: Field> Flattener<'ast, T> {
 
                     output
                 }
+                FlatStatement::Log(format_string, expressions, mut conditions) => {
+                    conditions.push(condition.clone());
+                    vec![FlatStatement::Log(format_string, expressions, conditions)]
+                }
                 s => vec![s],
             })
             .collect()
@@ -999,11 +1007,20 @@ impl<'ast, T: Field> Flattener<'ast, T> {
             })
             .collect();
 
-        match embed {
+        // attribute the statements of the embed to a frame of its own
+        let span = self.span.clone();
+        if let Some(span) = span.clone() {
+            let mut call_stack = span.call_stack.clone();
+            call_stack.push(CallFrame::embed(embed.id()));
+            statements_flattened.push(FlatStatement::Span(SourceSpan { call_stack, ..span }));
+        }
+
+        let res = match embed {
             FlatEmbed::U8ToBits => self.u_to_bits(params.pop().unwrap(), 8.into()),
             FlatEmbed::U16ToBits => self.u_to_bits(params.pop().unwrap(), 16.into()),
             FlatEmbed::U32ToBits => self.u_to_bits(params.pop().unwrap(), 32.into()),
             FlatEmbed::U64ToBits => self.u_to_bits(params.pop().unwrap(), 64.into()),
+            FlatEmbed::U128ToBits => self.u_to_bits(params.pop().unwrap(), 128.into()),
             FlatEmbed::U8FromBits => {
                 vec![self.bits_to_u(params, 8.into())]
             }
@@ -1016,6 +1033,9 @@ impl<'ast, T: Field> Flattener<'ast, T> {
             FlatEmbed::U64FromBits => {
                 vec![self.bits_to_u(params, 64.into())]
             }
+            FlatEmbed::U128FromBits => {
+                vec![self.bits_to_u(params, 128.into())]
+            }
             FlatEmbed::BitArrayLe => {
                 // get the length of the bit arrays
                 let len = generics[0];
@@ -1118,6 +1138,9 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                                 inputs: new_inputs,
                             })
                         }
+                        s @ FlatStatement::Log(..) | s @ FlatStatement::Span(..) => {
+                            s.apply_substitution(&replacement_map)
+                        }
                     })
                     .collect();
 
@@ -1133,7 +1156,11 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     _ => unreachable!(),
                 }
             }
-        }
+        };
+
+        statements_flattened.extend(span.map(FlatStatement::Span));
+
+        res
     }
 
     /// Flattens an expression
@@ -1275,7 +1302,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         }));
 
         // q in range
-        let _ = self.get_bits(
+        let q_bits = self.get_bits(
             &FlatUExpression::with_field(FlatExpression::from(q)),
             target_bitwidth.to_usize(),
             target_bitwidth,
@@ -1294,19 +1321,56 @@ impl<'ast, T: Field> Flattener<'ast, T> {
         let _ = self.get_bits(
             &FlatUExpression::with_field(FlatExpression::Add(
                 box FlatExpression::Sub(box r.into(), box d.clone()),
-                box FlatExpression::Number(T::from(2_u128.pow(target_bitwidth.to_usize() as u32))),
+                box FlatExpression::Number(T::from(2).pow(target_bitwidth.to_usize())),
             )),
             target_bitwidth.to_usize(),
             target_bitwidth,
             statements_flattened,
         );
 
-        // q*d == n - r
-        statements_flattened.push(FlatStatement::Condition(
-            FlatExpression::Sub(box n, box r.into()),
-            FlatExpression::Mult(box q.into(), box d),
-            RuntimeError::Euclidean,
-        ));
+        let range_max = T::from(2).pow(target_bitwidth.to_usize()) - T::from(1);
+
+        match range_max.checked_mul(&range_max) {
+            // q*d == n - r
+            Some(_) => statements_flattened.push(FlatStatement::Condition(
+                FlatExpression::Sub(box n, box r.into()),
+                FlatExpression::Mult(box q.into(), box d),
+                RuntimeError::Euclidean,
+            )),
+            // q*d may not fit in the field, so we split q in two limbs and check
+            // q_low*d == n - r - 2**half * q_high*d  where  q_high*d < 2**(target_bitwidth - half)
+            None => {
+                let half = target_bitwidth.to_usize() / 2;
+
+                // bits are big endian
+                let mut q_high = q_bits;
+                let q_low = q_high.split_off(q_high.len() - half);
+
+                let high = self.define(
+                    FlatExpression::Mult(box flat_expression_from_bits(q_high), box d.clone()),
+                    statements_flattened,
+                );
+
+                let _ = self.get_bits(
+                    &FlatUExpression::with_field(FlatExpression::from(high)),
+                    target_bitwidth.to_usize() - half,
+                    (target_bitwidth.to_usize() - half).into(),
+                    statements_flattened,
+                );
+
+                statements_flattened.push(FlatStatement::Condition(
+                    FlatExpression::Sub(
+                        box FlatExpression::Sub(box n, box r.into()),
+                        box FlatExpression::Mult(
+                            box FlatExpression::Number(T::from(2).pow(half)),
+                            box high.into(),
+                        ),
+                    ),
+                    FlatExpression::Mult(box flat_expression_from_bits(q_low), box d),
+                    RuntimeError::Euclidean,
+                ));
+            }
+        }
 
         (q.into(), r.into())
     }
@@ -1465,35 +1529,76 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                 )
             }
             UExpressionInner::Mult(box left, box right) => {
-                let left_flattened = self
-                    .flatten_uint_expression(statements_flattened, left)
-                    .get_field_unchecked();
-                let right_flattened = self
-                    .flatten_uint_expression(statements_flattened, right)
-                    .get_field_unchecked();
-                let new_left = if left_flattened.is_linear() {
-                    left_flattened
-                } else {
-                    let id = self.use_sym();
-                    statements_flattened.push(FlatStatement::Definition(id, left_flattened));
-                    FlatExpression::Identifier(id)
-                };
-                let new_right = if right_flattened.is_linear() {
-                    right_flattened
+                let left_flattened = self.flatten_uint_expression(statements_flattened, left);
+                let right_flattened = self.flatten_uint_expression(statements_flattened, right);
+
+                let range_max = T::from(2).pow(target_bitwidth.to_usize()) - T::from(1);
+
+                // if the product of two reduced terms does not fit in the field, multiply by limbs (see uint optimizer)
+                let by_limbs = range_max.checked_mul(&range_max).is_none()
+                    && left_flattened.bits.is_some()
+                    && right_flattened.bits.is_some();
+
+                if by_limbs {
+                    // left * right = left_low * right + 2**half * left_high * right_low  mod 2**target_bitwidth
+                    let half = target_bitwidth.to_usize() / 2;
+
+                    // bits are big endian
+                    let mut left_high = left_flattened.bits.unwrap();
+                    let left_low = left_high.split_off(left_high.len() - half);
+                    let right = right_flattened.bits.unwrap();
+                    let right_low = right[right.len() - half..].to_vec();
+
+                    let low = self.define(
+                        FlatExpression::Mult(
+                            box flat_expression_from_bits(left_low),
+                            box flat_expression_from_bits(right),
+                        ),
+                        statements_flattened,
+                    );
+
+                    let high = self.define(
+                        FlatExpression::Mult(
+                            box flat_expression_from_bits(left_high),
+                            box flat_expression_from_bits(right_low),
+                        ),
+                        statements_flattened,
+                    );
+
+                    FlatUExpression::with_field(FlatExpression::Add(
+                        box low.into(),
+                        box FlatExpression::Mult(
+                            box FlatExpression::Number(T::from(2).pow(half)),
+                            box high.into(),
+                        ),
+                    ))
                 } else {
-                    let id = self.use_sym();
-                    statements_flattened.push(FlatStatement::Definition(id, right_flattened));
-                    FlatExpression::Identifier(id)
-                };
+                    let left_flattened = left_flattened.get_field_unchecked();
+                    let right_flattened = right_flattened.get_field_unchecked();
+                    let new_left = if left_flattened.is_linear() {
+                        left_flattened
+                    } else {
+                        let id = self.use_sym();
+                        statements_flattened.push(FlatStatement::Definition(id, left_flattened));
+                        FlatExpression::Identifier(id)
+                    };
+                    let new_right = if right_flattened.is_linear() {
+                        right_flattened
+                    } else {
+                        let id = self.use_sym();
+                        statements_flattened.push(FlatStatement::Definition(id, right_flattened));
+                        FlatExpression::Identifier(id)
+                    };
 
-                let res = self.use_sym();
+                    let res = self.use_sym();
 
-                statements_flattened.push(FlatStatement::Definition(
-                    res,
-                    FlatExpression::Mult(box new_left, box new_right),
-                ));
+                    statements_flattened.push(FlatStatement::Definition(
+                        res,
+                        FlatExpression::Mult(box new_left, box new_right),
+                    ));
 
-                FlatUExpression::with_field(FlatExpression::Identifier(res))
+                    FlatUExpression::with_field(FlatExpression::Identifier(res))
+                }
             }
             UExpressionInner::Div(box left, box right) => {
                 let (q, _) =
@@ -2172,7 +2277,7 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     expressions: flat_expressions,
                 }));
             }
-            ZirStatement::IfElse(condition, consequence, alternative) => {
+            ZirStatement::IfElse(condition, consequence, alternative, kind) => {
                 let condition_flat =
                     self.flatten_boolean_expression(statements_flattened, condition.clone());
 
@@ -2181,7 +2286,14 @@ impl<'ast, T: Field> Flattener<'ast, T> {
 
                 self.condition_cache.insert(condition, condition_id);
 
-                if self.config.isolate_branches {
+                // the branches of an if/else statement are only logically run if they are taken, so
+                // their assertions are made conditional regardless of the `isolate_branches` option
+                let isolate = match kind {
+                    IfElseKind::Statement => true,
+                    IfElseKind::Expression => self.config.isolate_branches,
+                };
+
+                if isolate {
                     let mut consequence_statements = vec![];
                     let mut alternative_statements = vec![];
 
@@ -2310,6 +2422,52 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                     }
                 }
             }
+            ZirStatement::Log(format_string, expressions) => {
+                // logged values are only needed when computing the witness, so they must not add
+                // constraints: the caches are restored afterwards so that no value computed here
+                // is reused by the rest of the program
+                let bits_cache = self.bits_cache.clone();
+                let condition_cache = self.condition_cache.clone();
+
+                let mut log_statements = vec![];
+
+                let expressions = expressions
+                    .into_iter()
+                    .map(|(t, e)| {
+                        (
+                            t,
+                            e.into_iter()
+                                .map(|e| {
+                                    self.flatten_expression(&mut log_statements, e)
+                                        .get_field_unchecked()
+                                })
+                                .collect(),
+                        )
+                    })
+                    .collect();
+
+                self.bits_cache = bits_cache;
+                self.condition_cache = condition_cache;
+
+                // definitions are turned into directives, and checks are dropped
+                statements_flattened.extend(log_statements.into_iter().filter_map(|s| match s {
+                    FlatStatement::Definition(v, e) => {
+                        Some(FlatStatement::Directive(FlatDirective {
+                            inputs: vec![e, FlatExpression::Number(T::one())],
+                            outputs: vec![v],
+                            solver: Solver::Div,
+                        }))
+                    }
+                    FlatStatement::Condition(..) => None,
+                    s => Some(s),
+                }));
+
+                statements_flattened.push(FlatStatement::Log(format_string, expressions, vec![]));
+            }
+            ZirStatement::Span(span) => {
+                self.span = Some(span.clone());
+                statements_flattened.push(FlatStatement::Span(span))
+            }
             ZirStatement::MultipleDefinition(vars, rhs) => {
                 // flatten the right side to p = sum(var_i.type.primitive_count) expressions
                 // define p new variables to the right side expressions
@@ -2344,7 +2502,8 @@ impl<'ast, T: Field> Flattener<'ast, T> {
                             .collect();
 
                         match embed {
-                            FlatEmbed::U64FromBits
+                            FlatEmbed::U128FromBits
+                            | FlatEmbed::U64FromBits
                             | FlatEmbed::U32FromBits
                             | FlatEmbed::U16FromBits
                             | FlatEmbed::U8FromBits => {
@@ -3321,6 +3480,63 @@ mod tests {
         flattener.flatten_field_expression(&mut FlatStatements::new(), expression);
     }
 
+    #[test]
+    fn if_else_statement_isolation() {
+        // def main():
+        //     field x = 1
+        //     if x == 1 then assert(x == 2) fi
+
+        let function = |kind| ZirFunction {
+            arguments: vec![],
+            statements: vec![
+                ZirStatement::Definition(
+                    Variable::field_element("x"),
+                    FieldElementExpression::Number(Bn128Field::from(1)).into(),
+                ),
+                ZirStatement::IfElse(
+                    BooleanExpression::FieldEq(
+                        box FieldElementExpression::Identifier("x".into()),
+                        box FieldElementExpression::Number(Bn128Field::from(1)),
+                    ),
+                    vec![ZirStatement::Assertion(
+                        BooleanExpression::FieldEq(
+                            box FieldElementExpression::Identifier("x".into()),
+                            box FieldElementExpression::Number(Bn128Field::from(2)),
+                        ),
+                        zir::RuntimeError::mock(),
+                    )],
+                    vec![],
+                    kind,
+                ),
+            ],
+            signature: Signature {
+                inputs: vec![],
+                outputs: vec![],
+            },
+        };
+
+        let isolated = |kind| {
+            let config = CompileConfig::default();
+            let mut flattener = Flattener::new(&config);
+
+            flattener
+                .flatten_function(function(kind))
+                .statements
+                .iter()
+                .any(|s| {
+                    matches!(
+                        s,
+                        FlatStatement::Condition(_, _, RuntimeError::BranchIsolation)
+                    )
+                })
+        };
+
+        // the branches of if/else statements are always isolated
+        assert!(isolated(IfElseKind::Statement));
+        // the side statements of conditional expressions are only isolated with `isolate_branches`
+        assert!(!isolated(IfElseKind::Expression));
+    }
+
     #[test]
     fn geq_leq() {
         let config = CompileConfig::default();
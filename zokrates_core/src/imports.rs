@@ -152,6 +152,10 @@ impl Importer {
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::BitArrayLe),
                 },
+                "u128_to_bits" => SymbolDeclaration {
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::U128ToBits),
+                },
                 "u64_to_bits" => SymbolDeclaration {
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U64ToBits),
@@ -168,6 +172,10 @@ impl Importer {
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U8ToBits),
                 },
+                "u128_from_bits" => SymbolDeclaration {
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::U128FromBits),
+                },
                 "u64_from_bits" => SymbolDeclaration {
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U64FromBits),
@@ -184,6 +192,38 @@ impl Importer {
                     id: symbol.get_alias(),
                     symbol: Symbol::Flat(FlatEmbed::U8FromBits),
                 },
+                "i64_to_bits" => SymbolDeclaration {
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I64ToBits),
+                },
+                "i32_to_bits" => SymbolDeclaration {
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I32ToBits),
+                },
+                "i16_to_bits" => SymbolDeclaration {
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I16ToBits),
+                },
+                "i8_to_bits" => SymbolDeclaration {
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I8ToBits),
+                },
+                "i64_from_bits" => SymbolDeclaration {
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I64FromBits),
+                },
+                "i32_from_bits" => SymbolDeclaration {
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I32FromBits),
+                },
+                "i16_from_bits" => SymbolDeclaration {
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I16FromBits),
+                },
+                "i8_from_bits" => SymbolDeclaration {
+                    id: symbol.get_alias(),
+                    symbol: Symbol::Flat(FlatEmbed::I8FromBits),
+                },
                 "FIELD_SIZE_IN_BITS" => SymbolDeclaration {
                     id: symbol.get_alias(),
                     symbol: Symbol::Here(SymbolDefinition::Constant(
@@ -0,0 +1,293 @@
+use crate::flat_absy::FlatVariable;
+use crate::ir::smtlib2::{get_values, EquivalenceQuery, SatResult, SmtSolver};
+use crate::ir::{Interpreter, Prog};
+use crate::proof_system::Seed;
+use num_bigint::BigUint;
+use rand_0_7::rngs::StdRng;
+use rand_0_7::{Rng, SeedableRng};
+use std::convert::TryFrom;
+use zokrates_field::Field;
+
+/// Evidence that two programs are not equivalent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Counterexample<T> {
+    /// Public arguments and return values which are accepted by exactly one of the programs
+    Relation {
+        public_arguments: Vec<T>,
+        returns: Vec<T>,
+    },
+    /// Arguments on which executing the programs gives different return values or errors
+    Execution {
+        arguments: Vec<T>,
+        left: Result<Vec<T>, String>,
+        right: Result<Vec<T>, String>,
+    },
+}
+
+/// The outcome of an equivalence check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Equivalence<T> {
+    /// The SMT solver proved that the programs define the same relation
+    Proven,
+    Counterexample(Counterexample<T>),
+    /// No counterexample was found, but the equivalence could not be proven
+    Unknown,
+}
+
+/// Returns a random field element, biased towards small values so that arguments expected to be
+/// booleans or integers are hit often enough
+fn random_value<T: Field, R: Rng>(rng: &mut R) -> T {
+    match rng.gen_range(0, 4) {
+        0 => T::from(rng.gen_range(0u32, 2)),
+        1 => T::from(rng.gen::<u32>()),
+        2 => T::from(rng.gen::<u128>()),
+        _ => {
+            let bytes: Vec<u8> = (0..(T::get_required_bits() + 7) / 8)
+                .map(|_| rng.gen())
+                .collect();
+            let modulus = T::max_value().to_biguint() + 1usize;
+            T::try_from(BigUint::from_bytes_le(&bytes) % modulus).unwrap()
+        }
+    }
+}
+
+impl<T: Field> Prog<T> {
+    /// Checks whether `self` and `other` accept the same public arguments and return values
+    ///
+    /// If `solver` is provided, it is asked for public arguments and return values accepted by
+    /// exactly one of the programs. If it cannot decide, both programs are executed on `runs`
+    /// random arguments, looking for different results.
+    pub fn equivalence(
+        &self,
+        other: &Prog<T>,
+        solver: Option<&dyn SmtSolver>,
+        runs: usize,
+        seed: Option<Seed>,
+    ) -> Result<Equivalence<T>, String> {
+        let visibilities = |p: &Prog<T>| p.arguments.iter().map(|a| a.private).collect::<Vec<_>>();
+
+        if visibilities(self) != visibilities(other) || self.returns.len() != other.returns.len() {
+            return Err(format!(
+                "The programs have different signatures: {} arguments ({} private) and {} return values against {} arguments ({} private) and {} return values",
+                self.arguments.len(),
+                self.arguments.iter().filter(|a| a.private).count(),
+                self.returns.len(),
+                other.arguments.len(),
+                other.arguments.iter().filter(|a| a.private).count(),
+                other.returns.len()
+            ));
+        }
+
+        if let Some(solver) = solver {
+            let query = EquivalenceQuery {
+                left: self,
+                right: other,
+            };
+            let output = solver.run(&query.to_string())?;
+
+            match SatResult::from_output(&output)? {
+                SatResult::Unsat => return Ok(Equivalence::Proven),
+                SatResult::Sat => {
+                    let values = get_values::<T>(&output)?;
+                    let value = |v: &FlatVariable| {
+                        values
+                            .get(&v.to_string())
+                            .cloned()
+                            .ok_or_else(|| format!("The SMT solver did not give a value for {}", v))
+                    };
+
+                    return Ok(Equivalence::Counterexample(Counterexample::Relation {
+                        public_arguments: self
+                            .arguments
+                            .iter()
+                            .filter(|a| !a.private)
+                            .map(|a| value(&a.id))
+                            .collect::<Result<_, _>>()?,
+                        returns: self.returns.iter().map(value).collect::<Result<_, _>>()?,
+                    }));
+                }
+                SatResult::Unknown => {}
+            }
+        }
+
+        let mut rng = match seed {
+            Some(seed) => StdRng::from_seed(seed),
+            None => StdRng::from_entropy(),
+        };
+        let interpreter = Interpreter::default();
+
+        for _ in 0..runs {
+            let arguments: Vec<T> = self
+                .arguments
+                .iter()
+                .map(|_| random_value(&mut rng))
+                .collect();
+
+            let execute = |p: &Prog<T>| {
+                interpreter
+                    .execute(p, &arguments)
+                    .map(|w| w.return_values())
+                    .map_err(|e| e.to_string())
+            };
+
+            let (left, right) = (execute(self), execute(other));
+
+            let agree = match (&left, &right) {
+                (Ok(left), Ok(right)) => left == right,
+                (Err(_), Err(_)) => true,
+                _ => false,
+            };
+
+            if !agree {
+                return Ok(Equivalence::Counterexample(Counterexample::Execution {
+                    arguments,
+                    left,
+                    right,
+                }));
+            }
+        }
+
+        Ok(Equivalence::Unknown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::FlatParameter;
+    use crate::ir::{LinComb, QuadComb, Statement};
+    use zokrates_field::Bn128Field;
+
+    struct Answer(&'static str);
+
+    impl SmtSolver for Answer {
+        fn run(&self, query: &str) -> Result<String, String> {
+            assert!(query.contains("(assert (distinct"));
+            Ok(self.0.to_string())
+        }
+    }
+
+    // def main(field a) -> field:
+    //     return a * a
+    fn square() -> Prog<Bn128Field> {
+        let a = FlatVariable::new(0);
+        Prog {
+            arguments: vec![FlatParameter::public(a)],
+            statements: vec![Statement::definition(
+                FlatVariable::public(0),
+                QuadComb::from_linear_combinations(a.into(), a.into()),
+            )],
+            returns: vec![FlatVariable::public(0)],
+        }
+    }
+
+    // def main(field a) -> field:
+    //     field b = a + 1
+    //     field c = b * a
+    //     return c - a
+    fn square_with_intermediate() -> Prog<Bn128Field> {
+        let a = FlatVariable::new(0);
+        let b = FlatVariable::new(1);
+        let c = FlatVariable::new(2);
+        Prog {
+            arguments: vec![FlatParameter::public(a)],
+            statements: vec![
+                Statement::definition(b, LinComb::from(a) + LinComb::one()),
+                Statement::definition(c, QuadComb::from_linear_combinations(b.into(), a.into())),
+                Statement::definition(FlatVariable::public(0), LinComb::from(c) - LinComb::from(a)),
+            ],
+            returns: vec![FlatVariable::public(0)],
+        }
+    }
+
+    // def main(field a) -> field:
+    //     return a
+    fn identity() -> Prog<Bn128Field> {
+        let a = FlatVariable::new(0);
+        Prog {
+            arguments: vec![FlatParameter::public(a)],
+            statements: vec![Statement::definition(FlatVariable::public(0), a)],
+            returns: vec![FlatVariable::public(0)],
+        }
+    }
+
+    #[test]
+    fn random_testing() {
+        let seed = Some([0; 32]);
+
+        assert_eq!(
+            square().equivalence(&square_with_intermediate(), None, 100, seed),
+            Ok(Equivalence::Unknown)
+        );
+
+        match square().equivalence(&identity(), None, 100, seed) {
+            Ok(Equivalence::Counterexample(Counterexample::Execution {
+                arguments,
+                left,
+                right,
+            })) => {
+                assert_eq!(left, Ok(vec![arguments[0].clone() * &arguments[0]]));
+                assert_eq!(right, Ok(arguments));
+            }
+            r => panic!("expected a counterexample, found {:?}", r),
+        }
+    }
+
+    #[test]
+    fn smt() {
+        assert_eq!(
+            square().equivalence(&square_with_intermediate(), Some(&Answer("unsat")), 0, None),
+            Ok(Equivalence::Proven)
+        );
+
+        assert_eq!(
+            square().equivalence(
+                &identity(),
+                Some(&Answer("sat\n((|_0| 2)\n (|~out_0| (- 1)))")),
+                0,
+                None
+            ),
+            Ok(Equivalence::Counterexample(Counterexample::Relation {
+                public_arguments: vec![Bn128Field::from(2)],
+                returns: vec![Bn128Field::from(-1)],
+            }))
+        );
+
+        // an undecided query falls back to random testing
+        assert_eq!(
+            square().equivalence(
+                &square_with_intermediate(),
+                Some(&Answer("unknown")),
+                10,
+                None
+            ),
+            Ok(Equivalence::Unknown)
+        );
+    }
+
+    #[test]
+    fn different_signatures() {
+        let private = Prog {
+            arguments: vec![FlatParameter::private(FlatVariable::new(0))],
+            ..square()
+        };
+
+        assert!(square().equivalence(&private, None, 10, None).is_err());
+    }
+
+    #[test]
+    fn query() {
+        let query = EquivalenceQuery {
+            left: &square(),
+            right: &square_with_intermediate(),
+        }
+        .to_string();
+
+        // the intermediate variables of the right program are renamed and quantified, the public
+        // argument and the return value are shared
+        assert!(query.contains("(declare-const |_0| Int)"));
+        assert!(query.contains("(declare-const |~out_0| Int)"));
+        assert!(query.contains("(exists ((|_3| Int)(|_4| Int))"));
+        assert!(query.ends_with("(check-sat)\n(get-value ( |_0| |~out_0|))"));
+    }
+}
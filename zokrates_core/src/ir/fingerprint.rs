@@ -0,0 +1,162 @@
+//! Module containing the fingerprint of a program, a hash of the parts of the program which keys
+//! depend on: its curve, its arguments, its constraints and its return values.
+//!
+//! Directives, logs and source spans only matter when computing the witness, so programs which
+//! only differ by those have the same fingerprint.
+
+use crate::flat_absy::{FlatParameter, FlatVariable};
+use crate::ir::{CanonicalLinComb, Prog, Statement};
+use bincode::{serialize_into, Infinite};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+use zokrates_field::Field;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint(pub [u8; 32]);
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.0))
+    }
+}
+
+impl FromStr for Fingerprint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut res = [0; 32];
+        hex::decode_to_slice(s, &mut res)
+            .map_err(|_| format!("Invalid fingerprint `{}`: expected 32 bytes in hex", s))?;
+        Ok(Fingerprint(res))
+    }
+}
+
+impl Serialize for Fingerprint {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Fingerprint {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        String::deserialize(d)?.parse().map_err(D::Error::custom)
+    }
+}
+
+/// The parts of a program which are hashed, with linear combinations in canonical form so that
+/// the order of their terms does not matter
+#[derive(Serialize)]
+struct Canonical<'a, T> {
+    curve: [u8; 4],
+    arguments: &'a [FlatParameter],
+    constraints: Vec<(
+        CanonicalLinComb<T>,
+        CanonicalLinComb<T>,
+        CanonicalLinComb<T>,
+    )>,
+    returns: &'a [FlatVariable],
+}
+
+impl<T: Field> Prog<T> {
+    pub fn fingerprint(&self) -> Fingerprint {
+        let canonical = Canonical {
+            curve: T::id(),
+            arguments: &self.arguments,
+            constraints: self
+                .statements
+                .iter()
+                .filter_map(|s| match s {
+                    Statement::Constraint(quad, lin, _) => Some((
+                        quad.left.clone().into_canonical(),
+                        quad.right.clone().into_canonical(),
+                        lin.clone().into_canonical(),
+                    )),
+                    _ => None,
+                })
+                .collect(),
+            returns: &self.returns,
+        };
+
+        let mut hasher = Sha256::new();
+        serialize_into(&mut HashWriter(&mut hasher), &canonical, Infinite).unwrap();
+
+        let mut res = [0; 32];
+        res.copy_from_slice(&hasher.finalize());
+        Fingerprint(res)
+    }
+}
+
+/// Feeds what is written to a hasher, to avoid holding the serialized program in memory
+struct HashWriter<'a>(&'a mut Sha256);
+
+impl<'a> std::io::Write for HashWriter<'a> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{Directive, LinComb, QuadComb};
+    use crate::solvers::Solver;
+    use zokrates_field::{Bls12_381Field, Bn128Field};
+
+    fn prog<T: Field>(statements: Vec<Statement<T>>) -> Prog<T> {
+        Prog {
+            arguments: vec![FlatParameter::private(FlatVariable::new(0))],
+            statements,
+            returns: vec![FlatVariable::public(0)],
+        }
+    }
+
+    #[test]
+    fn fingerprint() {
+        let x = FlatVariable::new(0);
+        let out = FlatVariable::public(0);
+
+        let p: Prog<Bn128Field> = prog(vec![Statement::constraint(
+            QuadComb::from_linear_combinations(x.into(), LinComb::from(x) + LinComb::one()),
+            out,
+        )]);
+
+        // the order of the terms and the statements which are not constraints do not matter
+        let q: Prog<Bn128Field> = prog(vec![
+            Statement::Directive(Directive {
+                inputs: vec![x.into(), LinComb::one().into()],
+                outputs: vec![FlatVariable::new(1)],
+                solver: Solver::Div,
+            }),
+            Statement::constraint(
+                QuadComb::from_linear_combinations(x.into(), LinComb::one() + LinComb::from(x)),
+                out,
+            ),
+        ]);
+        assert_eq!(p.fingerprint(), q.fingerprint());
+
+        // constraints do
+        let r: Prog<Bn128Field> = prog(vec![Statement::constraint(
+            QuadComb::from_linear_combinations(x.into(), x.into()),
+            out,
+        )]);
+        assert_ne!(p.fingerprint(), r.fingerprint());
+
+        // and so does the curve
+        let s: Prog<Bls12_381Field> = prog(vec![Statement::constraint(
+            QuadComb::from_linear_combinations(x.into(), LinComb::from(x) + LinComb::one()),
+            out,
+        )]);
+        assert_ne!(p.fingerprint(), s.fingerprint());
+
+        let fingerprint = p.fingerprint();
+        assert_eq!(fingerprint.to_string().parse(), Ok(fingerprint));
+    }
+}
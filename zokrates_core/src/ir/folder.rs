@@ -58,6 +58,25 @@ pub fn fold_statement<T: Field, F: Folder<T>>(f: &mut F, s: Statement<T>) -> Vec
             message,
         )],
         Statement::Directive(dir) => vec![Statement::Directive(f.fold_directive(dir))],
+        Statement::Log(format_string, expressions, conditions) => vec![Statement::Log(
+            format_string,
+            expressions
+                .into_iter()
+                .map(|(t, e)| {
+                    (
+                        t,
+                        e.into_iter()
+                            .map(|e| f.fold_quadratic_combination(e))
+                            .collect(),
+                    )
+                })
+                .collect(),
+            conditions
+                .into_iter()
+                .map(|c| f.fold_linear_combination(c))
+                .collect(),
+        )],
+        Statement::Span(span) => vec![Statement::Span(span)],
     }
 }
 
@@ -104,6 +104,20 @@ impl<T: Field> From<FlatStatement<T>> for Statement<T> {
                 e => Statement::Constraint(LinComb::from(e).into(), var.into(), None),
             },
             FlatStatement::Directive(ds) => Statement::Directive(ds.into()),
+            FlatStatement::Log(format_string, expressions, conditions) => Statement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .map(|(t, e)| {
+                        (
+                            t,
+                            e.into_iter().map(QuadComb::from_flat_expression).collect(),
+                        )
+                    })
+                    .collect(),
+                conditions.into_iter().map(LinComb::from).collect(),
+            ),
+            FlatStatement::Span(span) => Statement::Span(span),
             _ => panic!("return should be handled at the function level"),
         }
     }
@@ -1,10 +1,12 @@
 use crate::flat_absy::flat_variable::FlatVariable;
-use crate::flat_absy::RuntimeError;
+use crate::flat_absy::{RuntimeError, SourceSpan};
 use crate::ir::{LinComb, Prog, QuadComb, Statement, Witness};
 use crate::solvers::Solver;
+use crate::typed_absy::ConcreteType;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fmt;
+use std::io::Write;
 use zokrates_field::Field;
 
 pub type ExecutionResult<T> = Result<Witness<T>, Error>;
@@ -28,6 +30,16 @@ impl Interpreter {
 
 impl Interpreter {
     pub fn execute<T: Field>(&self, program: &Prog<T>, inputs: &[T]) -> ExecutionResult<T> {
+        self.execute_with_log_stream(program, inputs, &mut std::io::sink())
+    }
+
+    /// Execute `program` on `inputs`, writing the output of `log` statements to `log_stream`
+    pub fn execute_with_log_stream<W: Write, T: Field>(
+        &self,
+        program: &Prog<T>,
+        inputs: &[T],
+        log_stream: &mut W,
+    ) -> ExecutionResult<T> {
         self.check_inputs(program, inputs)?;
         let mut witness = BTreeMap::new();
         witness.insert(FlatVariable::one(), T::one());
@@ -36,6 +48,9 @@ impl Interpreter {
             witness.insert(arg.id, value.clone());
         }
 
+        // the source span of the statements being executed, if the program carries spans
+        let mut span = None;
+
         for statement in program.statements.iter() {
             match statement {
                 Statement::Constraint(quad, lin, error) => match lin.is_assignee(&witness) {
@@ -49,6 +64,7 @@ impl Interpreter {
                         if lhs_value != rhs_value {
                             return Err(Error::UnsatisfiedConstraint {
                                 error: error.to_owned(),
+                                span: span.cloned(),
                             });
                         }
                     }
@@ -75,6 +91,23 @@ impl Interpreter {
                         witness.insert(*o, res[i].clone());
                     }
                 }
+                Statement::Log(format_string, expressions, conditions) => {
+                    // logs in branches are only printed if their branches are taken
+                    if conditions
+                        .iter()
+                        .all(|c| c.evaluate(&witness).unwrap() == T::one())
+                    {
+                        let values = expressions.iter().map(|(ty, e)| {
+                            let values: Vec<_> =
+                                e.iter().map(|e| e.evaluate(&witness).unwrap()).collect();
+                            format_value(ty, &values)
+                        });
+
+                        writeln!(log_stream, "{}", format_string.format(values))
+                            .map_err(|_| Error::LogStream)?;
+                    }
+                }
+                Statement::Span(s) => span = Some(s),
             }
         }
 
@@ -237,6 +270,97 @@ impl Interpreter {
     }
 }
 
+/// Format the flattened `values` of a value of type `ty`, using the syntax of the language
+fn format_value<T: Field>(ty: &ConcreteType, values: &[T]) -> String {
+    match ty {
+        ConcreteType::FieldElement => values[0].to_dec_string(),
+        ConcreteType::Boolean => (values[0] == T::one()).to_string(),
+        ConcreteType::Uint(bitwidth) => {
+            // logged integers are not necessarily reduced to their bitwidth
+            let value: u128 = (values[0].to_biguint()
+                % (num_bigint::BigUint::from(1u32) << bitwidth.to_usize()))
+            .to_string()
+            .parse()
+            .unwrap();
+
+            match bitwidth.is_signed() {
+                true => bitwidth.to_signed(value).to_string(),
+                false => value.to_string(),
+            }
+        }
+        ConcreteType::Array(array_type) => format!(
+            "[{}]",
+            format_values(
+                std::iter::repeat(&*array_type.ty).take(array_type.size as usize),
+                values,
+                0
+            )
+            .join(", ")
+        ),
+        ConcreteType::Struct(struct_type) => format!(
+            "{} {{ {} }}",
+            struct_type.name(),
+            struct_type
+                .iter()
+                .map(|member| &member.id)
+                .zip(format_values(
+                    struct_type.iter().map(|member| &*member.ty),
+                    values,
+                    0
+                ))
+                .map(|(id, value)| format!("{}: {}", id, value))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ConcreteType::Tuple(tuple_type) => {
+            let elements = format_values(tuple_type.elements.iter(), values, 0);
+            match elements.len() {
+                1 => format!("({},)", elements[0]),
+                _ => format!("({})", elements.join(", ")),
+            }
+        }
+        ConcreteType::Enum(enum_type) => {
+            let tag: usize = values[0].to_dec_string().parse().unwrap();
+
+            // the payload of the active variant comes after the tag and the payloads of the previous variants
+            let offset = 1 + enum_type.variants[..tag]
+                .iter()
+                .flat_map(|v| v.payload.iter())
+                .map(|ty| ty.get_primitive_count())
+                .sum::<usize>();
+
+            let variant = &enum_type.variants[tag];
+
+            match variant.payload.len() {
+                0 => format!("{}::{}", enum_type.name(), variant.id),
+                _ => format!(
+                    "{}::{}({})",
+                    enum_type.name(),
+                    variant.id,
+                    format_values(variant.payload.iter(), values, offset).join(", ")
+                ),
+            }
+        }
+        ConcreteType::Int => unreachable!(),
+    }
+}
+
+/// Format consecutive values of types `types` found in `values`, starting at `offset`
+fn format_values<'a, T: Field, I: Iterator<Item = &'a ConcreteType>>(
+    types: I,
+    values: &[T],
+    offset: usize,
+) -> Vec<String> {
+    types
+        .scan(offset, |offset, ty| {
+            let count = ty.get_primitive_count();
+            let res = format_value(ty, &values[*offset..*offset + count]);
+            *offset += count;
+            Some(res)
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 pub struct EvaluationError;
 
@@ -271,15 +395,26 @@ impl<T: Field> QuadComb<T> {
 
 #[derive(PartialEq, Serialize, Deserialize, Clone)]
 pub enum Error {
-    UnsatisfiedConstraint { error: Option<RuntimeError> },
+    UnsatisfiedConstraint {
+        error: Option<RuntimeError>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        span: Option<SourceSpan>,
+    },
     Solver,
-    WrongInputCount { expected: usize, received: usize },
+    WrongInputCount {
+        expected: usize,
+        received: usize,
+    },
+    LogStream,
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Error::UnsatisfiedConstraint { ref error } => {
+            Error::UnsatisfiedConstraint {
+                ref error,
+                ref span,
+            } => {
                 write!(
                     f,
                     "{}",
@@ -289,6 +424,13 @@ impl fmt::Display for Error {
                         .expect("Found an unsatisfied constraint without an attached error.")
                 )?;
 
+                if let Some(span) = span {
+                    write!(f, "\n  --> {}", span)?;
+                    for call in span.call_stack.iter().rev() {
+                        write!(f, "\n  in {}", call)?;
+                    }
+                }
+
                 match error {
                     Some(e) if e.is_malicious() => {
                         writeln!(f)?;
@@ -298,6 +440,7 @@ impl fmt::Display for Error {
                 }
             }
             Error::Solver => write!(f, ""),
+            Error::LogStream => write!(f, "Error writing a log to the log stream"),
             Error::WrongInputCount { expected, received } => write!(
                 f,
                 "Program takes {} input{} but was passed {} value{}",
@@ -399,4 +542,51 @@ mod tests {
 
         assert_eq!(res, expected);
     }
+
+    #[test]
+    fn log() {
+        use crate::flat_absy::FlatParameter;
+        use crate::typed_absy::types::UBitwidth;
+        use crate::typed_absy::FormatString;
+
+        // def main(_0) -> (1):
+        //     log("{} {} {}", [_0], [258 * ~one], [255 * ~one])
+        //     (1 * ~one) * (1 * _0) == 1 * ~out_0
+        //     return ~out_0
+
+        let one = FlatVariable::one();
+        let _0 = FlatVariable::new(0);
+        let out_0 = FlatVariable::public(0);
+
+        let p: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(_0)],
+            statements: vec![
+                Statement::Log(
+                    FormatString::from("{} {} {}"),
+                    vec![
+                        (ConcreteType::FieldElement, vec![LinComb::from(_0).into()]),
+                        (
+                            ConcreteType::Uint(UBitwidth::B8),
+                            vec![LinComb::summand(258, one).into()],
+                        ),
+                        (
+                            ConcreteType::Uint(UBitwidth::I8),
+                            vec![LinComb::summand(255, one).into()],
+                        ),
+                    ],
+                    vec![],
+                ),
+                Statement::definition(out_0, LinComb::from(_0)),
+            ],
+            returns: vec![out_0],
+        };
+
+        let mut logs = vec![];
+
+        Interpreter::default()
+            .execute_with_log_stream(&p, &[Bn128Field::from(42)], &mut logs)
+            .unwrap();
+
+        assert_eq!(String::from_utf8(logs).unwrap(), "42 2 -1\n");
+    }
 }
@@ -1,31 +1,83 @@
 use crate::flat_absy::flat_parameter::FlatParameter;
-use crate::flat_absy::{FlatVariable, RuntimeError};
+use crate::flat_absy::{FlatVariable, RuntimeError, SourceSpan};
 use crate::solvers::Solver;
+use crate::typed_absy::{ConcreteType, FormatString};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::hash::Hash;
 use zokrates_field::Field;
 
+mod equivalence;
 mod expression;
+mod fingerprint;
 pub mod folder;
 mod from_flat;
 mod interpreter;
+pub mod native;
+mod profile;
+pub mod r1cs;
 mod serialize;
 pub mod smtlib2;
+mod source_map;
+mod uniqueness;
 pub mod visitor;
 mod witness;
 
+pub use self::equivalence::{Counterexample, Equivalence};
 pub use self::expression::QuadComb;
 pub use self::expression::{CanonicalLinComb, LinComb};
-pub use self::serialize::ProgEnum;
+pub use self::fingerprint::Fingerprint;
+pub use self::profile::{Cost, Costs, Profile};
+pub use self::serialize::{DeserializeError, ProgEnum, ProgMetadata};
+pub use self::source_map::SourceMap;
+pub use self::uniqueness::UnderConstrained;
 
 pub use self::interpreter::{Error, ExecutionResult, Interpreter};
-pub use self::witness::Witness;
+pub use self::witness::{is_binary_witness, BinaryWitnessReader, BinaryWitnessWriter, Witness};
 
 #[derive(Debug, Serialize, Deserialize, Clone, Hash, PartialEq, Eq)]
 pub enum Statement<T> {
     Constraint(QuadComb<T>, LinComb<T>, Option<RuntimeError>),
     Directive(Directive<T>),
+    /// Logs the values, if all the conditions of the branches containing the statement hold
+    Log(
+        FormatString,
+        #[serde(with = "log_arguments")] Vec<(ConcreteType, Vec<QuadComb<T>>)>,
+        Vec<LinComb<T>>,
+    ),
+    Span(SourceSpan),
+}
+
+/// `ConcreteType` is serialized to a self-describing format which `bincode` cannot read back,
+/// so we store the types of logged values as JSON strings
+mod log_arguments {
+    use super::*;
+    use serde::de::Error;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, T: Serialize>(
+        arguments: &[(ConcreteType, Vec<QuadComb<T>>)],
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        arguments
+            .iter()
+            .map(|(ty, e)| (serde_json::to_string(ty).unwrap(), e))
+            .collect::<Vec<_>>()
+            .serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+        d: D,
+    ) -> Result<Vec<(ConcreteType, Vec<QuadComb<T>>)>, D::Error> {
+        Vec::<(String, Vec<QuadComb<T>>)>::deserialize(d)?
+            .into_iter()
+            .map(|(ty, e)| {
+                serde_json::from_str(&ty)
+                    .map(|ty| (ty, e))
+                    .map_err(D::Error::custom)
+            })
+            .collect()
+    }
 }
 
 impl<T: Field> Statement<T> {
@@ -70,6 +122,29 @@ impl<T: Field> fmt::Display for Statement<T> {
         match *self {
             Statement::Constraint(ref quad, ref lin, _) => write!(f, "{} == {}", quad, lin),
             Statement::Directive(ref s) => write!(f, "{}", s),
+            Statement::Log(ref format_string, ref expressions, ref conditions) => {
+                write!(
+                    f,
+                    "log(\"{}\", {})",
+                    format_string,
+                    expressions
+                        .iter()
+                        .map(|(_, e)| format!(
+                            "[{}]",
+                            e.iter()
+                                .map(|e| e.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )?;
+                for condition in conditions {
+                    write!(f, " if {} == 1", condition)?;
+                }
+                Ok(())
+            }
+            Statement::Span(ref span) => write!(f, "// {}", span),
         }
     }
 }
@@ -0,0 +1,478 @@
+//! Module generating a standalone Rust crate which computes the witness of a program
+//!
+//! The generated code follows the interpreter statement by statement, but stores the witness in
+//! a vector indexed by variable instead of a map, and evaluates linear combinations without
+//! looking variables up. Directives call the solvers of the interpreter, so that the witness is
+//! identical to the one computed by `Interpreter::execute`. Log statements are ignored.
+
+use crate::flat_absy::{FlatVariable, SourceSpan};
+use crate::ir::{Error, LinComb, Prog, QuadComb, Statement};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+use zokrates_field::Field;
+
+/// The number of statements compiled to each function, as huge functions are slow to compile
+const STATEMENTS_PER_FUNCTION: usize = 1000;
+
+/// Where the generated crate gets the ZoKrates crates from. The interpreter solvers must be the
+/// ones of the compiler which produced the program for the witness to match.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Dependency {
+    /// A local checkout of the ZoKrates repository
+    Path(PathBuf),
+    /// A revision of a git repository of ZoKrates
+    Git { url: String, rev: String },
+}
+
+impl Dependency {
+    fn to_toml(&self, krate: &str) -> String {
+        match self {
+            Dependency::Path(root) => format!(
+                "{} = {{ path = {:?} }}",
+                krate,
+                root.join(krate).display().to_string()
+            ),
+            Dependency::Git { url, rev } => {
+                format!("{} = {{ git = {:?}, rev = {:?} }}", krate, url, rev)
+            }
+        }
+    }
+}
+
+/// A generator of a crate computing the witness of `prog`
+pub struct NativeWitnessGenerator<'a, T> {
+    pub prog: &'a Prog<T>,
+    /// The name of the crate
+    pub name: String,
+    pub dependency: Dependency,
+}
+
+impl<'a, T: Field> NativeWitnessGenerator<'a, T> {
+    /// Returns the files of the crate, with their paths relative to the root of the crate
+    pub fn files(&self) -> Result<Vec<(PathBuf, String)>, String> {
+        Ok(vec![
+            (PathBuf::from("Cargo.toml"), self.manifest()),
+            (PathBuf::from("src/lib.rs"), self.lib()?),
+            (PathBuf::from("src/main.rs"), self.main()),
+        ])
+    }
+
+    fn manifest(&self) -> String {
+        format!(
+            r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2018"
+
+[dependencies]
+{}
+{}
+serde_json = "1.0"
+"#,
+            self.name,
+            self.dependency.to_toml("zokrates_core"),
+            self.dependency.to_toml("zokrates_field")
+        )
+    }
+
+    fn lib(&self) -> Result<String, String> {
+        let field = match T::name() {
+            "bn128" => "Bn128Field",
+            "bls12_381" => "Bls12_381Field",
+            "bls12_377" => "Bls12_377Field",
+            "bw6_761" => "Bw6_761Field",
+            name => return Err(format!("Unsupported field {}", name)),
+        };
+
+        let mut emitter = Emitter::new(self.prog);
+        for statement in &self.prog.statements {
+            emitter.statement(statement)?;
+        }
+        emitter.flush();
+
+        let mut res = String::new();
+
+        writeln!(
+            res,
+            r#"//! Witness generator generated by ZoKrates, do not edit
+
+#![allow(unused_imports)]
+
+use zokrates_core::flat_absy::FlatVariable;
+use zokrates_core::ir::{{Error, Interpreter, Witness}};
+use zokrates_core::solvers::Solver;
+use zokrates_field::Field as _;
+
+pub type Field = zokrates_field::{};
+type T = Field;
+
+const ARGUMENT_COUNT: usize = {};
+
+/// The variable stored at each index of the witness
+const VARIABLES: &[&str] = &{:?};
+
+/// The coefficients of the program
+const CONSTANTS: &[&str] = &{:?};
+
+/// Computes the witness of the program on `inputs`
+pub fn generate_witness(inputs: &[T]) -> Result<Witness<T>, Error> {{
+    if inputs.len() != ARGUMENT_COUNT {{
+        return Err(Error::WrongInputCount {{
+            expected: ARGUMENT_COUNT,
+            received: inputs.len(),
+        }});
+    }}
+
+    let c: Vec<T> = CONSTANTS
+        .iter()
+        .map(|c| T::try_from_dec_str(c).unwrap())
+        .collect();
+
+    let mut w = vec![T::from(0u32); VARIABLES.len()];
+    w[0] = T::from(1u32);
+    w[1..=ARGUMENT_COUNT].clone_from_slice(inputs);
+"#,
+            field,
+            self.prog.arguments.len(),
+            emitter
+                .variables
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>(),
+            emitter
+                .constants
+                .iter()
+                .map(|c| c.to_dec_string())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+        for i in 0..emitter.parts.len() {
+            writeln!(res, "    part_{}(&mut w, &c)?;", i).unwrap();
+        }
+
+        writeln!(
+            res,
+            r#"
+    Ok(Witness(
+        VARIABLES
+            .iter()
+            .map(|v| FlatVariable::try_from_human_readable(v).unwrap())
+            .zip(w)
+            .collect(),
+    ))
+}}
+
+fn error(e: &str) -> Error {{
+    serde_json::from_str(e).unwrap()
+}}"#
+        )
+        .unwrap();
+
+        for (i, part) in emitter.parts.iter().enumerate() {
+            write!(
+                res,
+                "\n#[allow(clippy::all)]\nfn part_{}(w: &mut [T], c: &[T]) -> Result<(), Error> {{\n{}    Ok(())\n}}\n",
+                i, part
+            )
+            .unwrap();
+        }
+
+        Ok(res)
+    }
+
+    fn main(&self) -> String {
+        format!(
+            r#"use std::fs::File;
+use std::io::BufWriter;
+use zokrates_field::Field as _;
+use {}::{{generate_witness, Field}};
+
+fn main() {{
+    let mut args = std::env::args().skip(1);
+
+    let output = args.next().unwrap_or_else(|| {{
+        eprintln!("Usage: {} <OUTPUT> [ARGUMENTS]...");
+        std::process::exit(2)
+    }});
+
+    let inputs: Vec<Field> = args
+        .map(|a| {{
+            Field::try_from_dec_str(&a).unwrap_or_else(|_| {{
+                eprintln!("Invalid argument {{}}", a);
+                std::process::exit(2)
+            }})
+        }})
+        .collect();
+
+    let witness = generate_witness(&inputs).unwrap_or_else(|e| {{
+        eprintln!("Execution failed: {{}}", e);
+        std::process::exit(1)
+    }});
+
+    let file = File::create(&output).unwrap_or_else(|e| {{
+        eprintln!("Could not create {{}}: {{}}", output, e);
+        std::process::exit(1)
+    }});
+
+    witness.write(BufWriter::new(file)).unwrap_or_else(|e| {{
+        eprintln!("Could not write witness: {{}}", e);
+        std::process::exit(1)
+    }});
+}}
+"#,
+            self.name.replace('-', "_"),
+            self.name
+        )
+    }
+}
+
+/// Compiles statements to Rust, keeping track of the variables computed so far
+struct Emitter<T> {
+    /// The index of each variable computed so far
+    indices: HashMap<FlatVariable, usize>,
+    /// The variable at each index
+    variables: Vec<FlatVariable>,
+    constant_indices: HashMap<T, usize>,
+    constants: Vec<T>,
+    /// The source span of the statements being compiled
+    span: Option<SourceSpan>,
+    parts: Vec<String>,
+    current: String,
+    statement_count: usize,
+}
+
+impl<T: Field> Emitter<T> {
+    fn new(prog: &Prog<T>) -> Self {
+        let mut emitter = Emitter {
+            indices: HashMap::new(),
+            variables: vec![],
+            constant_indices: HashMap::new(),
+            constants: vec![],
+            span: None,
+            parts: vec![],
+            current: String::new(),
+            statement_count: 0,
+        };
+
+        emitter.assign(FlatVariable::one());
+        for a in &prog.arguments {
+            emitter.assign(a.id);
+        }
+
+        emitter
+    }
+
+    fn assign(&mut self, v: FlatVariable) -> usize {
+        let variables = &mut self.variables;
+        *self.indices.entry(v).or_insert_with(|| {
+            variables.push(v);
+            variables.len() - 1
+        })
+    }
+
+    fn variable(&self, v: &FlatVariable) -> Result<usize, String> {
+        self.indices
+            .get(v)
+            .cloned()
+            .ok_or_else(|| format!("Variable {} is used before it is computed", v))
+    }
+
+    fn constant(&mut self, c: &T) -> usize {
+        let constants = &mut self.constants;
+        *self.constant_indices.entry(c.clone()).or_insert_with(|| {
+            constants.push(c.clone());
+            constants.len() - 1
+        })
+    }
+
+    fn lin(&mut self, l: &LinComb<T>) -> Result<String, String> {
+        let terms =
+            l.0.iter()
+                .map(|(v, coefficient)| {
+                    let v = self.variable(v)?;
+                    Ok(match *coefficient == T::one() {
+                        true => format!("w[{}].clone()", v),
+                        false => format!("w[{}].clone() * &c[{}]", v, self.constant(coefficient)),
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(match terms.is_empty() {
+            true => "T::from(0u32)".to_string(),
+            false => terms.join(" + "),
+        })
+    }
+
+    fn quad(&mut self, q: &QuadComb<T>) -> Result<String, String> {
+        // linear expressions are represented as `~one * l`
+        if q.left == LinComb::one() {
+            return self.lin(&q.right);
+        }
+
+        Ok(format!(
+            "({}) * ({})",
+            self.lin(&q.left)?,
+            self.lin(&q.right)?
+        ))
+    }
+
+    fn error(&self, error: Error) -> String {
+        format!("error({:?})", serde_json::to_string(&error).unwrap())
+    }
+
+    fn statement(&mut self, s: &Statement<T>) -> Result<(), String> {
+        let code = match s {
+            Statement::Constraint(quad, lin, error) => match lin.0.as_slice() {
+                [(v, coefficient)] if *coefficient == T::one() && !self.indices.contains_key(v) => {
+                    let value = self.quad(quad)?;
+                    format!("    w[{}] = {};\n", self.assign(*v), value)
+                }
+                _ => {
+                    let error = self.error(Error::UnsatisfiedConstraint {
+                        error: error.clone(),
+                        span: self.span.clone(),
+                    });
+                    format!(
+                        "    if {} != {} {{\n        return Err({});\n    }}\n",
+                        self.quad(quad)?,
+                        self.lin(lin)?,
+                        error
+                    )
+                }
+            },
+            Statement::Directive(d) => {
+                let inputs = d
+                    .inputs
+                    .iter()
+                    .map(|i| self.quad(i))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let outputs: Vec<_> = d.outputs.iter().map(|o| self.assign(*o)).collect();
+
+                format!(
+                    "    let r = Interpreter::execute_solver(&Solver::{:?}, &[{}]).map_err(|_| Error::Solver)?;\n    for (o, v) in [{}].iter().zip(r) {{\n        w[*o] = v;\n    }}\n",
+                    d.solver,
+                    inputs.join(", "),
+                    outputs
+                        .iter()
+                        .map(|o| o.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            Statement::Span(span) => {
+                self.span = Some(span.clone());
+                return Ok(());
+            }
+            Statement::Log(..) => return Ok(()),
+        };
+
+        self.current.push_str(&code);
+        self.statement_count += 1;
+
+        if self.statement_count == STATEMENTS_PER_FUNCTION {
+            self.flush();
+        }
+
+        Ok(())
+    }
+
+    fn flush(&mut self) {
+        if self.statement_count > 0 {
+            self.parts.push(std::mem::take(&mut self.current));
+            self.statement_count = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::{FlatParameter, RuntimeError};
+    use crate::ir::Directive;
+    use crate::solvers::Solver;
+    use zokrates_field::Bn128Field;
+
+    #[test]
+    fn generate() {
+        // def main(private field a) -> field:
+        //     # _1, _2 = ConditionEq(a)
+        //     _1 * a == a
+        //     ~out_0 = 2 * _1
+        let a = FlatVariable::new(0);
+        let b = FlatVariable::new(1);
+        let inv = FlatVariable::new(2);
+        let out = FlatVariable::public(0);
+
+        let prog: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(a)],
+            statements: vec![
+                Statement::Directive(Directive {
+                    inputs: vec![a.into()],
+                    outputs: vec![b, inv],
+                    solver: Solver::ConditionEq,
+                }),
+                Statement::Constraint(
+                    QuadComb::from_linear_combinations(b.into(), a.into()),
+                    a.into(),
+                    Some(RuntimeError::Equal),
+                ),
+                Statement::definition(out, LinComb::from(b) * &Bn128Field::from(2)),
+            ],
+            returns: vec![out],
+        };
+
+        let generator = NativeWitnessGenerator {
+            prog: &prog,
+            name: "witness-generator".to_string(),
+            dependency: Dependency::Git {
+                url: "https://github.com/Zokrates/ZoKrates".to_string(),
+                rev: "develop".to_string(),
+            },
+        };
+
+        let files = generator.files().unwrap();
+
+        assert!(files[0].1.contains(
+            "zokrates_core = { git = \"https://github.com/Zokrates/ZoKrates\", rev = \"develop\" }"
+        ));
+
+        let lib = &files[1].1;
+        assert!(lib.contains("pub type Field = zokrates_field::Bn128Field;"));
+        assert!(
+            lib.contains(r#"const VARIABLES: &[&str] = &["~one", "_0", "_1", "_2", "~out_0"];"#)
+        );
+        assert!(lib.contains(r#"const CONSTANTS: &[&str] = &["2"];"#));
+        assert!(lib.contains(
+            "    let r = Interpreter::execute_solver(&Solver::ConditionEq, &[w[1].clone()]).map_err(|_| Error::Solver)?;\n    for (o, v) in [2, 3].iter().zip(r) {"
+        ));
+        assert!(lib.contains("    if (w[2].clone()) * (w[1].clone()) != w[1].clone() {"));
+        assert!(lib.contains(r#"\"error\":\"Equal\""#));
+        assert!(lib.contains("    w[4] = w[2].clone() * &c[0];\n"));
+
+        assert!(files[2]
+            .1
+            .contains("use witness_generator::{generate_witness, Field};"));
+    }
+
+    #[test]
+    fn use_before_definition() {
+        let prog: Prog<Bn128Field> = Prog {
+            arguments: vec![],
+            statements: vec![Statement::definition(
+                FlatVariable::public(0),
+                FlatVariable::new(0),
+            )],
+            returns: vec![FlatVariable::public(0)],
+        };
+
+        let generator = NativeWitnessGenerator {
+            prog: &prog,
+            name: "witness-generator".to_string(),
+            dependency: Dependency::Path(PathBuf::from("/zokrates")),
+        };
+
+        assert!(generator.files().is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use crate::flat_absy::CallFrame;
+use crate::ir::{Prog, SourceMap, Statement};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Write};
+use std::ops::AddAssign;
+use zokrates_field::Field;
+
+/// The number of constraints and directives attributed to some part of a program
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Cost {
+    pub constraints: usize,
+    pub directives: usize,
+}
+
+impl AddAssign for Cost {
+    fn add_assign(&mut self, other: Self) {
+        self.constraints += other.constraints;
+        self.directives += other.directives;
+    }
+}
+
+/// The inclusive and exclusive cost of a function or call path
+///
+/// The inclusive cost counts the statements of all the functions called, the exclusive cost
+/// only the statements of the function itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Costs {
+    pub inclusive: Cost,
+    pub exclusive: Cost,
+}
+
+/// The cost of a program broken down by call path
+///
+/// A call path is the list of functions which were inlined into the entry point to reach a
+/// statement, outermost first. The statements of the entry point itself have an empty path.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Profile {
+    paths: BTreeMap<Vec<CallFrame>, Cost>,
+}
+
+impl Profile {
+    pub fn new<T: Field>(prog: &Prog<T>, source_map: &SourceMap) -> Self {
+        let mut paths: BTreeMap<_, Cost> = BTreeMap::new();
+
+        for (index, statement) in prog.statements.iter().enumerate() {
+            let cost = match statement {
+                Statement::Constraint(..) => Cost {
+                    constraints: 1,
+                    directives: 0,
+                },
+                Statement::Directive(..) => Cost {
+                    constraints: 0,
+                    directives: 1,
+                },
+                Statement::Log(..) | Statement::Span(..) => continue,
+            };
+
+            let path = source_map
+                .span(index)
+                .map(|span| span.call_stack.clone())
+                .unwrap_or_default();
+
+            *paths.entry(path).or_default() += cost;
+        }
+
+        Profile { paths }
+    }
+
+    pub fn total(&self) -> Cost {
+        let mut total = Cost::default();
+        for cost in self.paths.values() {
+            total += *cost;
+        }
+        total
+    }
+
+    /// Returns the costs of each call path, including the paths of all callers
+    pub fn paths(&self) -> BTreeMap<&[CallFrame], Costs> {
+        let mut res: BTreeMap<&[CallFrame], Costs> = BTreeMap::new();
+
+        for (path, cost) in &self.paths {
+            res.entry(path).or_default().exclusive += *cost;
+            for len in 0..=path.len() {
+                res.entry(&path[..len]).or_default().inclusive += *cost;
+            }
+        }
+
+        res
+    }
+
+    /// Returns the costs of each function, summed over all the paths it appears in
+    pub fn functions(&self) -> BTreeMap<&CallFrame, Costs> {
+        let mut res: BTreeMap<&CallFrame, Costs> = BTreeMap::new();
+
+        for (path, cost) in &self.paths {
+            if let Some(frame) = path.last() {
+                res.entry(frame).or_default().exclusive += *cost;
+            }
+            // a function appearing several times in a path only counts once
+            for frame in path.iter().collect::<BTreeSet<_>>() {
+                res.entry(frame).or_default().inclusive += *cost;
+            }
+        }
+
+        res
+    }
+
+    /// Returns the inclusive cost of each function matching `predicate` which is called from a
+    /// function which does not match it
+    pub fn entry_points<P: Fn(&CallFrame) -> bool>(
+        &self,
+        predicate: P,
+    ) -> BTreeMap<&CallFrame, Cost> {
+        let mut res: BTreeMap<&CallFrame, Cost> = BTreeMap::new();
+
+        for (path, cost) in &self.paths {
+            if let Some(frame) = path.iter().find(|frame| predicate(frame)) {
+                *res.entry(frame).or_default() += *cost;
+            }
+        }
+
+        res
+    }
+
+    /// Writes the exclusive number of constraints of each call path in the folded stack format
+    /// read by flamegraph tools, with `main` as the root of all paths
+    pub fn write_folded<W: Write>(&self, mut w: W) -> io::Result<()> {
+        for (path, cost) in &self.paths {
+            if cost.constraints == 0 {
+                continue;
+            }
+
+            write!(w, "main")?;
+            for frame in path {
+                write!(w, ";{}", frame.to_string().replace(';', "_"))?;
+            }
+            writeln!(w, " {}", cost.constraints)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::{FlatVariable, SourceSpan};
+    use crate::parser::Position;
+    use crate::solvers::Solver;
+    use zokrates_field::Bn128Field;
+
+    fn span(call_stack: Vec<CallFrame>) -> Statement<Bn128Field> {
+        let position = Position { line: 1, col: 1 };
+        Statement::Span(SourceSpan {
+            call_stack,
+            ..SourceSpan::new("main.zok".into(), position, position)
+        })
+    }
+
+    fn profile() -> Profile {
+        let foo = CallFrame::new("main.zok", "foo");
+        let bits = CallFrame::embed("_U32_TO_BITS");
+
+        let prog = Prog {
+            arguments: vec![],
+            statements: vec![
+                span(vec![]),
+                Statement::constraint(FlatVariable::new(0), FlatVariable::new(0)),
+                span(vec![foo.clone()]),
+                Statement::constraint(FlatVariable::new(1), FlatVariable::new(1)),
+                span(vec![foo, bits]),
+                Statement::Directive(crate::ir::Directive {
+                    inputs: vec![FlatVariable::new(1).into()],
+                    outputs: vec![FlatVariable::new(2)],
+                    solver: Solver::Bits(1),
+                }),
+                Statement::constraint(FlatVariable::new(2), FlatVariable::new(1)),
+            ],
+            returns: vec![],
+        };
+
+        let (prog, source_map) = prog.extract_source_map();
+        Profile::new(&prog, &source_map)
+    }
+
+    #[test]
+    fn functions() {
+        let profile = profile();
+        let functions = profile.functions();
+
+        assert_eq!(
+            functions[&CallFrame::new("main.zok", "foo")],
+            Costs {
+                inclusive: Cost {
+                    constraints: 2,
+                    directives: 1
+                },
+                exclusive: Cost {
+                    constraints: 1,
+                    directives: 0
+                }
+            }
+        );
+        assert_eq!(
+            profile.total(),
+            Cost {
+                constraints: 3,
+                directives: 1
+            }
+        );
+    }
+
+    #[test]
+    fn folded() {
+        let mut buf = vec![];
+        profile().write_folded(&mut buf).unwrap();
+
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "main 1\n\
+            main;foo (main.zok) 1\n\
+            main;foo (main.zok);_U32_TO_BITS (EMBED) 1\n"
+        );
+    }
+}
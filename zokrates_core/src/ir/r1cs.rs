@@ -0,0 +1,287 @@
+//! Export to the iden3 binary formats used by circom and snarkjs
+//!
+//! Specifications:
+//! - `.r1cs`: https://github.com/iden3/r1csfile/blob/master/doc/r1cs_bin_format.md
+//! - `.wtns`: the format written by snarkjs `wtns calculate`
+
+use std::collections::{BTreeSet, HashMap};
+use std::io::{self, Write};
+
+use num_bigint::BigUint;
+use zokrates_field::Field;
+
+use super::visitor::*;
+use super::*;
+
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+const R1CS_VERSION: u32 = 1;
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+const WTNS_VERSION: u32 = 2;
+
+const R1CS_HEADER_SECTION: u32 = 1;
+const R1CS_CONSTRAINTS_SECTION: u32 = 2;
+const R1CS_WIRE_TO_LABEL_SECTION: u32 = 3;
+const WTNS_HEADER_SECTION: u32 = 1;
+const WTNS_WITNESS_SECTION: u32 = 2;
+
+struct FlatVariableCollector {
+    variables: BTreeSet<FlatVariable>,
+}
+
+impl<T: Field> Visitor<T> for FlatVariableCollector {
+    fn visit_variable(&mut self, v: &FlatVariable) {
+        self.variables.insert(*v);
+    }
+}
+
+/// The field size in bytes, rounded up to a multiple of 8 as required by both formats
+fn field_size<T: Field>() -> usize {
+    (T::get_required_bits() - 1) / 64 * 8 + 8
+}
+
+fn write_field_element<W: Write>(w: &mut W, e: &BigUint, n8: usize) -> io::Result<()> {
+    let mut bytes = e.to_bytes_le();
+    bytes.resize(n8, 0);
+    w.write_all(&bytes)
+}
+
+fn write_field_header<T: Field, W: Write>(w: &mut W) -> io::Result<()> {
+    let n8 = field_size::<T>();
+    w.write_all(&(n8 as u32).to_le_bytes())?;
+    write_field_element(w, &(T::max_value().to_biguint() + 1usize), n8)
+}
+
+fn write_section<W: Write>(w: &mut W, section_type: u32, data: &[u8]) -> io::Result<()> {
+    w.write_all(&section_type.to_le_bytes())?;
+    w.write_all(&(data.len() as u64).to_le_bytes())?;
+    w.write_all(data)
+}
+
+impl<T: Field> Prog<T> {
+    /// Returns the variables of the program in wire order: `~one`, the public outputs, the
+    /// public inputs, the private inputs and finally all other variables
+    pub fn wires(&self) -> Vec<FlatVariable> {
+        let mut collector = FlatVariableCollector {
+            variables: BTreeSet::new(),
+        };
+        collector.visit_module(self);
+
+        let public_inputs = self.arguments.iter().filter(|a| !a.private).map(|a| a.id);
+        let private_inputs = self.arguments.iter().filter(|a| a.private).map(|a| a.id);
+
+        let mut wires: Vec<_> = std::iter::once(FlatVariable::one())
+            .chain(self.returns.iter().cloned())
+            .chain(public_inputs)
+            .chain(private_inputs)
+            .collect();
+
+        for v in &wires {
+            collector.variables.remove(v);
+        }
+
+        wires.extend(collector.variables);
+        wires
+    }
+
+    /// Writes the constraint system in the iden3 `.r1cs` format
+    pub fn write_r1cs<W: Write>(&self, mut w: W) -> io::Result<()> {
+        let n8 = field_size::<T>();
+        let wires = self.wires();
+        let indices: HashMap<_, _> = wires
+            .iter()
+            .enumerate()
+            .map(|(index, v)| (*v, index as u32))
+            .collect();
+
+        let mut constraints = vec![];
+        let mut constraint_count = 0u32;
+
+        for s in &self.statements {
+            if let Statement::Constraint(quad, lin, _) = s {
+                for lc in [&quad.left, &quad.right, lin].iter() {
+                    let lc = (*lc).clone().into_canonical();
+                    constraints.write_all(&(lc.0.len() as u32).to_le_bytes())?;
+                    for (v, c) in lc.0 {
+                        constraints.write_all(&indices[&v].to_le_bytes())?;
+                        write_field_element(&mut constraints, &c.to_biguint(), n8)?;
+                    }
+                }
+                constraint_count += 1;
+            }
+        }
+
+        let private_count = self.arguments.iter().filter(|a| a.private).count();
+
+        let mut header = vec![];
+        write_field_header::<T, _>(&mut header)?;
+        header.write_all(&(wires.len() as u32).to_le_bytes())?;
+        header.write_all(&(self.returns.len() as u32).to_le_bytes())?;
+        header.write_all(&((self.arguments.len() - private_count) as u32).to_le_bytes())?;
+        header.write_all(&(private_count as u32).to_le_bytes())?;
+        header.write_all(&(wires.len() as u64).to_le_bytes())?;
+        header.write_all(&constraint_count.to_le_bytes())?;
+
+        let labels: Vec<u8> = (0..wires.len() as u64)
+            .flat_map(|label| label.to_le_bytes().to_vec())
+            .collect();
+
+        w.write_all(R1CS_MAGIC)?;
+        w.write_all(&R1CS_VERSION.to_le_bytes())?;
+        w.write_all(&3u32.to_le_bytes())?;
+        write_section(&mut w, R1CS_HEADER_SECTION, &header)?;
+        write_section(&mut w, R1CS_CONSTRAINTS_SECTION, &constraints)?;
+        write_section(&mut w, R1CS_WIRE_TO_LABEL_SECTION, &labels)?;
+
+        Ok(())
+    }
+}
+
+impl<T: Field> Witness<T> {
+    /// Writes the witness in the iden3 `.wtns` format, with values in the order given by `wires`
+    pub fn write_wtns<W: Write>(&self, wires: &[FlatVariable], mut w: W) -> io::Result<()> {
+        let n8 = field_size::<T>();
+
+        let mut header = vec![];
+        write_field_header::<T, _>(&mut header)?;
+        header.write_all(&(wires.len() as u32).to_le_bytes())?;
+
+        let mut values = vec![];
+        for v in wires {
+            let value = match *v == FlatVariable::one() {
+                true => T::one(),
+                false => self.0.get(v).cloned().ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("Missing value for {} in witness", v),
+                    )
+                })?,
+            };
+            write_field_element(&mut values, &value.to_biguint(), n8)?;
+        }
+
+        w.write_all(WTNS_MAGIC)?;
+        w.write_all(&WTNS_VERSION.to_le_bytes())?;
+        w.write_all(&2u32.to_le_bytes())?;
+        write_section(&mut w, WTNS_HEADER_SECTION, &header)?;
+        write_section(&mut w, WTNS_WITNESS_SECTION, &values)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::FlatParameter;
+    use zokrates_field::Bn128Field;
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        let mut buf = [0; 4];
+        buf.copy_from_slice(&bytes[offset..offset + 4]);
+        u32::from_le_bytes(buf)
+    }
+
+    fn prog() -> Prog<Bn128Field> {
+        // def main(private field a, field b) -> field: return a * b
+        Prog {
+            arguments: vec![
+                FlatParameter::private(FlatVariable::new(0)),
+                FlatParameter::public(FlatVariable::new(1)),
+            ],
+            statements: vec![Statement::definition(
+                FlatVariable::public(0),
+                QuadComb::from_linear_combinations(
+                    FlatVariable::new(0).into(),
+                    FlatVariable::new(1).into(),
+                ),
+            )],
+            returns: vec![FlatVariable::public(0)],
+        }
+    }
+
+    #[test]
+    fn wires() {
+        assert_eq!(
+            prog().wires(),
+            vec![
+                FlatVariable::one(),
+                FlatVariable::public(0),
+                FlatVariable::new(1),
+                FlatVariable::new(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn r1cs() {
+        let mut buf = vec![];
+        prog().write_r1cs(&mut buf).unwrap();
+
+        assert_eq!(&buf[0..4], b"r1cs");
+        assert_eq!(read_u32(&buf, 4), 1);
+        assert_eq!(read_u32(&buf, 8), 3);
+
+        // header section
+        assert_eq!(read_u32(&buf, 12), 1);
+        let header = &buf[24..];
+        assert_eq!(read_u32(header, 0), 32);
+        assert_eq!(
+            BigUint::from_bytes_le(&header[4..36]),
+            Bn128Field::max_value().to_biguint() + 1usize
+        );
+        // wires, public outputs, public inputs, private inputs
+        assert_eq!(read_u32(header, 36), 4);
+        assert_eq!(read_u32(header, 40), 1);
+        assert_eq!(read_u32(header, 44), 1);
+        assert_eq!(read_u32(header, 48), 1);
+        // constraints
+        assert_eq!(read_u32(header, 60), 1);
+
+        // constraint section: a * b == ~out_0
+        let constraints = &buf[24 + 64..];
+        assert_eq!(read_u32(constraints, 0), 2);
+        let a = &constraints[12..];
+        assert_eq!(read_u32(a, 0), 1);
+        assert_eq!(read_u32(a, 4), 3);
+        assert_eq!(a[8], 1);
+    }
+
+    #[test]
+    fn wtns() {
+        let prog = prog();
+        let witness = Witness(
+            vec![
+                (FlatVariable::new(0), Bn128Field::from(3)),
+                (FlatVariable::new(1), Bn128Field::from(5)),
+                (FlatVariable::public(0), Bn128Field::from(15)),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let mut buf = vec![];
+        witness.write_wtns(&prog.wires(), &mut buf).unwrap();
+
+        assert_eq!(&buf[0..4], b"wtns");
+        assert_eq!(read_u32(&buf, 4), 2);
+        assert_eq!(read_u32(&buf, 8), 2);
+
+        // header section: field size, prime, witness count
+        assert_eq!(read_u32(&buf, 24), 32);
+        assert_eq!(read_u32(&buf, 60), 4);
+
+        // witness section
+        let values = &buf[64 + 12..];
+        assert_eq!(values.len(), 4 * 32);
+        let values: Vec<_> = values.chunks(32).map(|c| c[0]).collect();
+        assert_eq!(values, vec![1, 15, 5, 3]);
+    }
+
+    #[test]
+    fn wtns_missing_value() {
+        let mut buf = vec![];
+        assert!(Witness::<Bn128Field>::empty()
+            .write_wtns(&prog().wires(), &mut buf)
+            .is_err());
+    }
+}
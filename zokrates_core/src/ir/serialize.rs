@@ -1,10 +1,82 @@
+use crate::compile::CompileConfig;
 use crate::ir::Prog;
 use bincode::{deserialize_from, serialize_into, Infinite};
-use std::io::{Read, Write};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::{self, Read, Write};
 use zokrates_field::*;
 
 const ZOKRATES_MAGIC: &[u8; 4] = &[0x5a, 0x4f, 0x4b, 0];
 const ZOKRATES_VERSION_1: &[u8; 4] = &[0, 0, 0, 1];
+const ZOKRATES_VERSION_2: &[u8; 4] = &[0, 0, 0, 2];
+
+/// The maximum size of the metadata of a program, to avoid allocating for corrupt lengths
+const MAX_METADATA_LENGTH: u64 = 1 << 24;
+
+// Version 2 adds the metadata of the program after the curve identifier, as a little-endian
+// `u64` length followed by as many bytes of JSON
+
+/// Information about how a program was compiled
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgMetadata {
+    /// The version of the compiler which compiled the program
+    pub compiler_version: String,
+    /// The SHA-256 hash of the sources of the program, in hexadecimal
+    #[serde(default)]
+    pub source_hash: Option<String>,
+    /// The configuration the program was compiled with
+    #[serde(default)]
+    pub config: Option<CompileConfig>,
+    /// The SHA-256 hash of the ABI of the program, in hexadecimal
+    #[serde(default)]
+    pub abi_hash: Option<String>,
+}
+
+impl Default for ProgMetadata {
+    fn default() -> Self {
+        ProgMetadata {
+            compiler_version: env!("CARGO_PKG_VERSION").to_string(),
+            source_hash: None,
+            config: None,
+            abi_hash: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DeserializeError {
+    Io(io::Error),
+    WrongMagicNumber,
+    UnknownVersion([u8; 4]),
+    UnknownCurve([u8; 4]),
+    InvalidMetadata(String),
+    InvalidProgram(String),
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeserializeError::Io(e) => write!(f, "Cannot read program: {}", e),
+            DeserializeError::WrongMagicNumber => write!(f, "Wrong magic number"),
+            DeserializeError::UnknownVersion(v) => write!(f, "Unknown version {:?}", v),
+            DeserializeError::UnknownCurve(c) => write!(f, "Unknown curve identifier {:?}", c),
+            DeserializeError::InvalidMetadata(e) => write!(f, "Invalid program metadata: {}", e),
+            DeserializeError::InvalidProgram(e) => write!(f, "Invalid program: {}", e),
+        }
+    }
+}
+
+impl From<io::Error> for DeserializeError {
+    fn from(e: io::Error) -> Self {
+        DeserializeError::Io(e)
+    }
+}
+
+impl From<DeserializeError> for String {
+    fn from(e: DeserializeError) -> Self {
+        e.to_string()
+    }
+}
 
 #[derive(PartialEq, Debug)]
 pub enum ProgEnum {
@@ -15,58 +87,99 @@ pub enum ProgEnum {
 }
 
 impl<T: Field> Prog<T> {
-    pub fn serialize<W: Write>(&self, mut w: W) {
-        w.write_all(ZOKRATES_MAGIC).unwrap();
-        w.write_all(ZOKRATES_VERSION_1).unwrap();
-        w.write_all(&T::id()).unwrap();
+    /// Serializes the program with default metadata
+    pub fn serialize<W: Write>(&self, w: W) {
+        self.serialize_with_metadata(w, &ProgMetadata::default())
+            .unwrap();
+    }
+
+    pub fn serialize_with_metadata<W: Write>(
+        &self,
+        mut w: W,
+        metadata: &ProgMetadata,
+    ) -> io::Result<()> {
+        w.write_all(ZOKRATES_MAGIC)?;
+        w.write_all(ZOKRATES_VERSION_2)?;
+        w.write_all(&T::id())?;
 
-        serialize_into(&mut w, self, Infinite).unwrap();
+        let metadata = serde_json::to_vec(metadata)?;
+        w.write_all(&(metadata.len() as u64).to_le_bytes())?;
+        w.write_all(&metadata)?;
+
+        serialize_into(&mut w, self, Infinite)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
     }
 }
 
 impl ProgEnum {
-    pub fn deserialize<R: Read>(mut r: R) -> Result<Self, String> {
+    pub fn deserialize<R: Read>(r: R) -> Result<Self, DeserializeError> {
+        Self::deserialize_with_metadata(r).map(|(p, _)| p)
+    }
+
+    /// Deserializes a program along with its metadata, which programs serialized in the first
+    /// version of the format do not have
+    pub fn deserialize_with_metadata<R: Read>(
+        mut r: R,
+    ) -> Result<(Self, Option<ProgMetadata>), DeserializeError> {
         // Check the magic number, `ZOK`
         let mut magic = [0; 4];
-        r.read_exact(&mut magic)
-            .map_err(|_| String::from("Cannot read magic number"))?;
-
-        if &magic == ZOKRATES_MAGIC {
-            // Check the version, 1
-            let mut version = [0; 4];
-            r.read_exact(&mut version)
-                .map_err(|_| String::from("Cannot read version"))?;
-
-            if &version == ZOKRATES_VERSION_1 {
-                // Check the curve identifier, deserializing accordingly
-                let mut curve = [0; 4];
-                r.read_exact(&mut curve)
-                    .map_err(|_| String::from("Cannot read curve identifier"))?;
-
-                match curve {
-                    m if m == Bls12_381Field::id() => Ok(ProgEnum::Bls12_381Program(
-                        deserialize_from(&mut r, Infinite).unwrap(),
-                    )),
-                    m if m == Bn128Field::id() => Ok(ProgEnum::Bn128Program(
-                        deserialize_from(&mut r, Infinite).unwrap(),
-                    )),
-                    m if m == Bls12_377Field::id() => Ok(ProgEnum::Bls12_377Program(
-                        deserialize_from(&mut r, Infinite).unwrap(),
-                    )),
-                    m if m == Bw6_761Field::id() => Ok(ProgEnum::Bw6_761Program(
-                        deserialize_from(&mut r, Infinite).unwrap(),
-                    )),
-                    _ => Err(String::from("Unknown curve identifier")),
+        r.read_exact(&mut magic)?;
+
+        if &magic != ZOKRATES_MAGIC {
+            return Err(DeserializeError::WrongMagicNumber);
+        }
+
+        let mut version = [0; 4];
+        r.read_exact(&mut version)?;
+
+        if &version != ZOKRATES_VERSION_1 && &version != ZOKRATES_VERSION_2 {
+            return Err(DeserializeError::UnknownVersion(version));
+        }
+
+        // Check the curve identifier, deserializing accordingly
+        let mut curve = [0; 4];
+        r.read_exact(&mut curve)?;
+
+        let metadata = match &version == ZOKRATES_VERSION_2 {
+            true => {
+                let mut length = [0; 8];
+                r.read_exact(&mut length)?;
+                let length = u64::from_le_bytes(length);
+
+                if length > MAX_METADATA_LENGTH {
+                    return Err(DeserializeError::InvalidMetadata(format!(
+                        "length {} is too large",
+                        length
+                    )));
                 }
-            } else {
-                Err(String::from("Unknown version"))
+
+                let mut metadata = vec![0; length as usize];
+                r.read_exact(&mut metadata)?;
+
+                Some(
+                    serde_json::from_slice(&metadata)
+                        .map_err(|e| DeserializeError::InvalidMetadata(e.to_string()))?,
+                )
             }
-        } else {
-            Err(String::from("Wrong magic number"))
-        }
+            false => None,
+        };
+
+        let prog = match curve {
+            m if m == Bls12_381Field::id() => ProgEnum::Bls12_381Program(deserialize_prog(&mut r)?),
+            m if m == Bn128Field::id() => ProgEnum::Bn128Program(deserialize_prog(&mut r)?),
+            m if m == Bls12_377Field::id() => ProgEnum::Bls12_377Program(deserialize_prog(&mut r)?),
+            m if m == Bw6_761Field::id() => ProgEnum::Bw6_761Program(deserialize_prog(&mut r)?),
+            _ => return Err(DeserializeError::UnknownCurve(curve)),
+        };
+
+        Ok((prog, metadata))
     }
 }
 
+fn deserialize_prog<T: Field, R: Read>(r: &mut R) -> Result<Prog<T>, DeserializeError> {
+    deserialize_from(r, Infinite).map_err(|e| DeserializeError::InvalidProgram(e.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,7 +188,7 @@ mod tests {
     use zokrates_field::{Bls12_381Field, Bn128Field};
 
     #[test]
-    fn ser_deser_v1() {
+    fn ser_deser() {
         let p: ir::Prog<Bn128Field> = ir::Prog::default();
 
         let mut buffer = Cursor::new(vec![]);
@@ -102,4 +215,79 @@ mod tests {
 
         assert_eq!(ProgEnum::Bls12_381Program(p), deserialized_p);
     }
+
+    #[test]
+    fn ser_deser_v2_metadata() {
+        let p: ir::Prog<Bn128Field> = ir::Prog::default();
+        let metadata = ProgMetadata {
+            source_hash: Some("00".into()),
+            config: Some(CompileConfig::default().isolate_branches(true)),
+            ..ProgMetadata::default()
+        };
+
+        let mut buffer = vec![];
+        p.serialize_with_metadata(&mut buffer, &metadata).unwrap();
+
+        assert_eq!(
+            ProgEnum::deserialize_with_metadata(&buffer[..]).unwrap(),
+            (ProgEnum::Bn128Program(p), Some(metadata))
+        );
+    }
+
+    #[test]
+    fn deser_v1() {
+        let p: ir::Prog<Bn128Field> = ir::Prog::default();
+
+        let mut buffer = vec![];
+        buffer.extend(ZOKRATES_MAGIC);
+        buffer.extend(ZOKRATES_VERSION_1);
+        buffer.extend(&Bn128Field::id());
+        serialize_into(&mut buffer, &p, Infinite).unwrap();
+
+        assert_eq!(
+            ProgEnum::deserialize_with_metadata(&buffer[..]).unwrap(),
+            (ProgEnum::Bn128Program(p), None)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let p: ir::Prog<Bn128Field> = ir::Prog::default();
+
+        let mut buffer = vec![];
+        p.serialize(&mut buffer);
+
+        // truncated anywhere
+        for length in 0..buffer.len() {
+            assert!(ProgEnum::deserialize(&buffer[..length]).is_err());
+        }
+
+        let mut wrong_magic = buffer.clone();
+        wrong_magic[0] = 0;
+        assert!(matches!(
+            ProgEnum::deserialize(&wrong_magic[..]),
+            Err(DeserializeError::WrongMagicNumber)
+        ));
+
+        let mut wrong_version = buffer.clone();
+        wrong_version[7] = 42;
+        assert!(matches!(
+            ProgEnum::deserialize(&wrong_version[..]),
+            Err(DeserializeError::UnknownVersion(_))
+        ));
+
+        let mut wrong_curve = buffer.clone();
+        wrong_curve[8..12].copy_from_slice(&[0xff; 4]);
+        assert!(matches!(
+            ProgEnum::deserialize(&wrong_curve[..]),
+            Err(DeserializeError::UnknownCurve(_))
+        ));
+
+        let mut wrong_metadata = buffer;
+        wrong_metadata[20] = b'!';
+        assert!(matches!(
+            ProgEnum::deserialize(&wrong_metadata[..]),
+            Err(DeserializeError::InvalidMetadata(_))
+        ));
+    }
 }
@@ -1,11 +1,13 @@
-use num_bigint::BigUint;
-use std::collections::BTreeSet;
+use num_bigint::{BigInt, BigUint};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::convert::TryFrom;
 
 use super::*;
 use zokrates_field::Field;
 
 use super::expression::LinComb;
 use super::expression::QuadComb;
+use super::folder::Folder;
 use super::visitor::*;
 
 pub trait SMTLib2 {
@@ -62,6 +64,335 @@ impl<T: Field> SMTLib2 for Prog<T> {
     }
 }
 
+/// The answer of an SMT solver to a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SatResult {
+    Sat,
+    Unsat,
+    Unknown,
+}
+
+impl SatResult {
+    /// Reads the answer to the `(check-sat)` command from the first line of the output of a solver
+    pub fn from_output(output: &str) -> Result<Self, String> {
+        match output.lines().next().map(|l| l.trim()) {
+            Some("sat") => Ok(SatResult::Sat),
+            Some("unsat") => Ok(SatResult::Unsat),
+            Some("unknown") => Ok(SatResult::Unknown),
+            _ => Err(format!("Unexpected output from the SMT solver: {}", output)),
+        }
+    }
+}
+
+/// An SMT solver which runs queries in the SMTLib2 format
+pub trait SmtSolver {
+    /// Runs `query`, returning the output of the solver
+    fn run(&self, query: &str) -> Result<String, String>;
+
+    fn check_sat(&self, query: &str) -> Result<SatResult, String> {
+        SatResult::from_output(&self.run(query)?)
+    }
+}
+
+/// Reads the answer to a `(get-value ...)` command following `(check-sat)` in the output of a
+/// solver, reducing the values in the field
+pub fn get_values<T: Field>(output: &str) -> Result<BTreeMap<String, T>, String> {
+    let invalid = || format!("Unexpected output from the SMT solver: {}", output);
+
+    // skip the answer to `(check-sat)`
+    let values = output.splitn(2, '\n').nth(1).ok_or_else(invalid)?;
+
+    let tokens: Vec<String> = values
+        .replace('(', " ( ")
+        .replace(')', " ) ")
+        .split_whitespace()
+        .map(|t| t.trim_matches('|').to_string())
+        .collect();
+
+    let modulus = BigInt::from(T::max_value().to_biguint() + 1usize);
+    let mut res = BTreeMap::new();
+    let mut tokens = tokens.iter().map(|t| t.as_str()).peekable();
+
+    if tokens.next() != Some("(") {
+        return Err(invalid());
+    }
+
+    // each value is either `(name n)` or `(name (- n))`
+    while tokens.next_if_eq(&"(").is_some() {
+        let name = tokens.next().ok_or_else(invalid)?;
+        let value = match tokens.next() {
+            Some("(") => match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some("-"), Some(n), Some(")")) => -n.parse::<BigInt>().map_err(|_| invalid())?,
+                _ => return Err(invalid()),
+            },
+            Some(n) => n.parse::<BigInt>().map_err(|_| invalid())?,
+            None => return Err(invalid()),
+        };
+        if tokens.next() != Some(")") {
+            return Err(invalid());
+        }
+
+        let value = ((value % &modulus) + &modulus) % &modulus;
+        let value = T::try_from(value.to_biguint().unwrap()).map_err(|_| invalid())?;
+        res.insert(name.to_string(), value);
+    }
+
+    Ok(res)
+}
+
+/// Queries asking for two witnesses of a program which agree on a set of shared variables but
+/// disagree on some other variable
+///
+/// A query is unsatisfiable if and only if the variable is uniquely determined by the shared
+/// variables. The second witness is encoded by renaming all variables which are not shared.
+pub struct UniquenessQuery<'a, T> {
+    prog: &'a Prog<T>,
+    copy: Vec<Statement<T>>,
+    variables: BTreeSet<FlatVariable>,
+    renaming: HashMap<FlatVariable, FlatVariable>,
+}
+
+struct Renamer<'a> {
+    renaming: &'a HashMap<FlatVariable, FlatVariable>,
+}
+
+impl<T: Field> Folder<T> for Renamer<'_> {
+    fn fold_variable(&mut self, v: FlatVariable) -> FlatVariable {
+        *self.renaming.get(&v).unwrap_or(&v)
+    }
+}
+
+impl<'a, T: Field> UniquenessQuery<'a, T> {
+    pub fn new(prog: &'a Prog<T>, shared: &HashSet<FlatVariable>) -> Self {
+        let mut collector = FlatVariableCollector {
+            variables: BTreeSet::<FlatVariable>::new(),
+        };
+        collector.visit_module(prog);
+        collector.variables.insert(FlatVariable::one());
+
+        // pick fresh variables after the largest intermediate variable
+        let first = collector
+            .variables
+            .iter()
+            .filter(|v| **v != FlatVariable::one() && !v.is_output())
+            .map(|v| v.id() + 1)
+            .max()
+            .unwrap_or(0);
+
+        let renaming: HashMap<_, _> = collector
+            .variables
+            .iter()
+            .filter(|v| **v != FlatVariable::one() && !shared.contains(v))
+            .enumerate()
+            .map(|(index, v)| (*v, FlatVariable::new(first + index)))
+            .collect();
+
+        let copy = prog
+            .statements
+            .iter()
+            .filter(|s| matches!(s, Statement::Constraint(..)))
+            .flat_map(|s| {
+                Renamer {
+                    renaming: &renaming,
+                }
+                .fold_statement(s.clone())
+            })
+            .collect();
+
+        UniquenessQuery {
+            prog,
+            copy,
+            variables: collector.variables,
+            renaming,
+        }
+    }
+
+    /// Returns the query for `variable` in the SMTLib2 format
+    pub fn query(&self, variable: FlatVariable) -> String {
+        format!("{}", UniquenessQueryDisplay(self, variable))
+    }
+}
+
+struct UniquenessQueryDisplay<'a, T>(&'a UniquenessQuery<'a, T>, FlatVariable);
+
+impl<T: Field> fmt::Display for UniquenessQueryDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (query, variable) = (self.0, self.1);
+        let copy = query.renaming.get(&variable).cloned().unwrap_or(variable);
+
+        writeln!(f, "; Auto generated by ZoKrates")?;
+        writeln!(
+            f,
+            "; Uniqueness of {}, renamed to {} in the copy",
+            variable, copy
+        )?;
+
+        writeln!(f, "(declare-const |~prime| Int)")?;
+        for v in query.variables.iter().chain(query.renaming.values()) {
+            writeln!(f, "(declare-const |{}| Int)", v)?;
+        }
+
+        writeln!(f, "(assert (and")?;
+        writeln!(f, "(= |~prime| {})", T::max_value().to_biguint() + 1usize)?;
+        writeln!(f, "(= |~one| 1)")?;
+        for s in query.prog.statements.iter().chain(query.copy.iter()) {
+            if let Statement::Constraint(..) = s {
+                s.to_smtlib2(f)?;
+                writeln!(f)?;
+            }
+        }
+        write!(f, "(distinct (mod ")?;
+        variable.to_smtlib2(f)?;
+        write!(f, " |~prime|) (mod ")?;
+        copy.to_smtlib2(f)?;
+        writeln!(f, " |~prime|))")?;
+        writeln!(f, "))")?;
+        write!(f, "(check-sat)")
+    }
+}
+
+/// A query asking for public arguments and return values which are accepted by exactly one of two
+/// programs, the private arguments and the intermediate variables of each program being
+/// existentially quantified
+///
+/// A query is unsatisfiable if and only if the programs define the same relation between their
+/// public arguments and return values. Both programs must have the same signature.
+pub struct EquivalenceQuery<'a, T> {
+    pub left: &'a Prog<T>,
+    pub right: &'a Prog<T>,
+}
+
+impl<'a, T: Field> EquivalenceQuery<'a, T> {
+    /// Returns the variables shared by both programs: `~one`, the public arguments of the left
+    /// program and the return values
+    pub fn shared(&self) -> Vec<FlatVariable> {
+        std::iter::once(FlatVariable::one())
+            .chain(
+                self.left
+                    .arguments
+                    .iter()
+                    .filter(|a| !a.private)
+                    .map(|a| a.id),
+            )
+            .chain(self.left.returns.iter().cloned())
+            .collect()
+    }
+
+    fn variables(prog: &Prog<T>) -> BTreeSet<FlatVariable> {
+        let mut collector = FlatVariableCollector {
+            variables: BTreeSet::<FlatVariable>::new(),
+        };
+        collector.visit_module(prog);
+        collector.variables
+    }
+
+    /// Renames the variables of the right program so that they do not clash with the ones of the
+    /// left program, except for the shared ones
+    fn rename_right(&self) -> Prog<T> {
+        let first = Self::variables(self.left)
+            .iter()
+            .chain(Self::variables(self.right).iter())
+            .filter(|v| **v != FlatVariable::one() && !v.is_output())
+            .map(|v| v.id() + 1)
+            .max()
+            .unwrap_or(0);
+
+        let public = |p: &'a Prog<T>| p.arguments.iter().filter(|a| !a.private).map(|a| a.id);
+
+        let mut renaming: HashMap<_, _> = public(self.right).zip(public(self.left)).collect();
+        let fresh: Vec<_> = Self::variables(self.right)
+            .into_iter()
+            .filter(|v| *v != FlatVariable::one() && !v.is_output() && !renaming.contains_key(v))
+            .collect();
+        renaming.extend(
+            fresh
+                .into_iter()
+                .enumerate()
+                .map(|(index, v)| (v, FlatVariable::new(first + index))),
+        );
+
+        Renamer {
+            renaming: &renaming,
+        }
+        .fold_module(self.right.clone())
+    }
+
+    fn system(
+        f: &mut fmt::Formatter,
+        prog: &Prog<T>,
+        shared: &HashSet<FlatVariable>,
+    ) -> fmt::Result {
+        let existentials: Vec<_> = Self::variables(prog)
+            .into_iter()
+            .filter(|v| !shared.contains(v))
+            .collect();
+
+        if !existentials.is_empty() {
+            write!(f, "(exists (")?;
+            for v in &existentials {
+                write!(f, "(|{}| Int)", v)?;
+            }
+            writeln!(f, ")")?;
+        }
+
+        writeln!(f, "(and true")?;
+        for s in &prog.statements {
+            if let Statement::Constraint(..) = s {
+                s.to_smtlib2(f)?;
+                writeln!(f)?;
+            }
+        }
+        write!(f, ")")?;
+
+        if !existentials.is_empty() {
+            write!(f, ")")?;
+        }
+
+        writeln!(f)
+    }
+}
+
+impl<T: Field> fmt::Display for EquivalenceQuery<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let shared = self.shared();
+        let right = self.rename_right();
+
+        writeln!(f, "; Auto generated by ZoKrates")?;
+        writeln!(f, "(declare-const |~prime| Int)")?;
+        for v in &shared {
+            writeln!(f, "(declare-const |{}| Int)", v)?;
+        }
+
+        writeln!(
+            f,
+            "(assert (= |~prime| {}))",
+            T::max_value().to_biguint() + 1usize
+        )?;
+        writeln!(f, "(assert (= |~one| 1))")?;
+        for v in shared.iter().skip(1) {
+            writeln!(f, "(assert (and (<= 0 |{}|) (< |{}| |~prime|)))", v, v)?;
+        }
+
+        let shared_set = shared.iter().cloned().collect();
+        writeln!(f, "(assert (distinct")?;
+        Self::system(f, self.left, &shared_set)?;
+        Self::system(f, &right, &shared_set)?;
+        writeln!(f, "))")?;
+        write!(f, "(check-sat)")?;
+
+        if shared.len() > 1 {
+            writeln!(f)?;
+            write!(f, "(get-value (")?;
+            for v in shared.iter().skip(1) {
+                write!(f, " |{}|", v)?;
+            }
+            write!(f, "))")?;
+        }
+
+        Ok(())
+    }
+}
+
 fn format_prefix_op_smtlib2<T: SMTLib2, Ts: SMTLib2>(
     f: &mut fmt::Formatter,
     op: &str,
@@ -86,6 +417,7 @@ impl<T: Field> SMTLib2 for Statement<T> {
                 write!(f, " |~prime|))")
             }
             Statement::Directive(ref s) => s.to_smtlib2(f),
+            Statement::Log(..) | Statement::Span(..) => write!(f, ""),
         }
     }
 }
@@ -0,0 +1,114 @@
+use crate::flat_absy::SourceSpan;
+use crate::ir::{Prog, Statement};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use zokrates_field::Field;
+
+/// A mapping from the statements of a program to the source spans they were compiled from
+///
+/// Each entry maps the index of a statement to the span of this statement and all following
+/// statements up to the next entry.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct SourceMap {
+    spans: BTreeMap<usize, SourceSpan>,
+}
+
+impl SourceMap {
+    /// Returns the source span of the statement at `index`, if any
+    pub fn span(&self, index: usize) -> Option<&SourceSpan> {
+        self.spans.range(..=index).next_back().map(|(_, span)| span)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    pub fn write<W: Write>(&self, writer: W) -> Result<(), String> {
+        serde_json::to_writer(writer, self).map_err(|e| e.to_string())
+    }
+
+    pub fn read<R: Read>(reader: R) -> Result<Self, String> {
+        serde_json::from_reader(reader).map_err(|e| e.to_string())
+    }
+}
+
+impl<T: Field> Prog<T> {
+    /// Removes the span markers from the program, returning the stripped program along with
+    /// the source map they describe
+    pub fn extract_source_map(self) -> (Self, SourceMap) {
+        let mut source_map = SourceMap::default();
+        let mut statements = Vec::with_capacity(self.statements.len());
+
+        for s in self.statements {
+            match s {
+                Statement::Span(span) => {
+                    source_map.spans.insert(statements.len(), span);
+                }
+                s => statements.push(s),
+            }
+        }
+
+        (Prog { statements, ..self }, source_map)
+    }
+
+    /// Inserts span markers described by `source_map` back into the program, so that execution
+    /// errors can refer to the source
+    pub fn with_source_map(self, source_map: &SourceMap) -> Self {
+        let mut spans = source_map.spans.iter().peekable();
+        let mut statements = Vec::with_capacity(self.statements.len() + source_map.spans.len());
+
+        for (index, s) in self.statements.into_iter().enumerate() {
+            while let Some((_, span)) = spans.next_if(|(i, _)| **i <= index) {
+                statements.push(Statement::Span(span.clone()));
+            }
+            statements.push(s);
+        }
+
+        Prog { statements, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::FlatVariable;
+    use crate::parser::Position;
+    use zokrates_field::Bn128Field;
+
+    fn span(line: usize) -> SourceSpan {
+        SourceSpan::new(
+            "main.zok".into(),
+            Position { line, col: 1 },
+            Position { line, col: 10 },
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let prog: Prog<Bn128Field> = Prog {
+            arguments: vec![],
+            statements: vec![
+                Statement::Span(span(1)),
+                Statement::definition(FlatVariable::new(0), FlatVariable::one()),
+                Statement::definition(FlatVariable::new(1), FlatVariable::one()),
+                Statement::Span(span(2)),
+                Statement::constraint(FlatVariable::new(0), FlatVariable::new(1)),
+            ],
+            returns: vec![],
+        };
+
+        let (stripped, source_map) = prog.clone().extract_source_map();
+
+        assert_eq!(stripped.statements.len(), 3);
+        assert_eq!(source_map.span(0), Some(&span(1)));
+        assert_eq!(source_map.span(1), Some(&span(1)));
+        assert_eq!(source_map.span(2), Some(&span(2)));
+
+        let mut buffer = vec![];
+        source_map.write(&mut buffer).unwrap();
+        let source_map = SourceMap::read(&buffer[..]).unwrap();
+
+        assert_eq!(stripped.with_source_map(&source_map), prog);
+    }
+}
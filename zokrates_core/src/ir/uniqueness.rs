@@ -0,0 +1,527 @@
+use crate::flat_absy::FlatVariable;
+use crate::ir::smtlib2::{SatResult, SmtSolver, UniquenessQuery};
+use crate::ir::{CanonicalLinComb, LinComb, Prog, Statement};
+use crate::solvers::Solver;
+use num_bigint::BigUint;
+use std::collections::{BTreeMap, HashSet};
+use zokrates_field::Field;
+
+/// A directive output which could not be proven to be uniquely determined by the arguments
+///
+/// A prover is free to pick any value for such a variable which satisfies the constraints, so
+/// unless this is intended, the program is under-constrained.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnderConstrained {
+    pub variable: FlatVariable,
+    /// The index of the directive defining the variable in the statements of the program
+    pub index: usize,
+    /// The solver of the directive defining the variable
+    pub solver: Solver,
+    /// Whether the SMT solver found two witnesses which disagree on the variable. If not, the
+    /// analysis was inconclusive.
+    pub not_unique: bool,
+}
+
+type Triple<T> = (
+    CanonicalLinComb<T>,
+    CanonicalLinComb<T>,
+    CanonicalLinComb<T>,
+);
+
+/// A constraint `a * b == c`, along with the linear form `l == 0` it is equivalent to if `a` or
+/// `b` is constant
+struct Constraint<T> {
+    variables: Vec<FlatVariable>,
+    a: CanonicalLinComb<T>,
+    b: CanonicalLinComb<T>,
+    c: CanonicalLinComb<T>,
+    linear: Option<CanonicalLinComb<T>>,
+}
+
+/// Propagates the knowledge of which variables are uniquely determined by the arguments through
+/// the constraints of a program
+struct Propagator<T> {
+    known: HashSet<FlatVariable>,
+    booleans: HashSet<FlatVariable>,
+    constraints: Vec<Constraint<T>>,
+    /// The outputs `y` of `ConditionEq` directives on `x`, which are determined by `x` through the
+    /// constraints `x * m == y` and `(1 - y) * x == 0`
+    conditions: Vec<(CanonicalLinComb<T>, FlatVariable)>,
+}
+
+fn constant<T: Field>(e: &CanonicalLinComb<T>) -> Option<T> {
+    match e.0.len() {
+        0 => Some(T::zero()),
+        1 => e.0.get(&FlatVariable::one()).cloned(),
+        _ => None,
+    }
+}
+
+fn summand<T: Field>(v: FlatVariable) -> CanonicalLinComb<T> {
+    LinComb::from(v).into_canonical()
+}
+
+fn is_power_of_two(n: &BigUint) -> Option<usize> {
+    match n.bits() {
+        0 => None,
+        bits => match *n == BigUint::from(1u32) << (bits - 1) {
+            true => Some(bits - 1),
+            false => None,
+        },
+    }
+}
+
+impl<T: Field> Propagator<T> {
+    fn new(prog: &Prog<T>) -> Self {
+        let mut constraints = vec![];
+        let mut booleans = HashSet::new();
+        let mut triples: HashSet<Triple<T>> = HashSet::new();
+
+        for s in &prog.statements {
+            if let Statement::Constraint(quad, lin, _) = s {
+                let (a, b, c) = (
+                    quad.left.clone().into_canonical(),
+                    quad.right.clone().into_canonical(),
+                    lin.clone().into_canonical(),
+                );
+
+                // `v * v == v` and `v * (v - 1) == 0` constrain `v` to be a boolean
+                let v = a.0.keys().next().cloned();
+                if let Some(v) = v.filter(|v| a == summand(*v) && *v != FlatVariable::one()) {
+                    let boolean = (b == a && c == a)
+                        || (b == (LinComb::from(v) - LinComb::one()).into_canonical()
+                            && c.0.is_empty());
+                    if boolean {
+                        booleans.insert(v);
+                    }
+                }
+
+                let linear = match (constant(&a), constant(&b)) {
+                    (Some(k), _) => Some(LinComb::from(b.clone()) * &k - LinComb::from(c.clone())),
+                    (_, Some(k)) => Some(LinComb::from(a.clone()) * &k - LinComb::from(c.clone())),
+                    _ => None,
+                }
+                .map(|l| l.into_canonical());
+
+                let mut variables: Vec<_> =
+                    a.0.keys()
+                        .chain(b.0.keys())
+                        .chain(c.0.keys())
+                        .filter(|v| **v != FlatVariable::one())
+                        .cloned()
+                        .collect();
+                variables.sort();
+                variables.dedup();
+
+                triples.insert((b.clone(), a.clone(), c.clone()));
+                triples.insert((a.clone(), b.clone(), c.clone()));
+
+                constraints.push(Constraint {
+                    variables,
+                    a,
+                    b,
+                    c,
+                    linear,
+                });
+            }
+        }
+
+        let conditions = prog
+            .statements
+            .iter()
+            .filter_map(|s| match s {
+                Statement::Directive(d) if d.solver == Solver::ConditionEq => {
+                    let x = d.inputs[0].clone().try_linear().ok()?.into_canonical();
+                    let (y, m) = (d.outputs[0], d.outputs[1]);
+                    let not_y = (LinComb::one() - LinComb::from(y)).into_canonical();
+
+                    match triples.contains(&(x.clone(), summand(m), summand(y)))
+                        && triples.contains(&(not_y, x.clone(), CanonicalLinComb(BTreeMap::new())))
+                    {
+                        true => Some((x, y)),
+                        false => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect();
+
+        let known = std::iter::once(FlatVariable::one())
+            .chain(prog.arguments.iter().map(|a| a.id))
+            .collect();
+
+        Propagator {
+            known,
+            booleans,
+            constraints,
+            conditions,
+        }
+    }
+
+    fn unknowns<'a>(&self, e: &'a CanonicalLinComb<T>) -> Vec<(&'a FlatVariable, &'a T)> {
+        e.0.iter()
+            .filter(|(v, _)| !self.known.contains(*v))
+            .collect()
+    }
+
+    /// Returns whether `unknowns` are the bits of a decomposition `k * sum(2^i * b_i)` for distinct
+    /// `i`, which has a unique solution as it cannot overflow the field
+    fn is_decomposition(&self, unknowns: &[(&FlatVariable, &T)]) -> bool {
+        if !unknowns.iter().all(|(v, _)| self.booleans.contains(*v)) {
+            return false;
+        }
+
+        let base = unknowns[0].1.inverse_mul().unwrap();
+
+        // the exponent of each coefficient relative to the first one
+        let exponents: Option<Vec<isize>> = unknowns
+            .iter()
+            .map(|(_, coefficient)| {
+                let ratio = (*coefficient).clone() * &base;
+                is_power_of_two(&ratio.to_biguint())
+                    .map(|e| e as isize)
+                    .or_else(|| {
+                        is_power_of_two(&ratio.inverse_mul().unwrap().to_biguint())
+                            .map(|e| -(e as isize))
+                    })
+            })
+            .collect();
+
+        match exponents {
+            Some(mut exponents) => {
+                exponents.sort_unstable();
+                let distinct = exponents.windows(2).all(|w| w[0] != w[1]);
+                let width = (exponents[exponents.len() - 1] - exponents[0] + 1) as usize;
+                distinct && width < T::get_required_bits()
+            }
+            None => false,
+        }
+    }
+
+    /// Marks the variables which can be determined from `constraint` as known, returning whether
+    /// any was found
+    fn propagate(&mut self, index: usize) -> bool {
+        let constraint = &self.constraints[index];
+
+        let determined: Vec<FlatVariable> = match &constraint.linear {
+            Some(linear) => {
+                let unknowns = self.unknowns(linear);
+                match unknowns.len() {
+                    0 => vec![],
+                    1 => vec![*unknowns[0].0],
+                    _ if self.is_decomposition(&unknowns) => {
+                        unknowns.into_iter().map(|(v, _)| *v).collect()
+                    }
+                    _ => vec![],
+                }
+            }
+            None => {
+                let unknowns = self.unknowns(&constraint.c);
+                match self.unknowns(&constraint.a).is_empty()
+                    && self.unknowns(&constraint.b).is_empty()
+                    && unknowns.len() == 1
+                {
+                    true => vec![*unknowns[0].0],
+                    false => vec![],
+                }
+            }
+        };
+
+        let found = !determined.is_empty();
+        self.known.extend(determined);
+        found
+    }
+
+    /// Propagates until no more variables can be determined
+    fn run(&mut self) {
+        let mut pending: Vec<usize> = (0..self.constraints.len()).collect();
+
+        loop {
+            let mut found = false;
+
+            for index in &pending {
+                found |= self.propagate(*index);
+            }
+
+            for (x, y) in &self.conditions {
+                if !self.known.contains(y) && self.unknowns(x).is_empty() {
+                    self.known.insert(*y);
+                    found = true;
+                }
+            }
+
+            let known = &self.known;
+            let constraints = &self.constraints;
+            pending.retain(|index| {
+                constraints[*index]
+                    .variables
+                    .iter()
+                    .any(|v| !known.contains(v))
+            });
+
+            if !found {
+                break;
+            }
+        }
+    }
+}
+
+impl<T: Field> Prog<T> {
+    /// Returns the directive outputs which could not be proven to be uniquely determined by the
+    /// arguments of the program
+    ///
+    /// Uniqueness is first established by propagating known variables through the linear
+    /// constraints, the bit decompositions and the equality checks of the program. If `solver` is
+    /// provided, it is then queried for each remaining output with two copies of the witness
+    /// which agree on all known variables.
+    pub fn under_constrained(
+        &self,
+        solver: Option<&dyn SmtSolver>,
+    ) -> Result<Vec<UnderConstrained>, String> {
+        let mut propagator = Propagator::new(self);
+        propagator.run();
+
+        let known = &propagator.known;
+
+        // outputs which appear in no constraint, such as values only computed to be logged, do
+        // not affect what is proven
+        let constrained: HashSet<_> = propagator
+            .constraints
+            .iter()
+            .flat_map(|c| c.variables.iter().cloned())
+            .collect();
+        let constrained = &constrained;
+
+        let candidates: Vec<_> = self
+            .statements
+            .iter()
+            .enumerate()
+            .filter_map(|(index, s)| match s {
+                Statement::Directive(d) => Some((index, d)),
+                _ => None,
+            })
+            .flat_map(|(index, d)| {
+                d.outputs
+                    .iter()
+                    .filter(move |v| constrained.contains(v))
+                    .filter(move |v| !known.contains(v))
+                    .map(move |v| UnderConstrained {
+                        variable: *v,
+                        index,
+                        solver: d.solver.clone(),
+                        not_unique: false,
+                    })
+            })
+            .collect();
+
+        let solver = match solver {
+            Some(solver) => solver,
+            None => return Ok(candidates),
+        };
+
+        let query = UniquenessQuery::new(self, &propagator.known);
+
+        candidates
+            .into_iter()
+            .filter_map(|c| match solver.check_sat(&query.query(c.variable)) {
+                Ok(SatResult::Unsat) => None,
+                Ok(SatResult::Sat) => Some(Ok(UnderConstrained {
+                    not_unique: true,
+                    ..c
+                })),
+                Ok(SatResult::Unknown) => Some(Ok(c)),
+                Err(e) => Some(Err(e)),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::FlatParameter;
+    use crate::ir::{Directive, QuadComb};
+    use zokrates_field::Bn128Field;
+
+    fn directive(
+        inputs: Vec<QuadComb<Bn128Field>>,
+        outputs: Vec<FlatVariable>,
+        solver: Solver,
+    ) -> Statement<Bn128Field> {
+        Statement::Directive(Directive {
+            inputs,
+            outputs,
+            solver,
+        })
+    }
+
+    fn boolean(v: FlatVariable) -> Statement<Bn128Field> {
+        Statement::constraint(QuadComb::from_linear_combinations(v.into(), v.into()), v)
+    }
+
+    struct Answer(&'static str);
+
+    impl SmtSolver for Answer {
+        fn run(&self, query: &str) -> Result<String, String> {
+            assert!(query.contains("(distinct (mod |_1| |~prime|)"));
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn linear() {
+        // def main(field a):
+        //     # _1 = Div(2 * a, 1)
+        //     _1 == 2 * a
+        let a = FlatVariable::new(0);
+        let x = FlatVariable::new(1);
+
+        let prog: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(a)],
+            statements: vec![
+                directive(
+                    vec![LinComb::summand(2, a).into(), LinComb::one().into()],
+                    vec![x],
+                    Solver::Div,
+                ),
+                Statement::definition(x, LinComb::summand(2, a)),
+            ],
+            returns: vec![],
+        };
+
+        assert_eq!(prog.under_constrained(None), Ok(vec![]));
+    }
+
+    #[test]
+    fn division() {
+        // def main(field a, field b):
+        //     # _2 = Div(a, b)
+        //     _2 * b == a
+        let a = FlatVariable::new(0);
+        let b = FlatVariable::new(1);
+        let q = FlatVariable::new(2);
+
+        let prog: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(a), FlatParameter::private(b)],
+            statements: vec![
+                directive(vec![a.into(), b.into()], vec![q], Solver::Div),
+                Statement::constraint(QuadComb::from_linear_combinations(q.into(), b.into()), a),
+            ],
+            returns: vec![],
+        };
+
+        // `b` may be zero, in which case `q` is free
+        assert_eq!(
+            prog.under_constrained(None),
+            Ok(vec![UnderConstrained {
+                variable: q,
+                index: 0,
+                solver: Solver::Div,
+                not_unique: false,
+            }])
+        );
+    }
+
+    #[test]
+    fn bits() {
+        // def main(field a):
+        //     # _1, _2, _3 = Bits(3)(a)
+        //     _1 * _1 == _1
+        //     ...
+        //     4 * _1 + 2 * _2 + _3 == a
+        let a = FlatVariable::new(0);
+        let bits: Vec<_> = (1..4).map(FlatVariable::new).collect();
+
+        let mut statements = vec![directive(vec![a.into()], bits.clone(), Solver::Bits(3))];
+        statements.extend(bits.iter().map(|b| boolean(*b)));
+        statements.push(Statement::constraint(
+            LinComb::summand(4, bits[0]) + LinComb::summand(2, bits[1]) + LinComb::from(bits[2]),
+            a,
+        ));
+
+        let prog: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(a)],
+            statements,
+            returns: vec![],
+        };
+
+        assert_eq!(prog.under_constrained(None), Ok(vec![]));
+
+        // without the boolean constraints, the decomposition is not unique
+        let prog = Prog {
+            statements: prog
+                .statements
+                .into_iter()
+                .filter(|s| s != &boolean(bits[1]))
+                .collect(),
+            ..prog
+        };
+
+        assert_eq!(prog.under_constrained(None).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn condition_eq() {
+        // def main(field a):
+        //     # _1, _2 = ConditionEq(a)
+        //     a * _2 == _1
+        //     (1 - _1) * a == 0
+        let a = FlatVariable::new(0);
+        let y = FlatVariable::new(1);
+        let m = FlatVariable::new(2);
+
+        let prog: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(a)],
+            statements: vec![
+                directive(vec![a.into()], vec![y, m], Solver::ConditionEq),
+                Statement::constraint(QuadComb::from_linear_combinations(a.into(), m.into()), y),
+                Statement::constraint(
+                    QuadComb::from_linear_combinations(LinComb::one() - y.into(), a.into()),
+                    LinComb::zero(),
+                ),
+            ],
+            returns: vec![],
+        };
+
+        // the inverse `_2` is free when `a` is zero, but `_1` is unique
+        assert_eq!(
+            prog.under_constrained(None),
+            Ok(vec![UnderConstrained {
+                variable: m,
+                index: 0,
+                solver: Solver::ConditionEq,
+                not_unique: false,
+            }])
+        );
+    }
+
+    #[test]
+    fn smt() {
+        // def main(field a, field b):
+        //     # _1 = Div(a, b)
+        //     _1 * b == a
+        let a = FlatVariable::new(0);
+        let q = FlatVariable::new(1);
+        let b = FlatVariable::new(2);
+
+        let prog: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(a), FlatParameter::private(b)],
+            statements: vec![
+                directive(vec![a.into(), b.into()], vec![q], Solver::Div),
+                Statement::constraint(QuadComb::from_linear_combinations(q.into(), b.into()), a),
+            ],
+            returns: vec![],
+        };
+
+        assert_eq!(prog.under_constrained(Some(&Answer("unsat"))), Ok(vec![]));
+        assert_eq!(
+            prog.under_constrained(Some(&Answer("sat"))),
+            Ok(vec![UnderConstrained {
+                variable: q,
+                index: 0,
+                solver: Solver::Div,
+                not_unique: true,
+            }])
+        );
+    }
+}
@@ -64,6 +64,17 @@ pub fn visit_statement<T: Field, F: Visitor<T>>(f: &mut F, s: &Statement<T>) {
             }
         }
         Statement::Directive(dir) => f.visit_directive(dir),
+        Statement::Log(_, expressions, conditions) => {
+            for (_, e) in expressions.iter() {
+                for e in e.iter() {
+                    f.visit_quadratic_combination(e);
+                }
+            }
+            for c in conditions.iter() {
+                f.visit_linear_combination(c);
+            }
+        }
+        Statement::Span(_) => {}
     }
 }
 
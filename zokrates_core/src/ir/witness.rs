@@ -1,10 +1,150 @@
 use crate::flat_absy::FlatVariable;
+use num_bigint::BigUint;
 use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
 use std::fmt;
 use std::io;
-use std::io::{Read, Write};
+use std::io::{Cursor, Read, Write};
+use std::marker::PhantomData;
 use zokrates_field::Field;
 
+// The binary witness format is made of
+// - the magic number `ZOKW`
+// - the version, 1
+// - the curve identifier, as in compiled programs
+// - the number of values, as a little-endian `u64`
+// - the value of each variable of the program in wire order (see `Prog::wires`), as a
+//   little-endian integer on the number of bytes needed to represent the field modulus
+// Variable ids are not stored: the program the witness was computed for is needed to read it.
+
+const WITNESS_MAGIC: &[u8; 4] = &[0x5a, 0x4f, 0x4b, 0x57];
+const WITNESS_VERSION_1: &[u8; 4] = &[0, 0, 0, 1];
+
+fn value_width<T: Field>() -> usize {
+    (T::get_required_bits() + 7) / 8
+}
+
+fn invalid_data<E: fmt::Display>(e: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
+/// Writes a witness in the binary format one value at a time
+pub struct BinaryWitnessWriter<W, T> {
+    writer: W,
+    count: u64,
+    written: u64,
+    _field: PhantomData<T>,
+}
+
+impl<W: Write, T: Field> BinaryWitnessWriter<W, T> {
+    /// Writes the header of the binary format for a witness of `count` values
+    pub fn new(mut writer: W, count: u64) -> io::Result<Self> {
+        writer.write_all(WITNESS_MAGIC)?;
+        writer.write_all(WITNESS_VERSION_1)?;
+        writer.write_all(&T::id())?;
+        writer.write_all(&count.to_le_bytes())?;
+
+        Ok(BinaryWitnessWriter {
+            writer,
+            count,
+            written: 0,
+            _field: PhantomData,
+        })
+    }
+
+    pub fn write_value(&mut self, value: &T) -> io::Result<()> {
+        if self.written == self.count {
+            return Err(invalid_data(format!(
+                "The witness only has {} values",
+                self.count
+            )));
+        }
+
+        let mut bytes = value.to_byte_vector();
+        bytes.resize(value_width::<T>(), 0);
+        self.writer.write_all(&bytes)?;
+        self.written += 1;
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.written != self.count {
+            return Err(invalid_data(format!(
+                "Expected {} values in witness, found {}",
+                self.count, self.written
+            )));
+        }
+
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads the values of a witness in the binary format one at a time
+pub struct BinaryWitnessReader<R, T> {
+    reader: R,
+    count: u64,
+    read: u64,
+    _field: PhantomData<T>,
+}
+
+impl<R: Read, T: Field> BinaryWitnessReader<R, T> {
+    /// Reads and checks the header of the binary format
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let mut header = [0; 20];
+        reader.read_exact(&mut header)?;
+
+        if &header[0..4] != WITNESS_MAGIC {
+            return Err(invalid_data("Wrong magic number"));
+        }
+        if &header[4..8] != WITNESS_VERSION_1 {
+            return Err(invalid_data("Unknown witness version"));
+        }
+        if header[8..12] != T::id() {
+            return Err(invalid_data(format!(
+                "The witness is not defined over the {} curve",
+                T::name()
+            )));
+        }
+
+        let mut count = [0; 8];
+        count.copy_from_slice(&header[12..20]);
+
+        Ok(BinaryWitnessReader {
+            reader,
+            count: u64::from_le_bytes(count),
+            read: 0,
+            _field: PhantomData,
+        })
+    }
+
+    /// Returns the number of values in the witness, as given by its header
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    fn read_value(&mut self) -> io::Result<T> {
+        let mut bytes = vec![0; value_width::<T>()];
+        self.reader.read_exact(&mut bytes)?;
+        self.read += 1;
+
+        T::try_from(BigUint::from_bytes_le(&bytes))
+            .map_err(|_| invalid_data(format!("Invalid value in witness at {}", self.read - 1)))
+    }
+}
+
+impl<R: Read, T: Field> Iterator for BinaryWitnessReader<R, T> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read < self.count {
+            true => Some(self.read_value()),
+            false => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Witness<T>(pub BTreeMap<FlatVariable, T>);
 
@@ -52,7 +192,63 @@ impl<T: Field> Witness<T> {
         Ok(())
     }
 
-    pub fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+    /// Writes the witness in the binary format, with values in the order given by `wires`
+    pub fn write_binary<W: Write>(&self, wires: &[FlatVariable], writer: W) -> io::Result<()> {
+        let mut writer = BinaryWitnessWriter::new(writer, wires.len() as u64)?;
+
+        for v in wires {
+            let value = match *v == FlatVariable::one() {
+                true => T::one(),
+                false => self.0.get(v).cloned().ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("Missing value for {} in witness", v),
+                    )
+                })?,
+            };
+            writer.write_value(&value)?;
+        }
+
+        writer.finish().map(|_| ())
+    }
+
+    /// Reads a witness in the binary or in the text format, depending on its header. The values
+    /// of a binary witness are assigned to `wires` in order.
+    pub fn read<R: Read>(mut reader: R, wires: &[FlatVariable]) -> io::Result<Self> {
+        let mut header = vec![];
+        reader.by_ref().take(4).read_to_end(&mut header)?;
+
+        let reader = Cursor::new(header).chain(reader);
+
+        match is_binary_witness(reader.get_ref().0.get_ref()) {
+            true => Self::read_binary(reader, wires),
+            false => Self::read_text(reader),
+        }
+    }
+
+    /// Reads a witness in the binary format, assigning its values to `wires` in order
+    pub fn read_binary<R: Read>(reader: R, wires: &[FlatVariable]) -> io::Result<Self> {
+        let reader = BinaryWitnessReader::new(reader)?;
+
+        if reader.count() != wires.len() as u64 {
+            return Err(invalid_data(format!(
+                "The witness has {} values but the program has {} variables",
+                reader.count(),
+                wires.len()
+            )));
+        }
+
+        wires
+            .iter()
+            .cloned()
+            .zip(reader)
+            .map(|(variable, value)| value.map(|value| (variable, value)))
+            .collect::<io::Result<_>>()
+            .map(Witness)
+    }
+
+    /// Reads a witness in the text format
+    pub fn read_text<R: Read>(mut reader: R) -> io::Result<Self> {
         let mut rdr = csv::ReaderBuilder::new()
             .delimiter(b' ')
             .flexible(true)
@@ -89,6 +285,11 @@ impl<T: Field> Witness<T> {
     }
 }
 
+/// Returns whether `header`, the first bytes of a witness, is the header of the binary format
+pub fn is_binary_witness(header: &[u8]) -> bool {
+    header.starts_with(WITNESS_MAGIC)
+}
+
 impl<T: Field> fmt::Display for Witness<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -106,7 +307,7 @@ impl<T: Field> fmt::Display for Witness<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use zokrates_field::Bn128Field;
+    use zokrates_field::{Bls12_381Field, Bn128Field};
 
     mod io {
         use super::*;
@@ -129,11 +330,108 @@ mod tests {
             w.write(&mut buff).unwrap();
             buff.set_position(0);
 
-            let r = Witness::read(buff).unwrap();
+            let r = Witness::read(buff, &[]).unwrap();
+
+            assert_eq!(w, r);
+        }
+
+        #[test]
+        fn serialize_deserialize_binary() {
+            let w = Witness(
+                vec![
+                    (FlatVariable::new(42), Bn128Field::from(42)),
+                    (FlatVariable::public(8), Bn128Field::from(-1)),
+                    (FlatVariable::one(), Bn128Field::from(1)),
+                ]
+                .into_iter()
+                .collect(),
+            );
+
+            let wires = vec![
+                FlatVariable::one(),
+                FlatVariable::public(8),
+                FlatVariable::new(42),
+            ];
+
+            let mut buff = Cursor::new(vec![]);
+
+            w.write_binary(&wires, &mut buff).unwrap();
+
+            // the header, the number of values and three 32 bytes values
+            assert_eq!(buff.get_ref().len(), 12 + 8 + 3 * 32);
+
+            buff.set_position(0);
+
+            let r = Witness::read(buff, &wires).unwrap();
 
             assert_eq!(w, r);
         }
 
+        #[test]
+        fn binary_missing_value() {
+            let w = Witness(
+                vec![(FlatVariable::one(), Bn128Field::from(1))]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut buff = Cursor::new(vec![]);
+
+            assert!(w
+                .write_binary(&[FlatVariable::one(), FlatVariable::new(42)], &mut buff)
+                .is_err());
+        }
+
+        #[test]
+        fn binary_wrong_wires() {
+            let w = Witness(
+                vec![(FlatVariable::one(), Bn128Field::from(1))]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut buff = Cursor::new(vec![]);
+            w.write_binary(&[FlatVariable::one()], &mut buff).unwrap();
+            buff.set_position(0);
+
+            assert!(Witness::<Bn128Field>::read(
+                buff,
+                &[FlatVariable::one(), FlatVariable::new(42)]
+            )
+            .is_err());
+        }
+
+        #[test]
+        fn binary_wrong_curve() {
+            let w = Witness(
+                vec![(FlatVariable::one(), Bls12_381Field::from(1))]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut buff = Cursor::new(vec![]);
+            w.write_binary(&[FlatVariable::one()], &mut buff).unwrap();
+            buff.set_position(0);
+
+            assert!(Witness::<Bn128Field>::read(buff, &[FlatVariable::one()]).is_err());
+        }
+
+        #[test]
+        fn binary_truncated() {
+            let w = Witness(
+                vec![(FlatVariable::one(), Bn128Field::from(1))]
+                    .into_iter()
+                    .collect(),
+            );
+
+            let mut buff = Cursor::new(vec![]);
+            w.write_binary(&[FlatVariable::one()], &mut buff).unwrap();
+            buff.get_mut().pop();
+            buff.set_position(0);
+
+            assert!(Witness::<Bn128Field>::read(buff, &[FlatVariable::one()]).is_err());
+        }
+
         #[test]
         fn wrong_value() {
             let mut buff = Cursor::new(vec![]);
@@ -141,7 +439,7 @@ mod tests {
             buff.write_all("_1 123bug".as_ref()).unwrap();
             buff.set_position(0);
 
-            assert!(Witness::<Bn128Field>::read(buff).is_err());
+            assert!(Witness::<Bn128Field>::read(buff, &[]).is_err());
         }
 
         #[test]
@@ -151,7 +449,7 @@ mod tests {
             buff.write_all("_1bug 123".as_ref()).unwrap();
             buff.set_position(0);
 
-            assert!(Witness::<Bn128Field>::read(buff).is_err());
+            assert!(Witness::<Bn128Field>::read(buff, &[]).is_err());
         }
 
         #[test]
@@ -160,7 +458,7 @@ mod tests {
             buff.write_all("whatwhat".as_ref()).unwrap();
             buff.set_position(0);
 
-            assert!(Witness::<Bn128Field>::read(buff).is_err());
+            assert!(Witness::<Bn128Field>::read(buff, &[]).is_err());
         }
     }
 }
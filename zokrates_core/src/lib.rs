@@ -15,7 +15,6 @@ mod macros;
 mod optimizer;
 mod parser;
 mod semantics;
-mod solvers;
 mod static_analysis;
 mod zir;
 
@@ -23,5 +22,7 @@ pub mod absy;
 pub mod compile;
 pub mod flat_absy;
 pub mod ir;
+pub mod passes;
 pub mod proof_system;
+pub mod solvers;
 pub mod typed_absy;
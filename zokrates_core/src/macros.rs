@@ -1,10 +1,16 @@
 use std::fmt;
 use zokrates_field::Field;
-use zokrates_pest_ast::File;
+use zokrates_pest_ast::{File, FunctionDefinition, SymbolDeclaration};
+
+/// Attribute marking a function as a test
+pub const TEST_ATTRIBUTE: &str = "test";
+/// Attribute marking a test as expected to fail
+pub const SHOULD_FAIL_ATTRIBUTE: &str = "should_fail";
 
 #[derive(Debug)]
 pub enum Error {
     Curve(String, String),
+    Attribute(String),
 }
 
 impl fmt::Display for Error {
@@ -15,11 +21,18 @@ impl fmt::Display for Error {
                 "When processing macros: curve `{}` is incompatible with curve `{}`",
                 found, expected
             ),
+            Error::Attribute(message) => write!(f, "When processing macros: {}", message),
         }
     }
 }
 
 pub fn process_macros<T: Field>(file: File) -> Result<File, Error> {
+    for declaration in &file.declarations {
+        if let SymbolDeclaration::Function(function) = declaration {
+            check_attributes(function)?;
+        }
+    }
+
     match &file.pragma {
         Some(pragma) => {
             if T::name() != pragma.curve.name {
@@ -34,3 +47,28 @@ pub fn process_macros<T: Field>(file: File) -> Result<File, Error> {
         None => Ok(file),
     }
 }
+
+fn check_attributes(function: &FunctionDefinition) -> Result<(), Error> {
+    let has_attribute = |name| {
+        function
+            .attributes
+            .iter()
+            .any(|a| a.id.span.as_str() == name)
+    };
+
+    for attribute in &function.attributes {
+        match attribute.id.span.as_str() {
+            TEST_ATTRIBUTE | SHOULD_FAIL_ATTRIBUTE => {}
+            name => return Err(Error::Attribute(format!("unknown attribute `#[{}]`", name))),
+        }
+    }
+
+    if has_attribute(SHOULD_FAIL_ATTRIBUTE) && !has_attribute(TEST_ATTRIBUTE) {
+        return Err(Error::Attribute(format!(
+            "`#[{}]` can only be used on functions marked with `#[{}]`",
+            SHOULD_FAIL_ATTRIBUTE, TEST_ATTRIBUTE
+        )));
+    }
+
+    Ok(())
+}
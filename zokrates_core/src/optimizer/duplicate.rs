@@ -51,6 +51,12 @@ impl<T: Field> Folder<T> for DuplicateOptimizer {
     }
 
     fn fold_statement(&mut self, s: Statement<T>) -> Vec<Statement<T>> {
+        // logging the same values twice is not a duplicate, as each log is printed, and
+        // repeated spans mark distinct regions of the program
+        if let Statement::Log(..) | Statement::Span(..) = s {
+            return vec![s];
+        }
+
         let hashed = hash(&s);
         let result = match self.seen.get(&hashed) {
             Some(_) => vec![],
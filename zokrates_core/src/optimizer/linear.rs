@@ -0,0 +1,470 @@
+//! Module containing the `LinearOptimizer` to remove linear constraints of the form
+// ```
+// a + 2 * b - c == d
+// ```
+// by substituting one of their variables into the other constraints
+
+// # Elimination rules
+
+// A linear constraint `l == 0` containing a variable `v` with coefficient `k` can be removed by
+// substituting `v` with `-(l - k * v) / k` in all other constraints, as long as:
+// - `v` is the output of a directive, so that it is still computed during witness generation
+//   even though the constraint defining it is gone. Arguments, return values and variables
+//   defined by constraints are never substituted.
+// - the constraint `l == 0` is a check and does not define a variable during witness generation
+// - every constraint defining a variable which `v` appears in comes after all the variables of
+//   `l` are computed
+// - no constraint grows past `max_density` terms
+//
+// Checks which `v` appears in are moved after the computation of all the variables of `l` if
+// needed, so that the witness can still be computed in order. Among the variables satisfying
+// these rules, the one appearing in the fewest constraints is substituted.
+
+use crate::flat_absy::flat_variable::FlatVariable;
+use crate::ir::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use zokrates_field::Field;
+
+type Constraint<T> = (
+    CanonicalLinComb<T>,
+    CanonicalLinComb<T>,
+    CanonicalLinComb<T>,
+);
+
+pub struct LinearOptimizer<T> {
+    max_density: usize,
+    statements: Vec<Option<Statement<T>>>,
+    /// The index of the first statement which can use each variable during witness generation
+    available_from: HashMap<FlatVariable, usize>,
+    /// The variables computed by directives
+    directive_outputs: HashSet<FlatVariable>,
+    /// The constraints which define a variable during witness generation
+    definitions: HashSet<usize>,
+    /// The constraints each variable appears in
+    occurrences: HashMap<FlatVariable, BTreeSet<usize>>,
+}
+
+fn substitute<T: Field>(
+    e: CanonicalLinComb<T>,
+    v: &FlatVariable,
+    value: &CanonicalLinComb<T>,
+) -> CanonicalLinComb<T> {
+    let mut e = e.0;
+    if let Some(coefficient) = e.remove(v) {
+        for (variable, c) in &value.0 {
+            let c = c.clone() * &coefficient;
+            let sum = e.get(variable).cloned().unwrap_or_else(T::zero) + c;
+            match sum == T::zero() {
+                true => e.remove(variable),
+                false => e.insert(*variable, sum),
+            };
+        }
+    }
+    CanonicalLinComb(e)
+}
+
+fn variables<T>(s: &Statement<T>) -> Vec<FlatVariable> {
+    match s {
+        Statement::Constraint(quad, lin, _) => quad
+            .left
+            .0
+            .iter()
+            .chain(quad.right.0.iter())
+            .chain(lin.0.iter())
+            .map(|(v, _)| *v)
+            .filter(|v| *v != FlatVariable::one())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+impl<T: Field> LinearOptimizer<T> {
+    fn new(p: &Prog<T>, max_density: usize) -> Self {
+        let mut available_from: HashMap<_, _> = p.arguments.iter().map(|a| (a.id, 0)).collect();
+        available_from.insert(FlatVariable::one(), 0);
+
+        let mut directive_outputs = HashSet::new();
+        let mut definitions = HashSet::new();
+        let mut occurrences: HashMap<_, BTreeSet<_>> = HashMap::new();
+
+        // follow the order in which the interpreter computes the witness
+        for (index, s) in p.statements.iter().enumerate() {
+            match s {
+                Statement::Directive(d) => {
+                    for o in &d.outputs {
+                        available_from.insert(*o, index + 1);
+                        directive_outputs.insert(*o);
+                    }
+                }
+                Statement::Constraint(_, lin, _) => {
+                    if let [(v, coefficient)] = lin.0.as_slice() {
+                        if *coefficient == T::one() && !available_from.contains_key(v) {
+                            available_from.insert(*v, index + 1);
+                            definitions.insert(index);
+                        }
+                    }
+
+                    for v in variables(s) {
+                        occurrences.entry(v).or_default().insert(index);
+                    }
+                }
+                Statement::Log(..) | Statement::Span(..) => {}
+            }
+        }
+
+        LinearOptimizer {
+            max_density,
+            statements: vec![],
+            available_from,
+            directive_outputs,
+            definitions,
+            occurrences,
+        }
+    }
+
+    pub fn optimize(p: Prog<T>, max_density: usize) -> Prog<T> {
+        let mut optimizer = Self::new(&p, max_density);
+        optimizer.statements = p.statements.into_iter().map(Some).collect();
+
+        for index in 0..optimizer.statements.len() {
+            optimizer.eliminate(index);
+        }
+
+        let statements = optimizer.schedule();
+
+        Prog { statements, ..p }
+    }
+
+    fn canonical(&self, index: usize) -> Option<Constraint<T>> {
+        match self.statements[index].as_ref()? {
+            Statement::Constraint(quad, lin, _) => Some((
+                quad.left.clone().into_canonical(),
+                quad.right.clone().into_canonical(),
+                lin.clone().into_canonical(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Tries to remove the constraint at `index` if it is a linear check
+    fn eliminate(&mut self, index: usize) {
+        if self.definitions.contains(&index) {
+            return;
+        }
+
+        let linear = match self.statements[index].as_ref() {
+            Some(Statement::Constraint(quad, lin, _)) => match quad.clone().try_linear() {
+                Ok(l) => (l - lin.clone()).into_canonical(),
+                Err(_) => return,
+            },
+            _ => return,
+        };
+
+        let mut candidates: Vec<_> = linear
+            .0
+            .keys()
+            .filter(|v| self.directive_outputs.contains(v))
+            .cloned()
+            .collect();
+        candidates.sort_by_key(|v| self.occurrences[v].len());
+
+        for v in candidates {
+            // the value of `v` according to the constraint
+            let mut value = linear.clone();
+            let coefficient = value.0.remove(&v).unwrap();
+            let value = LinComb::from(value) * &(T::zero() - coefficient).inverse_mul().unwrap();
+            let value = value.into_canonical();
+
+            // the first statement at which all the variables of the value are computed
+            let available = value
+                .0
+                .keys()
+                .map(|u| self.available_from[u])
+                .max()
+                .unwrap_or(0);
+
+            let others: Vec<_> = self.occurrences[&v]
+                .iter()
+                .cloned()
+                .filter(|i| *i != index && self.statements[*i].is_some())
+                .collect();
+
+            // definitions cannot be moved, so they must come after the value is computed
+            if others
+                .iter()
+                .any(|i| self.definitions.contains(i) && *i < available)
+            {
+                continue;
+            }
+
+            let substituted: Option<Vec<_>> = others
+                .iter()
+                .map(|i| {
+                    let (a, b, c) = self.canonical(*i).unwrap();
+                    let (a, b, c) = (
+                        substitute(a, &v, &value),
+                        substitute(b, &v, &value),
+                        substitute(c, &v, &value),
+                    );
+                    match a.0.len() + b.0.len() + c.0.len() <= self.max_density {
+                        true => Some((*i, a, b, c)),
+                        false => None,
+                    }
+                })
+                .collect();
+
+            let substituted = match substituted {
+                Some(substituted) => substituted,
+                None => continue,
+            };
+
+            for (i, a, b, c) in substituted {
+                let old = self.statements[i].take().unwrap();
+                let before = variables(&old);
+                let error = match old {
+                    Statement::Constraint(_, _, error) => error,
+                    _ => unreachable!(),
+                };
+
+                let statement = Statement::Constraint(
+                    QuadComb::from_linear_combinations(a.into(), b.into()),
+                    c.into(),
+                    error,
+                );
+
+                // the substitution can cancel variables out of the constraint
+                let after = variables(&statement);
+                for u in before.iter().filter(|u| !after.contains(u)) {
+                    if let Some(occurrences) = self.occurrences.get_mut(u) {
+                        occurrences.remove(&i);
+                    }
+                }
+                for u in &after {
+                    self.occurrences.entry(*u).or_default().insert(i);
+                }
+
+                self.statements[i] = Some(statement);
+            }
+
+            for u in variables(self.statements[index].as_ref().unwrap()) {
+                self.occurrences.get_mut(&u).unwrap().remove(&index);
+            }
+            self.occurrences.remove(&v);
+            self.statements[index] = None;
+
+            return;
+        }
+    }
+
+    /// Returns the remaining statements, moving the checks which use variables computed later
+    /// right after them
+    fn schedule(self) -> Vec<Statement<T>> {
+        let mut deferred: BTreeMap<usize, Vec<Statement<T>>> = BTreeMap::new();
+        let mut res = vec![];
+
+        for (index, s) in self.statements.into_iter().enumerate() {
+            if let Some(s) = s {
+                let available = variables(&s)
+                    .iter()
+                    .map(|v| self.available_from[v])
+                    .max()
+                    .unwrap_or(0);
+
+                match !self.definitions.contains(&index) && available > index {
+                    true => deferred.entry(available).or_default().push(s),
+                    false => res.push(s),
+                }
+            }
+
+            res.extend(deferred.remove(&(index + 1)).into_iter().flatten());
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::FlatParameter;
+    use crate::solvers::Solver;
+    use zokrates_field::Bn128Field;
+
+    fn copy(from: FlatVariable, to: FlatVariable) -> Statement<Bn128Field> {
+        Statement::Directive(Directive {
+            inputs: vec![from.into(), LinComb::one().into()],
+            outputs: vec![to],
+            solver: Solver::Div,
+        })
+    }
+
+    fn boolean(v: FlatVariable) -> Statement<Bn128Field> {
+        Statement::constraint(QuadComb::from_linear_combinations(v.into(), v.into()), v)
+    }
+
+    #[test]
+    fn eliminate() {
+        // def main(private field a) -> field:
+        //     # y = Div(a, 1)
+        //     y == a
+        //     ~out_0 = y * y
+
+        let a = FlatVariable::new(0);
+        let y = FlatVariable::new(1);
+        let out = FlatVariable::public(0);
+
+        let p: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(a)],
+            statements: vec![
+                copy(a, y),
+                Statement::constraint(y, a),
+                Statement::definition(out, QuadComb::from_linear_combinations(y.into(), y.into())),
+            ],
+            returns: vec![out],
+        };
+
+        let optimized: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(a)],
+            statements: vec![
+                copy(a, y),
+                Statement::definition(out, QuadComb::from_linear_combinations(a.into(), a.into())),
+            ],
+            returns: vec![out],
+        };
+
+        assert_eq!(LinearOptimizer::optimize(p.clone(), 3), optimized);
+
+        // the substituted definition would have 3 terms
+        assert_eq!(LinearOptimizer::optimize(p.clone(), 2), p);
+
+        let interpreter = Interpreter::default();
+        let input = [Bn128Field::from(42)];
+        assert_eq!(
+            interpreter.execute(&p, &input).unwrap().return_values(),
+            interpreter
+                .execute(&optimized, &input)
+                .unwrap()
+                .return_values()
+        );
+    }
+
+    #[test]
+    fn cancelled_variables() {
+        // def main(private field t) -> field:
+        //     # v = Div(t, 1)
+        //     # u = Div(t, 1)
+        //     # w = Div(0, 1)
+        //     v == u
+        //     v - u == w
+        //     t == u
+        //     ~out_0 = u * u
+
+        // substituting `v` with `u` cancels `u` out of the second constraint, which is then
+        // eliminated as well, so it must not be substituted into when eliminating the third one
+
+        let t = FlatVariable::new(0);
+        let v = FlatVariable::new(1);
+        let u = FlatVariable::new(2);
+        let w = FlatVariable::new(3);
+        let out = FlatVariable::public(0);
+
+        let zero = Statement::Directive(Directive {
+            inputs: vec![LinComb::zero().into(), LinComb::one().into()],
+            outputs: vec![w],
+            solver: Solver::Div,
+        });
+
+        let p: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(t)],
+            statements: vec![
+                copy(t, v),
+                copy(t, u),
+                zero.clone(),
+                Statement::constraint(v, u),
+                Statement::constraint(LinComb::from(v) - LinComb::from(u), w),
+                Statement::constraint(t, u),
+                Statement::definition(out, QuadComb::from_linear_combinations(u.into(), u.into())),
+            ],
+            returns: vec![out],
+        };
+
+        let optimized: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(t)],
+            statements: vec![
+                copy(t, v),
+                copy(t, u),
+                zero,
+                Statement::definition(out, QuadComb::from_linear_combinations(t.into(), t.into())),
+            ],
+            returns: vec![out],
+        };
+
+        assert_eq!(LinearOptimizer::optimize(p.clone(), 10), optimized);
+
+        let interpreter = Interpreter::default();
+        let input = [Bn128Field::from(42)];
+        assert_eq!(
+            interpreter.execute(&p, &input).unwrap().return_values(),
+            interpreter
+                .execute(&optimized, &input)
+                .unwrap()
+                .return_values()
+        );
+    }
+
+    #[test]
+    fn move_checks() {
+        // def main(private field a) -> field:
+        //     # y = Div(a, 1)
+        //     y * y == y
+        //     # z = Div(a, 1)
+        //     z == y
+        //     z * z == z
+        //     ~out_0 = z
+
+        // `y` appears in fewer constraints, so it is substituted with `z`, which is only computed
+        // after the first check
+
+        let a = FlatVariable::new(0);
+        let y = FlatVariable::new(1);
+        let z = FlatVariable::new(2);
+        let out = FlatVariable::public(0);
+
+        let p: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(a)],
+            statements: vec![
+                copy(a, y),
+                boolean(y),
+                copy(a, z),
+                Statement::constraint(z, y),
+                boolean(z),
+                Statement::definition(out, z),
+            ],
+            returns: vec![out],
+        };
+
+        let optimized: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(a)],
+            statements: vec![
+                copy(a, y),
+                copy(a, z),
+                boolean(z),
+                boolean(z),
+                Statement::definition(out, z),
+            ],
+            returns: vec![out],
+        };
+
+        assert_eq!(LinearOptimizer::optimize(p.clone(), 10), optimized);
+
+        let interpreter = Interpreter::default();
+        for input in &[[Bn128Field::from(1)], [Bn128Field::from(2)]] {
+            assert_eq!(
+                interpreter.execute(&p, input).map(|w| w.return_values()),
+                interpreter
+                    .execute(&optimized, input)
+                    .map(|w| w.return_values())
+            );
+        }
+    }
+}
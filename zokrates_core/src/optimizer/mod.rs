@@ -7,44 +7,46 @@
 mod canonicalizer;
 mod directive;
 mod duplicate;
+mod linear;
 mod redefinition;
 mod tautology;
 
 use self::directive::DirectiveOptimizer;
 use self::duplicate::DuplicateOptimizer;
+use self::linear::LinearOptimizer;
 use self::redefinition::RedefinitionOptimizer;
 use self::tautology::TautologyOptimizer;
 
+use crate::compile::CompileConfig;
 use crate::ir::Prog;
+use crate::passes::{PassManager, PassReport};
+use std::convert::Infallible;
 use zokrates_field::Field;
 
 impl<T: Field> Prog<T> {
-    pub fn optimize(self) -> Self {
-        // remove redefinitions
-        log::debug!("Constraints: {}", self.constraint_count());
-        log::debug!("Optimizer: Remove redefinitions");
-        let r = RedefinitionOptimizer::optimize(self);
-        log::trace!("\n{}\n", r);
-
-        // remove constraints that are always satisfied
-        log::debug!("Constraints: {}", r.constraint_count());
-        log::debug!("Optimizer: Remove tautologies");
-        let r = TautologyOptimizer::optimize(r);
-        log::trace!("\n{}\n", r);
-
-        // deduplicate directives which take the same input
-        log::debug!("Constraints: {}", r.constraint_count());
-        log::debug!("Optimizer: Remove duplicate directive");
-        let r = DirectiveOptimizer::optimize(r);
-        log::trace!("\n{}\n", r);
-
-        // remove duplicate constraints
-        log::debug!("Constraints: {}", r.constraint_count());
-        log::debug!("Optimizer: Remove duplicate constraints");
-        let r = DuplicateOptimizer::optimize(r);
-        log::trace!("\n{}\n", r);
+    pub fn optimize(self, config: &CompileConfig, reports: &mut Vec<PassReport>) -> Self {
+        PassManager::new(config)
+            // remove redefinitions
+            .pass("redefinition-removal", |p: Prog<T>| {
+                Ok::<_, Infallible>(RedefinitionOptimizer::optimize(p))
+            })
+            // remove constraints that are always satisfied
+            .pass("tautology-removal", |p| Ok(TautologyOptimizer::optimize(p)))
+            // deduplicate directives which take the same input
+            .pass("directive-deduplication", |p| {
+                Ok(DirectiveOptimizer::optimize(p))
+            })
+            // remove duplicate constraints
+            .pass("constraint-deduplication", |p| {
+                Ok(DuplicateOptimizer::optimize(p))
+            })
+            .run(self, reports)
+            .unwrap_or_else(|e| match e {})
+    }
 
-        log::debug!("Constraints: {}", r.constraint_count());
-        r
+    /// Removes linear constraints by substituting one of their variables in the other
+    /// constraints, as long as no constraint grows past `max_density` terms
+    pub fn eliminate_linear_constraints(self, max_density: usize) -> Self {
+        LinearOptimizer::optimize(self, max_density)
     }
 }
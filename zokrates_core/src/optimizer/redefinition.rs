@@ -38,7 +38,7 @@
 
 use crate::flat_absy::flat_variable::FlatVariable;
 use crate::flat_absy::FlatParameter;
-use crate::ir::folder::{fold_module, Folder};
+use crate::ir::folder::{fold_module, fold_statement, Folder};
 use crate::ir::LinComb;
 use crate::ir::*;
 use std::collections::{HashMap, HashSet};
@@ -184,6 +184,7 @@ impl<T: Field> Folder<T> for RedefinitionOptimizer<T> {
                     }
                 }
             }
+            s @ Statement::Log(..) | s @ Statement::Span(..) => fold_statement(self, s),
         }
     }
 
@@ -0,0 +1,477 @@
+//! Module containing the pass manager, which runs the named passes of the compiler in sequence
+//! depending on the optimization level, and reports what each of them did
+
+use crate::compile::CompileConfig;
+use crate::flat_absy::{FlatProg, FlatStatement};
+use crate::ir;
+use crate::typed_absy::TypedProgram;
+use crate::zir::ZirProgram;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::{Duration, Instant};
+use zokrates_field::Field;
+
+/// The optimization level, which decides which optional passes are run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum OptLevel {
+    /// Only the passes required for the program to compile
+    O0,
+    /// Cheap optimizations
+    O1,
+    /// All optimizations which do not change the shape of the constraints
+    O2,
+    /// Optimizations which trade constraint density for fewer constraints
+    O3,
+}
+
+impl Default for OptLevel {
+    fn default() -> Self {
+        OptLevel::O2
+    }
+}
+
+impl std::str::FromStr for OptLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "0" => Ok(OptLevel::O0),
+            "1" => Ok(OptLevel::O1),
+            "2" => Ok(OptLevel::O2),
+            "3" => Ok(OptLevel::O3),
+            _ => Err(format!(
+                "Invalid optimization level `{}`, expected 0, 1, 2 or 3",
+                s
+            )),
+        }
+    }
+}
+
+/// A pass of the compiler
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PassInfo {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// The lowest optimization level at which the pass runs, or `None` if the pass is required
+    pub level: Option<OptLevel>,
+}
+
+/// The passes of the compiler, in the order in which they run
+pub const PASSES: &[PassInfo] = &[
+    PassInfo {
+        name: "constant-resolution",
+        description: "Inline user-defined constants",
+        level: None,
+    },
+    PassInfo {
+        name: "branch-isolation",
+        description: "Isolate branches, if `isolate_branches` is set",
+        level: Some(OptLevel::O0),
+    },
+    PassInfo {
+        name: "reduction",
+        description: "Reduce the program to a single function",
+        level: None,
+    },
+    PassInfo {
+        name: "propagation",
+        description: "Propagate constants",
+        level: None,
+    },
+    PassInfo {
+        name: "struct-concretization",
+        description: "Concretize generic structs",
+        level: None,
+    },
+    PassInfo {
+        name: "variable-write-removal",
+        description: "Remove assignments to array elements at variable indices",
+        level: None,
+    },
+    PassInfo {
+        name: "constant-argument-check",
+        description: "Detect non constant shifts and constant lt bounds",
+        level: None,
+    },
+    PassInfo {
+        name: "out-of-bounds-check",
+        description: "Detect out of bounds reads and writes",
+        level: None,
+    },
+    PassInfo {
+        name: "zir-propagation",
+        description: "Propagate constants in zir",
+        level: Some(OptLevel::O1),
+    },
+    PassInfo {
+        name: "uint-optimization",
+        description: "Optimize uint expressions",
+        level: None,
+    },
+    PassInfo {
+        name: "flat-propagation",
+        description: "Propagate constants in the flat program",
+        level: Some(OptLevel::O1),
+    },
+    PassInfo {
+        name: "redefinition-removal",
+        description: "Remove redefinitions",
+        level: Some(OptLevel::O1),
+    },
+    PassInfo {
+        name: "tautology-removal",
+        description: "Remove constraints that are always satisfied",
+        level: Some(OptLevel::O1),
+    },
+    PassInfo {
+        name: "directive-deduplication",
+        description: "Deduplicate directives which take the same input",
+        level: Some(OptLevel::O2),
+    },
+    PassInfo {
+        name: "constraint-deduplication",
+        description: "Remove duplicate constraints",
+        level: Some(OptLevel::O2),
+    },
+    PassInfo {
+        name: "linear-elimination",
+        description:
+            "Eliminate linear constraints, also run below `-O3` if `linear_elimination` is set",
+        level: Some(OptLevel::O3),
+    },
+];
+
+pub fn pass_info(name: &str) -> Option<&'static PassInfo> {
+    PASSES.iter().find(|p| p.name == name)
+}
+
+/// Checks that the passes named in the configuration exist, either among the passes of the
+/// compiler or among the `custom` ones, and can be disabled
+pub fn check_config(config: &CompileConfig, custom: &[PassInfo]) -> Result<(), String> {
+    let info = |name: &str| {
+        pass_info(name)
+            .cloned()
+            .or_else(|| custom.iter().find(|p| p.name == name).cloned())
+    };
+
+    for name in config.disabled_passes.iter().chain(&config.print_after) {
+        info(name).ok_or_else(|| {
+            format!(
+                "Unknown pass `{}`, expected one of {}",
+                name,
+                PASSES
+                    .iter()
+                    .chain(custom)
+                    .map(|p| p.name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?;
+    }
+
+    for name in &config.disabled_passes {
+        if info(name).unwrap().level.is_none() {
+            return Err(format!(
+                "Pass `{}` is required and cannot be disabled",
+                name
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// A program which passes run on
+pub trait Program: fmt::Display {
+    /// The number of constraints of the program, if it is known at this stage
+    fn constraint_count(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<'ast, T: Field> Program for TypedProgram<'ast, T> {}
+
+impl<'ast, T: Field> Program for ZirProgram<'ast, T> {}
+
+impl<T: Field> Program for FlatProg<T> {
+    fn constraint_count(&self) -> Option<usize> {
+        Some(
+            self.main
+                .statements
+                .iter()
+                .filter(|s| matches!(s, FlatStatement::Condition(..)))
+                .count(),
+        )
+    }
+}
+
+impl<T: Field> Program for ir::Prog<T> {
+    fn constraint_count(&self) -> Option<usize> {
+        Some(ir::Prog::constraint_count(self))
+    }
+}
+
+/// What a pass did to the program
+#[derive(Debug, Clone)]
+pub struct PassReport {
+    pub name: &'static str,
+    pub duration: Duration,
+    /// The number of constraints before and after the pass
+    pub constraints: Option<(usize, usize)>,
+    /// The program after the pass, if it was requested with `print_after`
+    pub output: Option<String>,
+}
+
+impl fmt::Display for PassReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<26}{:>10.3}ms",
+            self.name,
+            self.duration.as_secs_f64() * 1000.0
+        )?;
+        match self.constraints {
+            Some((before, after)) => write!(
+                f,
+                "{:>12} constraints ({:+})",
+                after,
+                after as isize - before as isize
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+type PassFn<'a, P, E> = Box<dyn FnOnce(P) -> Result<P, E> + 'a>;
+
+/// A pass which is not part of the compiler, run on the ir program after its optimizations
+pub struct CustomPass<'a, T> {
+    pub info: PassInfo,
+    f: Box<dyn FnOnce(ir::Prog<T>) -> ir::Prog<T> + 'a>,
+}
+
+impl<'a, T> CustomPass<'a, T> {
+    pub fn new<F: FnOnce(ir::Prog<T>) -> ir::Prog<T> + 'a>(info: PassInfo, f: F) -> Self {
+        CustomPass {
+            info,
+            f: Box::new(f),
+        }
+    }
+
+    pub fn run(self, p: ir::Prog<T>) -> ir::Prog<T> {
+        (self.f)(p)
+    }
+}
+
+/// Runs a sequence of passes on a program of type `P`, skipping the ones which are not enabled
+pub struct PassManager<'a, P, E> {
+    config: &'a CompileConfig,
+    passes: Vec<(PassInfo, PassFn<'a, P, E>)>,
+}
+
+impl<'a, P: Program, E> PassManager<'a, P, E> {
+    pub fn new(config: &'a CompileConfig) -> Self {
+        PassManager {
+            config,
+            passes: vec![],
+        }
+    }
+
+    /// Adds the pass `name` of the compiler, which runs if it is required or enabled by the
+    /// optimization level
+    ///
+    /// # Panics
+    ///
+    /// If `name` is not in `PASSES`. Passes which are not part of the compiler are added with
+    /// `custom`
+    pub fn pass<F: FnOnce(P) -> Result<P, E> + 'a>(self, name: &'static str, f: F) -> Self {
+        let info = Self::info(name);
+        self.custom(info, f)
+    }
+
+    /// Adds the pass `name` of the compiler, which runs if `enabled` is set regardless of the
+    /// optimization level
+    ///
+    /// # Panics
+    ///
+    /// If `name` is not in `PASSES`
+    pub fn pass_if<F: FnOnce(P) -> Result<P, E> + 'a>(
+        self,
+        name: &'static str,
+        enabled: bool,
+        f: F,
+    ) -> Self {
+        let info = Self::info(name);
+        self.push(info, enabled, f)
+    }
+
+    /// Adds a pass described by `info`, which does not need to be one of the passes of the
+    /// compiler. It runs if it is required or enabled by the optimization level
+    pub fn custom<F: FnOnce(P) -> Result<P, E> + 'a>(self, info: PassInfo, f: F) -> Self {
+        let enabled = info.level.map_or(true, |l| l <= self.config.opt_level);
+        self.push(info, enabled, f)
+    }
+
+    fn push<F: FnOnce(P) -> Result<P, E> + 'a>(
+        mut self,
+        info: PassInfo,
+        enabled: bool,
+        f: F,
+    ) -> Self {
+        if enabled && !self.config.disabled_passes.iter().any(|n| n == info.name) {
+            self.passes.push((info, Box::new(f)));
+        } else {
+            log::debug!("Pass {} skipped", info.name);
+        }
+
+        self
+    }
+
+    fn info(name: &str) -> PassInfo {
+        *pass_info(name).unwrap_or_else(|| panic!("pass `{}` should be in `PASSES`", name))
+    }
+
+    pub fn run(self, p: P, reports: &mut Vec<PassReport>) -> Result<P, E> {
+        let config = self.config;
+
+        self.passes.into_iter().try_fold(p, |p, (info, f)| {
+            log::debug!("Pass {}: {}", info.name, info.description);
+
+            let before = p.constraint_count();
+            let start = Instant::now();
+            let p = f(p)?;
+            let duration = start.elapsed();
+
+            log::trace!("\n{}", p);
+
+            reports.push(PassReport {
+                name: info.name,
+                duration,
+                constraints: before.zip(p.constraint_count()),
+                output: match config.print_after.iter().any(|n| n == info.name) {
+                    true => Some(p.to_string()),
+                    false => None,
+                },
+            });
+
+            Ok(p)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::FlatVariable;
+    use crate::ir::{Prog, Statement};
+    use std::convert::Infallible;
+    use zokrates_field::Bn128Field;
+
+    fn prog() -> Prog<Bn128Field> {
+        Prog {
+            arguments: vec![],
+            statements: vec![
+                Statement::definition(FlatVariable::new(0), FlatVariable::one()),
+                Statement::definition(FlatVariable::new(0), FlatVariable::one()),
+            ],
+            returns: vec![],
+        }
+    }
+
+    fn dedup(p: Prog<Bn128Field>) -> Result<Prog<Bn128Field>, Infallible> {
+        Ok(Prog {
+            statements: p.statements.into_iter().take(1).collect(),
+            ..p
+        })
+    }
+
+    #[test]
+    fn levels() {
+        let mut reports = vec![];
+
+        let config = CompileConfig::default().opt_level(OptLevel::O1);
+        let p = PassManager::new(&config)
+            .pass("constraint-deduplication", dedup)
+            .run(prog(), &mut reports)
+            .unwrap();
+        assert_eq!(p, prog());
+        assert!(reports.is_empty());
+
+        let config = CompileConfig::default();
+        let p = PassManager::new(&config)
+            .pass("constraint-deduplication", dedup)
+            .run(prog(), &mut reports)
+            .unwrap();
+        assert_eq!(p.constraint_count(), 1);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].constraints, Some((2, 1)));
+        assert_eq!(reports[0].output, None);
+    }
+
+    #[test]
+    fn disable_and_print() {
+        let mut reports = vec![];
+
+        let config = CompileConfig::default()
+            .disable_pass("constraint-deduplication")
+            .print_after("tautology-removal");
+        let p = PassManager::new(&config)
+            .pass("tautology-removal", Ok::<_, Infallible>)
+            .pass("constraint-deduplication", dedup)
+            .run(prog(), &mut reports)
+            .unwrap();
+
+        assert_eq!(p, prog());
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].output, Some(prog().to_string()));
+    }
+
+    #[test]
+    fn custom() {
+        let mut reports = vec![];
+
+        let info = PassInfo {
+            name: "custom-deduplication",
+            description: "Remove the second constraint",
+            level: Some(OptLevel::O2),
+        };
+
+        let config = CompileConfig::default();
+        let p = PassManager::new(&config)
+            .custom(info, dedup)
+            .run(prog(), &mut reports)
+            .unwrap();
+        assert_eq!(p.constraint_count(), 1);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].name, "custom-deduplication");
+
+        // custom passes can be disabled like the passes of the compiler
+        let config = CompileConfig::default().disable_pass("custom-deduplication");
+        let p = PassManager::new(&config)
+            .custom(info, dedup)
+            .run(prog(), &mut reports)
+            .unwrap();
+        assert_eq!(p, prog());
+        assert_eq!(reports.len(), 1);
+    }
+
+    #[test]
+    fn config() {
+        assert!(check_config(
+            &CompileConfig::default().disable_pass("tautology-removal"),
+            &[]
+        )
+        .is_ok());
+        assert!(check_config(&CompileConfig::default().disable_pass("reduction"), &[]).is_err());
+        assert!(check_config(&CompileConfig::default().print_after("reduction"), &[]).is_ok());
+        assert!(check_config(&CompileConfig::default().print_after("foo"), &[]).is_err());
+
+        let custom = PassInfo {
+            name: "foo",
+            description: "",
+            level: None,
+        };
+        assert!(check_config(&CompileConfig::default().print_after("foo"), &[custom]).is_ok());
+        assert!(check_config(&CompileConfig::default().disable_pass("foo"), &[custom]).is_err());
+    }
+}
@@ -1,22 +1,33 @@
 use ark_gm17::{
-    prepare_verifying_key, verify_proof, PreparedVerifyingKey, Proof as ArkProof, ProvingKey,
-    VerifyingKey,
+    create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+    PreparedVerifyingKey, Proof as ArkProof, ProvingKey, VerifyingKey,
 };
 
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use zokrates_field::{ArkFieldExtensions, Bw6_761Field, Field};
 
 use crate::ir::{Prog, Witness};
-use crate::proof_system::ark::Ark;
+use crate::proof_system::ark::serialization;
 use crate::proof_system::ark::Computation;
+use crate::proof_system::ark::{get_rng, Ark};
 use crate::proof_system::ark::{parse_fr, parse_g1, parse_g2, parse_g2_fq};
-use crate::proof_system::gm17::{NotBw6_761Field, ProofPoints, VerificationKey, GM17};
-use crate::proof_system::Scheme;
+use crate::proof_system::gm17::{ProofPoints, VerificationKey, GM17};
 use crate::proof_system::{Backend, NonUniversalBackend, Proof, SetupKeypair};
+use crate::proof_system::{NotBw6_761Field, Scheme, Seed};
 
 impl<T: Field + ArkFieldExtensions + NotBw6_761Field> NonUniversalBackend<T, GM17> for Ark {
-    fn setup(program: Prog<T>) -> SetupKeypair<<GM17 as Scheme<T>>::VerificationKey> {
-        let parameters = Computation::without_witness(program).setup();
+    fn setup(
+        program: Prog<T>,
+        seed: Option<Seed>,
+    ) -> SetupKeypair<<GM17 as Scheme<T>>::VerificationKey> {
+        let computation = Computation::without_witness(program);
+
+        let rng = &mut get_rng(seed);
+        let parameters = generate_random_parameters::<<T as ArkFieldExtensions>::ArkEngine, _, _>(
+            computation,
+            rng,
+        )
+        .unwrap();
 
         let mut pk: Vec<u8> = Vec::new();
         parameters.serialize_uncompressed(&mut pk).unwrap();
@@ -44,6 +55,7 @@ impl<T: Field + ArkFieldExtensions + NotBw6_761Field> Backend<T, GM17> for Ark {
         program: Prog<T>,
         witness: Witness<T>,
         proving_key: Vec<u8>,
+        seed: Option<Seed>,
     ) -> Proof<<GM17 as Scheme<T>>::ProofPoints> {
         let computation = Computation::with_witness(program, witness);
         let params = ProvingKey::<<T as ArkFieldExtensions>::ArkEngine>::deserialize_uncompressed(
@@ -51,7 +63,8 @@ impl<T: Field + ArkFieldExtensions + NotBw6_761Field> Backend<T, GM17> for Ark {
         )
         .unwrap();
 
-        let proof = computation.clone().prove(&params);
+        let rng = &mut get_rng(seed);
+        let proof = create_random_proof(computation.clone(), &params, rng).unwrap();
         let proof_points = ProofPoints {
             a: parse_g1::<T>(&proof.a),
             b: parse_g2::<T>(&proof.b),
@@ -110,8 +123,17 @@ impl<T: Field + ArkFieldExtensions + NotBw6_761Field> Backend<T, GM17> for Ark {
 impl NonUniversalBackend<Bw6_761Field, GM17> for Ark {
     fn setup(
         program: Prog<Bw6_761Field>,
+        seed: Option<Seed>,
     ) -> SetupKeypair<<GM17 as Scheme<Bw6_761Field>>::VerificationKey> {
-        let parameters = Computation::without_witness(program).setup();
+        let computation = Computation::without_witness(program);
+
+        let rng = &mut get_rng(seed);
+        let parameters = generate_random_parameters::<
+            <Bw6_761Field as ArkFieldExtensions>::ArkEngine,
+            _,
+            _,
+        >(computation, rng)
+        .unwrap();
 
         let mut pk: Vec<u8> = Vec::new();
         parameters.serialize_uncompressed(&mut pk).unwrap();
@@ -139,6 +161,7 @@ impl Backend<Bw6_761Field, GM17> for Ark {
         program: Prog<Bw6_761Field>,
         witness: Witness<Bw6_761Field>,
         proving_key: Vec<u8>,
+        seed: Option<Seed>,
     ) -> Proof<<GM17 as Scheme<Bw6_761Field>>::ProofPoints> {
         let computation = Computation::with_witness(program, witness);
         let params =
@@ -147,7 +170,8 @@ impl Backend<Bw6_761Field, GM17> for Ark {
             )
                 .unwrap();
 
-        let proof = computation.clone().prove(&params);
+        let rng = &mut get_rng(seed);
+        let proof = create_random_proof(computation.clone(), &params, rng).unwrap();
         let proof_points = ProofPoints {
             a: parse_g1::<Bw6_761Field>(&proof.a),
             b: parse_g2_fq::<Bw6_761Field>(&proof.b),
@@ -203,51 +227,6 @@ impl Backend<Bw6_761Field, GM17> for Ark {
     }
 }
 
-pub mod serialization {
-    use crate::proof_system::{G1Affine, G2Affine, G2AffineFq};
-    use ark_ec::PairingEngine;
-    use ark_ff::FromBytes;
-    use zokrates_field::ArkFieldExtensions;
-
-    #[inline]
-    fn decode_hex(value: String) -> Vec<u8> {
-        let mut bytes = hex::decode(value.strip_prefix("0x").unwrap()).unwrap();
-        bytes.reverse();
-        bytes
-    }
-
-    pub fn to_g1<T: ArkFieldExtensions>(g1: G1Affine) -> <T::ArkEngine as PairingEngine>::G1Affine {
-        let mut bytes = vec![];
-        bytes.append(&mut decode_hex(g1.0));
-        bytes.append(&mut decode_hex(g1.1));
-        bytes.push(0u8); // infinity flag
-
-        <T::ArkEngine as PairingEngine>::G1Affine::read(&*bytes).unwrap()
-    }
-
-    pub fn to_g2<T: ArkFieldExtensions>(g2: G2Affine) -> <T::ArkEngine as PairingEngine>::G2Affine {
-        let mut bytes = vec![];
-        bytes.append(&mut decode_hex((g2.0).0));
-        bytes.append(&mut decode_hex((g2.0).1));
-        bytes.append(&mut decode_hex((g2.1).0));
-        bytes.append(&mut decode_hex((g2.1).1));
-        bytes.push(0u8); // infinity flag
-
-        <T::ArkEngine as PairingEngine>::G2Affine::read(&*bytes).unwrap()
-    }
-
-    pub fn to_g2_fq<T: ArkFieldExtensions>(
-        g2: G2AffineFq,
-    ) -> <T::ArkEngine as PairingEngine>::G2Affine {
-        let mut bytes = vec![];
-        bytes.append(&mut decode_hex(g2.0));
-        bytes.append(&mut decode_hex(g2.1));
-        bytes.push(0u8); // infinity flag
-
-        <T::ArkEngine as PairingEngine>::G2Affine::read(&*bytes).unwrap()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use crate::flat_absy::{FlatParameter, FlatVariable};
@@ -267,15 +246,17 @@ mod tests {
             )],
         };
 
-        let keypair = <Ark as NonUniversalBackend<Bls12_377Field, GM17>>::setup(program.clone());
+        let keypair =
+            <Ark as NonUniversalBackend<Bls12_377Field, GM17>>::setup(program.clone(), None);
         let interpreter = Interpreter::default();
 
         let witness = interpreter
             .execute(&program, &[Bls12_377Field::from(42)])
             .unwrap();
 
-        let proof =
-            <Ark as Backend<Bls12_377Field, GM17>>::generate_proof(program, witness, keypair.pk);
+        let proof = <Ark as Backend<Bls12_377Field, GM17>>::generate_proof(
+            program, witness, keypair.pk, None,
+        );
         let ans = <Ark as Backend<Bls12_377Field, GM17>>::verify(keypair.vk, proof);
 
         assert!(ans);
@@ -292,15 +273,17 @@ mod tests {
             )],
         };
 
-        let keypair = <Ark as NonUniversalBackend<Bw6_761Field, GM17>>::setup(program.clone());
+        let keypair =
+            <Ark as NonUniversalBackend<Bw6_761Field, GM17>>::setup(program.clone(), None);
         let interpreter = Interpreter::default();
 
         let witness = interpreter
             .execute(&program, &[Bw6_761Field::from(42)])
             .unwrap();
 
-        let proof =
-            <Ark as Backend<Bw6_761Field, GM17>>::generate_proof(program, witness, keypair.pk);
+        let proof = <Ark as Backend<Bw6_761Field, GM17>>::generate_proof(
+            program, witness, keypair.pk, None,
+        );
         let ans = <Ark as Backend<Bw6_761Field, GM17>>::verify(keypair.vk, proof);
 
         assert!(ans);
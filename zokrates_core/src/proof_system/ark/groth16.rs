@@ -0,0 +1,358 @@
+use ark_groth16::{
+    create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+    PreparedVerifyingKey, Proof as ArkProof, ProvingKey, VerifyingKey,
+};
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use zokrates_field::{ArkFieldExtensions, Bw6_761Field, Field};
+
+use crate::ir::{Prog, Witness};
+use crate::proof_system::ark::serialization;
+use crate::proof_system::ark::Computation;
+use crate::proof_system::ark::{get_rng, Ark};
+use crate::proof_system::ark::{parse_fr, parse_g1, parse_g2, parse_g2_fq};
+use crate::proof_system::groth16::{ProofPoints, VerificationKey, G16, G16_WARNING};
+use crate::proof_system::{Backend, NonUniversalBackend, Proof, SetupKeypair};
+use crate::proof_system::{NotBw6_761Field, Scheme, Seed};
+
+impl<T: Field + ArkFieldExtensions + NotBw6_761Field> NonUniversalBackend<T, G16> for Ark {
+    fn setup(
+        program: Prog<T>,
+        seed: Option<Seed>,
+    ) -> SetupKeypair<<G16 as Scheme<T>>::VerificationKey> {
+        println!("{}", G16_WARNING);
+
+        let computation = Computation::without_witness(program);
+
+        let rng = &mut get_rng(seed);
+        let parameters = generate_random_parameters::<<T as ArkFieldExtensions>::ArkEngine, _, _>(
+            computation,
+            rng,
+        )
+        .unwrap();
+
+        let mut pk: Vec<u8> = Vec::new();
+        parameters.serialize_uncompressed(&mut pk).unwrap();
+
+        let vk = VerificationKey {
+            alpha: parse_g1::<T>(&parameters.vk.alpha_g1),
+            beta: parse_g2::<T>(&parameters.vk.beta_g2),
+            gamma: parse_g2::<T>(&parameters.vk.gamma_g2),
+            delta: parse_g2::<T>(&parameters.vk.delta_g2),
+            gamma_abc: parameters
+                .vk
+                .gamma_abc_g1
+                .iter()
+                .map(|g1| parse_g1::<T>(g1))
+                .collect(),
+        };
+
+        SetupKeypair::new(vk, pk)
+    }
+}
+
+impl<T: Field + ArkFieldExtensions + NotBw6_761Field> Backend<T, G16> for Ark {
+    fn generate_proof(
+        program: Prog<T>,
+        witness: Witness<T>,
+        proving_key: Vec<u8>,
+        seed: Option<Seed>,
+    ) -> Proof<<G16 as Scheme<T>>::ProofPoints> {
+        println!("{}", G16_WARNING);
+
+        let computation = Computation::with_witness(program, witness);
+        let params = ProvingKey::<<T as ArkFieldExtensions>::ArkEngine>::deserialize_uncompressed(
+            &mut proving_key.as_slice(),
+        )
+        .unwrap();
+
+        let rng = &mut get_rng(seed);
+        let proof = create_random_proof(computation.clone(), &params, rng).unwrap();
+        let proof_points = ProofPoints {
+            a: parse_g1::<T>(&proof.a),
+            b: parse_g2::<T>(&proof.b),
+            c: parse_g1::<T>(&proof.c),
+        };
+
+        let inputs = computation
+            .public_inputs_values()
+            .iter()
+            .map(parse_fr::<T>)
+            .collect::<Vec<_>>();
+
+        Proof::new(proof_points, inputs)
+    }
+
+    fn verify(
+        vk: <G16 as Scheme<T>>::VerificationKey,
+        proof: Proof<<G16 as Scheme<T>>::ProofPoints>,
+    ) -> bool {
+        let vk = VerifyingKey {
+            alpha_g1: serialization::to_g1::<T>(vk.alpha),
+            beta_g2: serialization::to_g2::<T>(vk.beta),
+            gamma_g2: serialization::to_g2::<T>(vk.gamma),
+            delta_g2: serialization::to_g2::<T>(vk.delta),
+            gamma_abc_g1: vk
+                .gamma_abc
+                .into_iter()
+                .map(serialization::to_g1::<T>)
+                .collect(),
+        };
+
+        let ark_proof = ArkProof {
+            a: serialization::to_g1::<T>(proof.proof.a),
+            b: serialization::to_g2::<T>(proof.proof.b),
+            c: serialization::to_g1::<T>(proof.proof.c),
+        };
+
+        let pvk: PreparedVerifyingKey<<T as ArkFieldExtensions>::ArkEngine> =
+            prepare_verifying_key(&vk);
+
+        let public_inputs: Vec<_> = proof
+            .inputs
+            .iter()
+            .map(|s| {
+                T::try_from_str(s.trim_start_matches("0x"), 16)
+                    .unwrap()
+                    .into_ark()
+            })
+            .collect::<Vec<_>>();
+
+        verify_proof(&pvk, &ark_proof, &public_inputs).unwrap()
+    }
+}
+
+impl NonUniversalBackend<Bw6_761Field, G16> for Ark {
+    fn setup(
+        program: Prog<Bw6_761Field>,
+        seed: Option<Seed>,
+    ) -> SetupKeypair<<G16 as Scheme<Bw6_761Field>>::VerificationKey> {
+        println!("{}", G16_WARNING);
+
+        let computation = Computation::without_witness(program);
+
+        let rng = &mut get_rng(seed);
+        let parameters = generate_random_parameters::<
+            <Bw6_761Field as ArkFieldExtensions>::ArkEngine,
+            _,
+            _,
+        >(computation, rng)
+        .unwrap();
+
+        let mut pk: Vec<u8> = Vec::new();
+        parameters.serialize_uncompressed(&mut pk).unwrap();
+
+        let vk = VerificationKey {
+            alpha: parse_g1::<Bw6_761Field>(&parameters.vk.alpha_g1),
+            beta: parse_g2_fq::<Bw6_761Field>(&parameters.vk.beta_g2),
+            gamma: parse_g2_fq::<Bw6_761Field>(&parameters.vk.gamma_g2),
+            delta: parse_g2_fq::<Bw6_761Field>(&parameters.vk.delta_g2),
+            gamma_abc: parameters
+                .vk
+                .gamma_abc_g1
+                .iter()
+                .map(parse_g1::<Bw6_761Field>)
+                .collect(),
+        };
+
+        SetupKeypair::new(vk, pk)
+    }
+}
+
+impl Backend<Bw6_761Field, G16> for Ark {
+    fn generate_proof(
+        program: Prog<Bw6_761Field>,
+        witness: Witness<Bw6_761Field>,
+        proving_key: Vec<u8>,
+        seed: Option<Seed>,
+    ) -> Proof<<G16 as Scheme<Bw6_761Field>>::ProofPoints> {
+        println!("{}", G16_WARNING);
+
+        let computation = Computation::with_witness(program, witness);
+        let params =
+            ProvingKey::<<Bw6_761Field as ArkFieldExtensions>::ArkEngine>::deserialize_uncompressed(
+                &mut proving_key.as_slice(),
+            )
+            .unwrap();
+
+        let rng = &mut get_rng(seed);
+        let proof = create_random_proof(computation.clone(), &params, rng).unwrap();
+        let proof_points = ProofPoints {
+            a: parse_g1::<Bw6_761Field>(&proof.a),
+            b: parse_g2_fq::<Bw6_761Field>(&proof.b),
+            c: parse_g1::<Bw6_761Field>(&proof.c),
+        };
+
+        let inputs = computation
+            .public_inputs_values()
+            .iter()
+            .map(parse_fr::<Bw6_761Field>)
+            .collect::<Vec<_>>();
+
+        Proof::new(proof_points, inputs)
+    }
+
+    fn verify(
+        vk: <G16 as Scheme<Bw6_761Field>>::VerificationKey,
+        proof: Proof<<G16 as Scheme<Bw6_761Field>>::ProofPoints>,
+    ) -> bool {
+        let vk = VerifyingKey {
+            alpha_g1: serialization::to_g1::<Bw6_761Field>(vk.alpha),
+            beta_g2: serialization::to_g2_fq::<Bw6_761Field>(vk.beta),
+            gamma_g2: serialization::to_g2_fq::<Bw6_761Field>(vk.gamma),
+            delta_g2: serialization::to_g2_fq::<Bw6_761Field>(vk.delta),
+            gamma_abc_g1: vk
+                .gamma_abc
+                .into_iter()
+                .map(serialization::to_g1::<Bw6_761Field>)
+                .collect(),
+        };
+
+        let ark_proof = ArkProof {
+            a: serialization::to_g1::<Bw6_761Field>(proof.proof.a),
+            b: serialization::to_g2_fq::<Bw6_761Field>(proof.proof.b),
+            c: serialization::to_g1::<Bw6_761Field>(proof.proof.c),
+        };
+
+        let pvk: PreparedVerifyingKey<<Bw6_761Field as ArkFieldExtensions>::ArkEngine> =
+            prepare_verifying_key(&vk);
+
+        let public_inputs: Vec<_> = proof
+            .inputs
+            .iter()
+            .map(|s| {
+                Bw6_761Field::try_from_str(s.trim_start_matches("0x"), 16)
+                    .unwrap()
+                    .into_ark()
+            })
+            .collect::<Vec<_>>();
+
+        verify_proof(&pvk, &ark_proof, &public_inputs).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flat_absy::{FlatParameter, FlatVariable};
+    use crate::ir::{Interpreter, Prog, Statement};
+
+    use super::*;
+    use zokrates_field::{Bls12_377Field, Bn128Field, Bw6_761Field};
+
+    #[test]
+    fn verify_bn128_field() {
+        let program: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::public(FlatVariable::new(0))],
+            returns: vec![FlatVariable::public(0)],
+            statements: vec![Statement::constraint(
+                FlatVariable::new(0),
+                FlatVariable::public(0),
+            )],
+        };
+
+        let keypair = <Ark as NonUniversalBackend<Bn128Field, G16>>::setup(program.clone(), None);
+        let interpreter = Interpreter::default();
+
+        let witness = interpreter
+            .execute(&program, &[Bn128Field::from(42)])
+            .unwrap();
+
+        let proof =
+            <Ark as Backend<Bn128Field, G16>>::generate_proof(program, witness, keypair.pk, None);
+        let ans = <Ark as Backend<Bn128Field, G16>>::verify(keypair.vk, proof);
+
+        assert!(ans);
+    }
+
+    #[test]
+    fn verify_bls12_377_field() {
+        let program: Prog<Bls12_377Field> = Prog {
+            arguments: vec![FlatParameter::public(FlatVariable::new(0))],
+            returns: vec![FlatVariable::public(0)],
+            statements: vec![Statement::constraint(
+                FlatVariable::new(0),
+                FlatVariable::public(0),
+            )],
+        };
+
+        let keypair =
+            <Ark as NonUniversalBackend<Bls12_377Field, G16>>::setup(program.clone(), None);
+        let interpreter = Interpreter::default();
+
+        let witness = interpreter
+            .execute(&program, &[Bls12_377Field::from(42)])
+            .unwrap();
+
+        let proof = <Ark as Backend<Bls12_377Field, G16>>::generate_proof(
+            program, witness, keypair.pk, None,
+        );
+        let ans = <Ark as Backend<Bls12_377Field, G16>>::verify(keypair.vk, proof);
+
+        assert!(ans);
+    }
+
+    #[test]
+    fn verify_bw6_761_field() {
+        let program: Prog<Bw6_761Field> = Prog {
+            arguments: vec![FlatParameter::public(FlatVariable::new(0))],
+            returns: vec![FlatVariable::public(0)],
+            statements: vec![Statement::constraint(
+                FlatVariable::new(0),
+                FlatVariable::public(0),
+            )],
+        };
+
+        let keypair = <Ark as NonUniversalBackend<Bw6_761Field, G16>>::setup(program.clone(), None);
+        let interpreter = Interpreter::default();
+
+        let witness = interpreter
+            .execute(&program, &[Bw6_761Field::from(42)])
+            .unwrap();
+
+        let proof =
+            <Ark as Backend<Bw6_761Field, G16>>::generate_proof(program, witness, keypair.pk, None);
+        let ans = <Ark as Backend<Bw6_761Field, G16>>::verify(keypair.vk, proof);
+
+        assert!(ans);
+    }
+
+    #[test]
+    fn seeded() {
+        let program: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::public(FlatVariable::new(0))],
+            returns: vec![FlatVariable::public(0)],
+            statements: vec![Statement::constraint(
+                FlatVariable::new(0),
+                FlatVariable::public(0),
+            )],
+        };
+
+        let seed = Some([42; 32]);
+
+        let keypair = <Ark as NonUniversalBackend<Bn128Field, G16>>::setup(program.clone(), seed);
+        let other_keypair =
+            <Ark as NonUniversalBackend<Bn128Field, G16>>::setup(program.clone(), seed);
+
+        assert_eq!(keypair.pk, other_keypair.pk);
+
+        let interpreter = Interpreter::default();
+
+        let witness = interpreter
+            .execute(&program, &[Bn128Field::from(42)])
+            .unwrap();
+
+        let proof = <Ark as Backend<Bn128Field, G16>>::generate_proof(
+            program.clone(),
+            witness.clone(),
+            keypair.pk.clone(),
+            seed,
+        );
+        let other_proof =
+            <Ark as Backend<Bn128Field, G16>>::generate_proof(program, witness, keypair.pk, seed);
+
+        assert_eq!(
+            serde_json::to_string(&proof).unwrap(),
+            serde_json::to_string(&other_proof).unwrap()
+        );
+    }
+}
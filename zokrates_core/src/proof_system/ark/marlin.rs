@@ -1,30 +1,33 @@
 use ark_marlin::{IndexProverKey, IndexVerifierKey, Proof as ArkProof};
 
+use ark_marlin::ahp::AHPForR1CS;
 use ark_marlin::Marlin as ArkMarlin;
 
 use ark_ec::PairingEngine;
 use ark_poly::univariate::DensePolynomial;
+use ark_poly_commit::kzg10::UniversalParams;
 use ark_poly_commit::marlin_pc::MarlinKZG10;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use sha2::Sha256;
+use std::collections::BTreeMap;
+use std::io::{Read, Seek};
 
 use zokrates_field::{ArkFieldExtensions, Field};
 
 use crate::ir::{Prog, Witness};
 use crate::proof_system::ark::parse_fr;
-use crate::proof_system::ark::Ark;
 use crate::proof_system::ark::Computation;
+use crate::proof_system::ark::{get_rng, Ark};
 use crate::proof_system::marlin::{self, ProofPoints, VerificationKey};
-use crate::proof_system::Scheme;
+use crate::proof_system::ptau::PowersOfTau;
 use crate::proof_system::{Backend, Proof, SetupKeypair, UniversalBackend};
+use crate::proof_system::{Scheme, Seed};
 
 const MINIMUM_CONSTRAINT_COUNT: usize = 2;
 
 impl<T: Field + ArkFieldExtensions> UniversalBackend<T, marlin::Marlin> for Ark {
-    fn universal_setup(size: u32) -> Vec<u8> {
-        use rand_0_7::SeedableRng;
-
-        let rng = &mut rand_0_7::rngs::StdRng::from_entropy();
+    fn universal_setup(size: u32, seed: Option<Seed>) -> Vec<u8> {
+        let rng = &mut get_rng(seed);
 
         let srs = ArkMarlin::<
             <<T as ArkFieldExtensions>::ArkEngine as PairingEngine>::Fr,
@@ -45,6 +48,68 @@ impl<T: Field + ArkFieldExtensions> UniversalBackend<T, marlin::Marlin> for Ark
         res
     }
 
+    fn universal_setup_from_ptau<R: Read + Seek>(
+        size: u32,
+        ptau: &mut R,
+    ) -> Result<Vec<u8>, String> {
+        let mut ptau = PowersOfTau::read(ptau)?;
+        ptau.check_curve(T::name())?;
+
+        // the degree of the setup generated by `universal_setup` for the same size
+        let max_degree =
+            AHPForR1CS::<<<T as ArkFieldExtensions>::ArkEngine as PairingEngine>::Fr>::max_degree(
+                2usize.pow(size),
+                2usize.pow(size),
+                2usize.pow(size),
+            )
+            .map_err(|_| format!("Universal setup size {} is invalid", size))?;
+
+        let g1 = |points: Vec<Vec<u8>>| {
+            points
+                .iter()
+                .map(|p| {
+                    <T::ArkEngine as PairingEngine>::G1Affine::deserialize_uncompressed(
+                        &mut p.as_slice(),
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| "Invalid powers of tau: invalid point".to_string())
+        };
+        let g2 = |points: Vec<Vec<u8>>| {
+            points
+                .iter()
+                .map(|p| {
+                    <T::ArkEngine as PairingEngine>::G2Affine::deserialize_uncompressed(
+                        &mut p.as_slice(),
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| "Invalid powers of tau: invalid point".to_string())
+        };
+
+        let powers_of_g = g1(ptau.tau_g1(0, max_degree + 1)?)?;
+        // hiding commitments use a second generator whose discrete logarithm is unknown, for
+        // which `alpha` times the generator, destroyed by the ceremony like tau, is suitable
+        let powers_of_gamma_g = g1(ptau.alpha_tau_g1(0, max_degree + 2)?)?;
+        let tau_g2 = g2(ptau.tau_g2(0, 2)?)?;
+
+        let srs = UniversalParams::<T::ArkEngine> {
+            powers_of_g,
+            powers_of_gamma_g: powers_of_gamma_g.into_iter().enumerate().collect(),
+            h: tau_g2[0],
+            beta_h: tau_g2[1],
+            neg_powers_of_h: BTreeMap::new(),
+            prepared_h: tau_g2[0].into(),
+            prepared_beta_h: tau_g2[1].into(),
+        };
+
+        let mut res = vec![];
+
+        srs.serialize(&mut res).unwrap();
+
+        Ok(res)
+    }
+
     fn setup(
         universal_srs: Vec<u8>,
         program: Prog<T>,
@@ -95,12 +160,11 @@ impl<T: Field + ArkFieldExtensions> Backend<T, marlin::Marlin> for Ark {
         program: Prog<T>,
         witness: Witness<T>,
         proving_key: Vec<u8>,
+        seed: Option<Seed>,
     ) -> Proof<<marlin::Marlin as Scheme<T>>::ProofPoints> {
         let computation = Computation::with_witness(program, witness);
 
-        use rand_0_7::SeedableRng;
-
-        let rng = &mut rand_0_7::rngs::StdRng::from_entropy();
+        let rng = &mut get_rng(seed);
 
         let pk = IndexProverKey::<
             <<T as ArkFieldExtensions>::ArkEngine as PairingEngine>::Fr,
@@ -212,7 +276,7 @@ mod tests {
             ],
         };
 
-        let srs = <Ark as UniversalBackend<Bls12_377Field, Marlin>>::universal_setup(5);
+        let srs = <Ark as UniversalBackend<Bls12_377Field, Marlin>>::universal_setup(5, None);
         let keypair =
             <Ark as UniversalBackend<Bls12_377Field, Marlin>>::setup(srs, program.clone()).unwrap();
         let interpreter = Interpreter::default();
@@ -221,8 +285,9 @@ mod tests {
             .execute(&program, &[Bls12_377Field::from(42)])
             .unwrap();
 
-        let proof =
-            <Ark as Backend<Bls12_377Field, Marlin>>::generate_proof(program, witness, keypair.pk);
+        let proof = <Ark as Backend<Bls12_377Field, Marlin>>::generate_proof(
+            program, witness, keypair.pk, None,
+        );
         let ans = <Ark as Backend<Bls12_377Field, Marlin>>::verify(keypair.vk, proof);
 
         assert!(ans);
@@ -245,7 +310,7 @@ mod tests {
             ],
         };
 
-        let srs = <Ark as UniversalBackend<Bw6_761Field, Marlin>>::universal_setup(5);
+        let srs = <Ark as UniversalBackend<Bw6_761Field, Marlin>>::universal_setup(5, None);
         let keypair =
             <Ark as UniversalBackend<Bw6_761Field, Marlin>>::setup(srs, program.clone()).unwrap();
         let interpreter = Interpreter::default();
@@ -254,10 +319,97 @@ mod tests {
             .execute(&program, &[Bw6_761Field::from(42)])
             .unwrap();
 
-        let proof =
-            <Ark as Backend<Bw6_761Field, Marlin>>::generate_proof(program, witness, keypair.pk);
+        let proof = <Ark as Backend<Bw6_761Field, Marlin>>::generate_proof(
+            program, witness, keypair.pk, None,
+        );
         let ans = <Ark as Backend<Bw6_761Field, Marlin>>::verify(keypair.vk, proof);
 
         assert!(ans);
     }
+
+    #[test]
+    fn universal_setup_from_ptau() {
+        use ark_bn254::{Fr, G1Affine, G2Affine};
+        use ark_ec::{AffineCurve, ProjectiveCurve};
+        use ark_ff::{to_bytes, Field as ArkField, One, UniformRand};
+        use std::io::Cursor;
+        use zokrates_field::Bn128Field;
+
+        let program: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::private(FlatVariable::new(0))],
+            returns: vec![FlatVariable::public(0)],
+            statements: vec![
+                Statement::constraint(
+                    QuadComb::from_linear_combinations(
+                        FlatVariable::new(0).into(),
+                        FlatVariable::new(0).into(),
+                    ),
+                    FlatVariable::new(1),
+                ),
+                Statement::constraint(FlatVariable::new(1), FlatVariable::public(0)),
+            ],
+        };
+
+        // powers of tau for domains of up to 2^6 elements, from known secrets
+        let rng = &mut get_rng(Some([42; 32]));
+        let tau = Fr::rand(rng);
+        let alpha = Fr::rand(rng);
+
+        let power = 6;
+        let n = 1 << power;
+
+        let g1 = |x: Fr| {
+            let p = G1Affine::prime_subgroup_generator().mul(x).into_affine();
+            to_bytes![p.x, p.y].unwrap()
+        };
+        let g2 = |x: Fr| {
+            let p = G2Affine::prime_subgroup_generator().mul(x).into_affine();
+            to_bytes![p.x, p.y].unwrap()
+        };
+        let powers = |count: usize, factor: Fr| -> Vec<Fr> {
+            (0..count).map(|i| tau.pow([i as u64]) * factor).collect()
+        };
+
+        // the sections of tau in G1 and G2, and of alpha times tau in G1
+        let ptau = crate::proof_system::ptau::encode(
+            "bn128",
+            power,
+            vec![
+                (
+                    2,
+                    powers(2 * n - 1, Fr::one()).into_iter().map(g1).collect(),
+                ),
+                (3, powers(n, Fr::one()).into_iter().map(g2).collect()),
+                (4, powers(n, alpha).into_iter().map(g1).collect()),
+            ],
+        );
+
+        let srs = <Ark as UniversalBackend<Bn128Field, Marlin>>::universal_setup_from_ptau(
+            4,
+            &mut Cursor::new(&ptau),
+        )
+        .unwrap();
+        let keypair =
+            <Ark as UniversalBackend<Bn128Field, Marlin>>::setup(srs, program.clone()).unwrap();
+
+        let witness = Interpreter::default()
+            .execute(&program, &[Bn128Field::from(42)])
+            .unwrap();
+
+        let proof = <Ark as Backend<Bn128Field, Marlin>>::generate_proof(
+            program, witness, keypair.pk, None,
+        );
+        assert!(<Ark as Backend<Bn128Field, Marlin>>::verify(
+            keypair.vk, proof
+        ));
+
+        // the powers of tau are too small for larger setups
+        assert!(
+            <Ark as UniversalBackend<Bn128Field, Marlin>>::universal_setup_from_ptau(
+                5,
+                &mut Cursor::new(&ptau),
+            )
+            .is_err()
+        );
+    }
 }
@@ -1,12 +1,9 @@
 pub mod gm17;
+pub mod groth16;
 pub mod marlin;
 
 use crate::ir::{CanonicalLinComb, Prog, Statement, Witness};
-use ark_gm17::Proof;
-use ark_gm17::{
-    create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
-    ProvingKey,
-};
+use crate::proof_system::Seed;
 
 use crate::flat_absy::FlatVariable;
 use ark_ec::PairingEngine;
@@ -19,10 +16,18 @@ use zokrates_field::{ArkFieldExtensions, Field};
 
 pub use self::parse::*;
 
+use rand_0_7::rngs::StdRng;
 use rand_0_7::SeedableRng;
 
 pub struct Ark;
 
+fn get_rng(seed: Option<Seed>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::from_seed(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
 #[derive(Clone)]
 pub struct Computation<T> {
     program: Prog<T>,
@@ -149,21 +154,6 @@ impl<T: Field + ArkFieldExtensions> Prog<T> {
 }
 
 impl<T: Field + ArkFieldExtensions> Computation<T> {
-    pub fn prove(self, params: &ProvingKey<T::ArkEngine>) -> Proof<T::ArkEngine> {
-        let rng = &mut rand_0_7::rngs::StdRng::from_entropy();
-
-        let proof = create_random_proof(self.clone(), params, rng).unwrap();
-
-        let pvk = prepare_verifying_key(&params.vk);
-
-        // extract public inputs
-        let public_inputs = self.public_inputs_values();
-
-        assert!(verify_proof(&pvk, &proof, &public_inputs).unwrap());
-
-        proof
-    }
-
     pub fn public_inputs_values(&self) -> Vec<<T::ArkEngine as PairingEngine>::Fr> {
         self.program
             .public_inputs(self.witness.as_ref().unwrap())
@@ -171,13 +161,6 @@ impl<T: Field + ArkFieldExtensions> Computation<T> {
             .map(|v| v.clone().into_ark())
             .collect()
     }
-
-    pub fn setup(self) -> ProvingKey<T::ArkEngine> {
-        let rng = &mut rand_0_7::rngs::StdRng::from_entropy();
-
-        // run setup phase
-        generate_random_parameters(self, rng).unwrap()
-    }
 }
 
 impl<T: Field + ArkFieldExtensions>
@@ -277,3 +260,48 @@ mod parse {
         format!("0x{}", hex::encode(&bytes))
     }
 }
+
+pub mod serialization {
+    use crate::proof_system::{G1Affine, G2Affine, G2AffineFq};
+    use ark_ec::PairingEngine;
+    use ark_ff::FromBytes;
+    use zokrates_field::ArkFieldExtensions;
+
+    #[inline]
+    fn decode_hex(value: String) -> Vec<u8> {
+        let mut bytes = hex::decode(value.strip_prefix("0x").unwrap()).unwrap();
+        bytes.reverse();
+        bytes
+    }
+
+    pub fn to_g1<T: ArkFieldExtensions>(g1: G1Affine) -> <T::ArkEngine as PairingEngine>::G1Affine {
+        let mut bytes = vec![];
+        bytes.append(&mut decode_hex(g1.0));
+        bytes.append(&mut decode_hex(g1.1));
+        bytes.push(0u8); // infinity flag
+
+        <T::ArkEngine as PairingEngine>::G1Affine::read(&*bytes).unwrap()
+    }
+
+    pub fn to_g2<T: ArkFieldExtensions>(g2: G2Affine) -> <T::ArkEngine as PairingEngine>::G2Affine {
+        let mut bytes = vec![];
+        bytes.append(&mut decode_hex((g2.0).0));
+        bytes.append(&mut decode_hex((g2.0).1));
+        bytes.append(&mut decode_hex((g2.1).0));
+        bytes.append(&mut decode_hex((g2.1).1));
+        bytes.push(0u8); // infinity flag
+
+        <T::ArkEngine as PairingEngine>::G2Affine::read(&*bytes).unwrap()
+    }
+
+    pub fn to_g2_fq<T: ArkFieldExtensions>(
+        g2: G2AffineFq,
+    ) -> <T::ArkEngine as PairingEngine>::G2Affine {
+        let mut bytes = vec![];
+        bytes.append(&mut decode_hex(g2.0));
+        bytes.append(&mut decode_hex(g2.1));
+        bytes.push(0u8); // infinity flag
+
+        <T::ArkEngine as PairingEngine>::G2Affine::read(&*bytes).unwrap()
+    }
+}
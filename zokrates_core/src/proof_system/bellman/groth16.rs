@@ -4,31 +4,35 @@ use bellman::groth16::{
 };
 use pairing::{CurveAffine, Engine};
 
-use crate::proof_system::{Backend, NonUniversalBackend, Proof, SetupKeypair};
+use crate::proof_system::{
+    Backend, ContributionHash, MpcBackend, NonUniversalBackend, Proof, SetupKeypair,
+};
+use std::io::{Read, Seek, Write};
 use zokrates_field::BellmanFieldExtensions;
 use zokrates_field::Field;
 
 use crate::ir::{Prog, Witness};
+use crate::proof_system::bellman::mpc::{MpcParameters, Phase1Parameters};
 use crate::proof_system::bellman::Bellman;
-use crate::proof_system::bellman::Computation;
+use crate::proof_system::bellman::{get_rng, Computation};
 use crate::proof_system::bellman::{parse_fr, parse_g1, parse_g2};
-use crate::proof_system::groth16::{ProofPoints, VerificationKey, G16};
-use crate::proof_system::Scheme;
-
-const G16_WARNING: &str = "WARNING: You are using the G16 scheme which is subject to malleability. See zokrates.github.io/toolbox/proving_schemes.html#g16-malleability for implications.";
+use crate::proof_system::groth16::{ProofPoints, VerificationKey, G16, G16_WARNING};
+use crate::proof_system::ptau::PowersOfTau;
+use crate::proof_system::{NotBw6_761Field, Scheme, Seed};
 
-impl<T: Field + BellmanFieldExtensions> Backend<T, G16> for Bellman {
+impl<T: Field + BellmanFieldExtensions + NotBw6_761Field> Backend<T, G16> for Bellman {
     fn generate_proof(
         program: Prog<T>,
         witness: Witness<T>,
         proving_key: Vec<u8>,
+        seed: Option<Seed>,
     ) -> Proof<<G16 as Scheme<T>>::ProofPoints> {
         println!("{}", G16_WARNING);
 
         let computation = Computation::with_witness(program, witness);
         let params = Parameters::read(proving_key.as_slice(), true).unwrap();
 
-        let proof = computation.clone().prove(&params);
+        let proof = computation.clone().prove(&params, seed);
         let proof_points = ProofPoints {
             a: parse_g1::<T>(&proof.a),
             b: parse_g2::<T>(&proof.b),
@@ -83,31 +87,100 @@ impl<T: Field + BellmanFieldExtensions> Backend<T, G16> for Bellman {
     }
 }
 
-impl<T: Field + BellmanFieldExtensions> NonUniversalBackend<T, G16> for Bellman {
-    fn setup(program: Prog<T>) -> SetupKeypair<<G16 as Scheme<T>>::VerificationKey> {
+impl<T: Field + BellmanFieldExtensions + NotBw6_761Field> NonUniversalBackend<T, G16> for Bellman {
+    fn setup(
+        program: Prog<T>,
+        seed: Option<Seed>,
+    ) -> SetupKeypair<<G16 as Scheme<T>>::VerificationKey> {
         println!("{}", G16_WARNING);
 
-        let parameters = Computation::without_witness(program).setup();
-        let mut pk: Vec<u8> = Vec::new();
-        parameters.write(&mut pk).unwrap();
-
-        let vk = VerificationKey {
-            alpha: parse_g1::<T>(&parameters.vk.alpha_g1),
-            beta: parse_g2::<T>(&parameters.vk.beta_g2),
-            gamma: parse_g2::<T>(&parameters.vk.gamma_g2),
-            delta: parse_g2::<T>(&parameters.vk.delta_g2),
-            gamma_abc: parameters
-                .vk
-                .ic
-                .iter()
-                .map(|g1| parse_g1::<T>(g1))
-                .collect(),
-        };
+        let parameters = Computation::without_witness(program).setup(seed);
+        keypair::<T>(parameters)
+    }
+}
+
+impl<T: Field + BellmanFieldExtensions + NotBw6_761Field> MpcBackend<T, G16> for Bellman {
+    fn initialize<R: Read, W: Write>(
+        program: Prog<T>,
+        phase1_radix: &mut R,
+        output: &mut W,
+    ) -> Result<(), String> {
+        let params = MpcParameters::new(Computation::without_witness(program), phase1_radix)?;
+        params
+            .write(output)
+            .map_err(|why| format!("Could not write parameters: {}", why))
+    }
+
+    fn contribute<R: Read, W: Write>(
+        params: &mut R,
+        seed: Seed,
+        output: &mut W,
+    ) -> Result<ContributionHash, String> {
+        let mut params = MpcParameters::<T::BellmanEngine>::read(params)?;
+        let hash = params.contribute(&mut get_rng(Some(seed)));
+        params
+            .write(output)
+            .map_err(|why| format!("Could not write parameters: {}", why))?;
+        Ok(hash)
+    }
 
-        SetupKeypair::new(vk, pk)
+    fn verify_contributions<P: Read, R: Read>(
+        params: &mut P,
+        program: Prog<T>,
+        phase1_radix: &mut R,
+    ) -> Result<Vec<ContributionHash>, String> {
+        MpcParameters::<T::BellmanEngine>::read(params)?
+            .verify(Computation::without_witness(program), phase1_radix)
+    }
+
+    fn prepare_phase1<R: Read + Seek, W: Write>(
+        program: Prog<T>,
+        ptau: &mut R,
+        output: &mut W,
+    ) -> Result<(), String> {
+        let mut ptau = PowersOfTau::read(ptau)?;
+        ptau.check_curve(T::name())?;
+
+        Phase1Parameters::<T::BellmanEngine>::from_ptau(
+            Computation::without_witness(program),
+            &mut ptau,
+        )?
+        .write(output)
+        .map_err(|why| format!("Could not write parameters: {}", why))
+    }
+
+    fn export_keypair<R: Read>(
+        params: &mut R,
+        program: Prog<T>,
+    ) -> Result<SetupKeypair<<G16 as Scheme<T>>::VerificationKey>, String> {
+        let params = MpcParameters::<T::BellmanEngine>::read(params)?;
+        params.check_shape(Computation::without_witness(program))?;
+        Ok(keypair::<T>(params.into_parameters()))
     }
 }
 
+fn keypair<T: Field + BellmanFieldExtensions>(
+    parameters: Parameters<T::BellmanEngine>,
+) -> SetupKeypair<VerificationKey> {
+    let mut pk: Vec<u8> = Vec::new();
+    parameters.write(&mut pk).unwrap();
+
+    let vk = VerificationKey {
+        alpha: parse_g1::<T>(&parameters.vk.alpha_g1),
+        beta: parse_g2::<T>(&parameters.vk.beta_g2),
+        gamma: parse_g2::<T>(&parameters.vk.gamma_g2),
+        delta: parse_g2::<T>(&parameters.vk.delta_g2),
+        gamma_abc: parameters
+            .vk
+            .ic
+            .iter()
+            .map(|g1| parse_g1::<T>(g1))
+            .collect(),
+    };
+
+    SetupKeypair::new(vk, pk)
+}
+
 mod serialization {
     use pairing::{from_hex, CurveAffine, Engine};
 
@@ -150,17 +223,61 @@ mod tests {
             )],
         };
 
-        let keypair = <Bellman as NonUniversalBackend<Bn128Field, G16>>::setup(program.clone());
+        let keypair =
+            <Bellman as NonUniversalBackend<Bn128Field, G16>>::setup(program.clone(), None);
         let interpreter = Interpreter::default();
 
         let witness = interpreter
             .execute(&program, &[Bn128Field::from(42)])
             .unwrap();
 
-        let proof =
-            <Bellman as Backend<Bn128Field, G16>>::generate_proof(program, witness, keypair.pk);
+        let proof = <Bellman as Backend<Bn128Field, G16>>::generate_proof(
+            program, witness, keypair.pk, None,
+        );
         let ans = <Bellman as Backend<Bn128Field, G16>>::verify(keypair.vk, proof);
 
         assert!(ans);
     }
+
+    #[test]
+    fn seeded() {
+        let program: Prog<Bn128Field> = Prog {
+            arguments: vec![FlatParameter::public(FlatVariable::new(0))],
+            returns: vec![FlatVariable::public(0)],
+            statements: vec![Statement::constraint(
+                FlatVariable::new(0),
+                FlatVariable::public(0),
+            )],
+        };
+
+        let seed = Some([42; 32]);
+
+        let keypair =
+            <Bellman as NonUniversalBackend<Bn128Field, G16>>::setup(program.clone(), seed);
+        let other_keypair =
+            <Bellman as NonUniversalBackend<Bn128Field, G16>>::setup(program.clone(), seed);
+
+        assert_eq!(keypair.pk, other_keypair.pk);
+
+        let interpreter = Interpreter::default();
+
+        let witness = interpreter
+            .execute(&program, &[Bn128Field::from(42)])
+            .unwrap();
+
+        let proof = <Bellman as Backend<Bn128Field, G16>>::generate_proof(
+            program.clone(),
+            witness.clone(),
+            keypair.pk.clone(),
+            seed,
+        );
+        let other_proof = <Bellman as Backend<Bn128Field, G16>>::generate_proof(
+            program, witness, keypair.pk, seed,
+        );
+
+        assert_eq!(
+            serde_json::to_string(&proof).unwrap(),
+            serde_json::to_string(&other_proof).unwrap()
+        );
+    }
 }
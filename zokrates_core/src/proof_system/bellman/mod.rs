@@ -1,4 +1,5 @@
 pub mod groth16;
+pub mod mpc;
 
 use crate::ir::{CanonicalLinComb, Prog, Statement, Witness};
 use bellman::groth16::Proof;
@@ -13,6 +14,7 @@ use zokrates_field::BellmanFieldExtensions;
 use zokrates_field::Field;
 
 use crate::flat_absy::FlatVariable;
+use crate::proof_system::Seed;
 use rand_0_4::ChaChaRng;
 
 pub use self::parse::*;
@@ -145,23 +147,32 @@ impl<T: BellmanFieldExtensions + Field> Prog<T> {
     }
 }
 
-impl<T: BellmanFieldExtensions + Field> Computation<T> {
-    fn get_random_seed(&self) -> Result<[u32; 8], getrandom::Error> {
+fn get_rng(seed: Option<Seed>) -> ChaChaRng {
+    use rand_0_4::SeedableRng;
+
+    let seed = seed.unwrap_or_else(|| {
         let mut seed = [0u8; 32];
-        getrandom::getrandom(&mut seed)?;
-
-        use std::mem::transmute;
-        // This is safe because we are just reinterpreting the bytes (u8[32] -> u32[8]),
-        // byte order or the actual content does not matter here as this is used
-        // as a random seed for the rng.
-        let seed: [u32; 8] = unsafe { transmute(seed) };
-        Ok(seed)
-    }
+        getrandom::getrandom(&mut seed).unwrap();
+        seed
+    });
+
+    // reinterpret the bytes as words explicitly so that a given seed yields the same
+    // randomness on every platform
+    let seed: Vec<u32> = seed
+        .chunks(4)
+        .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+        .collect();
+
+    ChaChaRng::from_seed(&seed)
+}
 
-    pub fn prove(self, params: &Parameters<T::BellmanEngine>) -> Proof<T::BellmanEngine> {
-        use rand_0_4::SeedableRng;
-        let seed = self.get_random_seed().unwrap();
-        let rng = &mut ChaChaRng::from_seed(seed.as_ref());
+impl<T: BellmanFieldExtensions + Field> Computation<T> {
+    pub fn prove(
+        self,
+        params: &Parameters<T::BellmanEngine>,
+        seed: Option<Seed>,
+    ) -> Proof<T::BellmanEngine> {
+        let rng = &mut get_rng(seed);
 
         let proof = create_random_proof(self.clone(), params, rng).unwrap();
 
@@ -183,10 +194,8 @@ impl<T: BellmanFieldExtensions + Field> Computation<T> {
             .collect()
     }
 
-    pub fn setup(self) -> Parameters<T::BellmanEngine> {
-        use rand_0_4::SeedableRng;
-        let seed = self.get_random_seed().unwrap();
-        let rng = &mut ChaChaRng::from_seed(seed.as_ref());
+    pub fn setup(self, seed: Option<Seed>) -> Parameters<T::BellmanEngine> {
+        let rng = &mut get_rng(seed);
         // run setup phase
         generate_random_parameters(self, rng).unwrap()
     }
@@ -277,8 +286,8 @@ mod tests {
             let witness = interpreter.execute(&program, &[]).unwrap();
             let computation = Computation::with_witness(program, witness);
 
-            let params = computation.clone().setup();
-            let _proof = computation.prove(&params);
+            let params = computation.clone().setup(None);
+            let _proof = computation.prove(&params, None);
         }
 
         #[test]
@@ -300,8 +309,8 @@ mod tests {
 
             let computation = Computation::with_witness(program, witness);
 
-            let params = computation.clone().setup();
-            let _proof = computation.prove(&params);
+            let params = computation.clone().setup(None);
+            let _proof = computation.prove(&params, None);
         }
 
         #[test]
@@ -323,8 +332,8 @@ mod tests {
 
             let computation = Computation::with_witness(program, witness);
 
-            let params = computation.clone().setup();
-            let _proof = computation.prove(&params);
+            let params = computation.clone().setup(None);
+            let _proof = computation.prove(&params, None);
         }
 
         #[test]
@@ -343,8 +352,8 @@ mod tests {
             let witness = interpreter.execute(&program, &[]).unwrap();
             let computation = Computation::with_witness(program, witness);
 
-            let params = computation.clone().setup();
-            let _proof = computation.prove(&params);
+            let params = computation.clone().setup(None);
+            let _proof = computation.prove(&params, None);
         }
 
         #[test]
@@ -376,8 +385,8 @@ mod tests {
                 .unwrap();
             let computation = Computation::with_witness(program, witness);
 
-            let params = computation.clone().setup();
-            let _proof = computation.prove(&params);
+            let params = computation.clone().setup(None);
+            let _proof = computation.prove(&params, None);
         }
 
         #[test]
@@ -399,8 +408,8 @@ mod tests {
 
             let computation = Computation::with_witness(program, witness);
 
-            let params = computation.clone().setup();
-            let _proof = computation.prove(&params);
+            let params = computation.clone().setup(None);
+            let _proof = computation.prove(&params, None);
         }
 
         #[test]
@@ -424,8 +433,8 @@ mod tests {
                 .unwrap();
             let computation = Computation::with_witness(program, witness);
 
-            let params = computation.clone().setup();
-            let _proof = computation.prove(&params);
+            let params = computation.clone().setup(None);
+            let _proof = computation.prove(&params, None);
         }
     }
 }
@@ -0,0 +1,1063 @@
+//! Module containing the phase 2 of the Groth16 setup as a multi-party computation, following
+//! the protocol of Bowe, Gabizon and Miers and the parameter format of the `phase2` crate.
+//!
+//! The ceremony starts from phase 1 parameters, the powers of tau in Lagrange form for the
+//! evaluation domain of the program. Each participant then samples a secret `delta`, multiplies
+//! it into the parameters and publishes a proof of knowledge of it, so that the final keys are
+//! secure as long as one participant discarded their secret.
+
+use crate::proof_system::ptau::PowersOfTau;
+use crate::proof_system::ContributionHash;
+use bellman::groth16::{Parameters, VerifyingKey};
+use bellman::{Circuit, ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
+use blake2::{Blake2b, Digest};
+use pairing::ff::{Field, PrimeField};
+use pairing::{CurveAffine, CurveProjective, EncodedPoint, Engine};
+use rand_0_4::{ChaChaRng, Rng, SeedableRng};
+use std::io::{self, Read, Seek, Write};
+use std::sync::Arc;
+
+/// Phase 1 parameters for an evaluation domain of size `m`, in the format written by the
+/// `prepare_phase2` tool of the perpetual powers of tau
+pub struct Phase1Parameters<E: Engine> {
+    pub alpha_g1: E::G1Affine,
+    pub beta_g1: E::G1Affine,
+    pub beta_g2: E::G2Affine,
+    /// The Lagrange polynomials of the domain evaluated at tau
+    pub coeffs_g1: Vec<E::G1Affine>,
+    pub coeffs_g2: Vec<E::G2Affine>,
+    pub alpha_coeffs_g1: Vec<E::G1Affine>,
+    pub beta_coeffs_g1: Vec<E::G1Affine>,
+    /// `tau^i * (tau^m - 1)` for `i` in `0..m - 1`
+    pub h: Vec<E::G1Affine>,
+}
+
+fn read_point<G: CurveAffine, R: Read>(reader: &mut R) -> io::Result<G> {
+    let mut repr = G::Uncompressed::empty();
+    reader.read_exact(repr.as_mut())?;
+    let point = repr
+        .into_affine()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    match point.is_zero() {
+        true => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "point at infinity",
+        )),
+        false => Ok(point),
+    }
+}
+
+fn read_points<G: CurveAffine, R: Read>(reader: &mut R, count: usize) -> io::Result<Vec<G>> {
+    (0..count).map(|_| read_point(reader)).collect()
+}
+
+fn write_points<G: CurveAffine, W: Write>(writer: &mut W, points: &[G]) -> io::Result<()> {
+    points
+        .iter()
+        .try_for_each(|p| writer.write_all(p.into_uncompressed().as_ref()))
+}
+
+impl<E: Engine> Phase1Parameters<E> {
+    /// The size of the parameters for a domain of size `m`, in bytes
+    pub fn size(m: usize) -> usize {
+        let g1 = <E::G1Affine as CurveAffine>::Uncompressed::size();
+        let g2 = <E::G2Affine as CurveAffine>::Uncompressed::size();
+        (4 * m + 1) * g1 + (m + 1) * g2
+    }
+
+    /// Reads parameters for a domain of size `m`, failing if `reader` holds parameters for
+    /// another size
+    pub fn read<R: Read>(reader: &mut R, m: usize) -> Result<Self, String> {
+        let size_error = || {
+            format!(
+                "Invalid phase 1 parameters: expected parameters for 2^{} constraints ({} bytes)",
+                m.trailing_zeros(),
+                Self::size(m)
+            )
+        };
+
+        let read = |reader: &mut R| -> io::Result<Self> {
+            Ok(Phase1Parameters {
+                alpha_g1: read_point(reader)?,
+                beta_g1: read_point(reader)?,
+                beta_g2: read_point(reader)?,
+                coeffs_g1: read_points(reader, m)?,
+                coeffs_g2: read_points(reader, m)?,
+                alpha_coeffs_g1: read_points(reader, m)?,
+                beta_coeffs_g1: read_points(reader, m)?,
+                h: read_points(reader, m - 1)?,
+            })
+        };
+
+        let res = read(&mut *reader).map_err(|e| match e.kind() {
+            io::ErrorKind::UnexpectedEof => size_error(),
+            _ => format!("Invalid phase 1 parameters: {}", e),
+        })?;
+
+        // parameters for a larger domain start like valid parameters for this one
+        match reader.read(&mut [0]) {
+            Ok(0) => Ok(res),
+            _ => Err(size_error()),
+        }
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_points(writer, &[self.alpha_g1, self.beta_g1])?;
+        write_points(writer, &[self.beta_g2])?;
+        write_points(writer, &self.coeffs_g1)?;
+        write_points(writer, &self.coeffs_g2)?;
+        write_points(writer, &self.alpha_coeffs_g1)?;
+        write_points(writer, &self.beta_coeffs_g1)?;
+        write_points(writer, &self.h)
+    }
+
+    /// Computes the parameters for the domain of `circuit` from the output of a powers of tau
+    /// ceremony, which holds the powers of tau in monomial form
+    pub fn from_ptau<C: Circuit<E>, R: Read + Seek>(
+        circuit: C,
+        ptau: &mut PowersOfTau<R>,
+    ) -> Result<Self, String> {
+        let m = KeypairAssembly::synthesize(circuit)?.domain_size();
+
+        if m.trailing_zeros() > ptau.power() {
+            return Err(format!(
+                "The program needs 2^{} constraints, counting one per public input, but the powers of tau only support up to 2^{}",
+                m.trailing_zeros(),
+                ptau.power()
+            ));
+        }
+
+        let g1 = |points: Vec<Vec<u8>>| -> Result<Vec<E::G1Affine>, String> {
+            points.iter().map(|p| from_ptau_point(p, false)).collect()
+        };
+        let g2 = |points: Vec<Vec<u8>>| -> Result<Vec<E::G2Affine>, String> {
+            points.iter().map(|p| from_ptau_point(p, true)).collect()
+        };
+
+        let tau_g1 = g1(ptau.tau_g1(0, 2 * m - 1)?)?;
+        let tau_g2 = g2(ptau.tau_g2(0, m)?)?;
+        let alpha_tau_g1 = g1(ptau.alpha_tau_g1(0, m)?)?;
+        let beta_tau_g1 = g1(ptau.beta_tau_g1(0, m)?)?;
+        let beta_g2 = from_ptau_point(&ptau.beta_g2()?, true)?;
+
+        // tau^i * (tau^m - 1) = tau^(i + m) - tau^i
+        let h = (0..m - 1)
+            .map(|i| {
+                let mut res = tau_g1[i].into_projective();
+                res.negate();
+                res.add_assign_mixed(&tau_g1[i + m]);
+                res.into_affine()
+            })
+            .collect();
+
+        Ok(Phase1Parameters {
+            alpha_g1: alpha_tau_g1[0],
+            beta_g1: beta_tau_g1[0],
+            beta_g2,
+            coeffs_g1: lagrange(&tau_g1[..m]),
+            coeffs_g2: lagrange(&tau_g2),
+            alpha_coeffs_g1: lagrange(&alpha_tau_g1),
+            beta_coeffs_g1: lagrange(&beta_tau_g1),
+            h,
+        })
+    }
+}
+
+/// Converts a point read from a `.ptau` file to the uncompressed encoding of bellman, which holds
+/// the coordinates as big-endian integers, with `c1` before `c0` in G2
+fn from_ptau_point<G: CurveAffine>(point: &[u8], g2: bool) -> Result<G, String> {
+    let count = if g2 { 4 } else { 2 };
+
+    let mut coordinates: Vec<Vec<u8>> = point
+        .chunks(point.len() / count)
+        .map(|c| c.iter().rev().cloned().collect())
+        .collect();
+    if g2 {
+        coordinates.swap(0, 1);
+        coordinates.swap(2, 3);
+    }
+
+    let encoded = coordinates.concat();
+    if encoded.len() != G::Uncompressed::size() {
+        return Err("Invalid powers of tau: unexpected point size".to_string());
+    }
+
+    read_point(&mut &encoded[..]).map_err(|e| format!("Invalid powers of tau: {}", e))
+}
+
+/// Computes the Lagrange form of the powers of tau `points` over the domain of their size, which
+/// is their inverse Fourier transform
+fn lagrange<G: CurveAffine>(points: &[G]) -> Vec<G> {
+    let m = points.len();
+    let log_m = m.trailing_zeros();
+
+    // the generator of the domain, as chosen by bellman
+    let mut omega = G::Scalar::root_of_unity();
+    for _ in log_m..G::Scalar::S {
+        omega.square();
+    }
+    let omega_inv = omega.inverse().unwrap();
+
+    let bitreverse = |mut n: usize| {
+        let mut res = 0;
+        for _ in 0..log_m {
+            res = (res << 1) | (n & 1);
+            n >>= 1;
+        }
+        res
+    };
+
+    let mut a: Vec<G::Projective> = points.iter().map(|p| p.into_projective()).collect();
+    for k in 0..m {
+        let rk = bitreverse(k);
+        if k < rk {
+            a.swap(k, rk);
+        }
+    }
+
+    let mut half = 1;
+    while half < m {
+        let w_m = omega_inv.pow(&[(m / (2 * half)) as u64]);
+
+        for k in (0..m).step_by(2 * half) {
+            let mut w = G::Scalar::one();
+            for j in 0..half {
+                let mut t = a[k + j + half];
+                t.mul_assign(w);
+
+                a[k + j + half] = a[k + j];
+                a[k + j + half].sub_assign(&t);
+                a[k + j].add_assign(&t);
+
+                w.mul_assign(&w_m);
+            }
+        }
+
+        half *= 2;
+    }
+
+    let m_inv = G::Scalar::from_str(&m.to_string())
+        .unwrap()
+        .inverse()
+        .unwrap();
+
+    a.into_iter()
+        .map(|mut p| {
+            p.mul_assign(m_inv);
+            p.into_affine()
+        })
+        .collect()
+}
+
+/// A constraint system which records, for each variable, the constraints it appears in and with
+/// which coefficient
+struct KeypairAssembly<E: Engine> {
+    num_inputs: usize,
+    num_aux: usize,
+    num_constraints: usize,
+    at_inputs: Vec<Vec<(E::Fr, usize)>>,
+    bt_inputs: Vec<Vec<(E::Fr, usize)>>,
+    ct_inputs: Vec<Vec<(E::Fr, usize)>>,
+    at_aux: Vec<Vec<(E::Fr, usize)>>,
+    bt_aux: Vec<Vec<(E::Fr, usize)>>,
+    ct_aux: Vec<Vec<(E::Fr, usize)>>,
+}
+
+impl<E: Engine> KeypairAssembly<E> {
+    fn synthesize<C: Circuit<E>>(circuit: C) -> Result<Self, String> {
+        let mut assembly = KeypairAssembly {
+            num_inputs: 0,
+            num_aux: 0,
+            num_constraints: 0,
+            at_inputs: vec![],
+            bt_inputs: vec![],
+            ct_inputs: vec![],
+            at_aux: vec![],
+            bt_aux: vec![],
+            ct_aux: vec![],
+        };
+
+        let synthesize = |assembly: &mut Self| -> Result<(), SynthesisError> {
+            // allocate the `~one` input variable
+            assembly.alloc_input(|| "", || Ok(E::Fr::one()))?;
+
+            circuit.synthesize(assembly)?;
+
+            // constrain the inputs as `x * 0 == 0`, so that the IC query is fully dense, which
+            // is what the prover of bellman expects
+            for i in 0..assembly.num_inputs {
+                assembly.enforce(
+                    || "",
+                    |lc| lc + Variable::new_unchecked(Index::Input(i)),
+                    |lc| lc,
+                    |lc| lc,
+                );
+            }
+
+            Ok(())
+        };
+
+        synthesize(&mut assembly).map_err(|e| format!("Could not synthesize program: {}", e))?;
+
+        Ok(assembly)
+    }
+
+    /// The size of the evaluation domain, the smallest power of two which fits the constraints
+    fn domain_size(&self) -> usize {
+        self.num_constraints.next_power_of_two()
+    }
+}
+
+impl<E: Engine> ConstraintSystem<E> for KeypairAssembly<E> {
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, _: A, _: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        // there is no assignment, so the function providing it is not called
+        let index = self.num_aux;
+        self.num_aux += 1;
+
+        self.at_aux.push(vec![]);
+        self.bt_aux.push(vec![]);
+        self.ct_aux.push(vec![]);
+
+        Ok(Variable::new_unchecked(Index::Aux(index)))
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, _: A, _: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let index = self.num_inputs;
+        self.num_inputs += 1;
+
+        self.at_inputs.push(vec![]);
+        self.bt_inputs.push(vec![]);
+        self.ct_inputs.push(vec![]);
+
+        Ok(Variable::new_unchecked(Index::Input(index)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+        fn eval<E: Engine>(
+            l: LinearCombination<E>,
+            inputs: &mut [Vec<(E::Fr, usize)>],
+            aux: &mut [Vec<(E::Fr, usize)>],
+            this_constraint: usize,
+        ) {
+            for &(var, coeff) in l.as_ref() {
+                match var.get_unchecked() {
+                    Index::Input(id) => inputs[id].push((coeff, this_constraint)),
+                    Index::Aux(id) => aux[id].push((coeff, this_constraint)),
+                }
+            }
+        }
+
+        eval(
+            a(LinearCombination::zero()),
+            &mut self.at_inputs,
+            &mut self.at_aux,
+            self.num_constraints,
+        );
+        eval(
+            b(LinearCombination::zero()),
+            &mut self.bt_inputs,
+            &mut self.bt_aux,
+            self.num_constraints,
+        );
+        eval(
+            c(LinearCombination::zero()),
+            &mut self.ct_inputs,
+            &mut self.ct_aux,
+            self.num_constraints,
+        );
+
+        self.num_constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
+
+/// The QAP polynomials of some variables evaluated at tau, in the groups
+struct Evaluation<E: Engine> {
+    a_g1: Vec<E::G1Affine>,
+    b_g1: Vec<E::G1Affine>,
+    b_g2: Vec<E::G2Affine>,
+    /// `beta * A(tau) + alpha * B(tau) + C(tau)`
+    ext: Vec<E::G1Affine>,
+}
+
+fn evaluate<E: Engine>(
+    phase1: &Phase1Parameters<E>,
+    at: &[Vec<(E::Fr, usize)>],
+    bt: &[Vec<(E::Fr, usize)>],
+    ct: &[Vec<(E::Fr, usize)>],
+) -> Evaluation<E> {
+    let mut res = Evaluation {
+        a_g1: vec![],
+        b_g1: vec![],
+        b_g2: vec![],
+        ext: vec![],
+    };
+
+    for ((at, bt), ct) in at.iter().zip(bt).zip(ct) {
+        let mut a_g1 = E::G1::zero();
+        let mut b_g1 = E::G1::zero();
+        let mut b_g2 = E::G2::zero();
+        let mut ext = E::G1::zero();
+
+        for &(coeff, lag) in at {
+            a_g1.add_assign(&phase1.coeffs_g1[lag].mul(coeff));
+            ext.add_assign(&phase1.beta_coeffs_g1[lag].mul(coeff));
+        }
+
+        for &(coeff, lag) in bt {
+            b_g1.add_assign(&phase1.coeffs_g1[lag].mul(coeff));
+            b_g2.add_assign(&phase1.coeffs_g2[lag].mul(coeff));
+            ext.add_assign(&phase1.alpha_coeffs_g1[lag].mul(coeff));
+        }
+
+        for &(coeff, lag) in ct {
+            ext.add_assign(&phase1.coeffs_g1[lag].mul(coeff));
+        }
+
+        res.a_g1.push(a_g1.into_affine());
+        res.b_g1.push(b_g1.into_affine());
+        res.b_g2.push(b_g2.into_affine());
+        res.ext.push(ext.into_affine());
+    }
+
+    res
+}
+
+/// Hashes everything written to it with BLAKE2b
+#[derive(Clone)]
+struct HashWriter(Blake2b);
+
+impl HashWriter {
+    fn new() -> Self {
+        HashWriter(Blake2b::new())
+    }
+
+    fn into_hash(self) -> [u8; 64] {
+        let mut res = [0; 64];
+        res.copy_from_slice(&self.0.finalize());
+        res
+    }
+}
+
+impl Write for HashWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The public key of a contribution, which proves that the contributor knows the `delta` they
+/// multiplied into the parameters
+#[derive(Clone)]
+struct PublicKey<E: Engine> {
+    /// `delta` in G1 after the contribution, so that contributions can be checked without the
+    /// intermediate parameters
+    delta_after: E::G1Affine,
+    /// A random element chosen by the contributor
+    s: E::G1Affine,
+    /// `s * delta`
+    s_delta: E::G1Affine,
+    /// `r * delta`, where `r` is derived from the transcript
+    r_delta: E::G2Affine,
+    /// The hash of the transcript up to this contribution, which `r` is derived from
+    transcript: [u8; 64],
+}
+
+impl<E: Engine> PublicKey<E> {
+    fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_points(writer, &[self.delta_after, self.s, self.s_delta])?;
+        write_points(writer, &[self.r_delta])?;
+        writer.write_all(&self.transcript)
+    }
+
+    fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let delta_after = read_point(reader)?;
+        let s = read_point(reader)?;
+        let s_delta = read_point(reader)?;
+        let r_delta = read_point(reader)?;
+
+        let mut transcript = [0; 64];
+        reader.read_exact(&mut transcript)?;
+
+        Ok(PublicKey {
+            delta_after,
+            s,
+            s_delta,
+            r_delta,
+            transcript,
+        })
+    }
+
+    fn hash(&self) -> ContributionHash {
+        let mut sink = HashWriter::new();
+        self.write(&mut sink).unwrap();
+        sink.into_hash()
+    }
+}
+
+/// Deterministically maps a digest to an element of G2
+fn hash_to_g2<E: Engine>(digest: &[u8]) -> E::G2 {
+    let seed: Vec<u32> = digest[..32]
+        .chunks(4)
+        .map(|c| u32::from_be_bytes([c[0], c[1], c[2], c[3]]))
+        .collect();
+
+    ChaChaRng::from_seed(&seed).gen()
+}
+
+/// Checks that `g1.1 = g1.0 * x` and `g2.1 = g2.0 * x` for the same `x`
+fn same_ratio<G: CurveAffine>(g1: (G, G), g2: (G::Pair, G::Pair)) -> bool {
+    g1.0.pairing_with(&g2.1) == g1.1.pairing_with(&g2.0)
+}
+
+/// Combines `v1` and `v2` with the same random coefficients, so that `same_ratio` on the result
+/// checks that each element of `v2` is the corresponding element of `v1` times the same `x`
+fn merge_pairs<G: CurveAffine, R: Rng>(v1: &[G], v2: &[G], rng: &mut R) -> (G, G) {
+    assert_eq!(v1.len(), v2.len());
+
+    let mut s = G::Projective::zero();
+    let mut sx = G::Projective::zero();
+
+    for (v1, v2) in v1.iter().zip(v2) {
+        let rho: G::Scalar = rng.gen();
+        s.add_assign(&v1.mul(rho));
+        sx.add_assign(&v2.mul(rho));
+    }
+
+    (s.into_affine(), sx.into_affine())
+}
+
+/// The parameters of the ceremony: the current keys, the hash of the initial keys and the public
+/// keys of the contributions so far
+pub struct MpcParameters<E: Engine> {
+    params: Parameters<E>,
+    cs_hash: [u8; 64],
+    contributions: Vec<PublicKey<E>>,
+}
+
+impl<E: Engine> MpcParameters<E> {
+    /// Computes the initial parameters of `circuit`, with `delta = 1`, from phase 1 parameters
+    pub fn new<C: Circuit<E>, R: Read>(circuit: C, phase1: &mut R) -> Result<Self, String> {
+        let assembly = KeypairAssembly::synthesize(circuit)?;
+        let phase1 = Phase1Parameters::read(phase1, assembly.domain_size())?;
+
+        let inputs = evaluate(
+            &phase1,
+            &assembly.at_inputs,
+            &assembly.bt_inputs,
+            &assembly.ct_inputs,
+        );
+        let aux = evaluate(
+            &phase1,
+            &assembly.at_aux,
+            &assembly.bt_aux,
+            &assembly.ct_aux,
+        );
+
+        // every variable must be constrained, so that the L query is fully dense
+        if aux.ext.iter().any(|e| e.is_zero()) {
+            return Err(
+                "Could not initialize the ceremony: the program has unconstrained variables"
+                    .to_string(),
+            );
+        }
+
+        let vk = VerifyingKey {
+            alpha_g1: phase1.alpha_g1,
+            beta_g1: phase1.beta_g1,
+            beta_g2: phase1.beta_g2,
+            gamma_g2: E::G2Affine::one(),
+            delta_g1: E::G1Affine::one(),
+            delta_g2: E::G2Affine::one(),
+            ic: inputs.ext,
+        };
+
+        // filter points at infinity away from the A and B queries, as the prover expects
+        let dense = |points: Vec<E::G1Affine>| points.into_iter().filter(|p| !p.is_zero());
+
+        let params = Parameters {
+            vk,
+            h: Arc::new(phase1.h),
+            l: Arc::new(aux.ext),
+            a: Arc::new(dense(inputs.a_g1).chain(dense(aux.a_g1)).collect()),
+            b_g1: Arc::new(dense(inputs.b_g1).chain(dense(aux.b_g1)).collect()),
+            b_g2: Arc::new(
+                inputs
+                    .b_g2
+                    .into_iter()
+                    .chain(aux.b_g2)
+                    .filter(|p| !p.is_zero())
+                    .collect(),
+            ),
+        };
+
+        let mut sink = HashWriter::new();
+        params.write(&mut sink).unwrap();
+
+        Ok(MpcParameters {
+            params,
+            cs_hash: sink.into_hash(),
+            contributions: vec![],
+        })
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, String> {
+        let read = |reader: &mut R| -> io::Result<Self> {
+            let params = Parameters::read(&mut *reader, true)?;
+
+            let mut cs_hash = [0; 64];
+            reader.read_exact(&mut cs_hash)?;
+
+            let mut count = [0; 4];
+            reader.read_exact(&mut count)?;
+            let contributions = (0..u32::from_be_bytes(count))
+                .map(|_| PublicKey::read(reader))
+                .collect::<io::Result<_>>()?;
+
+            Ok(MpcParameters {
+                params,
+                cs_hash,
+                contributions,
+            })
+        };
+
+        read(reader).map_err(|e| format!("Invalid MPC parameters: {}", e))
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.params.write(&mut *writer)?;
+        writer.write_all(&self.cs_hash)?;
+        writer.write_all(&(self.contributions.len() as u32).to_be_bytes())?;
+        self.contributions
+            .iter()
+            .try_for_each(|pubkey| pubkey.write(writer))
+    }
+
+    /// Multiplies a random secret `delta` into the parameters, returning the hash of the
+    /// contribution
+    pub fn contribute<R: Rng>(&mut self, rng: &mut R) -> ContributionHash {
+        let delta: E::Fr = rng.gen();
+
+        // prove knowledge of delta with a pair `(s, s * delta)` in G1 and a pair
+        // `(r, r * delta)` in G2, where `r` depends on the transcript so far
+        let s = rng.gen::<E::G1>().into_affine();
+        let s_delta = s.mul(delta).into_affine();
+
+        let mut sink = HashWriter::new();
+        sink.write_all(&self.cs_hash).unwrap();
+        for pubkey in &self.contributions {
+            pubkey.write(&mut sink).unwrap();
+        }
+        write_points(&mut sink, &[s, s_delta]).unwrap();
+        let transcript = sink.into_hash();
+
+        let r = hash_to_g2::<E>(&transcript).into_affine();
+        let r_delta = r.mul(delta).into_affine();
+
+        let delta_inv = delta.inverse().unwrap();
+        let scale = |points: &[E::G1Affine]| -> Vec<E::G1Affine> {
+            points
+                .iter()
+                .map(|p| p.mul(delta_inv).into_affine())
+                .collect()
+        };
+
+        self.params.l = Arc::new(scale(&self.params.l));
+        self.params.h = Arc::new(scale(&self.params.h));
+        self.params.vk.delta_g1 = self.params.vk.delta_g1.mul(delta).into_affine();
+        self.params.vk.delta_g2 = self.params.vk.delta_g2.mul(delta).into_affine();
+
+        let pubkey = PublicKey {
+            delta_after: self.params.vk.delta_g1,
+            s,
+            s_delta,
+            r_delta,
+            transcript,
+        };
+
+        let hash = pubkey.hash();
+        self.contributions.push(pubkey);
+        hash
+    }
+
+    /// Checks that the parameters were derived from the initial parameters of `circuit` by valid
+    /// contributions, returning the hashes of the contributions
+    pub fn verify<C: Circuit<E>, R: Read>(
+        &self,
+        circuit: C,
+        phase1: &mut R,
+    ) -> Result<Vec<ContributionHash>, String> {
+        let initial = MpcParameters::new(circuit, phase1)?;
+
+        let error = |reason: &str| Err(format!("Invalid MPC parameters: {}", reason));
+
+        // H and L change with each contribution but keep their length
+        if initial.params.h.len() != self.params.h.len()
+            || initial.params.l.len() != self.params.l.len()
+        {
+            return error("the parameters are for another program");
+        }
+
+        // everything else but delta does not change
+        if initial.params.a != self.params.a
+            || initial.params.b_g1 != self.params.b_g1
+            || initial.params.b_g2 != self.params.b_g2
+            || initial.params.vk.alpha_g1 != self.params.vk.alpha_g1
+            || initial.params.vk.beta_g1 != self.params.vk.beta_g1
+            || initial.params.vk.beta_g2 != self.params.vk.beta_g2
+            || initial.params.vk.gamma_g2 != self.params.vk.gamma_g2
+            || initial.params.vk.ic != self.params.vk.ic
+            || initial.cs_hash != self.cs_hash
+        {
+            return error("the parameters are for another program or other phase 1 parameters");
+        }
+
+        let mut sink = HashWriter::new();
+        sink.write_all(&initial.cs_hash).unwrap();
+
+        let mut current_delta = E::G1Affine::one();
+        let mut res = vec![];
+
+        for (index, pubkey) in self.contributions.iter().enumerate() {
+            let mut transcript = sink.clone();
+            write_points(&mut transcript, &[pubkey.s, pubkey.s_delta]).unwrap();
+            let transcript = transcript.into_hash();
+
+            if pubkey.transcript[..] != transcript[..] {
+                return error(&format!(
+                    "the transcript of contribution {} is inconsistent",
+                    index + 1
+                ));
+            }
+
+            let r = hash_to_g2::<E>(&transcript).into_affine();
+
+            // the contributor knows delta
+            if !same_ratio((r, pubkey.r_delta), (pubkey.s, pubkey.s_delta)) {
+                return error(&format!(
+                    "contribution {} has an invalid proof of knowledge",
+                    index + 1
+                ));
+            }
+
+            // delta was updated with the same secret
+            if !same_ratio((current_delta, pubkey.delta_after), (r, pubkey.r_delta)) {
+                return error(&format!("contribution {} has an invalid delta", index + 1));
+            }
+
+            pubkey.write(&mut sink).unwrap();
+            current_delta = pubkey.delta_after;
+            res.push(pubkey.hash());
+        }
+
+        // the final delta is the one of the last contribution, in both groups
+        if current_delta != self.params.vk.delta_g1
+            || !same_ratio(
+                (E::G1Affine::one(), current_delta),
+                (E::G2Affine::one(), self.params.vk.delta_g2),
+            )
+        {
+            return error("delta is inconsistent with the contributions");
+        }
+
+        // H and L were divided by delta
+        let rng = &mut super::get_rng(None);
+        if !same_ratio(
+            merge_pairs(&initial.params.h, &self.params.h, rng),
+            (self.params.vk.delta_g2, E::G2Affine::one()),
+        ) || !same_ratio(
+            merge_pairs(&initial.params.l, &self.params.l, rng),
+            (self.params.vk.delta_g2, E::G2Affine::one()),
+        ) {
+            return error("the H and L queries are inconsistent with delta");
+        }
+
+        Ok(res)
+    }
+
+    /// Checks that the parameters have the shape of the parameters of `circuit`, which is much
+    /// cheaper than `verify` but does not check the contributions
+    pub fn check_shape<C: Circuit<E>>(&self, circuit: C) -> Result<(), String> {
+        let assembly = KeypairAssembly::<E>::synthesize(circuit)?;
+
+        match self.params.vk.ic.len() == assembly.num_inputs
+            && self.params.l.len() == assembly.num_aux
+            && self.params.h.len() == assembly.domain_size() - 1
+        {
+            true => Ok(()),
+            false => Err("The MPC parameters are for another program".to_string()),
+        }
+    }
+
+    pub fn into_parameters(self) -> Parameters<E> {
+        self.params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flat_absy::{FlatParameter, FlatVariable};
+    use crate::ir::{Interpreter, Prog, QuadComb, Statement};
+    use crate::proof_system::bellman::Computation;
+    use bellman::groth16::{create_random_proof, prepare_verifying_key, verify_proof};
+    use pairing::bn256::{Bn256, Fq, Fr, G1Affine, G2Affine};
+    use pairing::ff::PrimeFieldRepr;
+    use std::io::Cursor;
+    use zokrates_field::{BellmanFieldExtensions, Bn128Field};
+
+    /// Phase 1 parameters from known secrets, which must only be done for testing
+    fn phase1(m: usize, rng: &mut ChaChaRng) -> Phase1Parameters<Bn256> {
+        phase1_from_secrets(m, rng.gen(), rng.gen(), rng.gen())
+    }
+
+    fn phase1_from_secrets(m: usize, tau: Fr, alpha: Fr, beta: Fr) -> Phase1Parameters<Bn256> {
+        // the generator of the domain, as chosen by bellman
+        let mut omega = Fr::root_of_unity();
+        for _ in m.trailing_zeros()..Fr::S {
+            omega.square();
+        }
+
+        // tau^m - 1
+        let mut z = tau.pow(&[m as u64]);
+        z.sub_assign(&Fr::one());
+
+        // the i-th Lagrange polynomial at tau is omega^i * (tau^m - 1) / (m * (tau - omega^i))
+        let mut m_inv = Fr::from_str(&m.to_string()).unwrap().inverse().unwrap();
+        m_inv.mul_assign(&z);
+        let lagrange: Vec<Fr> = (0..m)
+            .map(|i| {
+                let omega_i = omega.pow(&[i as u64]);
+                let mut denominator = tau;
+                denominator.sub_assign(&omega_i);
+                let mut l = denominator.inverse().unwrap();
+                l.mul_assign(&omega_i);
+                l.mul_assign(&m_inv);
+                l
+            })
+            .collect();
+
+        let g1 = |x: &Fr| <Bn256 as Engine>::G1Affine::one().mul(*x).into_affine();
+        let g2 = |x: &Fr| <Bn256 as Engine>::G2Affine::one().mul(*x).into_affine();
+        let times = |x: &Fr, y: &Fr| {
+            let mut res = *x;
+            res.mul_assign(y);
+            res
+        };
+
+        Phase1Parameters {
+            alpha_g1: g1(&alpha),
+            beta_g1: g1(&beta),
+            beta_g2: g2(&beta),
+            coeffs_g1: lagrange.iter().map(g1).collect(),
+            coeffs_g2: lagrange.iter().map(g2).collect(),
+            alpha_coeffs_g1: lagrange.iter().map(|l| g1(&times(l, &alpha))).collect(),
+            beta_coeffs_g1: lagrange.iter().map(|l| g1(&times(l, &beta))).collect(),
+            h: (0..m - 1)
+                .map(|i| g1(&times(&tau.pow(&[i as u64]), &z)))
+                .collect(),
+        }
+    }
+
+    fn program() -> Prog<Bn128Field> {
+        // def main(private field a) -> field:
+        //     return a * a
+        Prog {
+            arguments: vec![FlatParameter::private(FlatVariable::new(0))],
+            statements: vec![Statement::definition(
+                FlatVariable::public(0),
+                QuadComb::from_linear_combinations(
+                    FlatVariable::new(0).into(),
+                    FlatVariable::new(0).into(),
+                ),
+            )],
+            returns: vec![FlatVariable::public(0)],
+        }
+    }
+
+    #[test]
+    fn ceremony() {
+        let rng = &mut ChaChaRng::from_seed(&[42]);
+
+        // one constraint and two input constraints
+        let mut radix = vec![];
+        phase1(4, rng).write(&mut radix).unwrap();
+
+        let circuit = || Computation::without_witness(program());
+
+        let mut params = MpcParameters::<Bn256>::new(circuit(), &mut &radix[..]).unwrap();
+        let first = params.contribute(rng);
+        let second = params.contribute(rng);
+
+        // the parameters survive serialization
+        let mut buffer = vec![];
+        params.write(&mut buffer).unwrap();
+        let params = MpcParameters::<Bn256>::read(&mut &buffer[..]).unwrap();
+
+        assert_eq!(
+            params.verify(circuit(), &mut &radix[..]).unwrap(),
+            vec![first, second]
+        );
+        assert!(params.check_shape(circuit()).is_ok());
+
+        // the final keys produce valid proofs
+        let params = params.into_parameters();
+        let witness = Interpreter::default()
+            .execute(&program(), &[Bn128Field::from(3)])
+            .unwrap();
+        let public_inputs: Vec<_> = program()
+            .public_inputs(&witness)
+            .into_iter()
+            .map(|v| v.into_bellman())
+            .collect();
+
+        let proof =
+            create_random_proof(Computation::with_witness(program(), witness), &params, rng)
+                .unwrap();
+        assert!(verify_proof(&prepare_verifying_key(&params.vk), &proof, &public_inputs).unwrap());
+    }
+
+    #[test]
+    fn tampered() {
+        let rng = &mut ChaChaRng::from_seed(&[42]);
+
+        let mut radix = vec![];
+        phase1(4, rng).write(&mut radix).unwrap();
+
+        let circuit = || Computation::without_witness(program());
+
+        let mut params = MpcParameters::<Bn256>::new(circuit(), &mut &radix[..]).unwrap();
+        params.contribute(rng);
+
+        // changing delta without a contribution is detected
+        let mut tampered = MpcParameters {
+            params: params.params.clone(),
+            cs_hash: params.cs_hash,
+            contributions: params.contributions.clone(),
+        };
+        tampered.params.vk.delta_g1 = <Bn256 as Engine>::G1Affine::one();
+        assert!(tampered.verify(circuit(), &mut &radix[..]).is_err());
+
+        // phase 1 parameters for another domain size are rejected
+        let mut other_radix = vec![];
+        phase1(8, rng).write(&mut other_radix).unwrap();
+        assert!(MpcParameters::<Bn256>::new(circuit(), &mut &other_radix[..]).is_err());
+        assert!(params.verify(circuit(), &mut &other_radix[..]).is_err());
+
+        // phase 1 parameters from other secrets are detected
+        let mut other_radix = vec![];
+        phase1(4, rng).write(&mut other_radix).unwrap();
+        assert!(params.verify(circuit(), &mut &other_radix[..]).is_err());
+    }
+
+    #[test]
+    fn from_ptau() {
+        let rng = &mut ChaChaRng::from_seed(&[42]);
+
+        let tau: Fr = rng.gen();
+        let alpha: Fr = rng.gen();
+        let beta: Fr = rng.gen();
+
+        // powers of tau for domains of up to 2^3 elements
+        let power = 3;
+        let n = 1 << power;
+
+        let coordinates = |c: &[Fq]| -> Vec<u8> {
+            let mut res = vec![];
+            for c in c {
+                c.into_repr().write_le(&mut res).unwrap();
+            }
+            res
+        };
+        let g1 = |x: Fr| {
+            let (x, y) = G1Affine::one().mul(x).into_affine().into_xy_unchecked();
+            coordinates(&[x, y])
+        };
+        let g2 = |x: Fr| {
+            let (x, y) = G2Affine::one().mul(x).into_affine().into_xy_unchecked();
+            coordinates(&[x.c0, x.c1, y.c0, y.c1])
+        };
+        let powers = |count: usize, factor: Fr| -> Vec<Fr> {
+            (0..count)
+                .map(|i| {
+                    let mut res = tau.pow(&[i as u64]);
+                    res.mul_assign(&factor);
+                    res
+                })
+                .collect()
+        };
+
+        let ptau = crate::proof_system::ptau::encode(
+            "bn128",
+            power,
+            vec![
+                (
+                    2,
+                    powers(2 * n - 1, Fr::one()).into_iter().map(g1).collect(),
+                ),
+                (3, powers(n, Fr::one()).into_iter().map(g2).collect()),
+                (4, powers(n, alpha).into_iter().map(g1).collect()),
+                (5, powers(n, beta).into_iter().map(g1).collect()),
+                (6, vec![g2(beta)]),
+            ],
+        );
+        let mut ptau = PowersOfTau::read(Cursor::new(ptau)).unwrap();
+
+        let circuit = || Computation::without_witness(program());
+
+        // the program needs a domain of 4 elements
+        let mut expected = vec![];
+        phase1_from_secrets(4, tau, alpha, beta)
+            .write(&mut expected)
+            .unwrap();
+
+        let mut actual = vec![];
+        Phase1Parameters::<Bn256>::from_ptau(circuit(), &mut ptau)
+            .unwrap()
+            .write(&mut actual)
+            .unwrap();
+
+        assert_eq!(actual, expected);
+
+        // the powers of tau must support the domain of the program
+        let ptau = crate::proof_system::ptau::encode("bn128", 1, vec![]);
+        let mut ptau = PowersOfTau::read(Cursor::new(ptau)).unwrap();
+        assert!(Phase1Parameters::<Bn256>::from_ptau(circuit(), &mut ptau).is_err());
+    }
+}
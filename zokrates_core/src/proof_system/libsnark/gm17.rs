@@ -4,8 +4,8 @@ use crate::proof_system::libsnark::ffi::{c_free, Buffer, ProofResult, SetupResul
 use crate::proof_system::libsnark::{
     prepare_generate_proof, prepare_public_inputs, prepare_setup, serialization::*, Libsnark,
 };
-use crate::proof_system::Scheme;
 use crate::proof_system::{Backend, G1Affine, G2Affine, NonUniversalBackend, Proof, SetupKeypair};
+use crate::proof_system::{Scheme, Seed};
 use std::io::{BufReader, BufWriter, Write};
 use zokrates_field::{Bn128Field, Field};
 
@@ -43,6 +43,7 @@ impl Backend<Bn128Field, GM17> for Libsnark {
         program: Prog<Bn128Field>,
         witness: Witness<Bn128Field>,
         proving_key: Vec<u8>,
+        _seed: Option<Seed>,
     ) -> Proof<<GM17 as Scheme<Bn128Field>>::ProofPoints> {
         let (public_inputs_arr, public_inputs_length, private_inputs_arr, private_inputs_length) =
             prepare_generate_proof(program.clone(), witness.clone());
@@ -132,6 +133,7 @@ impl Backend<Bn128Field, GM17> for Libsnark {
 impl NonUniversalBackend<Bn128Field, GM17> for Libsnark {
     fn setup(
         program: Prog<Bn128Field>,
+        _seed: Option<Seed>,
     ) -> SetupKeypair<<GM17 as Scheme<Bn128Field>>::VerificationKey> {
         let (a_arr, b_arr, c_arr, a_vec, b_vec, c_vec, num_constraints, num_variables, num_inputs) =
             prepare_setup(program);
@@ -207,15 +209,17 @@ mod tests {
             )],
         };
 
-        let keypair = <Libsnark as NonUniversalBackend<Bn128Field, GM17>>::setup(program.clone());
+        let keypair =
+            <Libsnark as NonUniversalBackend<Bn128Field, GM17>>::setup(program.clone(), None);
         let interpreter = Interpreter::default();
 
         let witness = interpreter
             .execute(&program, &vec![Bn128Field::from(42)])
             .unwrap();
 
-        let proof =
-            <Libsnark as Backend<Bn128Field, GM17>>::generate_proof(program, witness, keypair.pk);
+        let proof = <Libsnark as Backend<Bn128Field, GM17>>::generate_proof(
+            program, witness, keypair.pk, None,
+        );
 
         let ans = <Libsnark as Backend<Bn128Field, GM17>>::verify(keypair.vk, proof);
         assert!(ans);
@@ -242,7 +242,7 @@ pub fn r1cs_program<T: Field>(
     // first pass through statements to populate `variables`
     for (quad, lin) in prog.statements.iter().filter_map(|s| match s {
         Statement::Constraint(quad, lin, _) => Some((quad, lin)),
-        Statement::Directive(..) => None,
+        Statement::Directive(..) | Statement::Log(..) | Statement::Span(..) => None,
     }) {
         for (k, _) in &quad.left.0 {
             provide_variable_idx(&mut variables, &k);
@@ -262,7 +262,7 @@ pub fn r1cs_program<T: Field>(
     // second pass to convert program to raw sparse vectors
     for (quad, lin) in prog.statements.into_iter().filter_map(|s| match s {
         Statement::Constraint(quad, lin, _) => Some((quad, lin)),
-        Statement::Directive(..) => None,
+        Statement::Directive(..) | Statement::Log(..) | Statement::Span(..) => None,
     }) {
         a.push(
             quad.left
@@ -7,7 +7,7 @@ use crate::proof_system::{Backend, G1Affine, G2Affine, NonUniversalBackend, Proo
 use crate::ir::{Prog, Witness};
 use crate::proof_system::libsnark::serialization::{read_g1, read_g2, write_g1, write_g2};
 use crate::proof_system::pghr13::{ProofPoints, VerificationKey, PGHR13};
-use crate::proof_system::Scheme;
+use crate::proof_system::{Scheme, Seed};
 use std::io::{BufReader, BufWriter, Write};
 use zokrates_field::Bn128Field;
 use zokrates_field::Field;
@@ -46,6 +46,7 @@ impl Backend<Bn128Field, PGHR13> for Libsnark {
         program: Prog<Bn128Field>,
         witness: Witness<Bn128Field>,
         proving_key: Vec<u8>,
+        _seed: Option<Seed>,
     ) -> Proof<<PGHR13 as Scheme<Bn128Field>>::ProofPoints> {
         let (public_inputs_arr, public_inputs_length, private_inputs_arr, private_inputs_length) =
             prepare_generate_proof(program.clone(), witness.clone());
@@ -158,6 +159,7 @@ impl Backend<Bn128Field, PGHR13> for Libsnark {
 impl NonUniversalBackend<Bn128Field, PGHR13> for Libsnark {
     fn setup(
         program: Prog<Bn128Field>,
+        _seed: Option<Seed>,
     ) -> SetupKeypair<<PGHR13 as Scheme<Bn128Field>>::VerificationKey> {
         let (a_arr, b_arr, c_arr, a_vec, b_vec, c_vec, num_constraints, num_variables, num_inputs) =
             prepare_setup(program);
@@ -237,15 +239,17 @@ mod tests {
             )],
         };
 
-        let keypair = <Libsnark as NonUniversalBackend<Bn128Field, PGHR13>>::setup(program.clone());
+        let keypair =
+            <Libsnark as NonUniversalBackend<Bn128Field, PGHR13>>::setup(program.clone(), None);
         let interpreter = Interpreter::default();
 
         let witness = interpreter
             .execute(&program, &vec![Bn128Field::from(42)])
             .unwrap();
 
-        let proof =
-            <Libsnark as Backend<Bn128Field, PGHR13>>::generate_proof(program, witness, keypair.pk);
+        let proof = <Libsnark as Backend<Bn128Field, PGHR13>>::generate_proof(
+            program, witness, keypair.pk, None,
+        );
 
         let ans = <Libsnark as Backend<Bn128Field, PGHR13>>::verify(keypair.vk, proof);
         assert!(ans);
@@ -5,6 +5,7 @@ pub mod bellman;
 #[cfg(feature = "libsnark")]
 pub mod libsnark;
 
+pub mod ptau;
 mod scheme;
 mod solidity;
 
@@ -12,8 +13,11 @@ pub use self::scheme::*;
 pub use self::solidity::*;
 
 use crate::ir;
+use crate::ir::Fingerprint;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::{Read, Seek, Write};
 use zokrates_field::Field;
 
 #[derive(Serialize)]
@@ -28,19 +32,142 @@ impl<V: Serialize + DeserializeOwned> SetupKeypair<V> {
     }
 }
 
+impl<V> SetupKeypair<V> {
+    /// Records in both keys that they were made for the program with fingerprint `fingerprint`
+    pub fn bind(self, fingerprint: Fingerprint) -> SetupKeypair<BoundVerificationKey<V>> {
+        let mut pk = Vec::with_capacity(PROVING_KEY_MAGIC.len() + 32 + self.pk.len());
+        pk.extend(PROVING_KEY_MAGIC);
+        pk.extend(&fingerprint.0);
+        pk.extend(self.pk);
+
+        SetupKeypair {
+            vk: BoundVerificationKey {
+                fingerprint: Some(fingerprint),
+                vk: self.vk,
+            },
+            pk,
+        }
+    }
+}
+
+/// The prefix of proving keys which record the program they were made for, followed by its
+/// fingerprint and the key of the backend
+const PROVING_KEY_MAGIC: &[u8; 4] = &[0x5a, 0x4f, 0x4b, 0x50];
+
+/// Splits a proving key into the fingerprint of the program it was made for, which keys made
+/// before fingerprints were introduced do not record, and the key expected by the backend
+pub fn unbind_proving_key(mut pk: Vec<u8>) -> (Option<Fingerprint>, Vec<u8>) {
+    let header = PROVING_KEY_MAGIC.len() + 32;
+
+    match pk.len() >= header && pk.starts_with(PROVING_KEY_MAGIC) {
+        true => {
+            let mut fingerprint = [0; 32];
+            fingerprint.copy_from_slice(&pk[PROVING_KEY_MAGIC.len()..header]);
+            (Some(Fingerprint(fingerprint)), pk.split_off(header))
+        }
+        false => (None, pk),
+    }
+}
+
+/// Returns the key expected by the backend, checking that the proving key `pk` was made for the
+/// program with fingerprint `fingerprint` if it records the program it was made for
+pub fn check_proving_key(pk: Vec<u8>, fingerprint: Fingerprint) -> Result<Vec<u8>, String> {
+    let (recorded, pk) = unbind_proving_key(pk);
+    check_fingerprints(recorded, Some(fingerprint), "proving key", "program")?;
+    Ok(pk)
+}
+
+/// Checks that `proof` was generated for the program `vk` was made for, if both record it
+pub fn check_proof<V, P>(vk: &BoundVerificationKey<V>, proof: &Proof<P>) -> Result<(), String> {
+    check_fingerprints(
+        proof.fingerprint,
+        vk.fingerprint,
+        "proof",
+        "verification key",
+    )
+}
+
+fn check_fingerprints(
+    left: Option<Fingerprint>,
+    right: Option<Fingerprint>,
+    left_name: &str,
+    right_name: &str,
+) -> Result<(), String> {
+    match (left, right) {
+        (Some(left), Some(right)) if left != right => Err(format!(
+            "The {} was made for a different program than the {} (fingerprint {}, expected {})",
+            left_name, right_name, left, right
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// A verification key along with the fingerprint of the program it was made for, which is
+/// ignored when reading the key as a verification key of the scheme
+#[derive(Serialize, Deserialize)]
+pub struct BoundVerificationKey<V> {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<Fingerprint>,
+    #[serde(flatten)]
+    pub vk: V,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct Proof<T> {
     pub proof: T,
     pub inputs: Vec<String>,
+    /// The fingerprint of the program the proof was generated for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<Fingerprint>,
 }
 
 #[allow(dead_code)]
 impl<T: Serialize + DeserializeOwned> Proof<T> {
     fn new(proof: T, inputs: Vec<String>) -> Self {
-        Proof { proof, inputs }
+        Proof {
+            proof,
+            inputs,
+            fingerprint: None,
+        }
     }
 }
 
+/// A seed for the randomness used by a backend. Setting it makes setups and proofs
+/// reproducible, which must only be done for testing. Backends which rely on their own
+/// source of randomness (libsnark) ignore it.
+pub type Seed = [u8; 32];
+
+/// Derives the seed of a contribution to a ceremony from entropy provided by the participant,
+/// mixed with randomness from the operating system so that a weak input is not fatal
+pub fn contribution_seed(entropy: &[u8]) -> Seed {
+    let mut randomness = [0u8; 32];
+    getrandom::getrandom(&mut randomness).unwrap();
+
+    let mut hasher = Sha256::new();
+    hasher.update(entropy);
+    hasher.update(&randomness);
+
+    let mut res = [0; 32];
+    res.copy_from_slice(&hasher.finalize());
+    res
+}
+
+/// Derives the seed of the final contribution to a ceremony from a public random beacon, by
+/// hashing it `2^exponent` times so that it cannot be influenced by whoever chooses the beacon
+pub fn beacon_seed(beacon: &[u8; 32], exponent: u32) -> Seed {
+    assert!(exponent < 64);
+
+    let mut res = *beacon;
+    for _ in 0..1u64 << exponent {
+        res.copy_from_slice(&Sha256::digest(&res));
+    }
+    res
+}
+
+/// The hash of a contribution to a ceremony, which participants publish so that anyone can check
+/// that their contribution was included
+pub type ContributionHash = [u8; 64];
+
 pub type Fr = String;
 pub type Fq = String;
 pub type Fq2 = (String, String);
@@ -84,19 +211,107 @@ pub trait Backend<T: Field, S: Scheme<T>> {
         program: ir::Prog<T>,
         witness: ir::Witness<T>,
         proving_key: Vec<u8>,
+        seed: Option<Seed>,
     ) -> Proof<S::ProofPoints>;
 
     fn verify(vk: S::VerificationKey, proof: Proof<S::ProofPoints>) -> bool;
 }
 pub trait NonUniversalBackend<T: Field, S: NonUniversalScheme<T>>: Backend<T, S> {
-    fn setup(program: ir::Prog<T>) -> SetupKeypair<S::VerificationKey>;
+    fn setup(program: ir::Prog<T>, seed: Option<Seed>) -> SetupKeypair<S::VerificationKey>;
+}
+
+/// A backend which can run the circuit specific part of the setup as a multi-party computation,
+/// starting from the parameters of a universal ceremony (phase 1)
+pub trait MpcBackend<T: Field, S: NonUniversalScheme<T>>: Backend<T, S> {
+    /// Writes the initial parameters of the ceremony for `program`
+    fn initialize<R: Read, W: Write>(
+        program: ir::Prog<T>,
+        phase1_radix: &mut R,
+        output: &mut W,
+    ) -> Result<(), String>;
+
+    /// Contributes randomness derived from `seed` to the parameters, returning the hash of the
+    /// contribution
+    fn contribute<R: Read, W: Write>(
+        params: &mut R,
+        seed: Seed,
+        output: &mut W,
+    ) -> Result<ContributionHash, String>;
+
+    /// Checks the transcript of the ceremony, returning the hashes of the contributions in order
+    fn verify_contributions<P: Read, R: Read>(
+        params: &mut P,
+        program: ir::Prog<T>,
+        phase1_radix: &mut R,
+    ) -> Result<Vec<ContributionHash>, String>;
+
+    /// Writes the phase 1 parameters for `program` in the format `initialize` reads, computed
+    /// from the output of a powers of tau ceremony in the `.ptau` format
+    fn prepare_phase1<R: Read + Seek, W: Write>(
+        program: ir::Prog<T>,
+        ptau: &mut R,
+        output: &mut W,
+    ) -> Result<(), String>;
+
+    /// Extracts the keys from the parameters of the ceremony
+    fn export_keypair<R: Read>(
+        params: &mut R,
+        program: ir::Prog<T>,
+    ) -> Result<SetupKeypair<S::VerificationKey>, String>;
 }
 
 pub trait UniversalBackend<T: Field, S: UniversalScheme<T>>: Backend<T, S> {
-    fn universal_setup(size: u32) -> Vec<u8>;
+    fn universal_setup(size: u32, seed: Option<Seed>) -> Vec<u8>;
+
+    /// Derives the universal setup of size `2^size` from the output of a powers of tau ceremony
+    /// in the `.ptau` format
+    fn universal_setup_from_ptau<R: Read + Seek>(
+        size: u32,
+        ptau: &mut R,
+    ) -> Result<Vec<u8>, String>;
 
     fn setup(
         srs: Vec<u8>,
         program: ir::Prog<T>,
     ) -> Result<SetupKeypair<S::VerificationKey>, String>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bind() {
+        let fingerprint = Fingerprint([1; 32]);
+        let keypair = SetupKeypair::new(42u32, vec![1, 2, 3]).bind(fingerprint);
+
+        assert_eq!(keypair.vk.fingerprint, Some(fingerprint));
+        assert_eq!(
+            unbind_proving_key(keypair.pk.clone()),
+            (Some(fingerprint), vec![1, 2, 3])
+        );
+        assert_eq!(
+            check_proving_key(keypair.pk.clone(), fingerprint),
+            Ok(vec![1, 2, 3])
+        );
+        assert!(check_proving_key(keypair.pk, Fingerprint([2; 32])).is_err());
+
+        // keys which do not record a program are accepted
+        assert_eq!(unbind_proving_key(vec![1, 2, 3]), (None, vec![1, 2, 3]));
+        assert_eq!(
+            check_proving_key(vec![1, 2, 3], fingerprint),
+            Ok(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn beacon() {
+        let beacon = [0; 32];
+
+        let mut once = [0; 32];
+        once.copy_from_slice(&Sha256::digest(&beacon));
+        assert_eq!(beacon_seed(&beacon, 0), once);
+        assert_eq!(beacon_seed(&once, 0), beacon_seed(&beacon, 1));
+        assert_ne!(beacon_seed(&beacon, 1), beacon_seed(&beacon, 2));
+    }
+}
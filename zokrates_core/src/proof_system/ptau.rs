@@ -0,0 +1,336 @@
+//! Module reading the `.ptau` files written by snarkjs, such as the outputs of the perpetual
+//! powers of tau ceremony, so that they can be used as the universal part of a setup.
+//!
+//! A `.ptau` file starts with the magic number `ptau`, a version and a number of sections, each
+//! made of a type, a size and its data. Points are stored with their coordinates in Montgomery
+//! form. They are returned with their coordinates in standard form, as little-endian integers
+//! of the size of the base field: `x, y` in G1 and `x.c0, x.c1, y.c0, y.c1` in G2.
+
+use num_bigint::BigUint;
+use std::collections::BTreeMap;
+use std::io::{self, Read, Seek, SeekFrom};
+
+const MAGIC: &[u8; 4] = b"ptau";
+
+const HEADER: u32 = 1;
+const TAU_G1: u32 = 2;
+const TAU_G2: u32 = 3;
+const ALPHA_TAU_G1: u32 = 4;
+const BETA_TAU_G1: u32 = 5;
+const BETA_G2: u32 = 6;
+
+/// The moduli of the base fields of the curves supported by snarkjs
+const CURVES: &[(&str, &str)] = &[
+    (
+        "bn128",
+        "21888242871839275222246405745257275098696311157297823662689037894645226208583",
+    ),
+    (
+        "bls12_381",
+        "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787",
+    ),
+];
+
+#[derive(Debug, Clone, Copy)]
+enum Group {
+    G1,
+    G2,
+}
+
+impl Group {
+    fn coordinates(self) -> usize {
+        match self {
+            Group::G1 => 2,
+            Group::G2 => 4,
+        }
+    }
+}
+
+pub struct PowersOfTau<R> {
+    reader: R,
+    /// The size of a coordinate in bytes
+    n8: usize,
+    modulus: BigUint,
+    /// The inverse of the Montgomery factor `2^(8 * n8)`
+    r_inv: BigUint,
+    /// The ceremony supports domains of up to `2^power` elements
+    power: u32,
+    /// The offset and size of each section
+    sections: BTreeMap<u32, (u64, u64)>,
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut buf = [0; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+impl<R: Read + Seek> PowersOfTau<R> {
+    /// Reads the header and the table of sections of a `.ptau` file
+    pub fn read(mut reader: R) -> Result<Self, String> {
+        let mut magic = [0; 4];
+        reader
+            .read_exact(&mut magic)
+            .map_err(|_| "Invalid powers of tau: the file is empty".to_string())?;
+        if &magic != MAGIC {
+            return Err("Invalid powers of tau: wrong magic number".to_string());
+        }
+
+        let read = |reader: &mut R| -> io::Result<_> {
+            let _version = read_u32(reader)?;
+            let count = read_u32(reader)?;
+
+            let mut sections = BTreeMap::new();
+            for _ in 0..count {
+                let id = read_u32(reader)?;
+                let size = read_u64(reader)?;
+                let offset = reader.seek(SeekFrom::Current(0))?;
+                sections.insert(id, (offset, size));
+                reader.seek(SeekFrom::Current(size as i64))?;
+            }
+
+            let header = sections.get(&HEADER).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "the header is missing")
+            })?;
+            reader.seek(SeekFrom::Start(header.0))?;
+
+            let n8 = read_u32(reader)? as usize;
+            let mut modulus = vec![0; n8];
+            reader.read_exact(&mut modulus)?;
+            let power = read_u32(reader)?;
+
+            Ok((sections, n8, BigUint::from_bytes_le(&modulus), power))
+        };
+
+        let (sections, n8, modulus, power) =
+            read(&mut reader).map_err(|e| format!("Invalid powers of tau: {}", e))?;
+
+        if modulus < BigUint::from(3u32) {
+            return Err("Invalid powers of tau: invalid field modulus".to_string());
+        }
+
+        let r = (BigUint::from(1u32) << (8 * n8)) % &modulus;
+        let r_inv = r.modpow(&(&modulus - BigUint::from(2u32)), &modulus);
+
+        Ok(PowersOfTau {
+            reader,
+            n8,
+            modulus,
+            r_inv,
+            power,
+            sections,
+        })
+    }
+
+    /// The base two logarithm of the largest domain supported by the ceremony
+    pub fn power(&self) -> u32 {
+        self.power
+    }
+
+    /// The name of the curve of the ceremony, if it is known
+    pub fn curve(&self) -> Option<&'static str> {
+        CURVES
+            .iter()
+            .find(|(_, modulus)| {
+                BigUint::parse_bytes(modulus.as_bytes(), 10).unwrap() == self.modulus
+            })
+            .map(|(name, _)| *name)
+    }
+
+    pub fn check_curve(&self, curve: &str) -> Result<(), String> {
+        match self.curve() {
+            Some(c) if c == curve => Ok(()),
+            Some(c) => Err(format!(
+                "The powers of tau are for the curve {}, expected {}",
+                c, curve
+            )),
+            None => Err(format!(
+                "The powers of tau are for an unknown curve, expected {}",
+                curve
+            )),
+        }
+    }
+
+    /// `tau^i` in G1, for `i` in `start..start + count`
+    pub fn tau_g1(&mut self, start: usize, count: usize) -> Result<Vec<Vec<u8>>, String> {
+        self.points(TAU_G1, Group::G1, start, count)
+    }
+
+    /// `tau^i` in G2, for `i` in `start..start + count`
+    pub fn tau_g2(&mut self, start: usize, count: usize) -> Result<Vec<Vec<u8>>, String> {
+        self.points(TAU_G2, Group::G2, start, count)
+    }
+
+    /// `alpha * tau^i` in G1, for `i` in `start..start + count`
+    pub fn alpha_tau_g1(&mut self, start: usize, count: usize) -> Result<Vec<Vec<u8>>, String> {
+        self.points(ALPHA_TAU_G1, Group::G1, start, count)
+    }
+
+    /// `beta * tau^i` in G1, for `i` in `start..start + count`
+    pub fn beta_tau_g1(&mut self, start: usize, count: usize) -> Result<Vec<Vec<u8>>, String> {
+        self.points(BETA_TAU_G1, Group::G1, start, count)
+    }
+
+    /// `beta` in G2
+    pub fn beta_g2(&mut self) -> Result<Vec<u8>, String> {
+        Ok(self.points(BETA_G2, Group::G2, 0, 1)?.remove(0))
+    }
+
+    fn points(
+        &mut self,
+        section: u32,
+        group: Group,
+        start: usize,
+        count: usize,
+    ) -> Result<Vec<Vec<u8>>, String> {
+        let (offset, size) = *self
+            .sections
+            .get(&section)
+            .ok_or_else(|| format!("Invalid powers of tau: section {} is missing", section))?;
+
+        let point_size = group.coordinates() * self.n8;
+        let available = (size / point_size as u64) as usize;
+
+        if start + count > available {
+            return Err(format!(
+                "The powers of tau are too small: {} powers are needed but only {} are available",
+                start + count,
+                available
+            ));
+        }
+
+        let error = |e: io::Error| format!("Invalid powers of tau: {}", e);
+
+        self.reader
+            .seek(SeekFrom::Start(offset + (start * point_size) as u64))
+            .map_err(error)?;
+
+        let mut res = Vec::with_capacity(count);
+        let mut buf = vec![0; point_size];
+
+        for _ in 0..count {
+            self.reader.read_exact(&mut buf).map_err(error)?;
+
+            let mut point = Vec::with_capacity(point_size);
+            for coordinate in buf.chunks(self.n8) {
+                let coordinate = BigUint::from_bytes_le(coordinate);
+                if coordinate >= self.modulus {
+                    return Err("Invalid powers of tau: coordinate out of range".to_string());
+                }
+
+                let mut bytes = (coordinate * &self.r_inv % &self.modulus).to_bytes_le();
+                bytes.resize(self.n8, 0);
+                point.extend(bytes);
+            }
+
+            res.push(point);
+        }
+
+        Ok(res)
+    }
+}
+
+/// Writes a `.ptau` file with the given sections of points, which must be in standard form as
+/// returned by `PowersOfTau`
+#[cfg(test)]
+pub fn encode(curve: &str, power: u32, sections: Vec<(u32, Vec<Vec<u8>>)>) -> Vec<u8> {
+    let modulus = CURVES.iter().find(|(name, _)| *name == curve).unwrap().1;
+    let modulus = BigUint::parse_bytes(modulus.as_bytes(), 10).unwrap();
+    let n8 = (modulus.bits() + 63) / 64 * 8;
+
+    let mut header = vec![];
+    header.extend(&(n8 as u32).to_le_bytes());
+    let mut modulus_bytes = modulus.to_bytes_le();
+    modulus_bytes.resize(n8, 0);
+    header.extend(modulus_bytes);
+    header.extend(&power.to_le_bytes());
+    header.extend(&power.to_le_bytes());
+
+    let sections: Vec<(u32, Vec<u8>)> = std::iter::once((HEADER, header))
+        .chain(sections.into_iter().map(|(id, points)| {
+            let data = points
+                .iter()
+                .flat_map(|p| p.chunks(n8))
+                .flat_map(|c| {
+                    let mut bytes =
+                        ((BigUint::from_bytes_le(c) << (8 * n8)) % &modulus).to_bytes_le();
+                    bytes.resize(n8, 0);
+                    bytes
+                })
+                .collect();
+            (id, data)
+        }))
+        .collect();
+
+    let mut res = MAGIC.to_vec();
+    res.extend(&1u32.to_le_bytes());
+    res.extend(&(sections.len() as u32).to_le_bytes());
+    for (id, data) in sections {
+        res.extend(&id.to_le_bytes());
+        res.extend(&(data.len() as u64).to_le_bytes());
+        res.extend(data);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn point(coordinates: &[u8]) -> Vec<u8> {
+        coordinates
+            .iter()
+            .flat_map(|c| {
+                let mut bytes = vec![0; 32];
+                bytes[0] = *c;
+                bytes
+            })
+            .collect()
+    }
+
+    #[test]
+    fn read() {
+        let ptau = encode(
+            "bn128",
+            1,
+            vec![
+                (TAU_G1, vec![point(&[1, 2]), point(&[3, 4]), point(&[5, 6])]),
+                (BETA_G2, vec![point(&[1, 2, 3, 4])]),
+            ],
+        );
+
+        let mut ptau = PowersOfTau::read(Cursor::new(ptau)).unwrap();
+        assert_eq!(ptau.power(), 1);
+        assert_eq!(ptau.curve(), Some("bn128"));
+        assert!(ptau.check_curve("bn128").is_ok());
+        assert!(ptau.check_curve("bls12_381").is_err());
+
+        assert_eq!(
+            ptau.tau_g1(1, 2).unwrap(),
+            vec![point(&[3, 4]), point(&[5, 6])]
+        );
+        assert_eq!(ptau.beta_g2().unwrap(), point(&[1, 2, 3, 4]));
+
+        // reading more points than available fails
+        assert!(ptau.tau_g1(0, 4).is_err());
+        // and so does reading missing sections
+        assert!(ptau.tau_g2(0, 1).is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(PowersOfTau::read(Cursor::new(vec![])).is_err());
+        assert!(PowersOfTau::read(Cursor::new(b"ptax".to_vec())).is_err());
+
+        // the header is truncated
+        let ptau = encode("bn128", 1, vec![]);
+        assert!(PowersOfTau::read(Cursor::new(&ptau[..ptau.len() - 8])).is_err());
+    }
+}
@@ -1,9 +1,13 @@
-use crate::proof_system::scheme::{NonUniversalScheme, Scheme};
+use crate::proof_system::scheme::{NonUniversalScheme, NotBw6_761Field, Scheme};
 use crate::proof_system::solidity::solidity_pairing_lib;
-use crate::proof_system::{G1Affine, G2Affine, SolidityCompatibleField, SolidityCompatibleScheme};
+use crate::proof_system::{
+    G1Affine, G2Affine, G2AffineFq, SolidityCompatibleField, SolidityCompatibleScheme,
+};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use zokrates_field::Field;
+use zokrates_field::{Bw6_761Field, Field};
+
+pub const G16_WARNING: &str = "WARNING: You are using the G16 scheme which is subject to malleability. See zokrates.github.io/toolbox/proving_schemes.html#g16-malleability for implications.";
 
 pub struct G16;
 
@@ -23,14 +27,21 @@ pub struct VerificationKey<G1, G2> {
     pub gamma_abc: Vec<G1>,
 }
 
-impl<T: Field> Scheme<T> for G16 {
+impl<T: Field + NotBw6_761Field> Scheme<T> for G16 {
     type VerificationKey = VerificationKey<G1Affine, G2Affine>;
     type ProofPoints = ProofPoints<G1Affine, G2Affine>;
 }
 
-impl<T: Field> NonUniversalScheme<T> for G16 {}
+impl Scheme<Bw6_761Field> for G16 {
+    type VerificationKey = VerificationKey<G1Affine, G2AffineFq>;
+    type ProofPoints = ProofPoints<G1Affine, G2AffineFq>;
+}
+
+impl<T: Field + NotBw6_761Field> NonUniversalScheme<T> for G16 {}
+
+impl NonUniversalScheme<Bw6_761Field> for G16 {}
 
-impl<T: SolidityCompatibleField> SolidityCompatibleScheme<T> for G16 {
+impl<T: SolidityCompatibleField + NotBw6_761Field> SolidityCompatibleScheme<T> for G16 {
     fn export_solidity_verifier(vk: <G16 as Scheme<T>>::VerificationKey) -> String {
         let (mut template_text, solidity_pairing_lib_sans_bn256g2) =
             (String::from(CONTRACT_TEMPLATE), solidity_pairing_lib(false));
@@ -1,6 +1,6 @@
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use zokrates_field::Field;
+use zokrates_field::{Bls12_377Field, Bls12_381Field, Bn128Field, Field};
 
 pub mod gm17;
 pub mod groth16;
@@ -12,6 +12,11 @@ pub use self::groth16::G16;
 pub use self::marlin::Marlin;
 pub use self::pghr13::PGHR13;
 
+pub trait NotBw6_761Field {}
+impl NotBw6_761Field for Bls12_377Field {}
+impl NotBw6_761Field for Bls12_381Field {}
+impl NotBw6_761Field for Bn128Field {}
+
 pub trait Scheme<T: Field> {
     type VerificationKey: Serialize + DeserializeOwned;
     type ProofPoints: Serialize + DeserializeOwned;
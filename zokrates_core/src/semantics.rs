@@ -6,13 +6,16 @@
 
 use crate::absy::Identifier;
 use crate::absy::*;
+use crate::flat_absy::SourceSpan;
 use crate::typed_absy::types::{GGenericsAssignment, GenericsAssignment};
 use crate::typed_absy::*;
 use crate::typed_absy::{DeclarationParameter, DeclarationVariable, Variable};
 use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
 use std::collections::{btree_map::Entry, BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt;
 use std::path::PathBuf;
+use std::str::FromStr;
 use zokrates_field::Field;
 
 use crate::parser::Position;
@@ -21,8 +24,9 @@ use crate::absy::types::{UnresolvedSignature, UnresolvedType, UserTypeId};
 
 use crate::typed_absy::types::{
     check_type, specialize_declaration_type, ArrayType, DeclarationArrayType, DeclarationConstant,
-    DeclarationFunctionKey, DeclarationSignature, DeclarationStructMember, DeclarationStructType,
-    DeclarationType, GenericIdentifier, StructLocation, StructMember,
+    DeclarationEnumType, DeclarationEnumVariant, DeclarationFunctionKey, DeclarationSignature,
+    DeclarationStructMember, DeclarationStructType, DeclarationType, GenericIdentifier,
+    StructLocation, StructMember,
 };
 use std::hash::{Hash, Hasher};
 
@@ -55,6 +59,108 @@ impl ErrorInner {
     }
 }
 
+/// A check for code which is valid but likely to be a mistake
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Lint {
+    /// A local variable is never read
+    UnusedVariables,
+    /// A function parameter is never read
+    UnusedParameters,
+    /// An imported symbol is never used
+    UnusedImports,
+    /// A constant of the main module is never used
+    UnusedConstants,
+    /// A variable holding a return value of a function call is never read
+    UnusedReturnValues,
+    /// A variable has the name of a variable in an outer scope or of a function
+    Shadowing,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 6] = [
+        Lint::UnusedVariables,
+        Lint::UnusedParameters,
+        Lint::UnusedImports,
+        Lint::UnusedConstants,
+        Lint::UnusedReturnValues,
+        Lint::Shadowing,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnusedVariables => "unused_variables",
+            Lint::UnusedParameters => "unused_parameters",
+            Lint::UnusedImports => "unused_imports",
+            Lint::UnusedConstants => "unused_constants",
+            Lint::UnusedReturnValues => "unused_return_values",
+            Lint::Shadowing => "shadowing",
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Lint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Lint::ALL
+            .iter()
+            .find(|l| l.name() == s)
+            .cloned()
+            .ok_or_else(|| format!("Unknown lint `{}`", s))
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct WarningInner {
+    pos: Option<(Position, Position)>,
+    lint: Lint,
+    message: String,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Warning {
+    pub inner: WarningInner,
+    pub module_id: PathBuf,
+}
+
+impl WarningInner {
+    pub fn pos(&self) -> &Option<(Position, Position)> {
+        &self.pos
+    }
+
+    pub fn lint(&self) -> Lint {
+        self.lint
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn in_file(self, id: &ModuleId) -> Warning {
+        Warning {
+            inner: self,
+            module_id: id.to_path_buf(),
+        }
+    }
+}
+
+impl fmt::Display for WarningInner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let location = self
+            .pos
+            .map(|p| format!("{}", p.0))
+            .unwrap_or_else(|| "".to_string());
+        write!(f, "{}\n\t{} (`{}`)", location, self.message, self.lint)
+    }
+}
+
 // a single struct to cover all cases of user-defined types
 #[derive(Debug, Clone)]
 struct UserDeclarationType<'ast, T> {
@@ -96,6 +202,31 @@ struct State<'ast, T> {
     types: TypeMap<'ast, T>,
     // The user-defined constants
     constants: ConstantMap<'ast, T>,
+    /// The warnings found in the checked modules
+    warnings: Vec<Warning>,
+    /// The module symbols which are used, either in their module or by being imported
+    used_symbols: HashSet<(OwnedModuleId, String)>,
+    /// The module symbols to report if they turn out to be unused once the whole program is checked
+    declared_symbols: Vec<DeclaredSymbol<'ast>>,
+}
+
+/// An import or a constant, reported with `lint` if it is never used
+#[derive(Debug)]
+struct DeclaredSymbol<'ast> {
+    module_id: OwnedModuleId,
+    id: Identifier<'ast>,
+    pos: (Position, Position),
+    lint: Lint,
+}
+
+/// A variable declared in the source, reported with `lint` if it is never read
+#[derive(Debug)]
+struct LocalVariable<'ast> {
+    id: Identifier<'ast>,
+    level: usize,
+    pos: (Position, Position),
+    lint: Lint,
+    used: bool,
 }
 
 /// A symbol for a given name: either a type or a group of functions. Not both!
@@ -171,6 +302,9 @@ impl<'ast, T: Field> State<'ast, T> {
             typed_modules: BTreeMap::new(),
             types: BTreeMap::new(),
             constants: BTreeMap::new(),
+            warnings: vec![],
+            used_symbols: HashSet::new(),
+            declared_symbols: vec![],
         }
     }
 }
@@ -345,6 +479,16 @@ pub struct Checker<'ast, T> {
     scope: Scope<'ast, T>,
     functions: HashSet<DeclarationFunctionKey<'ast, T>>,
     level: usize,
+    destructuring_count: usize,
+    match_count: usize,
+    /// The variables declared in the current function, to report the ones which are never read
+    variables: Vec<LocalVariable<'ast>>,
+    /// The module symbols used in the module being checked
+    used_symbols: HashSet<(OwnedModuleId, String)>,
+    /// The warnings found in the module being checked
+    warnings: Vec<WarningInner>,
+    /// The function of the main module used as entry point, `main` if none
+    entry_point: Option<String>,
 }
 
 impl<'ast, T: Field> Checker<'ast, T> {
@@ -357,10 +501,47 @@ impl<'ast, T: Field> Checker<'ast, T> {
         Checker::default().check_program(prog)
     }
 
+    /// Check a `Program`, also returning the warnings found
+    ///
+    /// # Arguments
+    ///
+    /// * `prog` - The `Program` to be checked
+    pub fn check_with_warnings(
+        prog: Program<'ast>,
+    ) -> Result<(TypedProgram<'ast, T>, Vec<Warning>), Vec<Error>> {
+        Checker::default().check_program_with_warnings(prog)
+    }
+
+    /// Check a `Program` whose entry point is the function `entry_point` of the main module
+    /// rather than `main`, also returning the warnings found
+    ///
+    /// # Arguments
+    ///
+    /// * `prog` - The `Program` to be checked
+    /// * `entry_point` - The name of the entry point
+    pub fn check_with_entry_point(
+        prog: Program<'ast>,
+        entry_point: &str,
+    ) -> Result<(TypedProgram<'ast, T>, Vec<Warning>), Vec<Error>> {
+        Checker {
+            entry_point: Some(entry_point.to_string()),
+            ..Checker::default()
+        }
+        .check_program_with_warnings(prog)
+    }
+
     fn check_program(
         &mut self,
         program: Program<'ast>,
     ) -> Result<TypedProgram<'ast, T>, Vec<Error>> {
+        self.check_program_with_warnings(program)
+            .map(|(program, _)| program)
+    }
+
+    fn check_program_with_warnings(
+        &mut self,
+        program: Program<'ast>,
+    ) -> Result<(TypedProgram<'ast, T>, Vec<Warning>), Vec<Error>> {
         let mut state = State::new(program.modules);
 
         let mut errors = vec![];
@@ -377,19 +558,52 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
         let main_id = program.main.clone();
 
-        Checker::check_single_main(state.typed_modules.get(&program.main).unwrap()).map_err(
-            |inner| {
+        let entry_point = self.entry_point.as_deref().unwrap_or("main");
+
+        Checker::check_single_main(state.typed_modules.get(&program.main).unwrap(), entry_point)
+            .map_err(|inner| {
                 vec![Error {
                     inner,
                     module_id: main_id,
                 }]
-            },
-        )?;
+            })?;
 
-        Ok(TypedProgram {
-            main: program.main,
-            modules: state.typed_modules,
-        })
+        // only report the warnings of the main module: other modules, such as the ones of the
+        // standard library, are not under the control of the user
+        let mut warnings: Vec<_> = state
+            .warnings
+            .into_iter()
+            .filter(|w| w.module_id == program.main)
+            .collect();
+        let used_symbols = state.used_symbols;
+
+        // report the imports and the constants of the main module which are never used
+        warnings.extend(
+            state
+                .declared_symbols
+                .into_iter()
+                .filter(|s| s.module_id == program.main)
+                .filter(|s| !used_symbols.contains(&(s.module_id.clone(), s.id.to_string())))
+                .map(|s| {
+                    WarningInner {
+                        pos: Some(s.pos),
+                        lint: s.lint,
+                        message: match s.lint {
+                            Lint::UnusedImports => format!("Import `{}` is never used", s.id),
+                            _ => format!("Constant `{}` is never used", s.id),
+                        },
+                    }
+                    .in_file(&s.module_id)
+                }),
+        );
+
+        Ok((
+            TypedProgram {
+                main: program.main,
+                modules: state.typed_modules,
+            },
+            warnings,
+        ))
     }
 
     fn check_type_definition(
@@ -505,6 +719,12 @@ impl<'ast, T: Field> Checker<'ast, T> {
             DeclarationType::Struct(ref struct_ty) => {
                 StructExpression::try_from_typed(checked_expr, struct_ty).map(TypedExpression::from)
             }
+            DeclarationType::Tuple(ref tuple_ty) => {
+                TupleExpression::try_from_typed(checked_expr, tuple_ty).map(TypedExpression::from)
+            }
+            DeclarationType::Enum(ref enum_ty) => {
+                EnumExpression::try_from_typed(checked_expr, enum_ty).map(TypedExpression::from)
+            }
             DeclarationType::Int => Err(checked_expr), // Integers cannot be assigned
         }
         .map_err(|e| ErrorInner {
@@ -620,6 +840,72 @@ impl<'ast, T: Field> Checker<'ast, T> {
         ))
     }
 
+    fn check_enum_type_declaration(
+        &mut self,
+        id: String,
+        e: EnumDefinitionNode<'ast>,
+        module_id: &ModuleId,
+        state: &State<'ast, T>,
+    ) -> Result<DeclarationEnumType<'ast, T>, Vec<ErrorInner>> {
+        let pos = e.pos();
+        let e = e.value;
+
+        let mut errors = vec![];
+        let mut variants = vec![];
+        let mut variants_set = HashSet::new();
+
+        if e.variants.is_empty() {
+            errors.push(ErrorInner {
+                pos: Some(pos),
+                message: format!("Enum {} must have at least one variant", id),
+            });
+        }
+
+        for variant in e.variants {
+            let variant_pos = variant.pos();
+            let variant_id = variant.value.id.to_string();
+
+            // enum payloads cannot be generic
+            let payload: Result<Vec<_>, _> = variant
+                .value
+                .payload
+                .into_iter()
+                .map(|ty| {
+                    self.check_declaration_type(
+                        ty,
+                        module_id,
+                        state,
+                        &BTreeMap::default(),
+                        &mut HashSet::default(),
+                    )
+                })
+                .collect();
+
+            match payload {
+                Ok(payload) => match variants_set.insert(variant_id.clone()) {
+                    true => variants.push(DeclarationEnumVariant::new(variant_id, payload)),
+                    false => errors.push(ErrorInner {
+                        pos: Some(variant_pos),
+                        message: format!("Duplicate variant {} in enum definition", variant_id),
+                    }),
+                },
+                Err(e) => {
+                    errors.push(e);
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(DeclarationEnumType::new(
+            module_id.to_path_buf(),
+            id,
+            variants,
+        ))
+    }
+
     fn check_symbol_declaration(
         &mut self,
         declaration: SymbolDeclarationNode<'ast>,
@@ -681,6 +967,48 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     })),
                 }
             }
+            Symbol::Here(SymbolDefinition::Enum(e)) => {
+                match self.check_enum_type_declaration(
+                    declaration.id.to_string(),
+                    e.clone(),
+                    module_id,
+                    state,
+                ) {
+                    Ok(ty) => {
+                        match symbol_unifier.insert_type(declaration.id) {
+                            false => errors.push(
+                                ErrorInner {
+                                    pos: Some(pos),
+                                    message: format!(
+                                        "{} conflicts with another symbol",
+                                        declaration.id
+                                    ),
+                                }
+                                .in_file(module_id),
+                            ),
+                            true => {
+                                // there should be no entry in the map for this type yet
+                                assert!(state
+                                    .types
+                                    .entry(module_id.to_path_buf())
+                                    .or_default()
+                                    .insert(
+                                        declaration.id.to_string(),
+                                        UserDeclarationType {
+                                            generics: vec![],
+                                            ty: DeclarationType::Enum(ty)
+                                        }
+                                    )
+                                    .is_none());
+                            }
+                        };
+                    }
+                    Err(e) => errors.extend(e.into_iter().map(|inner| Error {
+                        inner,
+                        module_id: module_id.to_path_buf(),
+                    })),
+                }
+            }
             Symbol::Here(SymbolDefinition::Constant(c)) => {
                 match self.check_constant_definition(declaration.id, c, module_id, state) {
                     Ok(c) => {
@@ -719,6 +1047,12 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     .or_default()
                                     .insert(declaration.id, c.ty)
                                     .is_none());
+                                state.declared_symbols.push(DeclaredSymbol {
+                                    module_id: module_id.to_path_buf(),
+                                    id: declaration.id,
+                                    pos,
+                                    lint: Lint::UnusedConstants,
+                                });
                             }
                         };
                     }
@@ -803,6 +1137,15 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 let pos = import.pos();
                 let import = import.value;
 
+                // importing a symbol uses it
+                self.use_symbol(&import.module_id, import.symbol_id);
+                state.declared_symbols.push(DeclaredSymbol {
+                    module_id: module_id.to_path_buf(),
+                    id: declaration.id,
+                    pos,
+                    lint: Lint::UnusedImports,
+                });
+
                 match Checker::default().check_module(&import.module_id, state) {
                     Ok(()) => {
                         // find candidates in the checked module
@@ -849,6 +1192,13 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                             }),
                                             ..t
                                         }),
+                                        DeclarationType::Enum(t) => DeclarationType::Enum(DeclarationEnumType {
+                                            location: Some(StructLocation {
+                                                name: declaration.id.into(),
+                                                module: module_id.to_path_buf()
+                                            }),
+                                            ..t
+                                        }),
                                         _ => t.ty // all other cases
                                     },
                                     ..t
@@ -955,6 +1305,13 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 };
             }
             Symbol::Flat(funct) => {
+                state.declared_symbols.push(DeclaredSymbol {
+                    module_id: module_id.to_path_buf(),
+                    id: declaration.id,
+                    pos,
+                    lint: Lint::UnusedImports,
+                });
+
                 match symbol_unifier.insert_function(declaration.id, funct.typed_signature()) {
                     false => {
                         errors.push(
@@ -1029,6 +1386,11 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     )?
                 }
 
+                state
+                    .warnings
+                    .extend(self.warnings.drain(..).map(|w| w.in_file(module_id)));
+                state.used_symbols.extend(self.used_symbols.drain());
+
                 Some(TypedModule {
                     symbols: checked_symbols,
                 })
@@ -1047,20 +1409,20 @@ impl<'ast, T: Field> Checker<'ast, T> {
         Ok(())
     }
 
-    fn check_single_main(module: &TypedModule<T>) -> Result<(), ErrorInner> {
+    fn check_single_main(module: &TypedModule<T>, entry_point: &str) -> Result<(), ErrorInner> {
         match module
             .functions_iter()
-            .filter(|d| d.key.id == "main")
+            .filter(|d| d.key.id == entry_point)
             .count()
         {
             1 => Ok(()),
             0 => Err(ErrorInner {
                 pos: None,
-                message: "No main function found".into(),
+                message: format!("No {} function found", entry_point),
             }),
             n => Err(ErrorInner {
                 pos: None,
-                message: format!("Only one main function allowed, found {}", n),
+                message: format!("Only one {} function allowed, found {}", entry_point, n),
             }),
         }
     }
@@ -1137,7 +1499,13 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         id: decl_v.clone().id,
                         _type: ty,
                     }) {
-                        true => {}
+                        true => {
+                            self.declare_variable(
+                                arg.id.value.id,
+                                arg.id.pos(),
+                                Lint::UnusedParameters,
+                            );
+                        }
                         false => {
                             errors.push(ErrorInner {
                                 pos: Some(pos),
@@ -1168,9 +1536,9 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         found_return = true;
                     }
 
-                    match self.check_statement(stat, module_id, &state.types) {
-                        Ok(statement) => {
-                            if let TypedStatement::Return(e) = &statement {
+                    match self.check_statement_with_span(stat, module_id, &state.types) {
+                        Ok(statements) => {
+                            if let Some(TypedStatement::Return(e)) = statements.last() {
                                 match e.iter().map(|e| e.get_type()).collect::<Vec<_>>()
                                     == s.outputs
                                 {
@@ -1193,7 +1561,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     }),
                                 }
                             };
-                            statements_checked.push(statement);
+                            statements_checked.extend(statements);
                         }
                         Err(e) => {
                             errors.extend(e);
@@ -1334,6 +1702,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
             UnresolvedType::FieldElement => Ok(Type::FieldElement),
             UnresolvedType::Boolean => Ok(Type::Boolean),
             UnresolvedType::Uint(bitwidth) => Ok(Type::uint(bitwidth)),
+            UnresolvedType::Int(bitwidth) => Ok(Type::uint(UBitwidth::signed(bitwidth))),
             UnresolvedType::Array(t, size) => {
                 let size = self.check_expression(size, module_id, types)?;
 
@@ -1375,7 +1744,15 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     size,
                 )))
             }
+            UnresolvedType::Tuple(elements) => Ok(Type::Tuple(TupleType::new(
+                elements
+                    .into_iter()
+                    .map(|e| self.check_type(e, module_id, types))
+                    .collect::<Result<_, _>>()?,
+            ))),
             UnresolvedType::User(id, generics) => {
+                self.use_symbol(module_id, &id);
+
                 let declared_ty =
                     types
                         .get(module_id)
@@ -1477,6 +1854,8 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                 match (constants_map.get(name), generics_map.get(&name)) {
                     (Some(ty), None) => {
+                        self.use_symbol(module_id, name);
+
                         match ty {
                             DeclarationType::Uint(UBitwidth::B32) => Ok(DeclarationConstant::Constant(CanonicalConstantIdentifier::new(name, module_id.into()))),
                             _ => Err(ErrorInner {
@@ -1488,11 +1867,13 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             })
                         }
                     }
-                    (None, Some(index)) => Ok(DeclarationConstant::Generic(GenericIdentifier::with_name(name).with_index(*index))),
+                    (None, Some(index)) => Ok(DeclarationConstant::Generic(
+                        GenericIdentifier::with_name(name).with_index(*index),
+                    )),
                     _ => Err(ErrorInner {
                         pos: Some(pos),
-                        message: format!("Undeclared symbol `{}`", name)
-                    })
+                        message: format!("Undeclared symbol `{}`", name),
+                    }),
                 }
             }
             e => Err(ErrorInner {
@@ -1520,6 +1901,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
             UnresolvedType::FieldElement => Ok(DeclarationType::FieldElement),
             UnresolvedType::Boolean => Ok(DeclarationType::Boolean),
             UnresolvedType::Uint(bitwidth) => Ok(DeclarationType::uint(bitwidth)),
+            UnresolvedType::Int(bitwidth) => Ok(DeclarationType::uint(UBitwidth::signed(bitwidth))),
             UnresolvedType::Array(t, size) => {
                 let checked_size = self.check_generic_expression(
                     size.clone(),
@@ -1534,7 +1916,25 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     checked_size,
                 )))
             }
+            UnresolvedType::Tuple(elements) => {
+                Ok(DeclarationType::Tuple(DeclarationTupleType::new(
+                    elements
+                        .into_iter()
+                        .map(|e| {
+                            self.check_declaration_type(
+                                e,
+                                module_id,
+                                state,
+                                generics_map,
+                                used_generics,
+                            )
+                        })
+                        .collect::<Result<_, _>>()?,
+                )))
+            }
             UnresolvedType::User(id, generics) => {
+                self.use_symbol(module_id, &id);
+
                 let ty = state
                     .types
                     .get(module_id)
@@ -1632,6 +2032,8 @@ impl<'ast, T: Field> Checker<'ast, T> {
     ) -> Result<TypedStatement<'ast, T>, Vec<ErrorInner>> {
         self.check_for_var(&var).map_err(|e| vec![e])?;
 
+        let var_pos = var.pos();
+        let id = var.value.id;
         let var = self.check_variable(var, module_id, types).unwrap();
 
         let from = self
@@ -1701,24 +2103,80 @@ impl<'ast, T: Field> Checker<'ast, T> {
         }
         .map_err(|e| vec![e])?;
 
-        self.insert_into_scope(var.clone());
+        // the loop variable is allowed to shadow a variable or a constant, but that is likely to be
+        // a mistake
+        if !self.insert_into_scope(var.clone()) {
+            self.warnings.push(WarningInner {
+                pos: Some(var_pos),
+                lint: Lint::Shadowing,
+                message: format!(
+                    "Loop variable `{}` shadows a variable or a constant with the same name",
+                    id
+                ),
+            });
+        }
+        self.declare_variable(id, var_pos, Lint::UnusedVariables);
 
         let mut checked_statements = vec![];
 
         for stat in statements {
-            let checked_stat = self.check_statement(stat, module_id, types)?;
-            checked_statements.push(checked_stat);
+            let checked_stats = self.check_statement_with_span(stat, module_id, types)?;
+            checked_statements.extend(checked_stats);
         }
 
         Ok(TypedStatement::For(var, from, to, checked_statements))
     }
 
+    // check the statements of a branch of an if statement in their own scope
+    fn check_branch(
+        &mut self,
+        statements: Vec<StatementNode<'ast>>,
+        module_id: &ModuleId,
+        types: &TypeMap<'ast, T>,
+    ) -> Result<Vec<TypedStatement<'ast, T>>, Vec<ErrorInner>> {
+        self.enter_scope();
+
+        let res = statements
+            .into_iter()
+            .map(|stat| match stat.value {
+                Statement::Return(..) => Err(vec![ErrorInner {
+                    pos: Some(stat.pos()),
+                    message: "Return statements are not allowed inside if statements".to_string(),
+                }]),
+                _ => self.check_statement_with_span(stat, module_id, types),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|statements| statements.into_iter().flatten().collect());
+
+        self.exit_scope();
+
+        res
+    }
+
+    // check a statement, preceding the result with the span of the statement so that what it compiles to
+    // can be traced back to the source
+    fn check_statement_with_span(
+        &mut self,
+        stat: StatementNode<'ast>,
+        module_id: &ModuleId,
+        types: &TypeMap<'ast, T>,
+    ) -> Result<Vec<TypedStatement<'ast, T>>, Vec<ErrorInner>> {
+        let span = SourceSpan::new(module_id.display().to_string(), stat.start, stat.end);
+
+        self.check_statement(stat, module_id, types)
+            .map(|statements| {
+                std::iter::once(TypedStatement::Span(span))
+                    .chain(statements)
+                    .collect()
+            })
+    }
+
     fn check_statement(
         &mut self,
         stat: StatementNode<'ast>,
         module_id: &ModuleId,
         types: &TypeMap<'ast, T>,
-    ) -> Result<TypedStatement<'ast, T>, Vec<ErrorInner>> {
+    ) -> Result<Vec<TypedStatement<'ast, T>>, Vec<ErrorInner>> {
         let pos = stat.pos();
 
         match stat.value {
@@ -1797,12 +2255,17 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     return Err(errors);
                 }
 
-                Ok(res)
+                Ok(vec![res])
             }
             Statement::Declaration(var) => {
+                let var_pos = var.pos();
+                let id = var.value.id;
                 let var = self.check_variable(var, module_id, types)?;
                 match self.insert_into_scope(var.clone()) {
-                    true => Ok(TypedStatement::Declaration(var)),
+                    true => {
+                        self.declare_variable(id, var_pos, Lint::UnusedVariables);
+                        Ok(vec![TypedStatement::Declaration(var)])
+                    }
                     false => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!("Duplicate declaration for variable named {}", var.id),
@@ -1846,6 +2309,14 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         StructExpression::try_from_typed(checked_expr, struct_ty)
                             .map(TypedExpression::from)
                     }
+                    Type::Tuple(ref tuple_ty) => {
+                        TupleExpression::try_from_typed(checked_expr, tuple_ty)
+                            .map(TypedExpression::from)
+                    }
+                    Type::Enum(ref enum_ty) => {
+                        EnumExpression::try_from_typed(checked_expr, enum_ty)
+                            .map(TypedExpression::from)
+                    }
                     Type::Int => Err(checked_expr), // Integers cannot be assigned
                 }
                 .map_err(|e| ErrorInner {
@@ -1858,7 +2329,7 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         var_type
                     ),
                 })
-                .map(|rhs| TypedStatement::Definition(var, rhs))
+                .map(|rhs| vec![TypedStatement::Definition(var, rhs)])
                 .map_err(|e| vec![e])
             }
             Statement::Assertion(e, message) => {
@@ -1867,14 +2338,14 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     .map_err(|e| vec![e])?;
 
                 match e {
-                    TypedExpression::Boolean(e) => Ok(TypedStatement::Assertion(
+                    TypedExpression::Boolean(e) => Ok(vec![TypedStatement::Assertion(
                         e,
                         RuntimeError::SourceAssertion(AssertionMetadata {
                             file: module_id.display().to_string(),
                             position: pos.0,
                             message,
                         }),
-                    )),
+                    )]),
                     e => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!(
@@ -1893,13 +2364,75 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                 self.exit_scope();
 
-                res
+                res.map(|s| vec![s])
+            }
+            Statement::IfElse(condition, consequence, alternative) => {
+                let condition_checked = self
+                    .check_expression(condition, module_id, types)
+                    .map_err(|e| vec![e])?;
+
+                let condition = match condition_checked {
+                    TypedExpression::Boolean(condition) => Ok(condition),
+                    c => Err(vec![ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Expected condition in if statement to be of type bool, found {}",
+                            c.get_type()
+                        ),
+                    }]),
+                }?;
+
+                let consequence = self.check_branch(consequence, module_id, types)?;
+                let alternative = self.check_branch(alternative, module_id, types)?;
+
+                Ok(vec![TypedStatement::IfElse(
+                    condition,
+                    consequence,
+                    alternative,
+                )])
+            }
+            Statement::Log(format_string, expressions) => {
+                let format_string = FormatString::from(format_string.as_str());
+
+                if format_string.placeholder_count() != expressions.len() {
+                    return Err(vec![ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Wrong argument count in log call: expected {}, got {}",
+                            format_string.placeholder_count(),
+                            expressions.len()
+                        ),
+                    }]);
+                }
+
+                let expressions = expressions
+                    .into_iter()
+                    .map(|e| {
+                        let e = self.check_expression(e, module_id, types)?;
+
+                        // integer literals are logged as field elements
+                        let ty = match e.get_type() {
+                            Type::Int => Type::FieldElement,
+                            Type::Array(array_ty) if *array_ty.ty == Type::Int => {
+                                Type::array(ArrayType::new(Type::FieldElement, array_ty.size))
+                            }
+                            ty => ty,
+                        };
+
+                        TypedExpression::align_to_type(e, &ty).map_err(|(e, ty)| ErrorInner {
+                            pos: Some(pos),
+                            message: format!("Expected {} to have type {}", e, ty),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| vec![e])?;
+
+                Ok(vec![TypedStatement::Log(format_string, expressions)])
             }
             Statement::MultipleDefinition(assignees, rhs) => {
                 match rhs.value {
-                    // Right side has to be a function call
+                    // Right side is a function call
                     Expression::FunctionCall(fun_id_expression, generics, arguments) => {
-
                         let fun_id = match fun_id_expression.value {
                             Expression::Identifier(id) => Ok(id),
                             e => Err(vec![ErrorInner {
@@ -1911,32 +2444,50 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             }])
                         }?;
 
+                        self.use_symbol(module_id, fun_id);
+
                         // check the generic arguments, if any
                         let generics_checked: Option<Vec<Option<UExpression<'ast, T>>>> = generics
-                            .map(|generics|
-                                generics.into_iter().map(|g|
-                                    g.map(|g| {
-                                        let pos = g.pos();
-                                        self.check_expression(g, module_id, types).and_then(|g| {
-                                            UExpression::try_from_typed(g, &UBitwidth::B32).map_err(
-                                                |e| ErrorInner {
-                                                    pos: Some(pos),
-                                                    message: format!(
+                            .map(|generics| {
+                                generics
+                                    .into_iter()
+                                    .map(|g| {
+                                        g.map(|g| {
+                                            let pos = g.pos();
+                                            self.check_expression(g, module_id, types).and_then(
+                                                |g| {
+                                                    UExpression::try_from_typed(g, &UBitwidth::B32)
+                                                        .map_err(|e| ErrorInner {
+                                                            pos: Some(pos),
+                                                            message: format!(
                                                         "Expected {} to be of type u32, found {}",
                                                         e,
                                                         e.get_type(),
                                                     ),
+                                                        })
                                                 },
                                             )
                                         })
+                                        .transpose()
                                     })
-                                    .transpose()
-                                )
-                            .collect::<Result<_, _>>()
-                        ).transpose().map_err(|e| vec![e])?;
+                                    .collect::<Result<_, _>>()
+                            })
+                            .transpose()
+                            .map_err(|e| vec![e])?;
+
+                        let assignee_ids: Vec<_> = assignees
+                            .iter()
+                            .filter_map(|a| match a.value {
+                                Assignee::Identifier(id) => Some(id),
+                                _ => None,
+                            })
+                            .collect();
 
                         // check lhs assignees are defined
-                        let (assignees, errors): (Vec<_>, Vec<_>) = assignees.into_iter().map(|a| self.check_assignee(a, module_id, types)).partition(|r| r.is_ok());
+                        let (assignees, errors): (Vec<_>, Vec<_>) = assignees
+                            .into_iter()
+                            .map(|a| self.check_assignee(a, module_id, types))
+                            .partition(|r| r.is_ok());
 
                         if !errors.is_empty() {
                             return Err(errors.into_iter().map(|e| e.unwrap_err()).collect());
@@ -1944,19 +2495,29 @@ impl<'ast, T: Field> Checker<'ast, T> {
 
                         let assignees: Vec<_> = assignees.into_iter().map(|a| a.unwrap()).collect();
 
-                        let assignee_types: Vec<_> = assignees.iter().map(|a| Some(a.get_type().clone())).collect();
+                        let assignee_types: Vec<_> = assignees
+                            .iter()
+                            .map(|a| Some(a.get_type().clone()))
+                            .collect();
 
                         // find argument types
                         let mut arguments_checked = vec![];
                         for arg in arguments {
-                            let arg_checked = self.check_expression(arg, module_id, types).map_err(|e| vec![e])?;
+                            let arg_checked = self
+                                .check_expression(arg, module_id, types)
+                                .map_err(|e| vec![e])?;
                             arguments_checked.push(arg_checked);
                         }
 
                         let arguments_types: Vec<_> =
                             arguments_checked.iter().map(|a| a.get_type()).collect();
 
-                        let query = FunctionQuery::new(fun_id, &generics_checked, &arguments_types, &assignee_types);
+                        let query = FunctionQuery::new(
+                            fun_id,
+                            &generics_checked,
+                            &arguments_types,
+                            &assignee_types,
+                        );
 
                         let functions = self.find_functions(&query);
 
@@ -1972,9 +2533,11 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     message: format!("Expected function call argument to be of type {}, found {} of type {}", e.1, e.0, e.0.get_type())
                                 }])?;
 
+                                self.declare_return_values(&assignee_ids, pos);
+
                                 let call = TypedExpressionList::function_call(f.clone(), generics_checked.unwrap_or_else(|| vec![None; f.signature.generics.len()]), arguments_checked).annotate(Types { inner: assignees.iter().map(|a| a.get_type()).collect()});
 
-                                Ok(TypedStatement::MultipleDefinition(assignees, call))
+                                Ok(vec![TypedStatement::MultipleDefinition(assignees, call)])
                     		},
                     		0 => Err(ErrorInner {                         pos: Some(pos),
  message: format!("Function definition for function {} with signature {} not found.", fun_id, query) }),
@@ -1982,15 +2545,305 @@ impl<'ast, T: Field> Checker<'ast, T> {
                         pos: Some(pos),
                         message: format!("Ambiguous call to function {}, {} candidates were found. Please be more explicit.", fun_id, n)
                     })
-                    	}
+                    	}.map_err(|e| vec![e])
                     }
-                    _ => Err(ErrorInner {
-                        pos: Some(pos),
-                        message: format!("{} should be a function call", rhs),
-                    }),
-                }.map_err(|e| vec![e])
+                    // otherwise, the right side has to be a tuple which we destructure
+                    _ => self.check_destructuring(assignees, rhs, module_id, types),
+                }
+            }
+        }
+    }
+
+    /// Check a destructuring definition `a, b = t` where `t` is a tuple
+    ///
+    /// The tuple is first stored in an internal variable so that the assignees can appear in `t`,
+    /// for example in `a, b = (b, a)`, then each assignee is defined as the corresponding element.
+    fn check_destructuring(
+        &mut self,
+        assignees: Vec<AssigneeNode<'ast>>,
+        rhs: ExpressionNode<'ast>,
+        module_id: &ModuleId,
+        types: &TypeMap<'ast, T>,
+    ) -> Result<Vec<TypedStatement<'ast, T>>, Vec<ErrorInner>> {
+        let pos = rhs.pos();
+
+        let checked_rhs = self
+            .check_expression(rhs, module_id, types)
+            .map_err(|e| vec![e])?;
+
+        // check lhs assignees are defined
+        let (assignees, errors): (Vec<_>, Vec<_>) = assignees
+            .into_iter()
+            .map(|a| self.check_assignee(a, module_id, types))
+            .partition(|r| r.is_ok());
+
+        if !errors.is_empty() {
+            return Err(errors.into_iter().map(|e| e.unwrap_err()).collect());
+        }
+
+        let assignees: Vec<_> = assignees.into_iter().map(|a| a.unwrap()).collect();
+
+        let tuple_ty = TupleType::new(assignees.iter().map(|a| a.get_type()).collect());
+
+        let tuple = TupleExpression::try_from_typed(checked_rhs, &tuple_ty).map_err(|e| {
+            vec![ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                    "Expression `{}` of type `{}` cannot be destructured into {} variables of type `{}`",
+                    e,
+                    e.get_type(),
+                    assignees.len(),
+                    tuple_ty
+                ),
+            }]
+        })?;
+
+        let tuple_ty = tuple.ty().clone();
+
+        let id = CoreIdentifier::Internal("tuple", self.destructuring_count);
+        self.destructuring_count += 1;
+
+        let variable = Variable::with_id_and_type(id.clone(), Type::Tuple(tuple_ty.clone()));
+
+        let mut statements = vec![
+            TypedStatement::Declaration(variable.clone()),
+            TypedStatement::Definition(TypedAssignee::Identifier(variable), tuple.into()),
+        ];
+
+        statements.extend(assignees.into_iter().enumerate().map(|(index, assignee)| {
+            TypedStatement::Definition(
+                assignee,
+                TypedExpression::element(
+                    TupleExpressionInner::Identifier(id.clone().into()).annotate(tuple_ty.clone()),
+                    index as u32,
+                ),
+            )
+        }));
+
+        Ok(statements)
+    }
+
+    /// Check a match expression `match e { A::V(a, _) => x, _ => y }`
+    ///
+    /// The matched value is first stored in an internal variable, then the arms are turned into a chain of
+    /// conditionals on the variant tag, in which each binding is defined as the corresponding payload element.
+    fn check_match(
+        &mut self,
+        e: ExpressionNode<'ast>,
+        arms: Vec<MatchArmNode<'ast>>,
+        module_id: &ModuleId,
+        types: &TypeMap<'ast, T>,
+    ) -> Result<TypedExpression<'ast, T>, ErrorInner> {
+        let pos = e.pos();
+
+        let matched = match self.check_expression(e, module_id, types)? {
+            TypedExpression::Enum(e) => Ok(e),
+            e => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                    "Cannot match on expression {} of type {}, expected an enum",
+                    e,
+                    e.get_type()
+                ),
+            }),
+        }?;
+
+        let enum_ty = matched.ty().clone();
+
+        let id = CoreIdentifier::Internal("match", self.match_count);
+        self.match_count += 1;
+
+        let variable = Variable::with_id_and_type(id.clone(), Type::Enum(enum_ty.clone()));
+
+        let statements = vec![
+            TypedStatement::Declaration(variable.clone()),
+            TypedStatement::Definition(TypedAssignee::Identifier(variable), matched.into()),
+        ];
+
+        let matched = EnumExpressionInner::Identifier(id.into()).annotate(enum_ty.clone());
+
+        let mut covered = vec![false; enum_ty.variants.len()];
+        let mut has_wildcard = false;
+        let mut checked_arms = vec![];
+
+        for arm in arms {
+            let arm_pos = arm.pos();
+            let arm = arm.value;
+
+            if has_wildcard || covered.iter().all(|c| *c) {
+                return Err(ErrorInner {
+                    pos: Some(arm_pos),
+                    message: format!("Unreachable match arm {}", arm.pattern),
+                });
+            }
+
+            match arm.pattern {
+                MatchPattern::Wildcard => {
+                    has_wildcard = true;
+
+                    let e = self.check_expression(arm.expression, module_id, types)?;
+                    checked_arms.push((None, vec![], e));
+                }
+                MatchPattern::Variant(ty_id, variant, bindings) => {
+                    // the pattern type is resolved in the current module, so that aliases and imports match
+                    self.use_symbol(module_id, &ty_id);
+                    let matches_type = match types.get(module_id).unwrap().get(&ty_id) {
+                        Some(UserDeclarationType {
+                            ty: DeclarationType::Enum(pattern_ty),
+                            ..
+                        }) => pattern_ty.canonical_location == enum_ty.canonical_location,
+                        _ => false,
+                    };
+
+                    if !matches_type {
+                        return Err(ErrorInner {
+                            pos: Some(arm_pos),
+                            message: format!(
+                                "Pattern {}::{} does not match type {}",
+                                ty_id, variant, enum_ty
+                            ),
+                        });
+                    }
+
+                    let index = enum_ty.variant_index(variant).ok_or_else(|| ErrorInner {
+                        pos: Some(arm_pos),
+                        message: format!("Enum {} has no variant {}", enum_ty, variant),
+                    })?;
+
+                    if covered[index] {
+                        return Err(ErrorInner {
+                            pos: Some(arm_pos),
+                            message: format!("Unreachable match arm {}::{}", ty_id, variant),
+                        });
+                    }
+
+                    covered[index] = true;
+
+                    let payload = enum_ty.variants[index].payload.clone();
+
+                    if bindings.len() != payload.len() {
+                        return Err(ErrorInner {
+                            pos: Some(arm_pos),
+                            message: format!(
+                                "Pattern {}::{} expects {} binding{}, found {}",
+                                ty_id,
+                                variant,
+                                payload.len(),
+                                if payload.len() == 1 { "" } else { "s" },
+                                bindings.len()
+                            ),
+                        });
+                    }
+
+                    let payload_tuple = TupleExpression::payload(matched.clone(), index as u32);
+
+                    self.enter_scope();
+
+                    let mut arm_statements = vec![];
+                    let mut res = Ok(());
+
+                    for (j, (binding, ty)) in bindings.into_iter().zip(payload).enumerate() {
+                        if let Some(binding) = binding {
+                            let v = Variable::with_id_and_type(binding, ty);
+
+                            if !self.insert_into_scope(v.clone()) {
+                                res = Err(ErrorInner {
+                                    pos: Some(arm_pos),
+                                    message: format!(
+                                        "Duplicate declaration for variable named {}",
+                                        binding
+                                    ),
+                                });
+                                break;
+                            }
+
+                            self.declare_variable(binding, arm_pos, Lint::UnusedVariables);
+
+                            arm_statements.push(TypedStatement::Declaration(v.clone()));
+                            arm_statements.push(TypedStatement::Definition(
+                                TypedAssignee::Identifier(v),
+                                TypedExpression::element(payload_tuple.clone(), j as u32),
+                            ));
+                        }
+                    }
+
+                    let e =
+                        res.and_then(|_| self.check_expression(arm.expression, module_id, types));
+
+                    self.exit_scope();
+
+                    checked_arms.push((Some(index), arm_statements, e?));
+                }
+            }
+        }
+
+        if !has_wildcard {
+            let missing: Vec<_> = enum_ty
+                .variants
+                .iter()
+                .zip(covered)
+                .filter(|(_, covered)| !covered)
+                .map(|(v, _)| format!("{}::{}", enum_ty, v.id))
+                .collect();
+
+            if !missing.is_empty() {
+                return Err(ErrorInner {
+                    pos: Some(pos),
+                    message: format!(
+                        "Non-exhaustive match on {}: {} not covered",
+                        enum_ty,
+                        missing.join(", ")
+                    ),
+                });
             }
         }
+
+        // all arms must have the same type, integer literals take the type of the other arms
+        let ty = checked_arms
+            .iter()
+            .map(|(_, _, e)| e.get_type())
+            .find(|ty| *ty != Type::Int)
+            .ok_or_else(|| ErrorInner {
+                pos: Some(pos),
+                message: "Cannot infer the type of the match expression".into(),
+            })?;
+
+        let mut checked_arms = checked_arms
+            .into_iter()
+            .map(|(index, statements, e)| {
+                TypedExpression::align_to_type(e, &ty)
+                    .map(|e| match statements.is_empty() {
+                        true => (index, e),
+                        false => (index, TypedExpression::block(statements, e)),
+                    })
+                    .map_err(|(e, ty)| ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Match arms should have the same type, expected {}, found {} of type {}",
+                            ty,
+                            e,
+                            e.get_type()
+                        ),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // the last arm is the fallback, the ones before it are conditional on the tag
+        let (_, last) = checked_arms.pop().unwrap();
+
+        let res = checked_arms
+            .into_iter()
+            .rev()
+            .fold(last, |acc, (index, e)| {
+                TypedExpression::conditional(
+                    BooleanExpression::IsVariant(box matched.clone(), index.unwrap() as u32),
+                    e,
+                    acc,
+                    ConditionalKind::IfElse,
+                )
+            });
+
+        Ok(TypedExpression::block(statements, res))
     }
 
     fn check_assignee(
@@ -2091,6 +2944,30 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     }),
                 }
             }
+            Assignee::Element(box assignee, index) => {
+                let checked_assignee = self.check_assignee(assignee, module_id, types)?;
+
+                let ty = checked_assignee.get_type();
+                match &ty {
+                    Type::Tuple(tuple_ty) => match (index as usize) < tuple_ty.elements.len() {
+                        true => Ok(TypedAssignee::Element(box checked_assignee, index)),
+                        false => Err(ErrorInner {
+                            pos: Some(pos),
+                            message: format!(
+                                "Tuple index {} out of bounds for {} of type {}",
+                                index, checked_assignee, ty
+                            ),
+                        }),
+                    },
+                    ty => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Cannot access element {} on {} of type {}",
+                            index, checked_assignee, ty,
+                        ),
+                    }),
+                }
+            }
         }
     }
 
@@ -2141,26 +3018,43 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     .get_key_value_scope(name)
                     .map(|(x, y)| (x.clone(), y.clone()))
                 {
-                    Some((id, ty)) => match ty {
-                        Type::Boolean => Ok(BooleanExpression::Identifier(id.id.into()).into()),
-                        Type::Uint(bitwidth) => Ok(UExpressionInner::Identifier(id.id.into())
-                            .annotate(bitwidth)
-                            .into()),
-                        Type::FieldElement => {
-                            Ok(FieldElementExpression::Identifier(id.id.into()).into())
-                        }
-                        Type::Array(array_type) => {
-                            Ok(ArrayExpressionInner::Identifier(id.id.into())
-                                .annotate(*array_type.ty, array_type.size)
-                                .into())
-                        }
-                        Type::Struct(members) => {
-                            Ok(StructExpressionInner::Identifier(id.id.into())
-                                .annotate(members)
-                                .into())
+                    Some((id, ty)) => {
+                        match &id.id {
+                            CoreIdentifier::Constant(c) => self.use_symbol(&c.module, c.id),
+                            _ => self.read_variable(name),
+                        };
+
+                        match ty {
+                            Type::Boolean => Ok(BooleanExpression::Identifier(id.id.into()).into()),
+                            Type::Uint(bitwidth) => Ok(UExpressionInner::Identifier(id.id.into())
+                                .annotate(bitwidth)
+                                .into()),
+                            Type::FieldElement => {
+                                Ok(FieldElementExpression::Identifier(id.id.into()).into())
+                            }
+                            Type::Array(array_type) => {
+                                Ok(ArrayExpressionInner::Identifier(id.id.into())
+                                    .annotate(*array_type.ty, array_type.size)
+                                    .into())
+                            }
+                            Type::Struct(members) => {
+                                Ok(StructExpressionInner::Identifier(id.id.into())
+                                    .annotate(members)
+                                    .into())
+                            }
+                            Type::Tuple(tuple_ty) => {
+                                Ok(TupleExpressionInner::Identifier(id.id.into())
+                                    .annotate(tuple_ty)
+                                    .into())
+                            }
+                            Type::Enum(enum_ty) => {
+                                Ok(EnumExpressionInner::Identifier(id.id.into())
+                                    .annotate(enum_ty)
+                                    .into())
+                            }
+                            Type::Int => unreachable!(),
                         }
-                        Type::Int => unreachable!(),
-                    },
+                    }
                     None => Err(ErrorInner {
                         pos: Some(pos),
                         message: format!("Identifier \"{}\" is undefined", name),
@@ -2361,6 +3255,19 @@ impl<'ast, T: Field> Checker<'ast, T> {
                 }
             }
             Expression::Neg(box e) => {
+                // negated literals are checked as a whole, so that the minimum value is accepted
+                let literal = match &e.value {
+                    Expression::I8Constant(n) => Some((n, UBitwidth::I8)),
+                    Expression::I16Constant(n) => Some((n, UBitwidth::I16)),
+                    Expression::I32Constant(n) => Some((n, UBitwidth::I32)),
+                    Expression::I64Constant(n) => Some((n, UBitwidth::I64)),
+                    _ => None,
+                };
+
+                if let Some((n, bitwidth)) = literal {
+                    return Self::check_signed_constant(n, bitwidth, true, pos).map(|e| e.into());
+                }
+
                 let e = self.check_expression(e, module_id, types)?;
 
                 match e {
@@ -2437,6 +3344,12 @@ impl<'ast, T: Field> Checker<'ast, T> {
                             (TypedExpression::Struct(consequence), TypedExpression::Struct(alternative)) => {
                                 Ok(StructExpression::conditional(condition, consequence, alternative, kind).into())
                             },
+                            (TypedExpression::Tuple(consequence), TypedExpression::Tuple(alternative)) => {
+                                Ok(TupleExpression::conditional(condition, consequence, alternative, kind).into())
+                            },
+                            (TypedExpression::Enum(consequence), TypedExpression::Enum(alternative)) => {
+                                Ok(EnumExpression::conditional(condition, consequence, alternative, kind).into())
+                            },
                             (TypedExpression::Uint(consequence), TypedExpression::Uint(alternative)) => {
                                 Ok(UExpression::conditional(condition, consequence, alternative, kind).into())
                             },
@@ -2473,6 +3386,19 @@ impl<'ast, T: Field> Checker<'ast, T> {
             Expression::U16Constant(n) => Ok(UExpressionInner::Value(n.into()).annotate(16).into()),
             Expression::U32Constant(n) => Ok(UExpressionInner::Value(n.into()).annotate(32).into()),
             Expression::U64Constant(n) => Ok(UExpressionInner::Value(n.into()).annotate(64).into()),
+            Expression::U128Constant(n) => Ok(UExpressionInner::Value(n).annotate(128).into()),
+            Expression::I8Constant(n) => {
+                Self::check_signed_constant(&n, UBitwidth::I8, false, pos).map(|e| e.into())
+            }
+            Expression::I16Constant(n) => {
+                Self::check_signed_constant(&n, UBitwidth::I16, false, pos).map(|e| e.into())
+            }
+            Expression::I32Constant(n) => {
+                Self::check_signed_constant(&n, UBitwidth::I32, false, pos).map(|e| e.into())
+            }
+            Expression::I64Constant(n) => {
+                Self::check_signed_constant(&n, UBitwidth::I64, false, pos).map(|e| e.into())
+            }
             Expression::FunctionCall(fun_id_expression, generics, arguments) => {
                 let fun_id = match fun_id_expression.value {
                     Expression::Identifier(id) => Ok(id),
@@ -2485,6 +3411,8 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     }),
                 }?;
 
+                self.use_symbol(module_id, fun_id);
+
                 // check the generic arguments, if any
                 let generics_checked: Option<Vec<Option<UExpression<'ast, T>>>> = generics
                     .map(|generics| {
@@ -2588,6 +3516,16 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     generics_checked,
                                     arguments_checked,
                                 ).annotate(struct_ty).into()),
+                                Type::Tuple(tuple_ty) => Ok(TupleExpression::function_call(
+                                    function_key,
+                                    generics_checked,
+                                    arguments_checked,
+                                ).annotate(tuple_ty).into()),
+                                Type::Enum(enum_ty) => Ok(EnumExpression::function_call(
+                                    function_key,
+                                    generics_checked,
+                                    arguments_checked,
+                                ).annotate(enum_ty).into()),
                                 Type::Array(array_ty) => Ok(ArrayExpression::function_call(
                                     function_key,
                                     generics_checked,
@@ -2749,6 +3687,12 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     (TypedExpression::Struct(e1), TypedExpression::Struct(e2)) => {
                         Ok(BooleanExpression::StructEq(box e1, box e2).into())
                     }
+                    (TypedExpression::Tuple(e1), TypedExpression::Tuple(e2)) => {
+                        Ok(BooleanExpression::TupleEq(box e1, box e2).into())
+                    }
+                    (TypedExpression::Enum(e1), TypedExpression::Enum(e2)) => {
+                        Ok(BooleanExpression::EnumEq(box e1, box e2).into())
+                    }
                     (TypedExpression::Uint(e1), TypedExpression::Uint(e2))
                         if e1.get_type() == e2.get_type() =>
                     {
@@ -2950,6 +3894,8 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                     Type::Boolean => Ok(BooleanExpression::select(a, index).into()),
                                     Type::Array(..) => Ok(ArrayExpression::select(a, index).into()),
                                     Type::Struct(..) => Ok(StructExpression::select(a, index).into()),
+                                    Type::Tuple(..) => Ok(TupleExpression::select(a, index).into()),
+                                    Type::Enum(..) => Ok(EnumExpression::select(a, index).into()),
                                     Type::Int => unreachable!(),
                                 }
                             }
@@ -2991,6 +3937,12 @@ impl<'ast, T: Field> Checker<'ast, T> {
                                 Type::Struct(..) => {
                                     Ok(StructExpression::member(s, id.to_string()).into())
                                 }
+                                Type::Tuple(..) => {
+                                    Ok(TupleExpression::member(s, id.to_string()).into())
+                                }
+                                Type::Enum(..) => {
+                                    Ok(EnumExpression::member(s, id.to_string()).into())
+                                }
                             },
                             None => Err(ErrorInner {
                                 pos: Some(pos),
@@ -3018,6 +3970,67 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     }),
                 }
             }
+            Expression::Element(box e, index) => {
+                let e = self.check_expression(e, module_id, types)?;
+
+                match e {
+                    TypedExpression::Tuple(t) => {
+                        let ty = t.ty().elements.get(index as usize).cloned();
+
+                        match ty {
+                            // integer elements can only appear in inline tuples, so we can access them directly
+                            Some(Type::Int) => {
+                                let ty = t.ty().clone();
+
+                                match t.into_inner() {
+                                    TupleExpressionInner::Value(mut values) => {
+                                        Ok(values.swap_remove(index as usize))
+                                    }
+                                    t => Err(ErrorInner {
+                                        pos: Some(pos),
+                                        message: format!(
+                                            "Cannot infer the type of element {} of {}",
+                                            index,
+                                            t.annotate(ty)
+                                        ),
+                                    }),
+                                }
+                            }
+                            Some(_) => Ok(TypedExpression::element(t, index)),
+                            None => Err(ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Tuple index {} out of bounds for {} of type {}",
+                                    index,
+                                    t,
+                                    t.get_type()
+                                ),
+                            }),
+                        }
+                    }
+                    e => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Cannot access element {} on expression of type {}",
+                            index,
+                            e.get_type()
+                        ),
+                    }),
+                }
+            }
+            Expression::InlineTuple(expressions) => {
+                let mut expressions_checked = vec![];
+                for e in expressions {
+                    let e_checked = self.check_expression(e, module_id, types)?;
+                    expressions_checked.push(e_checked);
+                }
+
+                let ty = TupleType::new(expressions_checked.iter().map(|e| e.get_type()).collect());
+
+                Ok(TupleExpressionInner::Value(expressions_checked)
+                    .annotate(ty)
+                    .into())
+            }
             Expression::InlineArray(expressions_or_spreads) => {
                 // check each expression, getting its type
                 let mut expressions_or_spreads_checked = vec![];
@@ -3115,6 +4128,8 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     .into())
             }
             Expression::InlineStruct(id, inline_members) => {
+                self.use_symbol(module_id, &id);
+
                 let ty = match types.get(module_id).unwrap().get(&id).cloned() {
                     None => Err(ErrorInner {
                         pos: Some(pos),
@@ -3243,30 +4258,125 @@ impl<'ast, T: Field> Checker<'ast, T> {
                     .annotate(inferred_struct_type)
                     .into())
             }
-            Expression::And(box e1, box e2) => {
-                let e1_checked = self.check_expression(e1, module_id, types)?;
-                let e2_checked = self.check_expression(e2, module_id, types)?;
+            Expression::InlineEnum(id, variant, arguments) => {
+                self.use_symbol(module_id, &id);
 
-                let (e1_checked, e2_checked) = TypedExpression::align_without_integers(
-                    e1_checked, e2_checked,
-                )
-                .map_err(|(e1, e2)| ErrorInner {
-                    pos: Some(pos),
-                    message: format!(
-                        "Cannot apply boolean operators to {} and {}",
-                        e1.get_type(),
-                        e2.get_type()
-                    ),
-                })?;
+                let ty = match types.get(module_id).unwrap().get(&id).cloned() {
+                    None => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!("Undefined type `{}`", id),
+                    }),
+                    Some(ty) => Ok(ty),
+                }?;
 
-                match (e1_checked, e2_checked) {
-                    (TypedExpression::Int(e1), TypedExpression::Int(e2)) => {
-                        Ok(IntExpression::And(box e1, box e2).into())
-                    }
-                    (TypedExpression::Boolean(e1), TypedExpression::Boolean(e2)) => {
-                        Ok(BooleanExpression::And(box e1, box e2).into())
-                    }
-                    (e1, e2) => Err(ErrorInner {
+                let declared_enum_type = match ty.ty {
+                    DeclarationType::Enum(enum_type) => Ok(enum_type),
+                    ty => Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!("Type `{}` is not an enum", ty),
+                    }),
+                }?;
+
+                let index =
+                    declared_enum_type
+                        .variant_index(variant)
+                        .ok_or_else(|| ErrorInner {
+                            pos: Some(pos),
+                            message: format!(
+                                "Enum {} has no variant {}",
+                                declared_enum_type, variant
+                            ),
+                        })?;
+
+                let payload = declared_enum_type.variants[index].payload.clone();
+
+                // check that we provided the required number of values
+                if payload.len() != arguments.len() {
+                    return Err(ErrorInner {
+                        pos: Some(pos),
+                        message: format!(
+                            "Variant {}::{} expects {} value{}, found {}",
+                            id,
+                            variant,
+                            payload.len(),
+                            if payload.len() == 1 { "" } else { "s" },
+                            arguments.len()
+                        ),
+                    });
+                }
+
+                let values = arguments
+                    .into_iter()
+                    .zip(payload.iter())
+                    .map(|(value, ty)| {
+                        let value_checked = self.check_expression(value, module_id, types)?;
+
+                        TypedExpression::align_to_type(value_checked, ty)
+                            .map_err(|(e, _)| e)
+                            .and_then(|e| {
+                                match check_type(
+                                    ty,
+                                    &e.get_type(),
+                                    &mut GGenericsAssignment::default(),
+                                ) {
+                                    true => Ok(e),
+                                    false => Err(e),
+                                }
+                            })
+                            .map_err(|e| ErrorInner {
+                                pos: Some(pos),
+                                message: format!(
+                                    "Payload of variant {}::{} should be of type {}, found {} of type {}",
+                                    id,
+                                    variant,
+                                    ty,
+                                    e,
+                                    e.get_type()
+                                ),
+                            })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                // enum payloads are not generic, so this specialization cannot fail
+                let enum_type = match specialize_declaration_type(
+                    DeclarationType::Enum(declared_enum_type),
+                    &GGenericsAssignment::default(),
+                )
+                .unwrap()
+                {
+                    Type::Enum(enum_type) => enum_type,
+                    _ => unreachable!(),
+                };
+
+                Ok(EnumExpressionInner::Value(index as u32, values)
+                    .annotate(enum_type)
+                    .into())
+            }
+            Expression::Match(box e, arms) => self.check_match(e, arms, module_id, types),
+            Expression::And(box e1, box e2) => {
+                let e1_checked = self.check_expression(e1, module_id, types)?;
+                let e2_checked = self.check_expression(e2, module_id, types)?;
+
+                let (e1_checked, e2_checked) = TypedExpression::align_without_integers(
+                    e1_checked, e2_checked,
+                )
+                .map_err(|(e1, e2)| ErrorInner {
+                    pos: Some(pos),
+                    message: format!(
+                        "Cannot apply boolean operators to {} and {}",
+                        e1.get_type(),
+                        e2.get_type()
+                    ),
+                })?;
+
+                match (e1_checked, e2_checked) {
+                    (TypedExpression::Int(e1), TypedExpression::Int(e2)) => {
+                        Ok(IntExpression::And(box e1, box e2).into())
+                    }
+                    (TypedExpression::Boolean(e1), TypedExpression::Boolean(e2)) => {
+                        Ok(BooleanExpression::And(box e1, box e2).into())
+                    }
+                    (e1, e2) => Err(ErrorInner {
                         pos: Some(pos),
 
                         message: format!(
@@ -3461,6 +4571,45 @@ impl<'ast, T: Field> Checker<'ast, T> {
         }
     }
 
+    /// Checks that the literal `n`, negated if `negated` is set, fits in the signed type of
+    /// `bitwidth`, and returns its two's complement representation
+    fn check_signed_constant(
+        n: &BigUint,
+        bitwidth: UBitwidth,
+        negated: bool,
+        pos: (Position, Position),
+    ) -> Result<UExpression<'ast, T>, ErrorInner> {
+        let bits = bitwidth.to_usize();
+        let bound = 1u128 << (bits - 1);
+
+        let in_range = match negated {
+            true => *n <= BigUint::from(bound),
+            false => *n < BigUint::from(bound),
+        };
+
+        match in_range {
+            true => {
+                let value = u128::from_str_radix(&n.to_str_radix(16), 16).unwrap();
+                let value = match negated {
+                    true => value.wrapping_neg() & ((1u128 << bits) - 1),
+                    false => value,
+                };
+                Ok(UExpressionInner::Value(value).annotate(bitwidth))
+            }
+            false => Err(ErrorInner {
+                pos: Some(pos),
+                message: format!(
+                    "Signed constant `{}{}i{}` not in the representable range [-{}, {}]",
+                    if negated { "-" } else { "" },
+                    n,
+                    bits,
+                    bound,
+                    bound - 1
+                ),
+            }),
+        }
+    }
+
     fn get_key_value_scope<'a>(
         &'a self,
         identifier: &'ast str,
@@ -3498,8 +4647,76 @@ impl<'ast, T: Field> Checker<'ast, T> {
         let current_level = self.level;
         self.scope
             .retain(|scoped_variable, _| scoped_variable.level < current_level);
+
+        // report the variables going out of scope which were never read
+        let first_out_of_scope = self
+            .variables
+            .iter()
+            .position(|v| v.level >= current_level)
+            .unwrap_or(self.variables.len());
+
+        for v in self.variables.drain(first_out_of_scope..) {
+            if !v.used {
+                self.warnings.push(WarningInner {
+                    pos: Some(v.pos),
+                    lint: v.lint,
+                    message: match v.lint {
+                        Lint::UnusedParameters => format!("Parameter `{}` is never used", v.id),
+                        Lint::UnusedReturnValues => {
+                            format!("Return value assigned to `{}` is never used", v.id)
+                        }
+                        _ => format!("Variable `{}` is never used", v.id),
+                    },
+                });
+            }
+        }
+
         self.level -= 1;
     }
+
+    /// Keeps track of the variable `id` declared at `pos` in the current scope, to report it
+    /// with `lint` if it is never read
+    fn declare_variable(&mut self, id: Identifier<'ast>, pos: (Position, Position), lint: Lint) {
+        if self.functions.iter().any(|f| f.id == id) {
+            self.warnings.push(WarningInner {
+                pos: Some(pos),
+                lint: Lint::Shadowing,
+                message: format!("Variable `{}` shadows a function with the same name", id),
+            });
+        }
+
+        self.variables.push(LocalVariable {
+            id,
+            level: self.level,
+            pos,
+            lint,
+            used: false,
+        });
+    }
+
+    /// Reports the variables among `ids` which are declared in the statement at `pos` as unused
+    /// return values rather than unused variables if they are never read
+    fn declare_return_values(&mut self, ids: &[Identifier<'ast>], pos: (Position, Position)) {
+        for v in self
+            .variables
+            .iter_mut()
+            .filter(|v| ids.contains(&v.id) && v.pos.0 >= pos.0 && v.pos.1 <= pos.1)
+        {
+            v.lint = Lint::UnusedReturnValues;
+        }
+    }
+
+    fn read_variable(&mut self, id: Identifier<'ast>) {
+        if let Some(v) = self.variables.iter_mut().rev().find(|v| v.id == id) {
+            v.used = true;
+        }
+    }
+
+    /// Marks the symbol `id` of the module `module_id` as used
+    fn use_symbol(&mut self, module_id: &ModuleId, id: &str) {
+        self.used_symbols
+            .insert((module_id.to_path_buf(), id.to_string()));
+    }
 }
 
 #[cfg(test)]
@@ -3513,6 +4730,15 @@ mod tests {
     lazy_static! {
         static ref MODULE_ID: OwnedModuleId = OwnedModuleId::from("");
     }
+
+    // the span preceding each checked statement of a function whose nodes are mocked
+    fn mock_span<'ast>() -> TypedStatement<'ast, Bn128Field> {
+        TypedStatement::Span(SourceSpan::new(
+            MODULE_ID.display().to_string(),
+            Position::mock(),
+            Position::mock(),
+        ))
+    }
     mod constants {
         use super::*;
 
@@ -3527,6 +4753,53 @@ mod tests {
                 .is_ok());
         }
 
+        #[test]
+        fn signed_in_range() {
+            // `127i8` and `-128i8` are the bounds of `i8`
+            let expr = Expression::I8Constant(BigUint::from(127u32)).mock();
+            assert_eq!(
+                Checker::<Bn128Field>::default().check_expression(
+                    expr,
+                    &*MODULE_ID,
+                    &TypeMap::new()
+                ),
+                Ok(UExpressionInner::Value(127).annotate(UBitwidth::I8).into())
+            );
+
+            let expr =
+                Expression::Neg(box Expression::I8Constant(BigUint::from(128u32)).mock()).mock();
+            assert_eq!(
+                Checker::<Bn128Field>::default().check_expression(
+                    expr,
+                    &*MODULE_ID,
+                    &TypeMap::new()
+                ),
+                Ok(UExpressionInner::Value(0x80).annotate(UBitwidth::I8).into())
+            );
+        }
+
+        #[test]
+        fn signed_overflow() {
+            // `128i8` and `-129i8` are out of the bounds of `i8`
+            let expr = Expression::I8Constant(BigUint::from(128u32)).mock();
+            assert!(Checker::<Bn128Field>::default()
+                .check_expression(expr, &*MODULE_ID, &TypeMap::new())
+                .is_err());
+
+            let expr =
+                Expression::Neg(box Expression::I8Constant(BigUint::from(129u32)).mock()).mock();
+            assert!(Checker::<Bn128Field>::default()
+                .check_expression(expr, &*MODULE_ID, &TypeMap::new())
+                .is_err());
+
+            // literals larger than any integer type are rejected too
+            let value = BigUint::parse_bytes(b"99999999999999999999999999999999999999999", 10);
+            let expr = Expression::I64Constant(value.unwrap()).mock();
+            assert!(Checker::<Bn128Field>::default()
+                .check_expression(expr, &*MODULE_ID, &TypeMap::new())
+                .is_err());
+        }
+
         #[test]
         fn field_overflow() {
             // the value of `P` is an invalid field literal
@@ -4227,6 +5500,12 @@ mod tests {
             functions,
             level,
             return_types: None,
+            destructuring_count: 0,
+            match_count: 0,
+            variables: vec![],
+            used_symbols: HashSet::new(),
+            warnings: vec![],
+            entry_point: None,
         }
     }
 
@@ -4351,10 +5630,10 @@ mod tests {
         let mut checker: Checker<Bn128Field> = new_with_args(scope, 1, HashSet::new());
         assert_eq!(
             checker.check_statement(statement, &*MODULE_ID, &TypeMap::new()),
-            Ok(TypedStatement::Definition(
+            Ok(vec![TypedStatement::Definition(
                 TypedAssignee::Identifier(typed_absy::Variable::field_element("a")),
                 FieldElementExpression::Identifier("b".into()).into()
-            ))
+            )])
         );
     }
 
@@ -4632,7 +5911,9 @@ mod tests {
         ];
 
         let for_statements_checked = vec![
+            mock_span(),
             TypedStatement::Declaration(typed_absy::Variable::uint("a", UBitwidth::B32)),
+            mock_span(),
             TypedStatement::Definition(
                 TypedAssignee::Identifier(typed_absy::Variable::uint("a", UBitwidth::B32)),
                 UExpressionInner::Identifier("i".into())
@@ -4642,12 +5923,14 @@ mod tests {
         ];
 
         let foo_statements_checked = vec![
+            mock_span(),
             TypedStatement::For(
                 typed_absy::Variable::uint("i", UBitwidth::B32),
                 0u32.into(),
                 10u32.into(),
                 for_statements_checked,
             ),
+            mock_span(),
             TypedStatement::Return(vec![]),
         ];
 
@@ -5281,8 +6564,11 @@ mod tests {
         ];
 
         let bar_statements_checked: Vec<TypedStatement<Bn128Field>> = vec![
+            mock_span(),
             TypedStatement::Declaration(typed_absy::Variable::field_element("a")),
+            mock_span(),
             TypedStatement::Declaration(typed_absy::Variable::field_element("b")),
+            mock_span(),
             TypedStatement::MultipleDefinition(
                 vec![
                     typed_absy::Variable::field_element("a").into(),
@@ -5300,6 +6586,7 @@ mod tests {
                 )
                 .annotate(Types::new(vec![Type::FieldElement, Type::FieldElement])),
             ),
+            mock_span(),
             TypedStatement::Return(vec![FieldElementExpression::Add(
                 box FieldElementExpression::Identifier("a".into()),
                 box FieldElementExpression::Identifier("b".into()),
@@ -5475,7 +6762,7 @@ mod tests {
         // should fail
 
         let mut checker: Checker<Bn128Field> = Checker::default();
-        let _: Result<TypedStatement<Bn128Field>, Vec<ErrorInner>> = checker.check_statement(
+        let _: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker.check_statement(
             Statement::Declaration(
                 absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
             )
@@ -5483,7 +6770,7 @@ mod tests {
             &*MODULE_ID,
             &TypeMap::new(),
         );
-        let s2_checked: Result<TypedStatement<Bn128Field>, Vec<ErrorInner>> = checker
+        let s2_checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
             .check_statement(
                 Statement::Declaration(
                     absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
@@ -5509,7 +6796,7 @@ mod tests {
         // should fail
 
         let mut checker: Checker<Bn128Field> = Checker::default();
-        let _: Result<TypedStatement<Bn128Field>, Vec<ErrorInner>> = checker.check_statement(
+        let _: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker.check_statement(
             Statement::Declaration(
                 absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
             )
@@ -5517,7 +6804,7 @@ mod tests {
             &*MODULE_ID,
             &TypeMap::new(),
         );
-        let s2_checked: Result<TypedStatement<Bn128Field>, Vec<ErrorInner>> = checker
+        let s2_checked: Result<Vec<TypedStatement<Bn128Field>>, Vec<ErrorInner>> = checker
             .check_statement(
                 Statement::Declaration(
                     absy::Variable::new("a", UnresolvedType::Boolean.mock()).mock(),
@@ -6497,5 +7784,683 @@ mod tests {
                 ))
             );
         }
+
+        #[test]
+        fn tuple_element() {
+            // (field, bool) a
+            // a.1 = true
+            let a: AssigneeNode = Assignee::Element(box Assignee::Identifier("a").mock(), 1).mock();
+
+            let mut checker: Checker<Bn128Field> = Checker::default();
+            checker.enter_scope();
+
+            checker
+                .check_statement(
+                    Statement::Declaration(
+                        absy::Variable::new(
+                            "a",
+                            UnresolvedType::Tuple(vec![
+                                UnresolvedType::FieldElement.mock(),
+                                UnresolvedType::Boolean.mock(),
+                            ])
+                            .mock(),
+                        )
+                        .mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                checker.check_assignee(a, &*MODULE_ID, &TypeMap::new()),
+                Ok(TypedAssignee::Element(
+                    box TypedAssignee::Identifier(typed_absy::Variable::with_id_and_type(
+                        "a",
+                        Type::tuple(TupleType::new(vec![Type::FieldElement, Type::Boolean])),
+                    )),
+                    1
+                ))
+            );
+
+            // a.2 = true
+            let a: AssigneeNode = Assignee::Element(box Assignee::Identifier("a").mock(), 2).mock();
+
+            assert!(checker
+                .check_assignee(a, &*MODULE_ID, &TypeMap::new())
+                .is_err());
+        }
+    }
+
+    mod tuples {
+        use super::*;
+
+        fn declare<'ast>(
+            checker: &mut Checker<'ast, Bn128Field>,
+            id: &'ast str,
+            ty: UnresolvedType<'ast>,
+        ) {
+            checker
+                .check_statement(
+                    Statement::Declaration(absy::Variable::new(id, ty.mock()).mock()).mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+        }
+
+        #[test]
+        fn element_access() {
+            // (field, u32) t
+            // t.1 is a u32, t.2 is out of bounds
+            let mut checker: Checker<Bn128Field> = Checker::default();
+            checker.enter_scope();
+
+            declare(
+                &mut checker,
+                "t",
+                UnresolvedType::Tuple(vec![
+                    UnresolvedType::FieldElement.mock(),
+                    UnresolvedType::Uint(32).mock(),
+                ]),
+            );
+
+            assert_eq!(
+                checker
+                    .check_expression(
+                        Expression::Element(box Expression::Identifier("t").mock(), 1).mock(),
+                        &*MODULE_ID,
+                        &TypeMap::new()
+                    )
+                    .unwrap()
+                    .get_type(),
+                Type::Uint(UBitwidth::B32)
+            );
+
+            assert!(checker
+                .check_expression(
+                    Expression::Element(box Expression::Identifier("t").mock(), 2).mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new()
+                )
+                .is_err());
+        }
+
+        #[test]
+        fn destructuring() {
+            // field a
+            // u32 b
+            // a, b = (1, 2)
+            let mut checker: Checker<Bn128Field> = Checker::default();
+            checker.enter_scope();
+
+            declare(&mut checker, "a", UnresolvedType::FieldElement);
+            declare(&mut checker, "b", UnresolvedType::Uint(32));
+
+            let statements = checker
+                .check_statement(
+                    Statement::MultipleDefinition(
+                        vec![
+                            Assignee::Identifier("a").mock(),
+                            Assignee::Identifier("b").mock(),
+                        ],
+                        Expression::InlineTuple(vec![
+                            Expression::IntConstant(1usize.into()).mock(),
+                            Expression::IntConstant(2usize.into()).mock(),
+                        ])
+                        .mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+
+            let tuple_ty = TupleType::new(vec![Type::FieldElement, Type::Uint(UBitwidth::B32)]);
+            let tuple =
+                TupleExpressionInner::Identifier(CoreIdentifier::Internal("tuple", 0).into())
+                    .annotate(tuple_ty.clone());
+
+            assert_eq!(
+                statements,
+                vec![
+                    TypedStatement::Declaration(typed_absy::Variable::with_id_and_type(
+                        CoreIdentifier::Internal("tuple", 0),
+                        Type::Tuple(tuple_ty.clone())
+                    )),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(typed_absy::Variable::with_id_and_type(
+                            CoreIdentifier::Internal("tuple", 0),
+                            Type::Tuple(tuple_ty.clone())
+                        )),
+                        TupleExpressionInner::Value(vec![
+                            FieldElementExpression::Number(Bn128Field::from(1)).into(),
+                            UExpressionInner::Value(2).annotate(UBitwidth::B32).into(),
+                        ])
+                        .annotate(tuple_ty)
+                        .into()
+                    ),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(typed_absy::Variable::field_element("a")),
+                        FieldElementExpression::element(tuple.clone(), 0).into()
+                    ),
+                    TypedStatement::Definition(
+                        TypedAssignee::Identifier(typed_absy::Variable::uint("b", UBitwidth::B32)),
+                        UExpression::element(tuple, 1).into()
+                    ),
+                ]
+            );
+        }
+
+        #[test]
+        fn destructuring_arity_mismatch() {
+            // field a
+            // a, a = (1, 2, 3)
+            // should fail
+            let mut checker: Checker<Bn128Field> = Checker::default();
+            checker.enter_scope();
+
+            declare(&mut checker, "a", UnresolvedType::FieldElement);
+
+            assert!(checker
+                .check_statement(
+                    Statement::MultipleDefinition(
+                        vec![
+                            Assignee::Identifier("a").mock(),
+                            Assignee::Identifier("a").mock(),
+                        ],
+                        Expression::InlineTuple(vec![
+                            Expression::IntConstant(1usize.into()).mock(),
+                            Expression::IntConstant(2usize.into()).mock(),
+                            Expression::IntConstant(3usize.into()).mock(),
+                        ])
+                        .mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .is_err());
+        }
+    }
+
+    mod enums {
+        use super::*;
+
+        // enum State {
+        //     Idle
+        //     Active(field)
+        // }
+        fn state_type<'ast>() -> DeclarationEnumType<'ast, Bn128Field> {
+            DeclarationEnumType::new(
+                (*MODULE_ID).clone(),
+                "State".into(),
+                vec![
+                    DeclarationEnumVariant::new("Idle".into(), vec![]),
+                    DeclarationEnumVariant::new(
+                        "Active".into(),
+                        vec![DeclarationType::FieldElement],
+                    ),
+                ],
+            )
+        }
+
+        fn types<'ast>() -> TypeMap<'ast, Bn128Field> {
+            vec![(
+                (*MODULE_ID).clone(),
+                vec![(
+                    "State".to_string(),
+                    UserDeclarationType {
+                        generics: vec![],
+                        ty: DeclarationType::Enum(state_type()),
+                    },
+                )]
+                .into_iter()
+                .collect(),
+            )]
+            .into_iter()
+            .collect()
+        }
+
+        // State s
+        fn checker<'ast>() -> Checker<'ast, Bn128Field> {
+            let mut checker: Checker<Bn128Field> = Checker::default();
+            checker.enter_scope();
+
+            checker
+                .check_statement(
+                    Statement::Declaration(
+                        absy::Variable::new("s", UnresolvedType::User("State".into(), None).mock())
+                            .mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &types(),
+                )
+                .unwrap();
+
+            checker
+        }
+
+        fn arm<'ast>(
+            variant: Option<(&'ast str, Vec<Option<&'ast str>>)>,
+            expression: Expression<'ast>,
+        ) -> MatchArmNode<'ast> {
+            MatchArm {
+                pattern: variant
+                    .map(|(variant, bindings)| {
+                        MatchPattern::Variant("State".into(), variant, bindings)
+                    })
+                    .unwrap_or(MatchPattern::Wildcard),
+                expression: expression.mock(),
+            }
+            .mock()
+        }
+
+        #[test]
+        fn duplicate_variant_def() {
+            // enum State { Idle, Idle(field) } should be rejected
+            let modules = Modules::new();
+            let state = State::new(modules);
+
+            let declaration: EnumDefinitionNode = EnumDefinition {
+                variants: vec![
+                    EnumVariant {
+                        id: "Idle",
+                        payload: vec![],
+                    }
+                    .mock(),
+                    EnumVariant {
+                        id: "Idle",
+                        payload: vec![UnresolvedType::FieldElement.mock()],
+                    }
+                    .mock(),
+                ],
+            }
+            .mock();
+
+            assert_eq!(
+                Checker::<Bn128Field>::default()
+                    .check_enum_type_declaration("State".into(), declaration, &*MODULE_ID, &state)
+                    .unwrap_err()[0]
+                    .message,
+                "Duplicate variant Idle in enum definition"
+            );
+        }
+
+        #[test]
+        fn inline_arity_mismatch() {
+            // State::Active() should be rejected
+            let mut checker = checker();
+
+            assert_eq!(
+                checker
+                    .check_expression(
+                        Expression::InlineEnum("State".into(), "Active", vec![]).mock(),
+                        &*MODULE_ID,
+                        &types()
+                    )
+                    .unwrap_err()
+                    .message,
+                "Variant State::Active expects 1 value, found 0"
+            );
+        }
+
+        #[test]
+        fn exhaustive_match() {
+            // match s {
+            //     State::Idle => 0,
+            //     State::Active(a) => a,
+            // }
+            let mut checker = checker();
+
+            assert_eq!(
+                checker
+                    .check_expression(
+                        Expression::Match(
+                            box Expression::Identifier("s").mock(),
+                            vec![
+                                arm(
+                                    Some(("Idle", vec![])),
+                                    Expression::IntConstant(0usize.into())
+                                ),
+                                arm(
+                                    Some(("Active", vec![Some("a")])),
+                                    Expression::Identifier("a")
+                                ),
+                            ]
+                        )
+                        .mock(),
+                        &*MODULE_ID,
+                        &types()
+                    )
+                    .unwrap()
+                    .get_type(),
+                Type::FieldElement
+            );
+
+            // the binding is not visible outside of its arm
+            assert!(checker
+                .check_expression(Expression::Identifier("a").mock(), &*MODULE_ID, &types())
+                .is_err());
+        }
+
+        #[test]
+        fn non_exhaustive_match() {
+            // match s {
+            //     State::Active(_) => 1,
+            // }
+            let mut checker = checker();
+
+            assert_eq!(
+                checker
+                    .check_expression(
+                        Expression::Match(
+                            box Expression::Identifier("s").mock(),
+                            vec![arm(
+                                Some(("Active", vec![None])),
+                                Expression::FieldConstant(1usize.into())
+                            )]
+                        )
+                        .mock(),
+                        &*MODULE_ID,
+                        &types()
+                    )
+                    .unwrap_err()
+                    .message,
+                "Non-exhaustive match on State: State::Idle not covered"
+            );
+        }
+
+        #[test]
+        fn unreachable_arm() {
+            // match s {
+            //     _ => 0,
+            //     State::Idle => 1,
+            // }
+            let mut checker = checker();
+
+            assert_eq!(
+                checker
+                    .check_expression(
+                        Expression::Match(
+                            box Expression::Identifier("s").mock(),
+                            vec![
+                                arm(None, Expression::FieldConstant(0usize.into())),
+                                arm(
+                                    Some(("Idle", vec![])),
+                                    Expression::FieldConstant(1usize.into())
+                                ),
+                            ]
+                        )
+                        .mock(),
+                        &*MODULE_ID,
+                        &types()
+                    )
+                    .unwrap_err()
+                    .message,
+                "Unreachable match arm State::Idle"
+            );
+        }
+    }
+
+    mod if_else_statements {
+        use super::*;
+
+        // field a
+        fn checker<'ast>() -> Checker<'ast, Bn128Field> {
+            let mut checker: Checker<Bn128Field> = Checker::default();
+            checker.enter_scope();
+
+            checker
+                .check_statement(
+                    Statement::Declaration(
+                        absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+
+            checker
+        }
+
+        #[test]
+        fn assign_in_branches() {
+            // if a == 1 {
+            //   a = 2
+            // } else {
+            //   field b = 3
+            //   a = b
+            // }
+
+            let statement = Statement::IfElse(
+                Expression::Eq(
+                    box Expression::Identifier("a").mock(),
+                    box Expression::IntConstant(1usize.into()).mock(),
+                )
+                .mock(),
+                vec![Statement::Definition(
+                    Assignee::Identifier("a").mock(),
+                    Expression::IntConstant(2usize.into()).mock(),
+                )
+                .mock()],
+                vec![
+                    Statement::Declaration(
+                        absy::Variable::new("b", UnresolvedType::FieldElement.mock()).mock(),
+                    )
+                    .mock(),
+                    Statement::Definition(
+                        Assignee::Identifier("b").mock(),
+                        Expression::IntConstant(3usize.into()).mock(),
+                    )
+                    .mock(),
+                    Statement::Definition(
+                        Assignee::Identifier("a").mock(),
+                        Expression::Identifier("b").mock(),
+                    )
+                    .mock(),
+                ],
+            )
+            .mock();
+
+            assert_eq!(
+                checker().check_statement(statement, &*MODULE_ID, &TypeMap::new()),
+                Ok(vec![TypedStatement::IfElse(
+                    BooleanExpression::FieldEq(
+                        box FieldElementExpression::Identifier("a".into()),
+                        box FieldElementExpression::Number(Bn128Field::from(1)),
+                    ),
+                    vec![
+                        mock_span(),
+                        TypedStatement::Definition(
+                            typed_absy::Variable::field_element("a").into(),
+                            FieldElementExpression::Number(Bn128Field::from(2)).into(),
+                        )
+                    ],
+                    vec![
+                        mock_span(),
+                        TypedStatement::Declaration(typed_absy::Variable::field_element("b")),
+                        mock_span(),
+                        TypedStatement::Definition(
+                            typed_absy::Variable::field_element("b").into(),
+                            FieldElementExpression::Number(Bn128Field::from(3)).into(),
+                        ),
+                        mock_span(),
+                        TypedStatement::Definition(
+                            typed_absy::Variable::field_element("a").into(),
+                            FieldElementExpression::Identifier("b".into()).into(),
+                        ),
+                    ],
+                )])
+            );
+        }
+
+        #[test]
+        fn branch_scope() {
+            // if true {
+            //   field b = 3
+            // }
+            // a = b
+            // should fail
+
+            let mut checker = checker();
+
+            checker
+                .check_statement(
+                    Statement::IfElse(
+                        Expression::BooleanConstant(true).mock(),
+                        vec![
+                            Statement::Declaration(
+                                absy::Variable::new("b", UnresolvedType::FieldElement.mock())
+                                    .mock(),
+                            )
+                            .mock(),
+                            Statement::Definition(
+                                Assignee::Identifier("b").mock(),
+                                Expression::IntConstant(3usize.into()).mock(),
+                            )
+                            .mock(),
+                        ],
+                        vec![],
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                checker
+                    .check_statement(
+                        Statement::Definition(
+                            Assignee::Identifier("a").mock(),
+                            Expression::Identifier("b").mock(),
+                        )
+                        .mock(),
+                        &*MODULE_ID,
+                        &TypeMap::new(),
+                    )
+                    .unwrap_err()[0]
+                    .message,
+                "Identifier \"b\" is undefined"
+            );
+        }
+
+        #[test]
+        fn non_boolean_condition() {
+            // if a {
+            // }
+            // should fail
+
+            let statement =
+                Statement::IfElse(Expression::Identifier("a").mock(), vec![], vec![]).mock();
+
+            assert_eq!(
+                checker()
+                    .check_statement(statement, &*MODULE_ID, &TypeMap::new())
+                    .unwrap_err()[0]
+                    .message,
+                "Expected condition in if statement to be of type bool, found field"
+            );
+        }
+
+        #[test]
+        fn return_in_branch() {
+            // if true {
+            //   return
+            // }
+            // should fail
+
+            let statement = Statement::IfElse(
+                Expression::BooleanConstant(true).mock(),
+                vec![Statement::Return(
+                    ExpressionList {
+                        expressions: vec![],
+                    }
+                    .mock(),
+                )
+                .mock()],
+                vec![],
+            )
+            .mock();
+
+            assert_eq!(
+                checker()
+                    .check_statement(statement, &*MODULE_ID, &TypeMap::new())
+                    .unwrap_err()[0]
+                    .message,
+                "Return statements are not allowed inside if statements"
+            );
+        }
+    }
+
+    mod log {
+        use super::*;
+
+        // field a
+        fn checker<'ast>() -> Checker<'ast, Bn128Field> {
+            let mut checker: Checker<Bn128Field> = Checker::default();
+            checker.enter_scope();
+
+            checker
+                .check_statement(
+                    Statement::Declaration(
+                        absy::Variable::new("a", UnresolvedType::FieldElement.mock()).mock(),
+                    )
+                    .mock(),
+                    &*MODULE_ID,
+                    &TypeMap::new(),
+                )
+                .unwrap();
+
+            checker
+        }
+
+        #[test]
+        fn log_values() {
+            // log("{} and {}", a, 42)
+
+            let statement = Statement::Log(
+                "{} and {}".into(),
+                vec![
+                    Expression::Identifier("a").mock(),
+                    Expression::IntConstant(42usize.into()).mock(),
+                ],
+            )
+            .mock();
+
+            assert_eq!(
+                checker().check_statement(statement, &*MODULE_ID, &TypeMap::new()),
+                Ok(vec![TypedStatement::Log(
+                    FormatString {
+                        parts: vec!["".into(), " and ".into(), "".into()]
+                    },
+                    vec![
+                        FieldElementExpression::Identifier("a".into()).into(),
+                        FieldElementExpression::Number(Bn128Field::from(42)).into(),
+                    ]
+                )])
+            );
+        }
+
+        #[test]
+        fn wrong_argument_count() {
+            // log("{} and {}", a)
+            // should fail
+
+            let statement =
+                Statement::Log("{} and {}".into(), vec![Expression::Identifier("a").mock()]).mock();
+
+            assert_eq!(
+                checker()
+                    .check_statement(statement, &*MODULE_ID, &TypeMap::new())
+                    .unwrap_err()[0]
+                    .message,
+                "Wrong argument count in log call: expected 2, got 1"
+            );
+        }
     }
 }
@@ -277,6 +277,42 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ConstantInliner<'ast, T> {
             e => fold_struct_expression_inner(self, ty, e),
         }
     }
+
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType<'ast, T>,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> Result<TupleExpressionInner<'ast, T>, Self::Error> {
+        match e {
+            TupleExpressionInner::Identifier(ref id) => {
+                match self.get_constant_for_identifier(id) {
+                    Some(c) => {
+                        let e: TupleExpression<'ast, T> = c.try_into().unwrap();
+                        Ok(e.into_inner())
+                    }
+                    None => fold_tuple_expression_inner(self, ty, e),
+                }
+            }
+            e => fold_tuple_expression_inner(self, ty, e),
+        }
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        ty: &EnumType<'ast, T>,
+        e: EnumExpressionInner<'ast, T>,
+    ) -> Result<EnumExpressionInner<'ast, T>, Self::Error> {
+        match e {
+            EnumExpressionInner::Identifier(ref id) => match self.get_constant_for_identifier(id) {
+                Some(c) => {
+                    let e: EnumExpression<'ast, T> = c.try_into().unwrap();
+                    Ok(e.into_inner())
+                }
+                None => fold_enum_expression_inner(self, ty, e),
+            },
+            e => fold_enum_expression_inner(self, ty, e),
+        }
+    }
 }
 
 #[cfg(test)]
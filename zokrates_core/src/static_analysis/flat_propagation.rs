@@ -82,6 +82,18 @@ impl<T: Field> FlatStatement<T> {
                     .collect(),
                 ..d
             })),
+            FlatStatement::Log(format_string, expressions, conditions) => Some(FlatStatement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .map(|(t, e)| (t, e.into_iter().map(|e| e.propagate(constants)).collect()))
+                    .collect(),
+                conditions
+                    .into_iter()
+                    .map(|c| c.propagate(constants))
+                    .collect(),
+            )),
+            FlatStatement::Span(span) => Some(FlatStatement::Span(span)),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::flat_absy::{CallFrame, SourceSpan};
 use crate::typed_absy::types::UBitwidth;
 use crate::typed_absy::{self, Expr};
 use crate::zir;
@@ -9,6 +10,12 @@ use std::convert::{TryFrom, TryInto};
 #[derive(Default)]
 pub struct Flattener<T: Field> {
     phantom: PhantomData<T>,
+    // the index of the next internal variable
+    next_var_idx: usize,
+    // the functions inlined at the current statement, each with the span of its call site
+    call_stack: Vec<(CallFrame, Option<SourceSpan>)>,
+    // the span of the current statement
+    span: Option<SourceSpan>,
 }
 
 fn flatten_identifier_rec<'ast>(
@@ -46,6 +53,132 @@ fn flatten_identifier_rec<'ast>(
                 )
             })
             .collect(),
+        typed_absy::types::ConcreteType::Tuple(tuple_ty) => tuple_ty
+            .elements
+            .iter()
+            .enumerate()
+            .flat_map(|(i, ty)| {
+                flatten_identifier_rec(zir::SourceIdentifier::Element(box id.clone(), i as u32), ty)
+            })
+            .collect(),
+        typed_absy::types::ConcreteType::Enum(enum_ty) => std::iter::once(zir::Variable {
+            id: zir::Identifier::Source(zir::SourceIdentifier::Tag(box id.clone())),
+            _type: zir::Type::FieldElement,
+        })
+        .chain(
+            enum_ty
+                .variants
+                .iter()
+                .enumerate()
+                .flat_map(|(i, variant)| {
+                    let variant_id = zir::SourceIdentifier::Variant(box id.clone(), i as u32);
+                    variant
+                        .payload
+                        .iter()
+                        .enumerate()
+                        .flat_map(move |(j, ty)| {
+                            flatten_identifier_rec(
+                                zir::SourceIdentifier::Element(box variant_id.clone(), j as u32),
+                                ty,
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                }),
+        )
+        .collect(),
+    }
+}
+
+// for each enum found in a main function argument `id` of type `ty`, assert that its tag is the index of
+// one of its variants, so that the prover cannot pass an invalid variant
+fn enum_tag_range_checks<'ast, T: Field>(
+    id: zir::SourceIdentifier<'ast>,
+    ty: &typed_absy::types::ConcreteType,
+) -> Vec<zir::ZirStatement<'ast, T>> {
+    match ty {
+        typed_absy::types::ConcreteType::Array(array_type) => (0..array_type.size)
+            .flat_map(|i| {
+                enum_tag_range_checks(
+                    zir::SourceIdentifier::Select(box id.clone(), i),
+                    &array_type.ty,
+                )
+            })
+            .collect(),
+        typed_absy::types::ConcreteType::Struct(members) => members
+            .iter()
+            .flat_map(|struct_member| {
+                enum_tag_range_checks(
+                    zir::SourceIdentifier::Member(box id.clone(), struct_member.id.clone()),
+                    &struct_member.ty,
+                )
+            })
+            .collect(),
+        typed_absy::types::ConcreteType::Tuple(tuple_ty) => tuple_ty
+            .elements
+            .iter()
+            .enumerate()
+            .flat_map(|(i, ty)| {
+                enum_tag_range_checks(zir::SourceIdentifier::Element(box id.clone(), i as u32), ty)
+            })
+            .collect(),
+        typed_absy::types::ConcreteType::Enum(enum_ty) => {
+            let tag = zir::FieldElementExpression::Identifier(zir::Identifier::Source(
+                zir::SourceIdentifier::Tag(box id.clone()),
+            ));
+
+            // `(tag - 0) * (tag - 1) * ... * (tag - (n - 1)) == 0`
+            let product = (1..enum_ty.variants.len()).fold(tag.clone(), |acc, i| {
+                zir::FieldElementExpression::Mult(
+                    box acc,
+                    box zir::FieldElementExpression::Sub(
+                        box tag.clone(),
+                        box zir::FieldElementExpression::Number(T::from(i)),
+                    ),
+                )
+            });
+
+            std::iter::once(zir::ZirStatement::Assertion(
+                zir::BooleanExpression::FieldEq(
+                    box product,
+                    box zir::FieldElementExpression::Number(T::zero()),
+                ),
+                zir::RuntimeError::EnumTagRangeCheck,
+            ))
+            .chain(
+                enum_ty
+                    .variants
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, variant)| {
+                        let variant_id = zir::SourceIdentifier::Variant(box id.clone(), i as u32);
+                        variant
+                            .payload
+                            .iter()
+                            .enumerate()
+                            .flat_map(|(j, ty)| {
+                                enum_tag_range_checks(
+                                    zir::SourceIdentifier::Element(
+                                        box variant_id.clone(),
+                                        j as u32,
+                                    ),
+                                    ty,
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                    }),
+            )
+            .collect()
+        }
+        _ => vec![],
+    }
+}
+
+// the default value of a flattened type, used to fill the payloads of the inactive variants of an enum
+fn zero<'ast, T: Field>(ty: zir::Type) -> zir::ZirExpression<'ast, T> {
+    match ty {
+        zir::Type::FieldElement => zir::FieldElementExpression::Number(T::zero()).into(),
+        zir::Type::Boolean => zir::BooleanExpression::Value(false).into(),
+        zir::Type::Uint(bitwidth) => zir::UExpressionInner::Value(0).annotate(bitwidth).into(),
     }
 }
 
@@ -107,6 +240,26 @@ impl<'ast, T: Field> Flatten<'ast, T> for typed_absy::StructExpression<'ast, T>
     }
 }
 
+impl<'ast, T: Field> Flatten<'ast, T> for typed_absy::TupleExpression<'ast, T> {
+    fn flatten(
+        self,
+        f: &mut Flattener<T>,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        f.fold_tuple_expression(statements_buffer, self)
+    }
+}
+
+impl<'ast, T: Field> Flatten<'ast, T> for typed_absy::EnumExpression<'ast, T> {
+    fn flatten(
+        self,
+        f: &mut Flattener<T>,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        f.fold_enum_expression(statements_buffer, self)
+    }
+}
+
 impl<'ast, T: Field> Flattener<T> {
     pub fn flatten(p: typed_absy::TypedProgram<T>) -> zir::ZirProgram<T> {
         let mut f = Flattener::default();
@@ -135,6 +288,25 @@ impl<'ast, T: Field> Flattener<T> {
             .collect()
     }
 
+    // bind an expression to a fresh internal variable, so that it can be used several times without being
+    // computed again
+    fn define_uint(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        e: zir::UExpression<'ast, T>,
+    ) -> zir::UExpression<'ast, T> {
+        let bitwidth = e.bitwidth;
+        let id = zir::Identifier::Internal("SIGNED", self.next_var_idx);
+        self.next_var_idx += 1;
+
+        statements_buffer.push(zir::ZirStatement::Definition(
+            zir::Variable::uint(id.clone(), bitwidth),
+            e.into(),
+        ));
+
+        zir::UExpressionInner::Identifier(id).annotate(bitwidth)
+    }
+
     fn fold_name(&mut self, n: typed_absy::Identifier<'ast>) -> zir::SourceIdentifier<'ast> {
         zir::SourceIdentifier::Basic(n)
     }
@@ -191,6 +363,24 @@ impl<'ast, T: Field> Flattener<T> {
 
                 let a = self.fold_assignee(a);
 
+                a[offset..offset + size].to_vec()
+            }
+            typed_absy::TypedAssignee::Element(box a, index) => {
+                use typed_absy::Typed;
+
+                let tuple_ty = match typed_absy::ConcreteType::try_from(a.get_type()).unwrap() {
+                    typed_absy::ConcreteType::Tuple(tuple_ty) => tuple_ty,
+                    _ => unreachable!(),
+                };
+
+                let offset: usize = tuple_ty.elements[..index as usize]
+                    .iter()
+                    .map(|ty| ty.get_primitive_count())
+                    .sum();
+                let size = tuple_ty.elements[index as usize].get_primitive_count();
+
+                let a = self.fold_assignee(a);
+
                 a[offset..offset + size].to_vec()
             }
         }
@@ -240,6 +430,10 @@ impl<'ast, T: Field> Flattener<T> {
             typed_absy::TypedExpression::Struct(e) => {
                 self.fold_struct_expression(statements_buffer, e)
             }
+            typed_absy::TypedExpression::Tuple(e) => {
+                self.fold_tuple_expression(statements_buffer, e)
+            }
+            typed_absy::TypedExpression::Enum(e) => self.fold_enum_expression(statements_buffer, e),
             typed_absy::TypedExpression::Int(_) => unreachable!(),
         }
     }
@@ -261,6 +455,22 @@ impl<'ast, T: Field> Flattener<T> {
         fold_struct_expression(self, statements_buffer, e)
     }
 
+    fn fold_tuple_expression(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        e: typed_absy::TupleExpression<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_tuple_expression(self, statements_buffer, e)
+    }
+
+    fn fold_enum_expression(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        e: typed_absy::EnumExpression<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_enum_expression(self, statements_buffer, e)
+    }
+
     fn fold_expression_list(
         &mut self,
         statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
@@ -269,7 +479,7 @@ impl<'ast, T: Field> Flattener<T> {
         match es.into_inner() {
             typed_absy::TypedExpressionListInner::EmbedCall(embed, generics, arguments) => {
                 zir::ZirExpressionList::EmbedCall(
-                    embed,
+                    embed.to_unsigned(),
                     generics,
                     arguments
                         .into_iter()
@@ -297,6 +507,14 @@ impl<'ast, T: Field> Flattener<T> {
         fold_member_expression(self, statements_buffer, m)
     }
 
+    fn fold_element_expression<E>(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        e: typed_absy::ElementExpression<'ast, T, E>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_element_expression(self, statements_buffer, e)
+    }
+
     fn fold_select_expression<E>(
         &mut self,
         statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
@@ -353,6 +571,24 @@ impl<'ast, T: Field> Flattener<T> {
     ) -> Vec<zir::ZirExpression<'ast, T>> {
         fold_struct_expression_inner(self, statements_buffer, ty, e)
     }
+
+    fn fold_tuple_expression_inner(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        ty: &typed_absy::types::ConcreteTupleType,
+        e: typed_absy::TupleExpressionInner<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_tuple_expression_inner(self, statements_buffer, ty, e)
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+        ty: &typed_absy::types::ConcreteEnumType,
+        e: typed_absy::EnumExpressionInner<'ast, T>,
+    ) -> Vec<zir::ZirExpression<'ast, T>> {
+        fold_enum_expression_inner(self, statements_buffer, ty, e)
+    }
 }
 
 fn fold_statement<'ast, T: Field>(
@@ -390,6 +626,33 @@ fn fold_statement<'ast, T: Field>(
             vec![zir::ZirStatement::Assertion(e, error)]
         }
         typed_absy::TypedStatement::For(..) => unreachable!(),
+        typed_absy::TypedStatement::IfElse(condition, consequence, alternative) => {
+            let condition = f.fold_boolean_expression(statements_buffer, condition);
+
+            let span = f.span.clone();
+
+            let mut consequence_statements = vec![];
+            let mut alternative_statements = vec![];
+
+            consequence
+                .into_iter()
+                .for_each(|s| f.fold_statement(&mut consequence_statements, s));
+            alternative
+                .into_iter()
+                .for_each(|s| f.fold_statement(&mut alternative_statements, s));
+
+            // what follows the branches belongs to the if statement again
+            f.span = span.clone();
+
+            std::iter::once(zir::ZirStatement::IfElse(
+                condition,
+                consequence_statements,
+                alternative_statements,
+                zir::IfElseKind::Statement,
+            ))
+            .chain(span.map(zir::ZirStatement::Span))
+            .collect()
+        }
         typed_absy::TypedStatement::MultipleDefinition(variables, elist) => {
             vec![zir::ZirStatement::MultipleDefinition(
                 variables
@@ -399,8 +662,45 @@ fn fold_statement<'ast, T: Field>(
                 f.fold_expression_list(statements_buffer, elist),
             )]
         }
-        typed_absy::TypedStatement::PushCallLog(..) => vec![],
-        typed_absy::TypedStatement::PopCallLog => vec![],
+        typed_absy::TypedStatement::Log(format_string, expressions) => {
+            use typed_absy::Typed;
+
+            vec![zir::ZirStatement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .map(|e| {
+                        let ty = typed_absy::ConcreteType::try_from(e.get_type()).unwrap();
+                        (ty, f.fold_expression(statements_buffer, e))
+                    })
+                    .collect(),
+            )]
+        }
+        typed_absy::TypedStatement::PushCallLog(key, _) => {
+            f.call_stack.push((
+                CallFrame::new(key.module.display().to_string(), key.id),
+                f.span.take(),
+            ));
+            vec![]
+        }
+        typed_absy::TypedStatement::PopCallLog => {
+            // back in the caller, the statements belong to the call site
+            let (_, span) = f.call_stack.pop().unwrap();
+            f.span = span.clone();
+            span.map(zir::ZirStatement::Span).into_iter().collect()
+        }
+        typed_absy::TypedStatement::Span(span) => {
+            let span = SourceSpan {
+                call_stack: f
+                    .call_stack
+                    .iter()
+                    .map(|(frame, _)| frame.clone())
+                    .collect(),
+                ..span
+            };
+            f.span = Some(span.clone());
+            vec![zir::ZirStatement::Span(span)]
+        }
     };
 
     statements_buffer.extend(res);
@@ -457,6 +757,9 @@ fn fold_array_expression_inner<'ast, T: Field>(
         typed_absy::ArrayExpressionInner::Select(select) => {
             f.fold_select_expression(statements_buffer, select)
         }
+        typed_absy::ArrayExpressionInner::Element(element) => {
+            f.fold_element_expression(statements_buffer, element)
+        }
         typed_absy::ArrayExpressionInner::Slice(box array, box from, box to) => {
             let array = f.fold_array_expression(statements_buffer, array);
             let from = f.fold_uint_expression(statements_buffer, from);
@@ -532,6 +835,148 @@ fn fold_struct_expression_inner<'ast, T: Field>(
         typed_absy::StructExpressionInner::Select(select) => {
             f.fold_select_expression(statements_buffer, select)
         }
+        typed_absy::StructExpressionInner::Element(element) => {
+            f.fold_element_expression(statements_buffer, element)
+        }
+    }
+}
+
+fn fold_tuple_expression_inner<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    ty: &typed_absy::types::ConcreteTupleType,
+    tuple: typed_absy::TupleExpressionInner<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    match tuple {
+        typed_absy::TupleExpressionInner::Block(block) => {
+            block
+                .statements
+                .into_iter()
+                .for_each(|s| f.fold_statement(statements_buffer, s));
+            f.fold_tuple_expression(statements_buffer, *block.value)
+        }
+        typed_absy::TupleExpressionInner::Identifier(id) => {
+            let variables = flatten_identifier_rec(
+                f.fold_name(id),
+                &typed_absy::types::ConcreteType::tuple(ty.clone()),
+            );
+            variables
+                .into_iter()
+                .map(|v| match v._type {
+                    zir::Type::FieldElement => zir::FieldElementExpression::Identifier(v.id).into(),
+                    zir::Type::Boolean => zir::BooleanExpression::Identifier(v.id).into(),
+                    zir::Type::Uint(bitwidth) => zir::UExpressionInner::Identifier(v.id)
+                        .annotate(bitwidth)
+                        .into(),
+                })
+                .collect()
+        }
+        typed_absy::TupleExpressionInner::Value(exprs) => exprs
+            .into_iter()
+            .flat_map(|e| f.fold_expression(statements_buffer, e))
+            .collect(),
+        typed_absy::TupleExpressionInner::FunctionCall(..) => unreachable!(),
+        typed_absy::TupleExpressionInner::Conditional(c) => {
+            f.fold_conditional_expression(statements_buffer, c)
+        }
+        typed_absy::TupleExpressionInner::Member(m) => {
+            f.fold_member_expression(statements_buffer, m)
+        }
+        typed_absy::TupleExpressionInner::Select(select) => {
+            f.fold_select_expression(statements_buffer, select)
+        }
+        typed_absy::TupleExpressionInner::Element(element) => {
+            f.fold_element_expression(statements_buffer, element)
+        }
+        typed_absy::TupleExpressionInner::Payload(box e, variant) => {
+            let enum_ty = typed_absy::types::ConcreteEnumType::try_from(e.ty().clone()).unwrap();
+
+            // the payloads come after the tag, in the order of the variants
+            let offset: usize = 1 + enum_ty.variants[..variant as usize]
+                .iter()
+                .flat_map(|v| v.payload.iter())
+                .map(|ty| ty.get_primitive_count())
+                .sum::<usize>();
+            let size: usize = ty.elements.iter().map(|ty| ty.get_primitive_count()).sum();
+
+            let e = f.fold_enum_expression(statements_buffer, e);
+
+            e[offset..offset + size].to_vec()
+        }
+    }
+}
+
+fn fold_enum_expression_inner<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    ty: &typed_absy::types::ConcreteEnumType,
+    e: typed_absy::EnumExpressionInner<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    match e {
+        typed_absy::EnumExpressionInner::Block(block) => {
+            block
+                .statements
+                .into_iter()
+                .for_each(|s| f.fold_statement(statements_buffer, s));
+            f.fold_enum_expression(statements_buffer, *block.value)
+        }
+        typed_absy::EnumExpressionInner::Identifier(id) => {
+            let variables = flatten_identifier_rec(
+                f.fold_name(id),
+                &typed_absy::types::ConcreteType::enumeration(ty.clone()),
+            );
+            variables
+                .into_iter()
+                .map(|v| match v._type {
+                    zir::Type::FieldElement => zir::FieldElementExpression::Identifier(v.id).into(),
+                    zir::Type::Boolean => zir::BooleanExpression::Identifier(v.id).into(),
+                    zir::Type::Uint(bitwidth) => zir::UExpressionInner::Identifier(v.id)
+                        .annotate(bitwidth)
+                        .into(),
+                })
+                .collect()
+        }
+        typed_absy::EnumExpressionInner::Value(tag, exprs) => {
+            let mut exprs = Some(
+                exprs
+                    .into_iter()
+                    .flat_map(|e| f.fold_expression(statements_buffer, e))
+                    .collect::<Vec<_>>(),
+            );
+
+            // the payload of the active variant is set, the others are zeroed
+            std::iter::once(zir::FieldElementExpression::Number(T::from(tag)).into())
+                .chain(
+                    ty.variants
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(i, variant)| match i == tag as usize {
+                            true => exprs.take().unwrap(),
+                            false => variant
+                                .payload
+                                .iter()
+                                .cloned()
+                                .flat_map(zir::from_type)
+                                .map(zero)
+                                .collect(),
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .collect()
+        }
+        typed_absy::EnumExpressionInner::FunctionCall(..) => unreachable!(),
+        typed_absy::EnumExpressionInner::Conditional(c) => {
+            f.fold_conditional_expression(statements_buffer, c)
+        }
+        typed_absy::EnumExpressionInner::Member(m) => {
+            f.fold_member_expression(statements_buffer, m)
+        }
+        typed_absy::EnumExpressionInner::Select(select) => {
+            f.fold_select_expression(statements_buffer, select)
+        }
+        typed_absy::EnumExpressionInner::Element(element) => {
+            f.fold_element_expression(statements_buffer, element)
+        }
     }
 }
 
@@ -571,6 +1016,28 @@ fn fold_member_expression<'ast, T: Field, E>(
     s[offset..offset + size].to_vec()
 }
 
+fn fold_element_expression<'ast, T: Field, E>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    e: typed_absy::ElementExpression<'ast, T, E>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    let t = *e.tuple;
+    let index = e.index as usize;
+
+    let tuple_ty = typed_absy::types::ConcreteTupleType::try_from(t.ty().clone()).unwrap();
+
+    let size = tuple_ty.elements[index].get_primitive_count();
+
+    let offset: usize = tuple_ty.elements[..index]
+        .iter()
+        .map(|ty| ty.get_primitive_count())
+        .sum();
+
+    let t = f.fold_tuple_expression(statements_buffer, t);
+
+    t[offset..offset + size].to_vec()
+}
+
 fn fold_select_expression<'ast, T: Field, E>(
     f: &mut Flattener<T>,
     statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
@@ -665,6 +1132,7 @@ fn fold_conditional_expression<'ast, T: Field, E: Flatten<'ast, T>>(
             condition.clone(),
             consequence_statements,
             alternative_statements,
+            zir::IfElseKind::Expression,
         ));
     }
 
@@ -761,6 +1229,12 @@ fn fold_field_expression<'ast, T: Field>(
             .unwrap()
             .try_into()
             .unwrap(),
+        typed_absy::FieldElementExpression::Element(element) => f
+            .fold_element_expression(statements_buffer, element)
+            .pop()
+            .unwrap()
+            .try_into()
+            .unwrap(),
         typed_absy::FieldElementExpression::Block(block) => {
             block
                 .statements
@@ -848,6 +1322,70 @@ fn fold_boolean_expression<'ast, T: Field>(
 
             conjunction_tree(&e1, &e2)
         }
+        typed_absy::BooleanExpression::TupleEq(box e1, box e2) => {
+            let e1 = f.fold_tuple_expression(statements_buffer, e1);
+            let e2 = f.fold_tuple_expression(statements_buffer, e2);
+
+            assert_eq!(e1.len(), e2.len());
+
+            conjunction_tree(&e1, &e2)
+        }
+        typed_absy::BooleanExpression::EnumEq(box e1, box e2) => {
+            let enum_ty = typed_absy::types::ConcreteEnumType::try_from(e1.ty().clone()).unwrap();
+
+            let e1 = f.fold_enum_expression(statements_buffer, e1);
+            let e2 = f.fold_enum_expression(statements_buffer, e2);
+
+            assert_eq!(e1.len(), e2.len());
+
+            let tag = match e1[0].clone() {
+                zir::ZirExpression::FieldElement(tag) => tag,
+                _ => unreachable!(),
+            };
+
+            // the tags must be equal, and so must the payloads of the active variant
+            let mut offset = 1;
+
+            enum_ty.variants.iter().enumerate().fold(
+                conjunction_tree(&e1[..1], &e2[..1]),
+                |acc, (i, variant)| {
+                    let size: usize = variant
+                        .payload
+                        .iter()
+                        .map(|ty| ty.get_primitive_count())
+                        .sum();
+                    let range = offset..offset + size;
+                    offset += size;
+
+                    match size {
+                        0 => acc,
+                        _ => zir::BooleanExpression::And(
+                            box acc,
+                            box zir::BooleanExpression::Or(
+                                box zir::BooleanExpression::Not(
+                                    box zir::BooleanExpression::FieldEq(
+                                        box tag.clone(),
+                                        box zir::FieldElementExpression::Number(T::from(i)),
+                                    ),
+                                ),
+                                box conjunction_tree(&e1[range.clone()], &e2[range]),
+                            ),
+                        ),
+                    }
+                },
+            )
+        }
+        typed_absy::BooleanExpression::IsVariant(box e, variant) => {
+            let e = f.fold_enum_expression(statements_buffer, e);
+
+            match e[0].clone() {
+                zir::ZirExpression::FieldElement(tag) => zir::BooleanExpression::FieldEq(
+                    box tag,
+                    box zir::FieldElementExpression::Number(T::from(variant)),
+                ),
+                _ => unreachable!(),
+            }
+        }
         typed_absy::BooleanExpression::UintEq(box e1, box e2) => {
             let e1 = f.fold_uint_expression(statements_buffer, e1);
             let e2 = f.fold_uint_expression(statements_buffer, e2);
@@ -875,23 +1413,27 @@ fn fold_boolean_expression<'ast, T: Field>(
             zir::BooleanExpression::FieldGe(box e1, box e2)
         }
         typed_absy::BooleanExpression::UintLt(box e1, box e2) => {
-            let e1 = f.fold_uint_expression(statements_buffer, e1);
-            let e2 = f.fold_uint_expression(statements_buffer, e2);
+            let bitwidth = e1.bitwidth;
+            let e1 = order_preserving(bitwidth, f.fold_uint_expression(statements_buffer, e1));
+            let e2 = order_preserving(bitwidth, f.fold_uint_expression(statements_buffer, e2));
             zir::BooleanExpression::UintLt(box e1, box e2)
         }
         typed_absy::BooleanExpression::UintLe(box e1, box e2) => {
-            let e1 = f.fold_uint_expression(statements_buffer, e1);
-            let e2 = f.fold_uint_expression(statements_buffer, e2);
+            let bitwidth = e1.bitwidth;
+            let e1 = order_preserving(bitwidth, f.fold_uint_expression(statements_buffer, e1));
+            let e2 = order_preserving(bitwidth, f.fold_uint_expression(statements_buffer, e2));
             zir::BooleanExpression::UintLe(box e1, box e2)
         }
         typed_absy::BooleanExpression::UintGt(box e1, box e2) => {
-            let e1 = f.fold_uint_expression(statements_buffer, e1);
-            let e2 = f.fold_uint_expression(statements_buffer, e2);
+            let bitwidth = e1.bitwidth;
+            let e1 = order_preserving(bitwidth, f.fold_uint_expression(statements_buffer, e1));
+            let e2 = order_preserving(bitwidth, f.fold_uint_expression(statements_buffer, e2));
             zir::BooleanExpression::UintGt(box e1, box e2)
         }
         typed_absy::BooleanExpression::UintGe(box e1, box e2) => {
-            let e1 = f.fold_uint_expression(statements_buffer, e1);
-            let e2 = f.fold_uint_expression(statements_buffer, e2);
+            let bitwidth = e1.bitwidth;
+            let e1 = order_preserving(bitwidth, f.fold_uint_expression(statements_buffer, e1));
+            let e2 = order_preserving(bitwidth, f.fold_uint_expression(statements_buffer, e2));
             zir::BooleanExpression::UintGe(box e1, box e2)
         }
         typed_absy::BooleanExpression::Or(box e1, box e2) => {
@@ -927,6 +1469,12 @@ fn fold_boolean_expression<'ast, T: Field>(
             .unwrap()
             .try_into()
             .unwrap(),
+        typed_absy::BooleanExpression::Element(element) => f
+            .fold_element_expression(statements_buffer, element)
+            .pop()
+            .unwrap()
+            .try_into()
+            .unwrap(),
     }
 }
 
@@ -983,12 +1531,35 @@ fn fold_uint_expression_inner<'ast, T: Field>(
 
             zir::UExpressionInner::Mult(box left, box right)
         }
+        typed_absy::UExpressionInner::Div(box left, box right) if bitwidth.is_signed() => {
+            let left = f.fold_uint_expression(statements_buffer, left);
+            let right = f.fold_uint_expression(statements_buffer, right);
+
+            // divide the absolute values, and negate the quotient if the signs differ
+            let (left, left_sign) = signed_abs(f, statements_buffer, left);
+            let (right, right_sign) = signed_abs(f, statements_buffer, right);
+            let quotient = f.define_uint(statements_buffer, left.div(right));
+            let sign = f.define_uint(statements_buffer, left_sign.xor(right_sign));
+
+            quotient.xor(sign.clone()).sub(sign).into_inner()
+        }
         typed_absy::UExpressionInner::Div(box left, box right) => {
             let left = f.fold_uint_expression(statements_buffer, left);
             let right = f.fold_uint_expression(statements_buffer, right);
 
             zir::UExpressionInner::Div(box left, box right)
         }
+        typed_absy::UExpressionInner::Rem(box left, box right) if bitwidth.is_signed() => {
+            let left = f.fold_uint_expression(statements_buffer, left);
+            let right = f.fold_uint_expression(statements_buffer, right);
+
+            // the remainder has the sign of the dividend
+            let (left, left_sign) = signed_abs(f, statements_buffer, left);
+            let (right, _) = signed_abs(f, statements_buffer, right);
+            let remainder = f.define_uint(statements_buffer, left.rem(right));
+
+            remainder.xor(left_sign.clone()).sub(left_sign).into_inner()
+        }
         typed_absy::UExpressionInner::Rem(box left, box right) => {
             let left = f.fold_uint_expression(statements_buffer, left);
             let right = f.fold_uint_expression(statements_buffer, right);
@@ -1031,7 +1602,23 @@ fn fold_uint_expression_inner<'ast, T: Field>(
                 _ => unreachable!("static analysis should have made sure that this is constant"),
             };
 
-            zir::UExpressionInner::RightShift(box e, *by as u32)
+            match bitwidth.is_signed() {
+                // shift arithmetically: `(e ^ m) >> by` is the shifted value, offset by `m >> by`
+                true => {
+                    let by = std::cmp::min(*by as u32, bitwidth.to_usize() as u32 - 1);
+                    let sign_bit = zir::UExpressionInner::Value(bitwidth.sign_bit())
+                        .annotate(bitwidth.to_usize());
+
+                    e.xor(sign_bit)
+                        .right_shift(by)
+                        .sub(
+                            zir::UExpressionInner::Value(bitwidth.sign_bit() >> by)
+                                .annotate(bitwidth.to_usize()),
+                        )
+                        .into_inner()
+                }
+                false => zir::UExpressionInner::RightShift(box e, *by as u32),
+            }
         }
         typed_absy::UExpressionInner::Not(box e) => {
             let e = f.fold_uint_expression(statements_buffer, e);
@@ -1070,6 +1657,13 @@ fn fold_uint_expression_inner<'ast, T: Field>(
         )
         .unwrap()
         .into_inner(),
+        typed_absy::UExpressionInner::Element(element) => zir::UExpression::try_from(
+            f.fold_element_expression(statements_buffer, element)
+                .pop()
+                .unwrap(),
+        )
+        .unwrap()
+        .into_inner(),
         typed_absy::UExpressionInner::Conditional(c) => zir::UExpression::try_from(
             f.fold_conditional_expression(statements_buffer, c)
                 .pop()
@@ -1080,11 +1674,55 @@ fn fold_uint_expression_inner<'ast, T: Field>(
     }
 }
 
+// signed integers are compared by flipping their sign bit and comparing the results as unsigned integers
+fn order_preserving<'ast, T: Field>(
+    bitwidth: UBitwidth,
+    e: zir::UExpression<'ast, T>,
+) -> zir::UExpression<'ast, T> {
+    match bitwidth.is_signed() {
+        true => {
+            e.xor(zir::UExpressionInner::Value(bitwidth.sign_bit()).annotate(bitwidth.to_usize()))
+        }
+        false => e,
+    }
+}
+
+// return the absolute value of a signed integer, along with a mask which is all ones if it is negative
+// and zero otherwise
+fn signed_abs<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    e: zir::UExpression<'ast, T>,
+) -> (zir::UExpression<'ast, T>, zir::UExpression<'ast, T>) {
+    let bitwidth = e.bitwidth;
+    let e = f.define_uint(statements_buffer, e);
+
+    // `(e ^ m) >> (n - 1)` is `0` if `e` is negative and `1` otherwise
+    let sign = e
+        .clone()
+        .xor(zir::UExpressionInner::Value(1 << (bitwidth.to_usize() - 1)).annotate(bitwidth))
+        .right_shift(bitwidth.to_usize() as u32 - 1)
+        .sub(zir::UExpressionInner::Value(1).annotate(bitwidth));
+    let sign = f.define_uint(statements_buffer, sign);
+
+    (e.xor(sign.clone()).sub(sign.clone()), sign)
+}
+
 fn fold_function<'ast, T: Field>(
     f: &mut Flattener<T>,
     fun: typed_absy::TypedFunction<'ast, T>,
 ) -> zir::ZirFunction<'ast, T> {
-    let mut main_statements_buffer = vec![];
+    // the enums passed as arguments must have a valid tag
+    let mut main_statements_buffer: Vec<_> = fun
+        .arguments
+        .iter()
+        .flat_map(|a| {
+            let v: typed_absy::Variable<'ast, T> =
+                crate::typed_absy::variable::try_from_g_variable(a.id.clone()).unwrap();
+            let ty = typed_absy::types::ConcreteType::try_from(v.get_type()).unwrap();
+            enum_tag_range_checks(f.fold_name(v.id), &ty)
+        })
+        .collect();
 
     fun.statements
         .into_iter()
@@ -1135,6 +1773,30 @@ fn fold_struct_expression<'ast, T: Field>(
     )
 }
 
+fn fold_tuple_expression<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    e: typed_absy::TupleExpression<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    f.fold_tuple_expression_inner(
+        statements_buffer,
+        &typed_absy::types::ConcreteTupleType::try_from(e.ty().clone()).unwrap(),
+        e.into_inner(),
+    )
+}
+
+fn fold_enum_expression<'ast, T: Field>(
+    f: &mut Flattener<T>,
+    statements_buffer: &mut Vec<zir::ZirStatement<'ast, T>>,
+    e: typed_absy::EnumExpression<'ast, T>,
+) -> Vec<zir::ZirExpression<'ast, T>> {
+    f.fold_enum_expression_inner(
+        statements_buffer,
+        &typed_absy::types::ConcreteEnumType::try_from(e.ty().clone()).unwrap(),
+        e.into_inner(),
+    )
+}
+
 fn fold_program<'ast, T: Field>(
     f: &mut Flattener<T>,
     mut p: typed_absy::TypedProgram<'ast, T>,
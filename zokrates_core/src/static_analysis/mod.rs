@@ -30,6 +30,7 @@ use self::unconstrained_vars::UnconstrainedVariableDetector;
 use self::variable_write_remover::VariableWriteRemover;
 use crate::compile::CompileConfig;
 use crate::ir::Prog;
+use crate::passes::{PassManager, PassReport};
 use crate::static_analysis::constant_resolver::ConstantResolver;
 use crate::static_analysis::zir_propagation::ZirPropagator;
 use crate::typed_absy::{abi::Abi, TypedProgram};
@@ -104,74 +105,73 @@ impl fmt::Display for Error {
 }
 
 impl<'ast, T: Field> TypedProgram<'ast, T> {
-    pub fn analyse(self, config: &CompileConfig) -> Result<(ZirProgram<'ast, T>, Abi), Error> {
-        // inline user-defined constants
-        log::debug!("Static analyser: Inline constants");
+    /// Inlines constants and reduces the program to a single function, leaving the rest of the
+    /// analysis out. Each inlined call is surrounded by call logs holding its concrete generics.
+    pub fn reduce(self) -> Result<Self, Error> {
         let r = ConstantResolver::inline(self);
-        log::trace!("\n{}", r);
-
-        // isolate branches
-        let r = if config.isolate_branches {
-            log::debug!("Static analyser: Isolate branches");
-            let r = Isolator::isolate(r);
-            log::trace!("\n{}", r);
-            r
-        } else {
-            log::debug!("Static analyser: Branch isolation skipped");
-            r
-        };
-
-        // reduce the program to a single function
-        log::debug!("Static analyser: Reduce program");
-        let r = reduce_program(r).map_err(Error::from)?;
-        log::trace!("\n{}", r);
-
-        log::debug!("Static analyser: Propagate");
-        let r = Propagator::propagate(r)?;
-        log::trace!("\n{}", r);
-
-        log::debug!("Static analyser: Concretize structs");
-        let r = StructConcretizer::concretize(r);
-        log::trace!("\n{}", r);
+        reduce_program(r).map_err(Error::from)
+    }
+
+    pub fn analyse(
+        self,
+        config: &CompileConfig,
+        reports: &mut Vec<PassReport>,
+    ) -> Result<(ZirProgram<'ast, T>, Abi), Error> {
+        let r = PassManager::new(config)
+            // inline user-defined constants
+            .pass("constant-resolution", |p: TypedProgram<'ast, T>| {
+                Ok(ConstantResolver::inline(p))
+            })
+            // isolate branches
+            .pass_if("branch-isolation", config.isolate_branches, |p| {
+                Ok(Isolator::isolate(p))
+            })
+            // reduce the program to a single function
+            .pass("reduction", |p| reduce_program(p).map_err(Error::from))
+            .pass("propagation", |p| {
+                Propagator::propagate(p).map_err(Error::from)
+            })
+            .pass("struct-concretization", |p| {
+                Ok(StructConcretizer::concretize(p))
+            })
+            .run(self, reports)?;
 
         // generate abi
         log::debug!("Static analyser: Generate abi");
         let abi = r.abi();
 
-        // propagate
-        log::debug!("Static analyser: Propagate");
-        let r = Propagator::propagate(r).map_err(Error::from)?;
-        log::trace!("\n{}", r);
-
-        // remove assignment to variable index
-        log::debug!("Static analyser: Remove variable index");
-        let r = VariableWriteRemover::apply(r);
-        log::trace!("\n{}", r);
-
-        // detect non constant shifts and constant lt bounds
-        log::debug!("Static analyser: Detect non constant arguments");
-        let r = ConstantArgumentChecker::check(r).map_err(Error::from)?;
-        log::trace!("\n{}", r);
-
-        // detect out of bounds reads and writes
-        log::debug!("Static analyser: Detect out of bound accesses");
-        let r = OutOfBoundsChecker::check(r).map_err(Error::from)?;
-        log::trace!("\n{}", r);
+        let r = PassManager::new(config)
+            // propagate
+            .pass("propagation", |p: TypedProgram<'ast, T>| {
+                Propagator::propagate(p).map_err(Error::from)
+            })
+            // remove assignment to variable index
+            .pass("variable-write-removal", |p| {
+                Ok(VariableWriteRemover::apply(p))
+            })
+            // detect non constant shifts and constant lt bounds
+            .pass("constant-argument-check", |p| {
+                ConstantArgumentChecker::check(p).map_err(Error::from)
+            })
+            // detect out of bounds reads and writes
+            .pass("out-of-bounds-check", |p| {
+                OutOfBoundsChecker::check(p).map_err(Error::from)
+            })
+            .run(r, reports)?;
 
         // convert to zir, removing complex types
         log::debug!("Static analyser: Convert to zir");
         let zir = Flattener::flatten(r);
         log::trace!("\n{}", zir);
 
-        // apply propagation in zir
-        log::debug!("Static analyser: Apply propagation in zir");
-        let zir = ZirPropagator::propagate(zir).map_err(Error::from)?;
-        log::trace!("\n{}", zir);
-
-        // optimize uint expressions
-        log::debug!("Static analyser: Optimize uints");
-        let zir = UintOptimizer::optimize(zir);
-        log::trace!("\n{}", zir);
+        let zir = PassManager::new(config)
+            // apply propagation in zir
+            .pass("zir-propagation", |p: ZirProgram<'ast, T>| {
+                ZirPropagator::propagate(p).map_err(Error::from)
+            })
+            // optimize uint expressions
+            .pass("uint-optimization", |p| Ok(UintOptimizer::optimize(p)))
+            .run(zir, reports)?;
 
         Ok((zir, abi))
     }
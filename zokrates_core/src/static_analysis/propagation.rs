@@ -126,6 +126,20 @@ impl<'ast, 'a, T: Field> Propagator<'ast, 'a, T> {
                 }
                 e => e,
             },
+            TypedAssignee::Element(box assignee, index) => {
+                match self.try_get_constant_mut(assignee) {
+                    Ok((v, c)) => match c {
+                        TypedExpression::Tuple(a) => match a.as_inner_mut() {
+                            TupleExpressionInner::Value(value) => {
+                                Ok((v, &mut value[*index as usize]))
+                            }
+                            _ => unreachable!("should be a tuple value"),
+                        },
+                        _ => unreachable!("should be a tuple expression"),
+                    },
+                    e => e,
+                }
+            }
         }
     }
 }
@@ -271,6 +285,49 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
 
                 Ok(vec![TypedStatement::For(v, from, to, statements)])
             }
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                let condition = self.fold_boolean_expression(condition)?;
+
+                // if the condition is known, only the branch which is taken is kept
+                let (consequence, alternative) = match condition {
+                    BooleanExpression::Value(true) => (consequence, vec![]),
+                    BooleanExpression::Value(false) => (vec![], alternative),
+                    _ => (consequence, alternative),
+                };
+
+                let consequence: Vec<_> = consequence
+                    .into_iter()
+                    .map(|s| self.fold_statement(s))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                let alternative: Vec<_> = alternative
+                    .into_iter()
+                    .map(|s| self.fold_statement(s))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect();
+
+                match condition {
+                    BooleanExpression::Value(true) => Ok(consequence),
+                    BooleanExpression::Value(false) => Ok(alternative),
+                    // branches which only hold spans have no effect
+                    _ if consequence
+                        .iter()
+                        .chain(alternative.iter())
+                        .all(|s| matches!(s, TypedStatement::Span(..))) =>
+                    {
+                        Ok(vec![])
+                    }
+                    condition => Ok(vec![TypedStatement::IfElse(
+                        condition,
+                        consequence,
+                        alternative,
+                    )]),
+                }
+            }
             TypedStatement::MultipleDefinition(assignees, expression_list) => {
                 let assignees: Vec<TypedAssignee<'ast, T>> = assignees
                     .into_iter()
@@ -357,7 +414,7 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                                     let mut num = v;
                                     let mut res = vec![];
 
-                                    for i in (0..bitwidth as u32).rev() {
+                                    for i in (0..bitwidth.to_usize() as u32).rev() {
                                         if 2u128.pow(i) <= num {
                                             num -= 2u128.pow(i);
                                             res.push(true);
@@ -384,6 +441,11 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                             true => {
                                 let r: Option<TypedExpression<'ast, T>> = match embed {
                                     FlatEmbed::BitArrayLe => Ok(None), // todo
+                                    FlatEmbed::U128FromBits => Ok(Some(process_u_from_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        UBitwidth::B128,
+                                    ))),
                                     FlatEmbed::U64FromBits => Ok(Some(process_u_from_bits(
                                         assignees.clone(),
                                         arguments.clone(),
@@ -404,6 +466,11 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                                         arguments.clone(),
                                         UBitwidth::B8,
                                     ))),
+                                    FlatEmbed::U128ToBits => Ok(Some(process_u_to_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        UBitwidth::B128,
+                                    ))),
                                     FlatEmbed::U64ToBits => Ok(Some(process_u_to_bits(
                                         assignees.clone(),
                                         arguments.clone(),
@@ -424,6 +491,46 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                                         arguments.clone(),
                                         UBitwidth::B8,
                                     ))),
+                                    FlatEmbed::I64FromBits => Ok(Some(process_u_from_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        UBitwidth::I64,
+                                    ))),
+                                    FlatEmbed::I32FromBits => Ok(Some(process_u_from_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        UBitwidth::I32,
+                                    ))),
+                                    FlatEmbed::I16FromBits => Ok(Some(process_u_from_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        UBitwidth::I16,
+                                    ))),
+                                    FlatEmbed::I8FromBits => Ok(Some(process_u_from_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        UBitwidth::I8,
+                                    ))),
+                                    FlatEmbed::I64ToBits => Ok(Some(process_u_to_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        UBitwidth::I64,
+                                    ))),
+                                    FlatEmbed::I32ToBits => Ok(Some(process_u_to_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        UBitwidth::I32,
+                                    ))),
+                                    FlatEmbed::I16ToBits => Ok(Some(process_u_to_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        UBitwidth::I16,
+                                    ))),
+                                    FlatEmbed::I8ToBits => Ok(Some(process_u_to_bits(
+                                        assignees.clone(),
+                                        arguments.clone(),
+                                        UBitwidth::I8,
+                                    ))),
                                     FlatEmbed::Unpack => {
                                         assert_eq!(assignees.len(), 1);
                                         assert_eq!(arguments.len(), 1);
@@ -653,11 +760,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e1)?.into_inner(),
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
-                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        (v1 + v2) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
-                }
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => Ok(
+                    UExpressionInner::Value(v1.wrapping_add(v2) & bitwidth.mask()),
+                ),
                 (e, UExpressionInner::Value(v)) | (UExpressionInner::Value(v), e) => match v {
                     0 => Ok(e),
                     _ => Ok(UExpressionInner::Add(
@@ -674,11 +779,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e1)?.into_inner(),
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
-                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        (v1.wrapping_sub(v2)) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
-                }
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => Ok(
+                    UExpressionInner::Value(v1.wrapping_sub(v2) & bitwidth.mask()),
+                ),
                 (e, UExpressionInner::Value(v)) => match v {
                     0 => Ok(e),
                     _ => Ok(UExpressionInner::Sub(
@@ -695,11 +798,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e1)?.into_inner(),
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
-                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        v1.saturating_sub(v2) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
-                }
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => Ok(
+                    UExpressionInner::Value(v1.saturating_sub(v2) & bitwidth.mask()),
+                ),
                 (e, UExpressionInner::Value(v)) => match v {
                     0 => Ok(e),
                     _ => Ok(UExpressionInner::FloorSub(
@@ -716,11 +817,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e1)?.into_inner(),
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
-                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        (v1 * v2) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
-                }
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => Ok(
+                    UExpressionInner::Value(v1.wrapping_mul(v2) & bitwidth.mask()),
+                ),
                 (e, UExpressionInner::Value(v)) | (UExpressionInner::Value(v), e) => match v {
                     0 => Ok(UExpressionInner::Value(0)),
                     1 => Ok(e),
@@ -738,10 +837,15 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e1)?.into_inner(),
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2))
+                    if bitwidth.is_signed() =>
+                {
+                    Ok(UExpressionInner::Value(bitwidth.from_signed(
+                        bitwidth.to_signed(v1) / bitwidth.to_signed(v2),
+                    )))
+                }
                 (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        (v1 / v2) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
+                    Ok(UExpressionInner::Value((v1 / v2) & bitwidth.mask()))
                 }
                 (e, UExpressionInner::Value(v)) => match v {
                     1 => Ok(e),
@@ -759,10 +863,15 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 self.fold_uint_expression(e1)?.into_inner(),
                 self.fold_uint_expression(e2)?.into_inner(),
             ) {
+                (UExpressionInner::Value(v1), UExpressionInner::Value(v2))
+                    if bitwidth.is_signed() =>
+                {
+                    Ok(UExpressionInner::Value(bitwidth.from_signed(
+                        bitwidth.to_signed(v1) % bitwidth.to_signed(v2),
+                    )))
+                }
                 (UExpressionInner::Value(v1), UExpressionInner::Value(v2)) => {
-                    Ok(UExpressionInner::Value(
-                        (v1 % v2) % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
-                    ))
+                    Ok(UExpressionInner::Value((v1 % v2) & bitwidth.mask()))
                 }
                 (e, UExpressionInner::Value(v)) => match v {
                     1 => Ok(UExpressionInner::Value(0)),
@@ -780,9 +889,18 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 let e = self.fold_uint_expression(e)?;
                 let by = self.fold_uint_expression(by)?;
                 match (e.into_inner(), by.into_inner()) {
-                    (UExpressionInner::Value(v), UExpressionInner::Value(by)) => {
-                        Ok(UExpressionInner::Value(v >> by))
+                    (UExpressionInner::Value(v), UExpressionInner::Value(by))
+                        if bitwidth.is_signed() =>
+                    {
+                        // shifting by more than the bitwidth only leaves the sign
+                        let by = std::cmp::min(by, bitwidth.to_usize() as u128 - 1);
+                        Ok(UExpressionInner::Value(
+                            bitwidth.from_signed(bitwidth.to_signed(v) >> by),
+                        ))
                     }
+                    (UExpressionInner::Value(v), UExpressionInner::Value(by)) => Ok(
+                        UExpressionInner::Value(v.checked_shr(by as u32).unwrap_or(0)),
+                    ),
                     (e, by) => Ok(UExpressionInner::RightShift(
                         box e.annotate(bitwidth),
                         box by.annotate(UBitwidth::B32),
@@ -793,9 +911,11 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 let e = self.fold_uint_expression(e)?;
                 let by = self.fold_uint_expression(by)?;
                 match (e.into_inner(), by.into_inner()) {
-                    (UExpressionInner::Value(v), UExpressionInner::Value(by)) => Ok(
-                        UExpressionInner::Value((v << by) & (2_u128.pow(bitwidth as u32) - 1)),
-                    ),
+                    (UExpressionInner::Value(v), UExpressionInner::Value(by)) => {
+                        Ok(UExpressionInner::Value(
+                            v.checked_shl(by as u32).unwrap_or(0) & bitwidth.mask(),
+                        ))
+                    }
                     (e, by) => Ok(UExpressionInner::LeftShift(
                         box e.annotate(bitwidth),
                         box by.annotate(UBitwidth::B32),
@@ -840,9 +960,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
             UExpressionInner::Not(box e) => {
                 let e = self.fold_uint_expression(e)?.into_inner();
                 match e {
-                    UExpressionInner::Value(v) => Ok(UExpressionInner::Value(
-                        (!v) & (2_u128.pow(bitwidth as u32) - 1),
-                    )),
+                    UExpressionInner::Value(v) => {
+                        Ok(UExpressionInner::Value((!v) & bitwidth.mask()))
+                    }
                     e => Ok(UExpressionInner::Not(box e.annotate(bitwidth))),
                 }
             }
@@ -850,8 +970,7 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
                 let e = self.fold_uint_expression(e)?.into_inner();
                 match e {
                     UExpressionInner::Value(v) => Ok(UExpressionInner::Value(
-                        (0u128.wrapping_sub(v))
-                            % 2_u128.pow(bitwidth.to_usize().try_into().unwrap()),
+                        0u128.wrapping_sub(v) & bitwidth.mask(),
                     )),
                     e => Ok(UExpressionInner::Neg(box e.annotate(bitwidth))),
                 }
@@ -982,6 +1101,30 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
         }
     }
 
+    fn fold_element_expression<
+        E: Expr<'ast, T> + Element<'ast, T> + From<TypedExpression<'ast, T>>,
+    >(
+        &mut self,
+        _: &E::Ty,
+        e: ElementExpression<'ast, T, E>,
+    ) -> Result<ElementOrExpression<'ast, T, E>, Self::Error> {
+        let index = e.index;
+
+        let tuple = self.fold_tuple_expression(*e.tuple)?;
+
+        let ty = tuple.ty().clone();
+
+        match tuple.into_inner() {
+            TupleExpressionInner::Value(v) => Ok(ElementOrExpression::Expression(
+                E::from(v.into_iter().nth(index as usize).unwrap()).into_inner(),
+            )),
+            inner => Ok(ElementOrExpression::Element(ElementExpression::new(
+                inner.annotate(ty),
+                index,
+            ))),
+        }
+    }
+
     fn fold_select_expression<
         E: Expr<'ast, T>
             + Select<'ast, T>
@@ -1133,6 +1276,54 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
         }
     }
 
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType<'ast, T>,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> Result<TupleExpressionInner<'ast, T>, Error> {
+        match e {
+            TupleExpressionInner::Identifier(id) => match self.constants.get(&id) {
+                Some(e) => match e {
+                    TypedExpression::Tuple(e) => Ok(e.as_inner().clone()),
+                    _ => panic!("constant stored for a tuple should be a tuple"),
+                },
+                None => Ok(TupleExpressionInner::Identifier(id)),
+            },
+            TupleExpressionInner::Payload(box e, variant) => {
+                let e = self.fold_enum_expression(e)?;
+                let enum_ty = e.ty().clone();
+
+                match e.into_inner() {
+                    EnumExpressionInner::Value(tag, values) if tag == variant => {
+                        Ok(TupleExpressionInner::Value(values))
+                    }
+                    inner => Ok(TupleExpressionInner::Payload(
+                        box inner.annotate(enum_ty),
+                        variant,
+                    )),
+                }
+            }
+            e => fold_tuple_expression_inner(self, ty, e),
+        }
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        ty: &EnumType<'ast, T>,
+        e: EnumExpressionInner<'ast, T>,
+    ) -> Result<EnumExpressionInner<'ast, T>, Error> {
+        match e {
+            EnumExpressionInner::Identifier(id) => match self.constants.get(&id) {
+                Some(e) => match e {
+                    TypedExpression::Enum(e) => Ok(e.as_inner().clone()),
+                    _ => panic!("constant stored for an enum should be an enum"),
+                },
+                None => Ok(EnumExpressionInner::Identifier(id)),
+            },
+            e => fold_enum_expression_inner(self, ty, e),
+        }
+    }
+
     fn fold_boolean_expression(
         &mut self,
         e: BooleanExpression<'ast, T>,
@@ -1217,6 +1408,52 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
 
                 Ok(BooleanExpression::StructEq(box e1, box e2))
             }
+            BooleanExpression::TupleEq(box e1, box e2) => {
+                let e1 = self.fold_tuple_expression(e1)?;
+                let e2 = self.fold_tuple_expression(e2)?;
+
+                if let (Ok(t1), Ok(t2)) = (
+                    ConcreteType::try_from(e1.get_type()),
+                    ConcreteType::try_from(e2.get_type()),
+                ) {
+                    if t1 != t2 {
+                        return Err(Error::Type(format!(
+                            "Cannot compare {} of type {} to {} of type {}",
+                            e1, t1, e2, t2
+                        )));
+                    }
+                };
+
+                Ok(BooleanExpression::TupleEq(box e1, box e2))
+            }
+            BooleanExpression::EnumEq(box e1, box e2) => {
+                let e1 = self.fold_enum_expression(e1)?;
+                let e2 = self.fold_enum_expression(e2)?;
+
+                if let (Ok(t1), Ok(t2)) = (
+                    ConcreteType::try_from(e1.get_type()),
+                    ConcreteType::try_from(e2.get_type()),
+                ) {
+                    if t1 != t2 {
+                        return Err(Error::Type(format!(
+                            "Cannot compare {} of type {} to {} of type {}",
+                            e1, t1, e2, t2
+                        )));
+                    }
+                };
+
+                Ok(BooleanExpression::EnumEq(box e1, box e2))
+            }
+            BooleanExpression::IsVariant(box e, variant) => {
+                let e = self.fold_enum_expression(e)?;
+
+                match e.as_inner() {
+                    EnumExpressionInner::Value(tag, _) => {
+                        Ok(BooleanExpression::Value(*tag == variant))
+                    }
+                    _ => Ok(BooleanExpression::IsVariant(box e, variant)),
+                }
+            }
             BooleanExpression::FieldLt(box e1, box e2) => {
                 let e1 = self.fold_field_expression(e1)?;
                 let e2 = self.fold_field_expression(e2)?;
@@ -1267,7 +1504,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
 
                 match (e1.as_inner(), e2.as_inner()) {
                     (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
-                        Ok(BooleanExpression::Value(n1 < n2))
+                        Ok(BooleanExpression::Value(
+                            e1.bitwidth.order_preserving(*n1) < e2.bitwidth.order_preserving(*n2),
+                        ))
                     }
                     _ => Ok(BooleanExpression::UintLt(box e1, box e2)),
                 }
@@ -1278,7 +1517,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
 
                 match (e1.as_inner(), e2.as_inner()) {
                     (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
-                        Ok(BooleanExpression::Value(n1 <= n2))
+                        Ok(BooleanExpression::Value(
+                            e1.bitwidth.order_preserving(*n1) <= e2.bitwidth.order_preserving(*n2),
+                        ))
                     }
                     _ => Ok(BooleanExpression::UintLe(box e1, box e2)),
                 }
@@ -1289,7 +1530,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
 
                 match (e1.as_inner(), e2.as_inner()) {
                     (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
-                        Ok(BooleanExpression::Value(n1 > n2))
+                        Ok(BooleanExpression::Value(
+                            e1.bitwidth.order_preserving(*n1) > e2.bitwidth.order_preserving(*n2),
+                        ))
                     }
                     _ => Ok(BooleanExpression::UintGt(box e1, box e2)),
                 }
@@ -1300,7 +1543,9 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Propagator<'ast, 'a, T> {
 
                 match (e1.as_inner(), e2.as_inner()) {
                     (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
-                        Ok(BooleanExpression::Value(n1 >= n2))
+                        Ok(BooleanExpression::Value(
+                            e1.bitwidth.order_preserving(*n1) >= e2.bitwidth.order_preserving(*n2),
+                        ))
                     }
                     _ => Ok(BooleanExpression::UintGe(box e1, box e2)),
                 }
@@ -1819,5 +2064,57 @@ mod tests {
                 );
             }
         }
+
+        #[cfg(test)]
+        mod int {
+            use super::*;
+
+            #[test]
+            fn div_rem_shift() {
+                let minus_seven = UExpressionInner::Value(0xf9).annotate(UBitwidth::I8);
+                let two = UExpressionInner::Value(0x02).annotate(UBitwidth::I8);
+
+                assert_eq!(
+                    Propagator::<Bn128Field>::with_constants(&mut Constants::new())
+                        .fold_uint_expression(minus_seven.clone() / two.clone()),
+                    Ok(UExpressionInner::Value(0xfd).annotate(UBitwidth::I8))
+                );
+                assert_eq!(
+                    Propagator::<Bn128Field>::with_constants(&mut Constants::new())
+                        .fold_uint_expression(minus_seven.clone() % two),
+                    Ok(UExpressionInner::Value(0xff).annotate(UBitwidth::I8))
+                );
+                assert_eq!(
+                    Propagator::<Bn128Field>::with_constants(&mut Constants::new())
+                        .fold_uint_expression(minus_seven.clone().right_shift(1u32.into())),
+                    Ok(UExpressionInner::Value(0xfc).annotate(UBitwidth::I8))
+                );
+                assert_eq!(
+                    Propagator::<Bn128Field>::with_constants(&mut Constants::new())
+                        .fold_uint_expression(minus_seven.right_shift(42u32.into())),
+                    Ok(UExpressionInner::Value(0xff).annotate(UBitwidth::I8))
+                );
+            }
+
+            #[test]
+            fn lt() {
+                let minus_one = UExpressionInner::Value(0xff).annotate(UBitwidth::I8);
+                let one = UExpressionInner::Value(0x01).annotate(UBitwidth::I8);
+
+                assert_eq!(
+                    Propagator::<Bn128Field>::with_constants(&mut Constants::new())
+                        .fold_boolean_expression(BooleanExpression::UintLt(
+                            box minus_one.clone(),
+                            box one.clone()
+                        )),
+                    Ok(BooleanExpression::Value(true))
+                );
+                assert_eq!(
+                    Propagator::<Bn128Field>::with_constants(&mut Constants::new())
+                        .fold_boolean_expression(BooleanExpression::UintLt(box one, box minus_one)),
+                    Ok(BooleanExpression::Value(false))
+                );
+            }
+        }
     }
 }
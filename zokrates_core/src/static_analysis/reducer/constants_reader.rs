@@ -3,9 +3,10 @@
 use crate::static_analysis::reducer::ConstantDefinitions;
 use crate::typed_absy::{
     folder::*, ArrayExpression, ArrayExpressionInner, ArrayType, BooleanExpression, CoreIdentifier,
-    DeclarationConstant, Expr, FieldElementExpression, Identifier, StructExpression,
-    StructExpressionInner, StructType, TypedProgram, TypedSymbolDeclaration, UBitwidth,
-    UExpression, UExpressionInner,
+    DeclarationConstant, EnumExpression, EnumExpressionInner, EnumType, Expr,
+    FieldElementExpression, Identifier, StructExpression, StructExpressionInner, StructType,
+    TupleExpression, TupleExpressionInner, TupleType, TypedProgram, TypedSymbolDeclaration,
+    UBitwidth, UExpression, UExpressionInner,
 };
 use zokrates_field::Field;
 
@@ -147,6 +148,52 @@ impl<'a, 'ast, T: Field> Folder<'ast, T> for ConstantsReader<'a, 'ast, T> {
         }
     }
 
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType<'ast, T>,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> TupleExpressionInner<'ast, T> {
+        match e {
+            TupleExpressionInner::Identifier(Identifier {
+                id: CoreIdentifier::Constant(c),
+                version,
+            }) => {
+                assert_eq!(version, 0);
+                match self.constants.get(&c).cloned() {
+                    Some(v) => TupleExpression::try_from(v).unwrap().into_inner(),
+                    None => TupleExpressionInner::Identifier(Identifier {
+                        id: CoreIdentifier::Constant(c),
+                        version,
+                    }),
+                }
+            }
+            e => fold_tuple_expression_inner(self, ty, e),
+        }
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        ty: &EnumType<'ast, T>,
+        e: EnumExpressionInner<'ast, T>,
+    ) -> EnumExpressionInner<'ast, T> {
+        match e {
+            EnumExpressionInner::Identifier(Identifier {
+                id: CoreIdentifier::Constant(c),
+                version,
+            }) => {
+                assert_eq!(version, 0);
+                match self.constants.get(&c).cloned() {
+                    Some(v) => EnumExpression::try_from(v).unwrap().into_inner(),
+                    None => EnumExpressionInner::Identifier(Identifier {
+                        id: CoreIdentifier::Constant(c),
+                        version,
+                    }),
+                }
+            }
+            e => fold_enum_expression_inner(self, ty, e),
+        }
+    }
+
     fn fold_declaration_constant(
         &mut self,
         c: DeclarationConstant<'ast, T>,
@@ -181,6 +181,7 @@ struct Reducer<'ast, 'a, T> {
     versions: &'a mut Versions<'ast>,
     substitutions: &'a mut Substitutions<'ast>,
     complete: bool,
+    in_branch: bool,
 }
 
 impl<'ast, 'a, T: Field> Reducer<'ast, 'a, T> {
@@ -204,6 +205,7 @@ impl<'ast, 'a, T: Field> Reducer<'ast, 'a, T> {
             program,
             versions,
             complete: true,
+            in_branch: false,
         }
     }
 }
@@ -430,6 +432,7 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Reducer<'ast, 'a, T> {
                             .collect();
 
                         let mut transformer = ShallowTransformer::with_versions(self.versions);
+                        transformer.in_branch = self.in_branch;
 
                         if to - from > MAX_FOR_LOOP_SIZE {
                             return Err(Error::LoopTooLarge(to.saturating_sub(*from)));
@@ -475,6 +478,38 @@ impl<'ast, 'a, T: Field> ResultFolder<'ast, T> for Reducer<'ast, 'a, T> {
                     }
                 }
             }
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                let condition = self.fold_boolean_expression(condition)?;
+
+                // statements created when visiting the condition go before the if statement, the ones created when
+                // visiting a branch stay inside it
+                let statement_buffer = std::mem::take(&mut self.statement_buffer);
+                let in_branch = std::mem::replace(&mut self.in_branch, true);
+
+                let consequence = consequence
+                    .into_iter()
+                    .map(|s| self.fold_statement(s))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                let alternative = alternative
+                    .into_iter()
+                    .map(|s| self.fold_statement(s))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect();
+
+                self.in_branch = in_branch;
+                self.statement_buffer = statement_buffer;
+
+                Ok(vec![TypedStatement::IfElse(
+                    condition,
+                    consequence,
+                    alternative,
+                )])
+            }
             s => fold_statement(self, s),
         };
 
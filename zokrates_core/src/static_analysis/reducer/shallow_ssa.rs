@@ -24,11 +24,37 @@
 //		endfor
 //		return b_3 // we leave versions b_1 and b_2 to make b accessible and modifiable inside the for-loop
 
+// If statements are visited, and the variables declared outside of them and assigned to in one of their branches
+// are merged after the statement
+// Assignments to array elements or struct members inside a branch are applied to a fresh copy of the variable, so that
+// the version before the statement stays available to the other branch
+
+// Example:
+// def main(bool c, field a) -> field:
+//		if c {
+//			a = a + 1
+//		} else {
+//			a = 2
+//		}
+//		return a
+
+// Should be turned into
+// def main(bool c_0, field a_0) -> field:
+//		if c_0 {
+//			a_1 = a_0 + 1
+//		} else {
+//			a_2 = a_0 // the alternative starts from the versions before the statement
+//			a_3 = 2
+//		}
+//		a_4 = if c_0 then a_1 else a_3 fi
+//		return a_4
+
 use crate::typed_absy::folder::*;
 use crate::typed_absy::types::ConcreteGenericsAssignment;
 use crate::typed_absy::types::Type;
 use crate::typed_absy::*;
 
+use std::collections::HashSet;
 use zokrates_field::Field;
 
 use super::{Output, Versions};
@@ -40,6 +66,8 @@ pub struct ShallowTransformer<'ast, 'a> {
     pub for_loop_backups: Vec<Versions<'ast>>,
     // whether all statements could be unrolled so far. Loops with variable bounds cannot.
     pub blocked: bool,
+    // whether the statements being visited are inside a branch of an if statement
+    pub in_branch: bool,
 }
 
 impl<'ast, 'a> ShallowTransformer<'ast, 'a> {
@@ -48,6 +76,7 @@ impl<'ast, 'a> ShallowTransformer<'ast, 'a> {
             versions,
             for_loop_backups: Vec::default(),
             blocked: false,
+            in_branch: false,
         }
     }
 
@@ -77,6 +106,130 @@ impl<'ast, 'a> ShallowTransformer<'ast, 'a> {
         }
     }
 
+    // issue a new version for an assignee if it is an identifier
+    // inside a branch, an assignment to a part of a variable is applied to a fresh version of the variable, which is
+    // defined as a copy of the current one
+    fn fold_defined_assignee<T: Field>(
+        &mut self,
+        a: TypedAssignee<'ast, T>,
+    ) -> (Option<TypedStatement<'ast, T>>, TypedAssignee<'ast, T>) {
+        match a {
+            TypedAssignee::Identifier(v) => {
+                let v = self.issue_next_ssa_variable(v);
+                (None, TypedAssignee::Identifier(self.fold_variable(v)))
+            }
+            a => {
+                let copy = match self.in_branch {
+                    true => {
+                        let base = base_variable(&a).clone();
+                        let current = self.fold_variable(base.clone());
+                        let next = self.issue_next_ssa_variable(base);
+                        let next = self.fold_variable(next);
+                        Some(TypedStatement::Definition(
+                            next.into(),
+                            TypedExpression::from(current),
+                        ))
+                    }
+                    false => None,
+                };
+                (copy, fold_assignee(self, a))
+            }
+        }
+    }
+
+    fn fold_if_else_statement<T: Field>(
+        &mut self,
+        condition: BooleanExpression<'ast, T>,
+        consequence: Vec<TypedStatement<'ast, T>>,
+        alternative: Vec<TypedStatement<'ast, T>>,
+    ) -> Vec<TypedStatement<'ast, T>> {
+        let condition = self.fold_boolean_expression(condition);
+
+        let merged = merged_variables(&consequence, &alternative);
+
+        let versions_before = self.versions.clone();
+
+        let in_branch = std::mem::replace(&mut self.in_branch, true);
+
+        let consequence: Vec<_> = consequence
+            .into_iter()
+            .flat_map(|s| self.fold_statement(s))
+            .collect();
+
+        let versions_after_consequence = self.versions.clone();
+
+        // the alternative must see the values from before the statement, so the variables modified in the
+        // consequence are redefined to their previous version
+        let mut alternative_statements = vec![];
+
+        for v in &merged {
+            if let Some(before) = versions_before.get(&v.id.id).cloned() {
+                if versions_after_consequence.get(&v.id.id) != Some(&before) {
+                    let previous = Variable::with_id_and_type(
+                        v.id.clone().version(before),
+                        self.fold_type(v._type.clone()),
+                    );
+                    let next = self.issue_next_ssa_variable(v.clone());
+                    let next = self.fold_variable(next);
+                    alternative_statements.push(TypedStatement::Definition(
+                        next.into(),
+                        TypedExpression::from(previous),
+                    ));
+                }
+            }
+        }
+
+        for s in alternative {
+            alternative_statements.extend(self.fold_statement(s));
+        }
+
+        self.in_branch = in_branch;
+
+        let versions_after_alternative = self.versions.clone();
+
+        let mut merges = vec![];
+
+        for v in merged {
+            let versions = (
+                versions_after_consequence.get(&v.id.id).cloned(),
+                versions_after_alternative.get(&v.id.id).cloned(),
+            );
+
+            if let (Some(consequence_version), Some(alternative_version)) = versions {
+                if consequence_version != alternative_version {
+                    let ty = self.fold_type(v._type.clone());
+                    let consequence_value = Variable::with_id_and_type(
+                        v.id.clone().version(consequence_version),
+                        ty.clone(),
+                    );
+                    let alternative_value =
+                        Variable::with_id_and_type(v.id.clone().version(alternative_version), ty);
+
+                    let next = self.issue_next_ssa_variable(v);
+                    let next = self.fold_variable(next);
+
+                    merges.push(TypedStatement::Definition(
+                        next.into(),
+                        TypedExpression::conditional(
+                            condition.clone(),
+                            consequence_value.into(),
+                            alternative_value.into(),
+                            ConditionalKind::IfElse,
+                        ),
+                    ));
+                }
+            }
+        }
+
+        std::iter::once(TypedStatement::IfElse(
+            condition,
+            consequence,
+            alternative_statements,
+        ))
+        .chain(merges)
+        .collect()
+    }
+
     pub fn transform<T: Field>(
         f: TypedFunction<'ast, T>,
         generics: &ConcreteGenericsAssignment<'ast>,
@@ -129,30 +282,30 @@ impl<'ast, 'a, T: Field> Folder<'ast, T> for ShallowTransformer<'ast, 'a> {
             TypedStatement::Definition(a, e) => {
                 let e = self.fold_expression(e);
 
-                let a = match a {
-                    TypedAssignee::Identifier(v) => {
-                        let v = self.issue_next_ssa_variable(v);
-                        TypedAssignee::Identifier(self.fold_variable(v))
-                    }
-                    a => fold_assignee(self, a),
-                };
+                let (copy, a) = self.fold_defined_assignee(a);
 
-                vec![TypedStatement::Definition(a, e)]
+                copy.into_iter()
+                    .chain(std::iter::once(TypedStatement::Definition(a, e)))
+                    .collect()
             }
             TypedStatement::MultipleDefinition(assignees, exprs) => {
                 let exprs = self.fold_expression_list(exprs);
-                let assignees = assignees
+
+                let (copies, assignees): (Vec<_>, Vec<_>) = assignees
+                    .into_iter()
+                    .map(|a| self.fold_defined_assignee(a))
+                    .unzip();
+
+                copies
                     .into_iter()
-                    .map(|a| match a {
-                        TypedAssignee::Identifier(v) => {
-                            let v = self.issue_next_ssa_variable(v);
-                            TypedAssignee::Identifier(self.fold_variable(v))
-                        }
-                        a => fold_assignee(self, a),
-                    })
-                    .collect();
-
-                vec![TypedStatement::MultipleDefinition(assignees, exprs)]
+                    .flatten()
+                    .chain(std::iter::once(TypedStatement::MultipleDefinition(
+                        assignees, exprs,
+                    )))
+                    .collect()
+            }
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                self.fold_if_else_statement(condition, consequence, alternative)
             }
             TypedStatement::For(v, from, to, stats) => {
                 let from = self.fold_uint_expression(from);
@@ -189,6 +342,64 @@ impl<'ast, 'a, T: Field> Folder<'ast, T> for ShallowTransformer<'ast, 'a> {
     }
 }
 
+// the variable which is assigned to by an assignee
+fn base_variable<'a, 'ast, T>(a: &'a TypedAssignee<'ast, T>) -> &'a Variable<'ast, T> {
+    match a {
+        TypedAssignee::Identifier(v) => v,
+        TypedAssignee::Select(box a, _)
+        | TypedAssignee::Member(box a, _)
+        | TypedAssignee::Element(box a, _) => base_variable(a),
+    }
+}
+
+// the variables which are assigned to in the branches of an if statement without being declared in them, in order of
+// first assignment
+fn merged_variables<'ast, T: Clone>(
+    consequence: &[TypedStatement<'ast, T>],
+    alternative: &[TypedStatement<'ast, T>],
+) -> Vec<Variable<'ast, T>> {
+    fn collect<'ast, T: Clone>(
+        statements: &[TypedStatement<'ast, T>],
+        declared: &mut HashSet<CoreIdentifier<'ast>>,
+        assigned: &mut Vec<Variable<'ast, T>>,
+    ) {
+        for s in statements {
+            match s {
+                TypedStatement::Declaration(v) => {
+                    declared.insert(v.id.id.clone());
+                }
+                TypedStatement::Definition(a, _) => assigned.push(base_variable(a).clone()),
+                TypedStatement::MultipleDefinition(assignees, _) => {
+                    assigned.extend(assignees.iter().map(|a| base_variable(a).clone()))
+                }
+                TypedStatement::For(v, _, _, statements) => {
+                    declared.insert(v.id.id.clone());
+                    collect(statements, declared, assigned);
+                }
+                TypedStatement::IfElse(_, consequence, alternative) => {
+                    collect(consequence, declared, assigned);
+                    collect(alternative, declared, assigned);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut declared = HashSet::new();
+    let mut assigned = vec![];
+
+    collect(consequence, &mut declared, &mut assigned);
+    collect(alternative, &mut declared, &mut assigned);
+
+    let mut seen = HashSet::new();
+
+    assigned
+        .into_iter()
+        .filter(|v| !declared.contains(&v.id.id))
+        .filter(|v| seen.insert(v.id.id.clone()))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -771,6 +982,171 @@ mod tests {
         }
     }
 
+    mod if_else {
+        use super::*;
+
+        #[test]
+        fn merge_branches() {
+            // field a
+            // bool c
+            // if c {
+            //     a = a + 1
+            // } else {
+            //     a = 2
+            // }
+
+            // should be turned into
+            // if c_0 {
+            //     a_1 = a_0 + 1
+            // } else {
+            //     a_2 = a_0
+            //     a_3 = 2
+            // }
+            // a_4 = if c_0 then a_1 else a_3 fi
+
+            let mut versions = vec![("a".into(), 0), ("c".into(), 0)]
+                .into_iter()
+                .collect::<Versions>();
+
+            let mut u = ShallowTransformer::with_versions(&mut versions);
+
+            let s: TypedStatement<Bn128Field> = TypedStatement::IfElse(
+                BooleanExpression::Identifier("c".into()),
+                vec![TypedStatement::Definition(
+                    Variable::field_element("a").into(),
+                    FieldElementExpression::Add(
+                        box FieldElementExpression::Identifier("a".into()),
+                        box FieldElementExpression::Number(Bn128Field::from(1)),
+                    )
+                    .into(),
+                )],
+                vec![TypedStatement::Definition(
+                    Variable::field_element("a").into(),
+                    FieldElementExpression::Number(Bn128Field::from(2)).into(),
+                )],
+            );
+
+            assert_eq!(
+                u.fold_statement(s),
+                vec![
+                    TypedStatement::IfElse(
+                        BooleanExpression::Identifier(Identifier::from("c").version(0)),
+                        vec![TypedStatement::Definition(
+                            Variable::field_element(Identifier::from("a").version(1)).into(),
+                            FieldElementExpression::Add(
+                                box FieldElementExpression::Identifier(
+                                    Identifier::from("a").version(0)
+                                ),
+                                box FieldElementExpression::Number(Bn128Field::from(1)),
+                            )
+                            .into(),
+                        )],
+                        vec![
+                            TypedStatement::Definition(
+                                Variable::field_element(Identifier::from("a").version(2)).into(),
+                                FieldElementExpression::Identifier(
+                                    Identifier::from("a").version(0)
+                                )
+                                .into(),
+                            ),
+                            TypedStatement::Definition(
+                                Variable::field_element(Identifier::from("a").version(3)).into(),
+                                FieldElementExpression::Number(Bn128Field::from(2)).into(),
+                            )
+                        ],
+                    ),
+                    TypedStatement::Definition(
+                        Variable::field_element(Identifier::from("a").version(4)).into(),
+                        FieldElementExpression::conditional(
+                            BooleanExpression::Identifier(Identifier::from("c").version(0)),
+                            FieldElementExpression::Identifier(Identifier::from("a").version(1)),
+                            FieldElementExpression::Identifier(Identifier::from("a").version(3)),
+                            ConditionalKind::IfElse,
+                        )
+                        .into(),
+                    )
+                ]
+            );
+        }
+
+        #[test]
+        fn element_write_in_branch() {
+            // field[2] a
+            // bool c
+            // if c {
+            //     a[1] = 2
+            // }
+
+            // should be turned into
+            // if c_0 {
+            //     a_1 = a_0
+            //     a_1[1] = 2
+            // } else {
+            //     a_2 = a_0
+            // }
+            // a_3 = if c_0 then a_1 else a_2 fi
+
+            let mut versions = vec![("a".into(), 0), ("c".into(), 0)]
+                .into_iter()
+                .collect::<Versions>();
+
+            let mut u = ShallowTransformer::with_versions(&mut versions);
+
+            let s: TypedStatement<Bn128Field> = TypedStatement::IfElse(
+                BooleanExpression::Identifier("c".into()),
+                vec![TypedStatement::Definition(
+                    TypedAssignee::Select(
+                        box TypedAssignee::Identifier(Variable::array(
+                            "a",
+                            Type::FieldElement,
+                            2u32,
+                        )),
+                        box UExpression::from(1u32),
+                    ),
+                    FieldElementExpression::Number(Bn128Field::from(2)).into(),
+                )],
+                vec![],
+            );
+
+            let a = |version| {
+                Variable::array(
+                    Identifier::from("a").version(version),
+                    Type::FieldElement,
+                    2u32,
+                )
+            };
+
+            assert_eq!(
+                u.fold_statement(s),
+                vec![
+                    TypedStatement::IfElse(
+                        BooleanExpression::Identifier(Identifier::from("c").version(0)),
+                        vec![
+                            TypedStatement::Definition(a(1).into(), a(0).into()),
+                            TypedStatement::Definition(
+                                TypedAssignee::Select(
+                                    box TypedAssignee::Identifier(a(1)),
+                                    box UExpression::from(1u32),
+                                ),
+                                FieldElementExpression::Number(Bn128Field::from(2)).into(),
+                            )
+                        ],
+                        vec![TypedStatement::Definition(a(2).into(), a(0).into())],
+                    ),
+                    TypedStatement::Definition(
+                        a(3).into(),
+                        TypedExpression::conditional(
+                            BooleanExpression::Identifier(Identifier::from("c").version(0)),
+                            a(1).into(),
+                            a(2).into(),
+                            ConditionalKind::IfElse,
+                        ),
+                    )
+                ]
+            );
+        }
+    }
+
     mod function_call {
         use super::*;
         use crate::typed_absy::types::GGenericsAssignment;
@@ -40,6 +40,18 @@ fn force_reduce<T: Field>(e: UExpression<T>) -> UExpression<T> {
     }
 }
 
+/// The max value of the product of two reduced terms of bitwidth `range` when it is computed by limbs: the flattener
+/// multiplies the low half of `left` by `right` and the high half of `left` by the low half of `right`, which is
+/// enough to get the result modulo 2**range
+fn limbs_mult_max<T: Field>(range: usize) -> T {
+    let half = range / 2;
+    let range_max = T::from(2).pow(range) - T::from(1);
+    let low_max = T::from(2).pow(half) - T::from(1);
+    let high_max = T::from(2).pow(range - half) - T::from(1);
+
+    low_max.clone() * range_max + high_max * low_max * T::from(2).pow(half)
+}
+
 fn force_no_reduce<T: Field>(e: UExpression<T>) -> UExpression<T> {
     let metadata = e.metadata.unwrap();
 
@@ -145,9 +157,10 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
 
         let range = e.bitwidth.to_usize();
 
-        let range_max: T = (2_u128.pow(range as u32) - 1).into();
+        let range_max: T = T::from(2).pow(range) - T::from(1);
 
-        assert!(range < max_bitwidth / 2);
+        // the product of two reduced terms may not fit in the field, in which case it is computed by limbs, see `Mult`
+        assert!(range + range / 2 < max_bitwidth);
 
         let inner = e.inner;
 
@@ -325,7 +338,12 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                                 left_max
                                     .checked_mul(&range_max.clone())
                                     .map(|max| (false, true, max))
-                                    .unwrap_or_else(|| (true, true, range_max.clone() * range_max))
+                                    .unwrap_or_else(|| {
+                                        let max = range_max
+                                            .checked_mul(&range_max)
+                                            .unwrap_or_else(|| limbs_mult_max(range));
+                                        (true, true, max)
+                                    })
                             })
                     });
 
@@ -368,9 +386,7 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                 let e = self.fold_uint_expression(e);
 
                 let e_max: num_bigint::BigUint = e.metadata.as_ref().unwrap().max.to_biguint();
-                let max = e_max
-                    .shl(by as usize)
-                    .bitand(&(2_u128.pow(range as u32) - 1).into());
+                let max = e_max.shl(by as usize).bitand(&range_max.to_biguint());
 
                 let max = T::try_from(max).unwrap();
 
@@ -381,9 +397,7 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                 let e = self.fold_uint_expression(e);
 
                 let e_max: num_bigint::BigUint = e.metadata.as_ref().unwrap().max.to_biguint();
-                let max = e_max
-                    .bitand(&(2_u128.pow(range as u32) - 1).into())
-                    .shr(by as usize);
+                let max = e_max.bitand(&range_max.to_biguint()).shr(by as usize);
 
                 let max = T::try_from(max).unwrap();
 
@@ -449,6 +463,16 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                 ZirExpressionList::EmbedCall(embed, generics, arguments),
             ) => {
                 match embed {
+                    FlatEmbed::U128FromBits => {
+                        assert_eq!(lhs.len(), 1);
+                        self.register(
+                            lhs[0].clone(),
+                            UMetadata {
+                                max: T::from(2).pow(128) - T::from(1),
+                                should_reduce: ShouldReduce::False,
+                            },
+                        );
+                    }
                     FlatEmbed::U64FromBits => {
                         assert_eq!(lhs.len(), 1);
                         self.register(
@@ -496,7 +520,8 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                     FlatEmbed::U8ToBits
                     | FlatEmbed::U16ToBits
                     | FlatEmbed::U32ToBits
-                    | FlatEmbed::U64ToBits => {
+                    | FlatEmbed::U64ToBits
+                    | FlatEmbed::U128ToBits => {
                         vec![ZirStatement::MultipleDefinition(
                             lhs,
                             ZirExpressionList::EmbedCall(
@@ -544,6 +569,27 @@ impl<'ast, T: Field> Folder<'ast, T> for UintOptimizer<'ast, T> {
                     metadata,
                 )]
             }
+            // logged values are reduced when they are printed, so we do not need to put them back in range here
+            ZirStatement::Log(format_string, expressions) => vec![ZirStatement::Log(
+                format_string,
+                expressions
+                    .into_iter()
+                    .map(|(t, e)| {
+                        (
+                            t,
+                            e.into_iter()
+                                .map(|e| match e {
+                                    ZirExpression::Uint(e) => {
+                                        let e = self.fold_uint_expression(e);
+                                        ZirExpression::Uint(force_no_reduce(e))
+                                    }
+                                    e => self.fold_expression(e),
+                                })
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            )],
             s => fold_statement(self, s),
         }
     }
@@ -1,7 +1,7 @@
 use crate::flat_absy::{FlatParameter, FlatVariable};
-use crate::ir::visitor::Visitor;
+use crate::ir::visitor::{visit_statement, Visitor};
 use crate::ir::Directive;
-use crate::ir::Prog;
+use crate::ir::{Prog, Statement};
 use std::collections::HashSet;
 use std::fmt;
 use zokrates_field::Field;
@@ -30,6 +30,32 @@ impl UnconstrainedVariableDetector {
         let mut instance = Self::default();
         instance.visit_module(p);
 
+        // the directive outputs which are only computed to be logged do not need constraints
+        let mut logged = HashSet::new();
+        for s in p.statements.iter().rev() {
+            match s {
+                Statement::Log(_, expressions, _) => logged.extend(
+                    expressions
+                        .iter()
+                        .flat_map(|(_, e)| e.iter())
+                        .flat_map(|e| e.left.0.iter().chain(e.right.0.iter()))
+                        .map(|(v, _)| *v),
+                ),
+                Statement::Directive(d) if d.outputs.iter().any(|o| logged.contains(o)) => {
+                    logged.extend(
+                        d.inputs
+                            .iter()
+                            .flat_map(|i| i.left.0.iter().chain(i.right.0.iter()))
+                            .map(|(v, _)| *v),
+                    );
+                    for o in &d.outputs {
+                        instance.variables.remove(o);
+                    }
+                }
+                _ => {}
+            }
+        }
+
         if instance.variables.is_empty() {
             Ok(())
         } else {
@@ -50,6 +76,13 @@ impl<T: Field> Visitor<T> for UnconstrainedVariableDetector {
     fn visit_directive(&mut self, d: &Directive<T>) {
         self.variables.extend(d.outputs.iter());
     }
+    fn visit_statement(&mut self, s: &Statement<T>) {
+        // logging a variable does not constrain it
+        if let Statement::Log(..) | Statement::Span(..) = s {
+            return;
+        }
+        visit_statement(self, s)
+    }
 }
 
 #[cfg(test)]
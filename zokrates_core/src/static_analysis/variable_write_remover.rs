@@ -97,6 +97,48 @@ impl<'ast> VariableWriteRemover {
                                             ConditionalKind::IfElse,
                                         )
                                         .into(),
+                                        Type::Tuple(..) => TupleExpression::conditional(
+                                            BooleanExpression::UintEq(
+                                                box i.into(),
+                                                box head.clone(),
+                                            ),
+                                            match Self::choose_many(
+                                                TupleExpression::select(base.clone(), i).into(),
+                                                tail.clone(),
+                                                new_expression.clone(),
+                                                statements,
+                                            ) {
+                                                TypedExpression::Tuple(e) => e,
+                                                e => unreachable!(
+                                            "the interior was expected to be a tuple, was {}",
+                                            e.get_type()
+                                        ),
+                                            },
+                                            TupleExpression::select(base.clone(), i),
+                                            ConditionalKind::IfElse,
+                                        )
+                                        .into(),
+                                        Type::Enum(..) => EnumExpression::conditional(
+                                            BooleanExpression::UintEq(
+                                                box i.into(),
+                                                box head.clone(),
+                                            ),
+                                            match Self::choose_many(
+                                                EnumExpression::select(base.clone(), i).into(),
+                                                tail.clone(),
+                                                new_expression.clone(),
+                                                statements,
+                                            ) {
+                                                TypedExpression::Enum(e) => e,
+                                                e => unreachable!(
+                                            "the interior was expected to be an enum, was {}",
+                                            e.get_type()
+                                        ),
+                                            },
+                                            EnumExpression::select(base.clone(), i),
+                                            ConditionalKind::IfElse,
+                                        )
+                                        .into(),
                                         Type::FieldElement => FieldElementExpression::conditional(
                                             BooleanExpression::UintEq(
                                                 box i.into(),
@@ -169,6 +211,9 @@ impl<'ast> VariableWriteRemover {
                             .into()
                         }
                         Access::Member(..) => unreachable!("can't get a member from an array"),
+                        Access::Element(..) => {
+                            unreachable!("can't get a tuple element from an array")
+                        }
                     }
                 }
                 TypedExpression::Struct(base) => {
@@ -263,12 +308,75 @@ impl<'ast> VariableWriteRemover {
                                             StructExpression::member(base.clone(), member.id).into()
                                         }
                                     }
+                                    Type::Tuple(..) => {
+                                        if member.id == head {
+                                            Self::choose_many(
+                                                TupleExpression::member(base.clone(), head.clone())
+                                                    .into(),
+                                                tail.clone(),
+                                                new_expression.clone(),
+                                                statements,
+                                            )
+                                        } else {
+                                            TupleExpression::member(base.clone(), member.id).into()
+                                        }
+                                    }
+                                    Type::Enum(..) => {
+                                        if member.id == head {
+                                            Self::choose_many(
+                                                EnumExpression::member(base.clone(), head.clone())
+                                                    .into(),
+                                                tail.clone(),
+                                                new_expression.clone(),
+                                                statements,
+                                            )
+                                        } else {
+                                            EnumExpression::member(base.clone(), member.id).into()
+                                        }
+                                    }
                                 })
                                 .collect(),
                         )
                         .annotate(members)
                         .into(),
                         Access::Select(..) => unreachable!("can't get a element from a struct"),
+                        Access::Element(..) => {
+                            unreachable!("can't get a tuple element from a struct")
+                        }
+                    }
+                }
+                TypedExpression::Tuple(base) => {
+                    let tuple_ty = match base.get_type() {
+                        Type::Tuple(tuple_ty) => tuple_ty,
+                        _ => unreachable!(),
+                    };
+
+                    let head = indices.remove(0);
+                    let tail = indices;
+
+                    match head {
+                        Access::Element(head) => TupleExpressionInner::Value(
+                            (0..tuple_ty.elements.len() as u32)
+                                .map(|i| {
+                                    let element = TypedExpression::element(base.clone(), i);
+
+                                    if i == head {
+                                        Self::choose_many(
+                                            element,
+                                            tail.clone(),
+                                            new_expression.clone(),
+                                            statements,
+                                        )
+                                    } else {
+                                        element
+                                    }
+                                })
+                                .collect(),
+                        )
+                        .annotate(tuple_ty)
+                        .into(),
+                        Access::Select(..) => unreachable!("can't get a element from a tuple"),
+                        Access::Member(..) => unreachable!("can't get a member from a tuple"),
                     }
                 }
                 e => unreachable!("can't make an access on a {}", e.get_type()),
@@ -281,6 +389,7 @@ impl<'ast> VariableWriteRemover {
 enum Access<'ast, T: Field> {
     Select(UExpression<'ast, T>),
     Member(MemberId),
+    Element(u32),
 }
 /// Turn an assignee into its representation as a base variable and a list accesses
 /// a[2][3][4] -> (a, [2, 3, 4])
@@ -297,6 +406,11 @@ fn linear<T: Field>(a: TypedAssignee<T>) -> (Variable<T>, Vec<Access<T>>) {
             indices.push(Access::Member(m));
             (v, indices)
         }
+        TypedAssignee::Element(box t, index) => {
+            let (v, mut indices) = linear(t);
+            indices.push(Access::Element(index));
+            (v, indices)
+        }
     }
 }
 
@@ -308,6 +422,7 @@ fn is_constant<T>(assignee: &TypedAssignee<T>) -> bool {
             _ => false,
         },
         TypedAssignee::Member(box assignee, _) => is_constant(assignee),
+        TypedAssignee::Element(box assignee, _) => is_constant(assignee),
     }
 }
 
@@ -344,6 +459,14 @@ impl<'ast, T: Field> Folder<'ast, T> for VariableWriteRemover {
                                 .annotate(members)
                                 .into()
                         }
+                        Type::Tuple(tuple_ty) => {
+                            TupleExpressionInner::Identifier(variable.id.clone())
+                                .annotate(tuple_ty)
+                                .into()
+                        }
+                        Type::Enum(enum_ty) => EnumExpressionInner::Identifier(variable.id.clone())
+                            .annotate(enum_ty)
+                            .into(),
                     };
 
                     let base = self.fold_expression(base);
@@ -475,7 +475,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                 match (e1.into_inner(), e2.into_inner()) {
                     (UExpressionInner::Value(0), e) | (e, UExpressionInner::Value(0)) => Ok(e),
                     (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
-                        UExpressionInner::Value((n1 + n2) % 2_u128.pow(bitwidth.to_usize() as u32)),
+                        UExpressionInner::Value(n1.wrapping_add(n2) & bitwidth.mask()),
                     ),
                     (e1, e2) => Ok(UExpressionInner::Add(
                         box e1.annotate(bitwidth),
@@ -489,11 +489,9 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
 
                 match (e1.into_inner(), e2.into_inner()) {
                     (e, UExpressionInner::Value(0)) => Ok(e),
-                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
-                        Ok(UExpressionInner::Value(
-                            n1.wrapping_sub(n2) % 2_u128.pow(bitwidth.to_usize() as u32),
-                        ))
-                    }
+                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
+                        UExpressionInner::Value(n1.wrapping_sub(n2) & bitwidth.mask()),
+                    ),
                     (e1, e2) => Ok(UExpressionInner::Sub(
                         box e1.annotate(bitwidth),
                         box e2.annotate(bitwidth),
@@ -510,7 +508,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                     }
                     (e, UExpressionInner::Value(1)) | (UExpressionInner::Value(1), e) => Ok(e),
                     (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
-                        UExpressionInner::Value((n1 * n2) % 2_u128.pow(bitwidth.to_usize() as u32)),
+                        UExpressionInner::Value(n1.wrapping_mul(n2) & bitwidth.mask()),
                     ),
                     (e1, e2) => Ok(UExpressionInner::Mult(
                         box e1.annotate(bitwidth),
@@ -525,9 +523,9 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                 match (e1.into_inner(), e2.into_inner()) {
                     (_, UExpressionInner::Value(n)) if n == 0 => Err(Error::DivisionByZero),
                     (e, UExpressionInner::Value(n)) if n == 1 => Ok(e),
-                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
-                        UExpressionInner::Value((n1 / n2) % 2_u128.pow(bitwidth.to_usize() as u32)),
-                    ),
+                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
+                        Ok(UExpressionInner::Value((n1 / n2) & bitwidth.mask()))
+                    }
                     (e1, e2) => Ok(UExpressionInner::Div(
                         box e1.annotate(bitwidth),
                         box e2.annotate(bitwidth),
@@ -539,9 +537,9 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                 let e2 = self.fold_uint_expression(e2)?;
 
                 match (e1.into_inner(), e2.into_inner()) {
-                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => Ok(
-                        UExpressionInner::Value((n1 % n2) % 2_u128.pow(bitwidth.to_usize() as u32)),
-                    ),
+                    (UExpressionInner::Value(n1), UExpressionInner::Value(n2)) => {
+                        Ok(UExpressionInner::Value((n1 % n2) & bitwidth.mask()))
+                    }
                     (e1, e2) => Ok(UExpressionInner::Rem(
                         box e1.annotate(bitwidth),
                         box e2.annotate(bitwidth),
@@ -569,7 +567,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
 
                 match (e1.into_inner(), e2.into_inner()) {
                     (e, UExpressionInner::Value(n)) | (UExpressionInner::Value(n), e)
-                        if n == 2_u128.pow(bitwidth.to_usize() as u32) - 1 =>
+                        if n == bitwidth.mask() =>
                     {
                         Ok(e)
                     }
@@ -592,7 +590,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                 match (e1.into_inner(), e2.into_inner()) {
                     (e, UExpressionInner::Value(0)) | (UExpressionInner::Value(0), e) => Ok(e),
                     (_, UExpressionInner::Value(n)) | (UExpressionInner::Value(n), _)
-                        if n == 2_u128.pow(bitwidth.to_usize() as u32) - 1 =>
+                        if n == bitwidth.mask() =>
                     {
                         Ok(UExpressionInner::Value(n))
                     }
@@ -610,9 +608,9 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
                 match (e.into_inner(), by) {
                     (e, 0) => Ok(e),
                     (_, by) if by >= bitwidth as u32 => Ok(UExpressionInner::Value(0)),
-                    (UExpressionInner::Value(n), by) => Ok(UExpressionInner::Value(
-                        (n << by) & (2_u128.pow(bitwidth as u32) - 1),
-                    )),
+                    (UExpressionInner::Value(n), by) => {
+                        Ok(UExpressionInner::Value((n << by) & bitwidth.mask()))
+                    }
                     (e, by) => Ok(UExpressionInner::LeftShift(box e.annotate(bitwidth), by)),
                 }
             }
@@ -628,9 +626,7 @@ impl<'ast, T: Field> ResultFolder<'ast, T> for ZirPropagator<'ast, T> {
             UExpressionInner::Not(box e) => {
                 let e = self.fold_uint_expression(e)?;
                 match e.into_inner() {
-                    UExpressionInner::Value(n) => Ok(UExpressionInner::Value(
-                        !n & (2_u128.pow(bitwidth as u32) - 1),
-                    )),
+                    UExpressionInner::Value(n) => Ok(UExpressionInner::Value(!n & bitwidth.mask())),
                     e => Ok(UExpressionInner::Not(box e.annotate(bitwidth))),
                 }
             }
@@ -31,7 +31,8 @@ impl Abi {
 mod tests {
     use super::*;
     use crate::typed_absy::types::{
-        ConcreteArrayType, ConcreteFunctionKey, ConcreteStructMember, ConcreteStructType, UBitwidth,
+        ConcreteArrayType, ConcreteEnumType, ConcreteEnumVariant, ConcreteFunctionKey,
+        ConcreteStructMember, ConcreteStructType, ConcreteTupleType, UBitwidth,
     };
     use crate::typed_absy::{
         parameter::DeclarationParameter, variable::DeclarationVariable, ConcreteType,
@@ -486,4 +487,112 @@ mod tests {
         let de_abi: Abi = serde_json::from_str(json.as_ref()).unwrap();
         assert_eq!(de_abi, abi);
     }
+
+    #[test]
+    fn serialize_tuple() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("a"),
+                public: false,
+                ty: ConcreteType::Tuple(ConcreteTupleType::new(vec![
+                    ConcreteType::FieldElement,
+                    ConcreteType::Array(ConcreteArrayType::new(ConcreteType::Boolean, 2u32)),
+                ])),
+            }],
+            outputs: vec![ConcreteType::Tuple(ConcreteTupleType::new(vec![]))],
+        };
+
+        let json = serde_json::to_string_pretty(&abi).unwrap();
+        assert_eq!(
+            &json,
+            r#"{
+  "inputs": [
+    {
+      "name": "a",
+      "public": false,
+      "type": "tuple",
+      "components": {
+        "elements": [
+          {
+            "type": "field"
+          },
+          {
+            "type": "array",
+            "components": {
+              "size": 2,
+              "type": "bool"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "outputs": [
+    {
+      "type": "tuple",
+      "components": {
+        "elements": []
+      }
+    }
+  ]
+}"#
+        );
+
+        let de_abi: Abi = serde_json::from_str(json.as_ref()).unwrap();
+        assert_eq!(de_abi, abi);
+    }
+
+    #[test]
+    fn serialize_enum() {
+        let abi: Abi = Abi {
+            inputs: vec![AbiInput {
+                name: String::from("s"),
+                public: true,
+                ty: ConcreteType::Enum(ConcreteEnumType::new(
+                    "".into(),
+                    "State".into(),
+                    vec![
+                        ConcreteEnumVariant::new("Idle".into(), vec![]),
+                        ConcreteEnumVariant::new("Active".into(), vec![ConcreteType::FieldElement]),
+                    ],
+                )),
+            }],
+            outputs: vec![],
+        };
+
+        let json = serde_json::to_string_pretty(&abi).unwrap();
+        assert_eq!(
+            &json,
+            r#"{
+  "inputs": [
+    {
+      "name": "s",
+      "public": true,
+      "type": "enum",
+      "components": {
+        "name": "State",
+        "variants": [
+          {
+            "name": "Idle",
+            "payload": []
+          },
+          {
+            "name": "Active",
+            "payload": [
+              {
+                "type": "field"
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "outputs": []
+}"#
+        );
+
+        let de_abi: Abi = serde_json::from_str(json.as_ref()).unwrap();
+        assert_eq!(de_abi, abi);
+    }
 }
@@ -32,6 +32,18 @@ impl<'ast, T: Field> Fold<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> Fold<'ast, T> for TupleExpression<'ast, T> {
+    fn fold<F: Folder<'ast, T>>(self, f: &mut F) -> Self {
+        f.fold_tuple_expression(self)
+    }
+}
+
+impl<'ast, T: Field> Fold<'ast, T> for EnumExpression<'ast, T> {
+    fn fold<F: Folder<'ast, T>>(self, f: &mut F) -> Self {
+        f.fold_enum_expression(self)
+    }
+}
+
 impl<'ast, T: Field> Fold<'ast, T> for ArrayExpression<'ast, T> {
     fn fold<F: Folder<'ast, T>>(self, f: &mut F) -> Self {
         f.fold_array_expression(self)
@@ -155,6 +167,8 @@ pub trait Folder<'ast, T: Field>: Sized {
         match t {
             Array(array_type) => Array(self.fold_array_type(array_type)),
             Struct(struct_type) => Struct(self.fold_struct_type(struct_type)),
+            Tuple(tuple_type) => Tuple(self.fold_tuple_type(tuple_type)),
+            Enum(enum_type) => Enum(self.fold_enum_type(enum_type)),
             t => t,
         }
     }
@@ -189,12 +203,34 @@ pub trait Folder<'ast, T: Field>: Sized {
         }
     }
 
+    fn fold_tuple_type(&mut self, t: TupleType<'ast, T>) -> TupleType<'ast, T> {
+        TupleType {
+            elements: t.elements.into_iter().map(|e| self.fold_type(e)).collect(),
+        }
+    }
+
+    fn fold_enum_type(&mut self, t: EnumType<'ast, T>) -> EnumType<'ast, T> {
+        EnumType {
+            variants: t
+                .variants
+                .into_iter()
+                .map(|v| EnumVariant {
+                    payload: v.payload.into_iter().map(|ty| self.fold_type(ty)).collect(),
+                    ..v
+                })
+                .collect(),
+            ..t
+        }
+    }
+
     fn fold_declaration_type(&mut self, t: DeclarationType<'ast, T>) -> DeclarationType<'ast, T> {
         use self::GType::*;
 
         match t {
             Array(array_type) => Array(self.fold_declaration_array_type(array_type)),
             Struct(struct_type) => Struct(self.fold_declaration_struct_type(struct_type)),
+            Tuple(tuple_type) => Tuple(self.fold_declaration_tuple_type(tuple_type)),
+            Enum(enum_type) => Enum(self.fold_declaration_enum_type(enum_type)),
             t => t,
         }
     }
@@ -231,6 +267,40 @@ pub trait Folder<'ast, T: Field>: Sized {
         }
     }
 
+    fn fold_declaration_tuple_type(
+        &mut self,
+        t: DeclarationTupleType<'ast, T>,
+    ) -> DeclarationTupleType<'ast, T> {
+        DeclarationTupleType {
+            elements: t
+                .elements
+                .into_iter()
+                .map(|e| self.fold_declaration_type(e))
+                .collect(),
+        }
+    }
+
+    fn fold_declaration_enum_type(
+        &mut self,
+        t: DeclarationEnumType<'ast, T>,
+    ) -> DeclarationEnumType<'ast, T> {
+        DeclarationEnumType {
+            variants: t
+                .variants
+                .into_iter()
+                .map(|v| DeclarationEnumVariant {
+                    payload: v
+                        .payload
+                        .into_iter()
+                        .map(|ty| self.fold_declaration_type(ty))
+                        .collect(),
+                    ..v
+                })
+                .collect(),
+            ..t
+        }
+    }
+
     fn fold_assignee(&mut self, a: TypedAssignee<'ast, T>) -> TypedAssignee<'ast, T> {
         fold_assignee(self, a)
     }
@@ -280,6 +350,8 @@ pub trait Folder<'ast, T: Field>: Sized {
             TypedExpression::Uint(e) => self.fold_uint_expression(e).into(),
             TypedExpression::Array(e) => self.fold_array_expression(e).into(),
             TypedExpression::Struct(e) => self.fold_struct_expression(e).into(),
+            TypedExpression::Tuple(e) => self.fold_tuple_expression(e).into(),
+            TypedExpression::Enum(e) => self.fold_enum_expression(e).into(),
             TypedExpression::Int(e) => self.fold_int_expression(e).into(),
         }
     }
@@ -315,6 +387,16 @@ pub trait Folder<'ast, T: Field>: Sized {
         fold_member_expression(self, ty, e)
     }
 
+    fn fold_element_expression<
+        E: Expr<'ast, T> + Element<'ast, T> + From<TypedExpression<'ast, T>>,
+    >(
+        &mut self,
+        ty: &E::Ty,
+        e: ElementExpression<'ast, T, E>,
+    ) -> ElementOrExpression<'ast, T, E> {
+        fold_element_expression(self, ty, e)
+    }
+
     fn fold_function_call_expression<
         E: Id<'ast, T> + From<TypedExpression<'ast, T>> + Expr<'ast, T> + FunctionCall<'ast, T>,
     >(
@@ -346,6 +428,14 @@ pub trait Folder<'ast, T: Field>: Sized {
         fold_struct_expression(self, e)
     }
 
+    fn fold_tuple_expression(&mut self, e: TupleExpression<'ast, T>) -> TupleExpression<'ast, T> {
+        fold_tuple_expression(self, e)
+    }
+
+    fn fold_enum_expression(&mut self, e: EnumExpression<'ast, T>) -> EnumExpression<'ast, T> {
+        fold_enum_expression(self, e)
+    }
+
     fn fold_expression_list(
         &mut self,
         es: TypedExpressionList<'ast, T>,
@@ -404,6 +494,22 @@ pub trait Folder<'ast, T: Field>: Sized {
     ) -> StructExpressionInner<'ast, T> {
         fold_struct_expression_inner(self, ty, e)
     }
+
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType<'ast, T>,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> TupleExpressionInner<'ast, T> {
+        fold_tuple_expression_inner(self, ty, e)
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        ty: &EnumType<'ast, T>,
+        e: EnumExpressionInner<'ast, T>,
+    ) -> EnumExpressionInner<'ast, T> {
+        fold_enum_expression_inner(self, ty, e)
+    }
 }
 
 pub fn fold_module<'ast, T: Field, F: Folder<'ast, T>>(
@@ -480,10 +586,28 @@ pub fn fold_statement<'ast, T: Field, F: Folder<'ast, T>>(
                 .flat_map(|s| f.fold_statement(s))
                 .collect(),
         ),
+        TypedStatement::IfElse(condition, consequence, alternative) => TypedStatement::IfElse(
+            f.fold_boolean_expression(condition),
+            consequence
+                .into_iter()
+                .flat_map(|s| f.fold_statement(s))
+                .collect(),
+            alternative
+                .into_iter()
+                .flat_map(|s| f.fold_statement(s))
+                .collect(),
+        ),
         TypedStatement::MultipleDefinition(assignees, elist) => TypedStatement::MultipleDefinition(
             assignees.into_iter().map(|a| f.fold_assignee(a)).collect(),
             f.fold_expression_list(elist),
         ),
+        TypedStatement::Log(format_string, expressions) => TypedStatement::Log(
+            format_string,
+            expressions
+                .into_iter()
+                .map(|e| f.fold_expression(e))
+                .collect(),
+        ),
         s => s,
     };
     vec![res]
@@ -525,6 +649,10 @@ pub fn fold_array_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
             MemberOrExpression::Member(m) => ArrayExpressionInner::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        ArrayExpressionInner::Element(m) => match f.fold_element_expression(ty, m) {
+            ElementOrExpression::Element(m) => ArrayExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
         ArrayExpressionInner::Slice(box array, box from, box to) => {
             let array = f.fold_array_expression(array);
             let from = f.fold_uint_expression(from);
@@ -572,6 +700,94 @@ pub fn fold_struct_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
             MemberOrExpression::Member(m) => StructExpressionInner::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        StructExpressionInner::Element(m) => match f.fold_element_expression(ty, m) {
+            ElementOrExpression::Element(m) => StructExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
+    }
+}
+
+pub fn fold_tuple_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
+    f: &mut F,
+    ty: &TupleType<'ast, T>,
+    e: TupleExpressionInner<'ast, T>,
+) -> TupleExpressionInner<'ast, T> {
+    match e {
+        TupleExpressionInner::Block(block) => {
+            TupleExpressionInner::Block(f.fold_block_expression(block))
+        }
+        TupleExpressionInner::Identifier(id) => TupleExpressionInner::Identifier(f.fold_name(id)),
+        TupleExpressionInner::Value(exprs) => {
+            TupleExpressionInner::Value(exprs.into_iter().map(|e| f.fold_expression(e)).collect())
+        }
+        TupleExpressionInner::FunctionCall(function_call) => {
+            match f.fold_function_call_expression(ty, function_call) {
+                FunctionCallOrExpression::FunctionCall(function_call) => {
+                    TupleExpressionInner::FunctionCall(function_call)
+                }
+                FunctionCallOrExpression::Expression(u) => u,
+            }
+        }
+        TupleExpressionInner::Conditional(c) => match f.fold_conditional_expression(ty, c) {
+            ConditionalOrExpression::Conditional(s) => TupleExpressionInner::Conditional(s),
+            ConditionalOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Select(select) => match f.fold_select_expression(ty, select) {
+            SelectOrExpression::Select(s) => TupleExpressionInner::Select(s),
+            SelectOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Member(m) => match f.fold_member_expression(ty, m) {
+            MemberOrExpression::Member(m) => TupleExpressionInner::Member(m),
+            MemberOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Element(m) => match f.fold_element_expression(ty, m) {
+            ElementOrExpression::Element(m) => TupleExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Payload(box e, variant) => {
+            TupleExpressionInner::Payload(box f.fold_enum_expression(e), variant)
+        }
+    }
+}
+
+pub fn fold_enum_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
+    f: &mut F,
+    ty: &EnumType<'ast, T>,
+    e: EnumExpressionInner<'ast, T>,
+) -> EnumExpressionInner<'ast, T> {
+    match e {
+        EnumExpressionInner::Block(block) => {
+            EnumExpressionInner::Block(f.fold_block_expression(block))
+        }
+        EnumExpressionInner::Identifier(id) => EnumExpressionInner::Identifier(f.fold_name(id)),
+        EnumExpressionInner::Value(variant, exprs) => EnumExpressionInner::Value(
+            variant,
+            exprs.into_iter().map(|e| f.fold_expression(e)).collect(),
+        ),
+        EnumExpressionInner::FunctionCall(function_call) => {
+            match f.fold_function_call_expression(ty, function_call) {
+                FunctionCallOrExpression::FunctionCall(function_call) => {
+                    EnumExpressionInner::FunctionCall(function_call)
+                }
+                FunctionCallOrExpression::Expression(u) => u,
+            }
+        }
+        EnumExpressionInner::Conditional(c) => match f.fold_conditional_expression(ty, c) {
+            ConditionalOrExpression::Conditional(s) => EnumExpressionInner::Conditional(s),
+            ConditionalOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Select(select) => match f.fold_select_expression(ty, select) {
+            SelectOrExpression::Select(s) => EnumExpressionInner::Select(s),
+            SelectOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Member(m) => match f.fold_member_expression(ty, m) {
+            MemberOrExpression::Member(m) => EnumExpressionInner::Member(m),
+            MemberOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Element(m) => match f.fold_element_expression(ty, m) {
+            ElementOrExpression::Element(m) => EnumExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     }
 }
 
@@ -647,6 +863,12 @@ pub fn fold_field_expression<'ast, T: Field, F: Folder<'ast, T>>(
             MemberOrExpression::Member(m) => FieldElementExpression::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        FieldElementExpression::Element(element) => {
+            match f.fold_element_expression(&Type::FieldElement, element) {
+                ElementOrExpression::Element(m) => FieldElementExpression::Element(m),
+                ElementOrExpression::Expression(u) => u,
+            }
+        }
     }
 }
 
@@ -684,6 +906,22 @@ pub fn fold_member_expression<
     ))
 }
 
+pub fn fold_element_expression<
+    'ast,
+    T: Field,
+    E: Expr<'ast, T> + Element<'ast, T> + From<TypedExpression<'ast, T>>,
+    F: Folder<'ast, T>,
+>(
+    f: &mut F,
+    _: &E::Ty,
+    e: ElementExpression<'ast, T, E>,
+) -> ElementOrExpression<'ast, T, E> {
+    ElementOrExpression::Element(ElementExpression::new(
+        f.fold_tuple_expression(*e.tuple),
+        e.index,
+    ))
+}
+
 pub fn fold_select_expression<
     'ast,
     T: Field,
@@ -735,6 +973,20 @@ pub fn fold_boolean_expression<'ast, T: Field, F: Folder<'ast, T>>(
             let e2 = f.fold_struct_expression(e2);
             BooleanExpression::StructEq(box e1, box e2)
         }
+        BooleanExpression::TupleEq(box e1, box e2) => {
+            let e1 = f.fold_tuple_expression(e1);
+            let e2 = f.fold_tuple_expression(e2);
+            BooleanExpression::TupleEq(box e1, box e2)
+        }
+        BooleanExpression::EnumEq(box e1, box e2) => {
+            let e1 = f.fold_enum_expression(e1);
+            let e2 = f.fold_enum_expression(e2);
+            BooleanExpression::EnumEq(box e1, box e2)
+        }
+        BooleanExpression::IsVariant(box e, variant) => {
+            let e = f.fold_enum_expression(e);
+            BooleanExpression::IsVariant(box e, variant)
+        }
         BooleanExpression::UintEq(box e1, box e2) => {
             let e1 = f.fold_uint_expression(e1);
             let e2 = f.fold_uint_expression(e2);
@@ -816,6 +1068,10 @@ pub fn fold_boolean_expression<'ast, T: Field, F: Folder<'ast, T>>(
             MemberOrExpression::Member(m) => BooleanExpression::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        BooleanExpression::Element(m) => match f.fold_element_expression(&Type::Boolean, m) {
+            ElementOrExpression::Element(m) => BooleanExpression::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     }
 }
 
@@ -939,6 +1195,10 @@ pub fn fold_uint_expression_inner<'ast, T: Field, F: Folder<'ast, T>>(
             MemberOrExpression::Member(m) => UExpressionInner::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        UExpressionInner::Element(m) => match f.fold_element_expression(&ty, m) {
+            ElementOrExpression::Element(m) => UExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     }
 }
 
@@ -1112,6 +1372,28 @@ pub fn fold_struct_expression<'ast, T: Field, F: Folder<'ast, T>>(
     }
 }
 
+pub fn fold_tuple_expression<'ast, T: Field, F: Folder<'ast, T>>(
+    f: &mut F,
+    e: TupleExpression<'ast, T>,
+) -> TupleExpression<'ast, T> {
+    let ty = f.fold_tuple_type(e.ty);
+    TupleExpression {
+        inner: f.fold_tuple_expression_inner(&ty, e.inner),
+        ty,
+    }
+}
+
+pub fn fold_enum_expression<'ast, T: Field, F: Folder<'ast, T>>(
+    f: &mut F,
+    e: EnumExpression<'ast, T>,
+) -> EnumExpression<'ast, T> {
+    let ty = f.fold_enum_type(e.ty);
+    EnumExpression {
+        inner: f.fold_enum_expression_inner(&ty, e.inner),
+        ty,
+    }
+}
+
 pub fn fold_constant<'ast, T: Field, F: Folder<'ast, T>>(
     f: &mut F,
     c: TypedConstant<'ast, T>,
@@ -1157,6 +1439,9 @@ pub fn fold_assignee<'ast, T: Field, F: Folder<'ast, T>>(
             TypedAssignee::Select(box f.fold_assignee(a), box f.fold_uint_expression(index))
         }
         TypedAssignee::Member(box s, m) => TypedAssignee::Member(box f.fold_assignee(s), m),
+        TypedAssignee::Element(box s, index) => {
+            TypedAssignee::Element(box f.fold_assignee(s), index)
+        }
     }
 }
 
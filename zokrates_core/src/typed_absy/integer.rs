@@ -1,14 +1,15 @@
 use crate::typed_absy::types::{
-    ArrayType, DeclarationArrayType, DeclarationConstant, DeclarationStructMember,
-    DeclarationStructType, DeclarationType, GArrayType, GStructType, GType, GenericIdentifier,
-    StructType, Type,
+    ArrayType, DeclarationArrayType, DeclarationConstant, DeclarationEnumType,
+    DeclarationEnumVariant, DeclarationStructMember, DeclarationStructType, DeclarationTupleType,
+    DeclarationType, EnumType, GArrayType, GEnumType, GStructType, GTupleType, GType,
+    GenericIdentifier, StructType, TupleType, Type,
 };
 use crate::typed_absy::UBitwidth;
 use crate::typed_absy::{
     ArrayExpression, ArrayExpressionInner, BooleanExpression, Conditional, ConditionalExpression,
-    Expr, FieldElementExpression, Select, SelectExpression, StructExpression,
-    StructExpressionInner, Typed, TypedExpression, TypedExpressionOrSpread, TypedSpread,
-    UExpression, UExpressionInner,
+    EnumExpression, Expr, FieldElementExpression, Select, SelectExpression, StructExpression,
+    StructExpressionInner, TupleExpression, TupleExpressionInner, Typed, TypedExpression,
+    TypedExpressionOrSpread, TypedSpread, UExpression, UExpressionInner,
 };
 use num_bigint::BigUint;
 use std::convert::TryFrom;
@@ -67,6 +68,14 @@ impl<'ast, T> IntegerInference for Type<'ast, T> {
                 t.get_common_pattern(u)
                     .map_err(|(t, u)| (Type::Struct(t), Type::Struct(u)))?,
             )),
+            (Type::Tuple(t), Type::Tuple(u)) => Ok(DeclarationType::Tuple(
+                t.get_common_pattern(u)
+                    .map_err(|(t, u)| (Type::Tuple(t), Type::Tuple(u)))?,
+            )),
+            (Type::Enum(t), Type::Enum(u)) => Ok(DeclarationType::Enum(
+                t.get_common_pattern(u)
+                    .map_err(|(t, u)| (Type::Enum(t), Type::Enum(u)))?,
+            )),
             (t, u) => Err((t, u)),
         }
     }
@@ -120,6 +129,60 @@ impl<'ast, T> IntegerInference for StructType<'ast, T> {
     }
 }
 
+impl<'ast, T> IntegerInference for TupleType<'ast, T> {
+    type Pattern = DeclarationTupleType<'ast, T>;
+
+    fn get_common_pattern(self, other: Self) -> Result<Self::Pattern, (Self, Self)> {
+        if self.elements.len() != other.elements.len() {
+            return Err((self, other));
+        }
+
+        self.elements
+            .iter()
+            .cloned()
+            .zip(other.elements.iter().cloned())
+            .map(|(t, u)| t.get_common_pattern(u))
+            .collect::<Result<Vec<_>, _>>()
+            .map(DeclarationTupleType::new)
+            .map_err(|_| (self, other))
+    }
+}
+
+impl<'ast, T> IntegerInference for EnumType<'ast, T> {
+    type Pattern = DeclarationEnumType<'ast, T>;
+
+    fn get_common_pattern(self, other: Self) -> Result<Self::Pattern, (Self, Self)> {
+        if self.canonical_location != other.canonical_location {
+            return Err((self, other));
+        }
+
+        // payloads are aligned to the declared types when the enum value is built, so they never contain integers
+        Ok(DeclarationEnumType {
+            variants: self
+                .variants
+                .into_iter()
+                .zip(other.variants.into_iter())
+                .map(|(v_t, v_u)| DeclarationEnumVariant {
+                    payload: v_t
+                        .payload
+                        .into_iter()
+                        .zip(v_u.payload.into_iter())
+                        .map(|(t, u)| match t.get_common_pattern(u) {
+                            Ok(ty) => ty,
+                            Err(..) => unreachable!(
+                                "enum instances of the same enum should always have a common type"
+                            ),
+                        })
+                        .collect(),
+                    id: v_t.id,
+                })
+                .collect(),
+            canonical_location: self.canonical_location,
+            location: self.location,
+        })
+    }
+}
+
 impl<'ast, T: Field> TypedExpression<'ast, T> {
     // return two TypedExpression, replacing IntExpression by FieldElement or Uint to try to align the two types if possible.
     // Post condition is that (lhs, rhs) cannot be made equal by further removing IntExpressions
@@ -197,6 +260,33 @@ impl<'ast, T: Field> TypedExpression<'ast, T> {
                         .into(),
                 ))
             }
+            (Tuple(lhs), Tuple(rhs)) => {
+                let common_type = lhs
+                    .get_type()
+                    .get_common_pattern(rhs.get_type())
+                    .map_err(|_| (lhs.clone().into(), rhs.clone().into()))?;
+
+                let common_type = match common_type {
+                    DeclarationType::Tuple(ty) => ty,
+                    _ => unreachable!(),
+                };
+
+                Ok((
+                    TupleExpression::try_from_int(lhs.clone(), &common_type)
+                        .map_err(|lhs| (lhs.clone(), rhs.clone().into()))?
+                        .into(),
+                    TupleExpression::try_from_int(rhs, &common_type)
+                        .map_err(|rhs| (lhs.clone().into(), rhs.clone()))?
+                        .into(),
+                ))
+            }
+            (Enum(lhs), Enum(rhs)) => {
+                if lhs.ty().canonical_location == rhs.ty().canonical_location {
+                    Ok((lhs.into(), rhs.into()))
+                } else {
+                    Err((lhs.into(), rhs.into()))
+                }
+            }
             (Uint(lhs), Uint(rhs)) => Ok((lhs.into(), rhs.into())),
             (Boolean(lhs), Boolean(rhs)) => Ok((lhs.into(), rhs.into())),
             (FieldElement(lhs), FieldElement(rhs)) => Ok((lhs.into(), rhs.into())),
@@ -223,6 +313,12 @@ impl<'ast, T: Field> TypedExpression<'ast, T> {
             GType::Struct(struct_ty) => {
                 StructExpression::try_from_typed(e, struct_ty).map(TypedExpression::from)
             }
+            GType::Tuple(tuple_ty) => {
+                TupleExpression::try_from_typed(e, tuple_ty).map(TypedExpression::from)
+            }
+            GType::Enum(enum_ty) => {
+                EnumExpression::try_from_typed(e, enum_ty).map(TypedExpression::from)
+            }
             GType::Int => Err(e),
         }
         .map_err(|e| (e, ty))
@@ -478,7 +574,7 @@ impl<'ast, T: Field> UExpression<'ast, T> {
 
         match i {
             Value(i) => {
-                if i <= BigUint::from(2u128.pow(bitwidth.to_usize() as u32) - 1) {
+                if i <= BigUint::from(bitwidth.max_value()) {
                     Ok(UExpressionInner::Value(
                         u128::from_str_radix(&i.to_str_radix(16), 16).unwrap(),
                     )
@@ -687,6 +783,82 @@ impl<'ast, T: Field> StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> TupleExpression<'ast, T> {
+    pub fn try_from_int<S: PartialEq<UExpression<'ast, T>>>(
+        tuple: Self,
+        target_tuple_ty: &GTupleType<S>,
+    ) -> Result<Self, TypedExpression<'ast, T>> {
+        let tuple_ty = tuple.ty().clone();
+
+        if tuple_ty.elements.len() != target_tuple_ty.elements.len() {
+            return Err(tuple.into());
+        }
+
+        match tuple.into_inner() {
+            // inline tuples may contain integers, so we align each element and recompute the type
+            TupleExpressionInner::Value(inline_tuple) => inline_tuple
+                .into_iter()
+                .zip(target_tuple_ty.elements.iter())
+                .map(|(value, target_ty)| match target_ty {
+                    GType::Int => Ok(value),
+                    _ => TypedExpression::align_to_type(value, target_ty),
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|v| {
+                    let ty = TupleType::new(v.iter().map(|e| e.get_type()).collect());
+                    TupleExpressionInner::Value(v).annotate(ty)
+                })
+                .map_err(|(v, _)| v),
+            t => {
+                if tuple_ty
+                    .elements
+                    .iter()
+                    .zip(target_tuple_ty.elements.iter())
+                    .all(|(e, target_e)| *target_e == *e)
+                {
+                    Ok(t.annotate(tuple_ty))
+                } else {
+                    Err(t.annotate(tuple_ty).into())
+                }
+            }
+        }
+    }
+
+    pub fn try_from_typed<S: PartialEq<UExpression<'ast, T>>>(
+        e: TypedExpression<'ast, T>,
+        target_tuple_ty: &GTupleType<S>,
+    ) -> Result<Self, TypedExpression<'ast, T>> {
+        match e {
+            TypedExpression::Tuple(e) => Self::try_from_int(e, target_tuple_ty),
+            e => Err(e),
+        }
+    }
+}
+
+impl<'ast, T: Field> EnumExpression<'ast, T> {
+    pub fn try_from_int<S>(
+        e: Self,
+        target_enum_ty: &GEnumType<S>,
+    ) -> Result<Self, TypedExpression<'ast, T>> {
+        // the payloads of enum values are aligned when they are built, so only the enum itself needs to match
+        if e.ty().canonical_location == target_enum_ty.canonical_location {
+            Ok(e)
+        } else {
+            Err(e.into())
+        }
+    }
+
+    pub fn try_from_typed<S>(
+        e: TypedExpression<'ast, T>,
+        target_enum_ty: &GEnumType<S>,
+    ) -> Result<Self, TypedExpression<'ast, T>> {
+        match e {
+            TypedExpression::Enum(e) => Self::try_from_int(e, target_enum_ty),
+            e => Err(e),
+        }
+    }
+}
+
 impl<'ast, T> From<BigUint> for IntExpression<'ast, T> {
     fn from(v: BigUint) -> Self {
         IntExpression::Value(v)
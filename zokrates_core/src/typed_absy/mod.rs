@@ -20,12 +20,16 @@ pub use self::identifier::CoreIdentifier;
 pub use self::parameter::{DeclarationParameter, GParameter};
 pub use self::types::{
     CanonicalConstantIdentifier, ConcreteFunctionKey, ConcreteSignature, ConcreteType,
-    ConstantIdentifier, DeclarationArrayType, DeclarationConstant, DeclarationFunctionKey,
-    DeclarationSignature, DeclarationStructType, DeclarationType, GArrayType, GStructType, GType,
-    GenericIdentifier, IntoTypes, Signature, StructType, Type, Types, UBitwidth,
+    ConstantIdentifier, DeclarationArrayType, DeclarationConstant, DeclarationEnumType,
+    DeclarationEnumVariant, DeclarationFunctionKey, DeclarationSignature, DeclarationStructType,
+    DeclarationTupleType, DeclarationType, EnumType, GArrayType, GEnumType, GEnumVariant,
+    GStructType, GTupleType, GType, GenericIdentifier, IntoTypes, Signature, StructType, TupleType,
+    Type, Types, UBitwidth,
 };
+use crate::flat_absy::SourceSpan;
 use crate::parser::Position;
 use crate::typed_absy::types::ConcreteGenericsAssignment;
+use serde::{Deserialize, Serialize};
 
 pub use self::variable::{ConcreteVariable, DeclarationVariable, GVariable, Variable};
 use std::marker::PhantomData;
@@ -429,6 +433,7 @@ pub enum TypedAssignee<'ast, T> {
     Identifier(Variable<'ast, T>),
     Select(Box<TypedAssignee<'ast, T>>, Box<UExpression<'ast, T>>),
     Member(Box<TypedAssignee<'ast, T>>, MemberId),
+    Element(Box<TypedAssignee<'ast, T>>, u32),
 }
 
 #[derive(Clone, PartialEq, Hash, Eq, Debug, PartialOrd, Ord)]
@@ -509,6 +514,18 @@ impl<'ast, T> From<StructExpression<'ast, T>> for TypedExpressionOrSpread<'ast,
     }
 }
 
+impl<'ast, T> From<TupleExpression<'ast, T>> for TypedExpressionOrSpread<'ast, T> {
+    fn from(e: TupleExpression<'ast, T>) -> Self {
+        TypedExpressionOrSpread::Expression(e.into())
+    }
+}
+
+impl<'ast, T> From<EnumExpression<'ast, T>> for TypedExpressionOrSpread<'ast, T> {
+    fn from(e: EnumExpression<'ast, T>) -> Self {
+        TypedExpressionOrSpread::Expression(e.into())
+    }
+}
+
 impl<'ast, T> From<TypedExpression<'ast, T>> for TypedExpressionOrSpread<'ast, T> {
     fn from(e: TypedExpression<'ast, T>) -> Self {
         TypedExpressionOrSpread::Expression(e)
@@ -562,6 +579,13 @@ impl<'ast, T: Clone> Typed<'ast, T> for TypedAssignee<'ast, T> {
                     _ => unreachable!("a struct access should only be defined over structs"),
                 }
             }
+            TypedAssignee::Element(ref t, index) => {
+                let t_type = t.get_type();
+                match t_type {
+                    Type::Tuple(tuple_ty) => tuple_ty.elements[index as usize].clone(),
+                    _ => unreachable!("a tuple access should only be defined over tuples"),
+                }
+            }
         }
     }
 }
@@ -572,6 +596,7 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedAssignee<'ast, T> {
             TypedAssignee::Identifier(ref s) => write!(f, "{}", s.id),
             TypedAssignee::Select(ref a, ref e) => write!(f, "{}[{}]", a, e),
             TypedAssignee::Member(ref s, ref m) => write!(f, "{}.{}", s, m),
+            TypedAssignee::Element(ref t, index) => write!(f, "{}.{}", t, index),
         }
     }
 }
@@ -608,6 +633,44 @@ impl fmt::Display for RuntimeError {
     }
 }
 
+/// The format string of a `log` statement, split around its `{}` placeholders
+#[derive(Clone, Debug, PartialEq, Hash, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct FormatString {
+    pub parts: Vec<String>,
+}
+
+impl FormatString {
+    /// The number of values expected by this format string
+    pub fn placeholder_count(&self) -> usize {
+        self.parts.len() - 1
+    }
+
+    /// Replace the placeholders of this format string with `values`, in order
+    pub fn format<I: IntoIterator<Item = String>>(&self, values: I) -> String {
+        let mut parts = self.parts.iter();
+        let mut res = parts.next().unwrap().clone();
+        for (value, part) in values.into_iter().zip(parts) {
+            res.push_str(&value);
+            res.push_str(part);
+        }
+        res
+    }
+}
+
+impl From<&str> for FormatString {
+    fn from(s: &str) -> Self {
+        FormatString {
+            parts: s.split("{}").map(|p| p.to_string()).collect(),
+        }
+    }
+}
+
+impl fmt::Display for FormatString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.parts.join("{}"))
+    }
+}
+
 /// A statement in a `TypedFunction`
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, PartialEq, Debug, Hash, Eq, PartialOrd, Ord)]
@@ -622,13 +685,20 @@ pub enum TypedStatement<'ast, T> {
         UExpression<'ast, T>,
         Vec<TypedStatement<'ast, T>>,
     ),
+    IfElse(
+        BooleanExpression<'ast, T>,
+        Vec<TypedStatement<'ast, T>>,
+        Vec<TypedStatement<'ast, T>>,
+    ),
     MultipleDefinition(Vec<TypedAssignee<'ast, T>>, TypedExpressionList<'ast, T>),
+    Log(FormatString, Vec<TypedExpression<'ast, T>>),
     // Aux
     PushCallLog(
         DeclarationFunctionKey<'ast, T>,
         ConcreteGenericsAssignment<'ast>,
     ),
     PopCallLog,
+    Span(SourceSpan),
 }
 
 impl<'ast, T: fmt::Display> TypedStatement<'ast, T> {
@@ -643,6 +713,20 @@ impl<'ast, T: fmt::Display> TypedStatement<'ast, T> {
                 }
                 write!(f, "{}endfor", "\t".repeat(depth))
             }
+            TypedStatement::IfElse(condition, consequence, alternative) => {
+                write!(f, "{}", "\t".repeat(depth))?;
+                writeln!(f, "if {} {{", condition)?;
+                for s in consequence {
+                    s.fmt_indented(f, depth + 1)?;
+                    writeln!(f)?;
+                }
+                writeln!(f, "{}}} else {{", "\t".repeat(depth))?;
+                for s in alternative {
+                    s.fmt_indented(f, depth + 1)?;
+                    writeln!(f)?;
+                }
+                write!(f, "{}}}", "\t".repeat(depth))
+            }
             s => write!(f, "{}{}", "\t".repeat(depth), s),
         }
     }
@@ -680,6 +764,17 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedStatement<'ast, T> {
                 }
                 write!(f, "\tendfor")
             }
+            TypedStatement::IfElse(ref condition, ref consequence, ref alternative) => {
+                writeln!(f, "if {} {{", condition)?;
+                for s in consequence {
+                    writeln!(f, "\t\t{}", s)?;
+                }
+                writeln!(f, "\t}} else {{")?;
+                for s in alternative {
+                    writeln!(f, "\t\t{}", s)?;
+                }
+                write!(f, "\t}}")
+            }
             TypedStatement::MultipleDefinition(ref ids, ref rhs) => {
                 for (i, id) in ids.iter().enumerate() {
                     write!(f, "{}", id)?;
@@ -689,6 +784,13 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedStatement<'ast, T> {
                 }
                 write!(f, " = {}", rhs)
             }
+            TypedStatement::Log(ref format_string, ref expressions) => {
+                write!(f, "log(\"{}\"", format_string)?;
+                for e in expressions {
+                    write!(f, ", {}", e)?;
+                }
+                write!(f, ")")
+            }
             TypedStatement::PushCallLog(ref key, ref generics) => write!(
                 f,
                 "// PUSH CALL TO {}/{}::<{}>",
@@ -697,6 +799,7 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedStatement<'ast, T> {
                 generics,
             ),
             TypedStatement::PopCallLog => write!(f, "// POP CALL",),
+            TypedStatement::Span(ref span) => write!(f, "// {}", span),
         }
     }
 }
@@ -714,6 +817,8 @@ pub enum TypedExpression<'ast, T> {
     Uint(UExpression<'ast, T>),
     Array(ArrayExpression<'ast, T>),
     Struct(StructExpression<'ast, T>),
+    Tuple(TupleExpression<'ast, T>),
+    Enum(EnumExpression<'ast, T>),
     Int(IntExpression<'ast, T>),
 }
 
@@ -753,6 +858,18 @@ impl<'ast, T> From<StructExpression<'ast, T>> for TypedExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> From<TupleExpression<'ast, T>> for TypedExpression<'ast, T> {
+    fn from(e: TupleExpression<'ast, T>) -> TypedExpression<T> {
+        TypedExpression::Tuple(e)
+    }
+}
+
+impl<'ast, T> From<EnumExpression<'ast, T>> for TypedExpression<'ast, T> {
+    fn from(e: EnumExpression<'ast, T>) -> TypedExpression<T> {
+        TypedExpression::Enum(e)
+    }
+}
+
 impl<'ast, T: fmt::Display> fmt::Display for TypedExpression<'ast, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -761,6 +878,8 @@ impl<'ast, T: fmt::Display> fmt::Display for TypedExpression<'ast, T> {
             TypedExpression::Uint(ref e) => write!(f, "{}", e),
             TypedExpression::Array(ref e) => write!(f, "{}", e),
             TypedExpression::Struct(ref s) => write!(f, "{}", s),
+            TypedExpression::Tuple(ref t) => write!(f, "{}", t),
+            TypedExpression::Enum(ref e) => write!(f, "{}", e),
             TypedExpression::Int(ref s) => write!(f, "{}", s),
         }
     }
@@ -795,6 +914,75 @@ impl<'ast, T: fmt::Display> fmt::Display for StructExpression<'ast, T> {
             StructExpressionInner::Conditional(ref c) => write!(f, "{}", c),
             StructExpressionInner::Member(ref m) => write!(f, "{}", m),
             StructExpressionInner::Select(ref select) => write!(f, "{}", select),
+            StructExpressionInner::Element(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<'ast, T: fmt::Display> fmt::Display for TupleExpression<'ast, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.inner {
+            TupleExpressionInner::Block(ref block) => write!(f, "{}", block),
+            TupleExpressionInner::Identifier(ref var) => write!(f, "{}", var),
+            TupleExpressionInner::Value(ref values) => {
+                write!(f, "(")?;
+                for (i, v) in values.iter().enumerate() {
+                    write!(f, "{}", v)?;
+                    if i < values.len() - 1 || values.len() == 1 {
+                        write!(f, ",")?;
+                    }
+                    if i < values.len() - 1 {
+                        write!(f, " ")?;
+                    }
+                }
+                write!(f, ")")
+            }
+            TupleExpressionInner::FunctionCall(ref function_call) => {
+                write!(f, "{}", function_call)
+            }
+            TupleExpressionInner::Conditional(ref c) => write!(f, "{}", c),
+            TupleExpressionInner::Member(ref m) => write!(f, "{}", m),
+            TupleExpressionInner::Select(ref select) => write!(f, "{}", select),
+            TupleExpressionInner::Element(ref e) => write!(f, "{}", e),
+            TupleExpressionInner::Payload(ref e, variant) => {
+                write!(f, "{}.{}", e, e.ty.variants[variant as usize].id)
+            }
+        }
+    }
+}
+
+impl<'ast, T: fmt::Display> fmt::Display for EnumExpression<'ast, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.inner {
+            EnumExpressionInner::Block(ref block) => write!(f, "{}", block),
+            EnumExpressionInner::Identifier(ref var) => write!(f, "{}", var),
+            EnumExpressionInner::Value(variant, ref payload) => {
+                write!(
+                    f,
+                    "{}::{}",
+                    self.ty.name(),
+                    self.ty.variants[variant as usize].id
+                )?;
+                if !payload.is_empty() {
+                    write!(
+                        f,
+                        "({})",
+                        payload
+                            .iter()
+                            .map(|e| e.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+            EnumExpressionInner::FunctionCall(ref function_call) => {
+                write!(f, "{}", function_call)
+            }
+            EnumExpressionInner::Conditional(ref c) => write!(f, "{}", c),
+            EnumExpressionInner::Member(ref m) => write!(f, "{}", m),
+            EnumExpressionInner::Select(ref select) => write!(f, "{}", select),
+            EnumExpressionInner::Element(ref e) => write!(f, "{}", e),
         }
     }
 }
@@ -807,6 +995,8 @@ impl<'ast, T: Clone> Typed<'ast, T> for TypedExpression<'ast, T> {
             TypedExpression::Array(ref e) => e.get_type(),
             TypedExpression::Uint(ref e) => e.get_type(),
             TypedExpression::Struct(ref s) => s.get_type(),
+            TypedExpression::Tuple(ref t) => t.get_type(),
+            TypedExpression::Enum(ref e) => e.get_type(),
             TypedExpression::Int(_) => Type::Int,
         }
     }
@@ -824,6 +1014,18 @@ impl<'ast, T: Clone> Typed<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Clone> Typed<'ast, T> for TupleExpression<'ast, T> {
+    fn get_type(&self) -> Type<'ast, T> {
+        Type::Tuple(self.ty.clone())
+    }
+}
+
+impl<'ast, T: Clone> Typed<'ast, T> for EnumExpression<'ast, T> {
+    fn get_type(&self) -> Type<'ast, T> {
+        Type::Enum(self.ty.clone())
+    }
+}
+
 impl<'ast, T: Clone> Typed<'ast, T> for FieldElementExpression<'ast, T> {
     fn get_type(&self) -> Type<'ast, T> {
         Type::FieldElement
@@ -914,6 +1116,29 @@ impl<'ast, T: fmt::Display, E> fmt::Display for MemberExpression<'ast, T, E> {
     }
 }
 
+#[derive(Clone, PartialEq, Debug, Hash, Eq, PartialOrd, Ord)]
+pub struct ElementExpression<'ast, T, E> {
+    pub tuple: Box<TupleExpression<'ast, T>>,
+    pub index: u32,
+    ty: PhantomData<E>,
+}
+
+impl<'ast, T, E> ElementExpression<'ast, T, E> {
+    pub fn new(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        ElementExpression {
+            tuple: box tuple,
+            index,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<'ast, T: fmt::Display, E> fmt::Display for ElementExpression<'ast, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.tuple, self.index)
+    }
+}
+
 #[derive(Clone, PartialEq, Debug, Hash, Eq, PartialOrd, Ord)]
 pub struct SelectExpression<'ast, T, E> {
     pub array: Box<ArrayExpression<'ast, T>>,
@@ -1068,6 +1293,7 @@ pub enum FieldElementExpression<'ast, T> {
     FunctionCall(FunctionCallExpression<'ast, T, Self>),
     Member(MemberExpression<'ast, T, Self>),
     Select(SelectExpression<'ast, T, Self>),
+    Element(ElementExpression<'ast, T, Self>),
 }
 impl<'ast, T> Add for FieldElementExpression<'ast, T> {
     type Output = Self;
@@ -1152,6 +1378,9 @@ pub enum BooleanExpression<'ast, T> {
         Box<StructExpression<'ast, T>>,
         Box<StructExpression<'ast, T>>,
     ),
+    TupleEq(Box<TupleExpression<'ast, T>>, Box<TupleExpression<'ast, T>>),
+    EnumEq(Box<EnumExpression<'ast, T>>, Box<EnumExpression<'ast, T>>),
+    IsVariant(Box<EnumExpression<'ast, T>>, u32),
     UintEq(Box<UExpression<'ast, T>>, Box<UExpression<'ast, T>>),
     Or(
         Box<BooleanExpression<'ast, T>>,
@@ -1166,6 +1395,7 @@ pub enum BooleanExpression<'ast, T> {
     Member(MemberExpression<'ast, T, Self>),
     FunctionCall(FunctionCallExpression<'ast, T, Self>),
     Select(SelectExpression<'ast, T, Self>),
+    Element(ElementExpression<'ast, T, Self>),
 }
 
 impl<'ast, T> From<bool> for BooleanExpression<'ast, T> {
@@ -1272,6 +1502,7 @@ pub enum ArrayExpressionInner<'ast, T> {
     Conditional(ConditionalExpression<'ast, T, ArrayExpression<'ast, T>>),
     Member(MemberExpression<'ast, T, ArrayExpression<'ast, T>>),
     Select(SelectExpression<'ast, T, ArrayExpression<'ast, T>>),
+    Element(ElementExpression<'ast, T, ArrayExpression<'ast, T>>),
     Slice(
         Box<ArrayExpression<'ast, T>>,
         Box<UExpression<'ast, T>>,
@@ -1336,6 +1567,7 @@ pub enum StructExpressionInner<'ast, T> {
     Conditional(ConditionalExpression<'ast, T, StructExpression<'ast, T>>),
     Member(MemberExpression<'ast, T, StructExpression<'ast, T>>),
     Select(SelectExpression<'ast, T, StructExpression<'ast, T>>),
+    Element(ElementExpression<'ast, T, StructExpression<'ast, T>>),
 }
 
 impl<'ast, T> StructExpressionInner<'ast, T> {
@@ -1344,6 +1576,106 @@ impl<'ast, T> StructExpressionInner<'ast, T> {
     }
 }
 
+/// An expression of type `tuple`
+/// # Remarks
+/// * As for structs, the type of the elements is kept alongside the inner expression
+#[derive(Clone, PartialEq, Debug, Hash, Eq, PartialOrd, Ord)]
+pub struct TupleExpression<'ast, T> {
+    ty: TupleType<'ast, T>,
+    inner: TupleExpressionInner<'ast, T>,
+}
+
+impl<'ast, T> TupleExpression<'ast, T> {
+    pub fn ty(&self) -> &TupleType<'ast, T> {
+        &self.ty
+    }
+
+    pub fn as_inner(&self) -> &TupleExpressionInner<'ast, T> {
+        &self.inner
+    }
+
+    pub fn as_inner_mut(&mut self) -> &mut TupleExpressionInner<'ast, T> {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> TupleExpressionInner<'ast, T> {
+        self.inner
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Hash, Eq, PartialOrd, Ord)]
+pub enum TupleExpressionInner<'ast, T> {
+    Block(BlockExpression<'ast, T, TupleExpression<'ast, T>>),
+    Identifier(Identifier<'ast>),
+    Value(Vec<TypedExpression<'ast, T>>),
+    FunctionCall(FunctionCallExpression<'ast, T, TupleExpression<'ast, T>>),
+    Conditional(ConditionalExpression<'ast, T, TupleExpression<'ast, T>>),
+    Member(MemberExpression<'ast, T, TupleExpression<'ast, T>>),
+    Select(SelectExpression<'ast, T, TupleExpression<'ast, T>>),
+    Element(ElementExpression<'ast, T, TupleExpression<'ast, T>>),
+    Payload(Box<EnumExpression<'ast, T>>, u32),
+}
+
+impl<'ast, T> TupleExpressionInner<'ast, T> {
+    pub fn annotate(self, ty: TupleType<'ast, T>) -> TupleExpression<'ast, T> {
+        TupleExpression { ty, inner: self }
+    }
+}
+
+impl<'ast, T: Clone> TupleExpression<'ast, T> {
+    /// The payload of `variant` in `e` as a tuple. Only meaningful if `e` is of that variant.
+    pub fn payload(e: EnumExpression<'ast, T>, variant: u32) -> Self {
+        let ty = TupleType::new(e.ty().variants[variant as usize].payload.clone());
+        TupleExpressionInner::Payload(box e, variant).annotate(ty)
+    }
+}
+
+/// An expression of type `enum`
+/// # Remarks
+/// * As for structs, the type of the variants is kept alongside the inner expression
+#[derive(Clone, PartialEq, Debug, Hash, Eq, PartialOrd, Ord)]
+pub struct EnumExpression<'ast, T> {
+    ty: EnumType<'ast, T>,
+    inner: EnumExpressionInner<'ast, T>,
+}
+
+impl<'ast, T> EnumExpression<'ast, T> {
+    pub fn ty(&self) -> &EnumType<'ast, T> {
+        &self.ty
+    }
+
+    pub fn as_inner(&self) -> &EnumExpressionInner<'ast, T> {
+        &self.inner
+    }
+
+    pub fn as_inner_mut(&mut self) -> &mut EnumExpressionInner<'ast, T> {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> EnumExpressionInner<'ast, T> {
+        self.inner
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Hash, Eq, PartialOrd, Ord)]
+pub enum EnumExpressionInner<'ast, T> {
+    Block(BlockExpression<'ast, T, EnumExpression<'ast, T>>),
+    Identifier(Identifier<'ast>),
+    /// The index of the variant, and the values of its payload
+    Value(u32, Vec<TypedExpression<'ast, T>>),
+    FunctionCall(FunctionCallExpression<'ast, T, EnumExpression<'ast, T>>),
+    Conditional(ConditionalExpression<'ast, T, EnumExpression<'ast, T>>),
+    Member(MemberExpression<'ast, T, EnumExpression<'ast, T>>),
+    Select(SelectExpression<'ast, T, EnumExpression<'ast, T>>),
+    Element(ElementExpression<'ast, T, EnumExpression<'ast, T>>),
+}
+
+impl<'ast, T> EnumExpressionInner<'ast, T> {
+    pub fn annotate(self, ty: EnumType<'ast, T>) -> EnumExpression<'ast, T> {
+        EnumExpression { ty, inner: self }
+    }
+}
+
 // Downcasts
 // Due to the fact that we keep TypedExpression simple, we end up with ArrayExpressionInner::Value whose elements are any TypedExpression, but we enforce by
 // construction that these elements are of the type declared in the corresponding ArrayExpression. As we know this by construction, we can downcast the TypedExpression to the correct type
@@ -1402,6 +1734,24 @@ impl<'ast, T> From<TypedExpression<'ast, T>> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> From<TypedExpression<'ast, T>> for TupleExpression<'ast, T> {
+    fn from(te: TypedExpression<'ast, T>) -> TupleExpression<'ast, T> {
+        match te {
+            TypedExpression::Tuple(e) => e,
+            _ => unreachable!("downcast failed"),
+        }
+    }
+}
+
+impl<'ast, T> From<TypedExpression<'ast, T>> for EnumExpression<'ast, T> {
+    fn from(te: TypedExpression<'ast, T>) -> EnumExpression<'ast, T> {
+        match te {
+            TypedExpression::Enum(e) => e,
+            _ => unreachable!("downcast failed"),
+        }
+    }
+}
+
 // `TypedExpressionList` can technically not be constructed from `TypedExpression`
 // However implementing `From<TypedExpression>` is required for `TypedExpressionList` to be `Expr`, which makes generic treatment of function calls possible
 // This could maybe be avoided by splitting the `Expr` trait into many, but I did not find a way
@@ -1441,6 +1791,18 @@ impl<'ast, T> From<TypedConstant<'ast, T>> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T> From<TypedConstant<'ast, T>> for TupleExpression<'ast, T> {
+    fn from(tc: TypedConstant<'ast, T>) -> TupleExpression<'ast, T> {
+        tc.expression.into()
+    }
+}
+
+impl<'ast, T> From<TypedConstant<'ast, T>> for EnumExpression<'ast, T> {
+    fn from(tc: TypedConstant<'ast, T>) -> EnumExpression<'ast, T> {
+        tc.expression.into()
+    }
+}
+
 impl<'ast, T> From<TypedConstant<'ast, T>> for IntExpression<'ast, T> {
     fn from(tc: TypedConstant<'ast, T>) -> IntExpression<'ast, T> {
         tc.expression.into()
@@ -1481,6 +1843,7 @@ impl<'ast, T: fmt::Display> fmt::Display for FieldElementExpression<'ast, T> {
             }
             FieldElementExpression::Member(ref m) => write!(f, "{}", m),
             FieldElementExpression::Select(ref select) => write!(f, "{}", select),
+            FieldElementExpression::Element(ref element) => write!(f, "{}", element),
         }
     }
 }
@@ -1489,6 +1852,9 @@ impl<'ast, T: fmt::Display> fmt::Display for UExpression<'ast, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.inner {
             UExpressionInner::Block(ref block) => write!(f, "{}", block,),
+            UExpressionInner::Value(ref v) if self.bitwidth.is_signed() => {
+                write!(f, "{}", self.bitwidth.to_signed(*v))
+            }
             UExpressionInner::Value(ref v) => write!(f, "{}", v),
             UExpressionInner::Identifier(ref var) => write!(f, "{}", var),
             UExpressionInner::Add(ref lhs, ref rhs) => write!(f, "({} + {})", lhs, rhs),
@@ -1511,6 +1877,7 @@ impl<'ast, T: fmt::Display> fmt::Display for UExpression<'ast, T> {
             UExpressionInner::FunctionCall(ref function_call) => write!(f, "{}", function_call),
             UExpressionInner::Conditional(ref c) => write!(f, "{}", c),
             UExpressionInner::Member(ref m) => write!(f, "{}", m),
+            UExpressionInner::Element(ref element) => write!(f, "{}", element),
         }
     }
 }
@@ -1532,6 +1899,15 @@ impl<'ast, T: fmt::Display> fmt::Display for BooleanExpression<'ast, T> {
             BooleanExpression::BoolEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::ArrayEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::StructEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
+            BooleanExpression::TupleEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
+            BooleanExpression::EnumEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
+            BooleanExpression::IsVariant(ref e, variant) => write!(
+                f,
+                "{} is {}::{}",
+                e,
+                e.ty.name(),
+                e.ty.variants[variant as usize].id
+            ),
             BooleanExpression::UintEq(ref lhs, ref rhs) => write!(f, "{} == {}", lhs, rhs),
             BooleanExpression::Or(ref lhs, ref rhs) => write!(f, "{} || {}", lhs, rhs),
             BooleanExpression::And(ref lhs, ref rhs) => write!(f, "{} && {}", lhs, rhs),
@@ -1541,6 +1917,7 @@ impl<'ast, T: fmt::Display> fmt::Display for BooleanExpression<'ast, T> {
             BooleanExpression::Conditional(ref c) => write!(f, "{}", c),
             BooleanExpression::Member(ref m) => write!(f, "{}", m),
             BooleanExpression::Select(ref select) => write!(f, "{}", select),
+            BooleanExpression::Element(ref element) => write!(f, "{}", element),
         }
     }
 }
@@ -1563,6 +1940,7 @@ impl<'ast, T: fmt::Display> fmt::Display for ArrayExpressionInner<'ast, T> {
             ArrayExpressionInner::Conditional(ref c) => write!(f, "{}", c),
             ArrayExpressionInner::Member(ref m) => write!(f, "{}", m),
             ArrayExpressionInner::Select(ref select) => write!(f, "{}", select),
+            ArrayExpressionInner::Element(ref element) => write!(f, "{}", element),
             ArrayExpressionInner::Slice(ref a, ref from, ref to) => {
                 write!(f, "{}[{}..{}]", a, from, to)
             }
@@ -1616,6 +1994,8 @@ impl<'ast, T: Field> From<Variable<'ast, T>> for TypedExpression<'ast, T> {
                 .annotate(*ty.ty, ty.size)
                 .into(),
             Type::Struct(ty) => StructExpressionInner::Identifier(v.id).annotate(ty).into(),
+            Type::Tuple(ty) => TupleExpressionInner::Identifier(v.id).annotate(ty).into(),
+            Type::Enum(ty) => EnumExpressionInner::Identifier(v.id).annotate(ty).into(),
             Type::Uint(w) => UExpressionInner::Identifier(v.id).annotate(w).into(),
             Type::Int => unreachable!(),
         }
@@ -1721,6 +2101,48 @@ impl<'ast, T: Clone> Expr<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Clone> Expr<'ast, T> for TupleExpression<'ast, T> {
+    type Inner = TupleExpressionInner<'ast, T>;
+    type Ty = TupleType<'ast, T>;
+
+    fn ty(&self) -> &Self::Ty {
+        &self.ty
+    }
+
+    fn into_inner(self) -> Self::Inner {
+        self.inner
+    }
+
+    fn as_inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn as_inner_mut(&mut self) -> &mut Self::Inner {
+        &mut self.inner
+    }
+}
+
+impl<'ast, T: Clone> Expr<'ast, T> for EnumExpression<'ast, T> {
+    type Inner = EnumExpressionInner<'ast, T>;
+    type Ty = EnumType<'ast, T>;
+
+    fn ty(&self) -> &Self::Ty {
+        &self.ty
+    }
+
+    fn into_inner(self) -> Self::Inner {
+        self.inner
+    }
+
+    fn as_inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn as_inner_mut(&mut self) -> &mut Self::Inner {
+        &mut self.inner
+    }
+}
+
 impl<'ast, T: Clone> Expr<'ast, T> for ArrayExpression<'ast, T> {
     type Inner = ArrayExpressionInner<'ast, T>;
     type Ty = ArrayType<'ast, T>;
@@ -1800,6 +2222,11 @@ pub enum MemberOrExpression<'ast, T, E: Expr<'ast, T>> {
     Expression(E::Inner),
 }
 
+pub enum ElementOrExpression<'ast, T, E: Expr<'ast, T>> {
+    Element(ElementExpression<'ast, T, E>),
+    Expression(E::Inner),
+}
+
 pub enum ConditionalOrExpression<'ast, T, E: Expr<'ast, T>> {
     Conditional(ConditionalExpression<'ast, T, E>),
     Expression(E::Inner),
@@ -1918,6 +2345,79 @@ impl<'ast, T: Clone> Conditional<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Clone> Conditional<'ast, T> for TupleExpression<'ast, T> {
+    fn conditional(
+        condition: BooleanExpression<'ast, T>,
+        consequence: Self,
+        alternative: Self,
+        kind: ConditionalKind,
+    ) -> Self {
+        let ty = consequence.ty().clone();
+        TupleExpressionInner::Conditional(ConditionalExpression::new(
+            condition,
+            consequence,
+            alternative,
+            kind,
+        ))
+        .annotate(ty)
+    }
+}
+
+impl<'ast, T: Clone> Conditional<'ast, T> for TypedExpression<'ast, T> {
+    fn conditional(
+        condition: BooleanExpression<'ast, T>,
+        consequence: Self,
+        alternative: Self,
+        kind: ConditionalKind,
+    ) -> Self {
+        match (consequence, alternative) {
+            (TypedExpression::Boolean(c), TypedExpression::Boolean(a)) => {
+                BooleanExpression::conditional(condition, c, a, kind).into()
+            }
+            (TypedExpression::FieldElement(c), TypedExpression::FieldElement(a)) => {
+                FieldElementExpression::conditional(condition, c, a, kind).into()
+            }
+            (TypedExpression::Uint(c), TypedExpression::Uint(a)) => {
+                UExpression::conditional(condition, c, a, kind).into()
+            }
+            (TypedExpression::Array(c), TypedExpression::Array(a)) => {
+                ArrayExpression::conditional(condition, c, a, kind).into()
+            }
+            (TypedExpression::Struct(c), TypedExpression::Struct(a)) => {
+                StructExpression::conditional(condition, c, a, kind).into()
+            }
+            (TypedExpression::Tuple(c), TypedExpression::Tuple(a)) => {
+                TupleExpression::conditional(condition, c, a, kind).into()
+            }
+            (TypedExpression::Enum(c), TypedExpression::Enum(a)) => {
+                EnumExpression::conditional(condition, c, a, kind).into()
+            }
+            (TypedExpression::Int(c), TypedExpression::Int(a)) => {
+                IntExpression::conditional(condition, c, a, kind).into()
+            }
+            _ => unreachable!("branches of a conditional should have the same type"),
+        }
+    }
+}
+
+impl<'ast, T: Clone> Conditional<'ast, T> for EnumExpression<'ast, T> {
+    fn conditional(
+        condition: BooleanExpression<'ast, T>,
+        consequence: Self,
+        alternative: Self,
+        kind: ConditionalKind,
+    ) -> Self {
+        let ty = consequence.ty().clone();
+        EnumExpressionInner::Conditional(ConditionalExpression::new(
+            condition,
+            consequence,
+            alternative,
+            kind,
+        ))
+        .annotate(ty)
+    }
+}
+
 pub trait Select<'ast, T> {
     fn select<I: Into<UExpression<'ast, T>>>(array: ArrayExpression<'ast, T>, index: I) -> Self;
 }
@@ -1945,6 +2445,8 @@ impl<'ast, T: Clone> Select<'ast, T> for TypedExpression<'ast, T> {
         match *array.ty().ty {
             Type::Array(..) => ArrayExpression::select(array, index).into(),
             Type::Struct(..) => StructExpression::select(array, index).into(),
+            Type::Tuple(..) => TupleExpression::select(array, index).into(),
+            Type::Enum(..) => EnumExpression::select(array, index).into(),
             Type::FieldElement => FieldElementExpression::select(array, index).into(),
             Type::Boolean => BooleanExpression::select(array, index).into(),
             Type::Int => IntExpression::select(array, index).into(),
@@ -1986,6 +2488,28 @@ impl<'ast, T: Clone> Select<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Clone> Select<'ast, T> for TupleExpression<'ast, T> {
+    fn select<I: Into<UExpression<'ast, T>>>(array: ArrayExpression<'ast, T>, index: I) -> Self {
+        let elements = match array.inner_type().clone() {
+            Type::Tuple(elements) => elements,
+            _ => unreachable!(),
+        };
+
+        TupleExpressionInner::Select(SelectExpression::new(array, index.into())).annotate(elements)
+    }
+}
+
+impl<'ast, T: Clone> Select<'ast, T> for EnumExpression<'ast, T> {
+    fn select<I: Into<UExpression<'ast, T>>>(array: ArrayExpression<'ast, T>, index: I) -> Self {
+        let variants = match array.inner_type().clone() {
+            Type::Enum(variants) => variants,
+            _ => unreachable!(),
+        };
+
+        EnumExpressionInner::Select(SelectExpression::new(array, index.into())).annotate(variants)
+    }
+}
+
 pub trait Member<'ast, T>: Sized {
     fn member(s: StructExpression<'ast, T>, id: MemberId) -> Self;
 }
@@ -2044,6 +2568,115 @@ impl<'ast, T: Clone> Member<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Clone> Member<'ast, T> for TupleExpression<'ast, T> {
+    fn member(s: StructExpression<'ast, T>, id: MemberId) -> Self {
+        let ty = s.ty().members.iter().find(|member| id == member.id);
+        let tuple_ty = match ty {
+            Some(crate::typed_absy::types::StructMember {
+                ty: box Type::Tuple(tuple_ty),
+                ..
+            }) => tuple_ty.clone(),
+            _ => unreachable!(),
+        };
+        TupleExpressionInner::Member(MemberExpression::new(s, id)).annotate(tuple_ty)
+    }
+}
+
+impl<'ast, T: Clone> Member<'ast, T> for EnumExpression<'ast, T> {
+    fn member(s: StructExpression<'ast, T>, id: MemberId) -> Self {
+        let ty = s.ty().members.iter().find(|member| id == member.id);
+        let enum_ty = match ty {
+            Some(crate::typed_absy::types::StructMember {
+                ty: box Type::Enum(enum_ty),
+                ..
+            }) => enum_ty.clone(),
+            _ => unreachable!(),
+        };
+        EnumExpressionInner::Member(MemberExpression::new(s, id)).annotate(enum_ty)
+    }
+}
+
+pub trait Element<'ast, T>: Sized {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self;
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for TypedExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        match &tuple.ty().elements[index as usize] {
+            Type::Array(..) => ArrayExpression::element(tuple, index).into(),
+            Type::Struct(..) => StructExpression::element(tuple, index).into(),
+            Type::Tuple(..) => TupleExpression::element(tuple, index).into(),
+            Type::Enum(..) => EnumExpression::element(tuple, index).into(),
+            Type::FieldElement => FieldElementExpression::element(tuple, index).into(),
+            Type::Boolean => BooleanExpression::element(tuple, index).into(),
+            Type::Uint(..) => UExpression::element(tuple, index).into(),
+            Type::Int => unreachable!("integer elements cannot be accessed on a tuple expression"),
+        }
+    }
+}
+
+impl<'ast, T> Element<'ast, T> for FieldElementExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        FieldElementExpression::Element(ElementExpression::new(tuple, index))
+    }
+}
+
+impl<'ast, T> Element<'ast, T> for BooleanExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        BooleanExpression::Element(ElementExpression::new(tuple, index))
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for UExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        let bitwidth = match &tuple.ty().elements[index as usize] {
+            Type::Uint(bitwidth) => *bitwidth,
+            _ => unreachable!(),
+        };
+        UExpressionInner::Element(ElementExpression::new(tuple, index)).annotate(bitwidth)
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for ArrayExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        let (ty, size) = match &tuple.ty().elements[index as usize] {
+            Type::Array(array_ty) => (*array_ty.ty.clone(), array_ty.size.clone()),
+            _ => unreachable!(),
+        };
+        ArrayExpressionInner::Element(ElementExpression::new(tuple, index)).annotate(ty, size)
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for StructExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        let struct_ty = match &tuple.ty().elements[index as usize] {
+            Type::Struct(struct_ty) => struct_ty.clone(),
+            _ => unreachable!(),
+        };
+        StructExpressionInner::Element(ElementExpression::new(tuple, index)).annotate(struct_ty)
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for TupleExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        let tuple_ty = match &tuple.ty().elements[index as usize] {
+            Type::Tuple(tuple_ty) => tuple_ty.clone(),
+            _ => unreachable!(),
+        };
+        TupleExpressionInner::Element(ElementExpression::new(tuple, index)).annotate(tuple_ty)
+    }
+}
+
+impl<'ast, T: Clone> Element<'ast, T> for EnumExpression<'ast, T> {
+    fn element(tuple: TupleExpression<'ast, T>, index: u32) -> Self {
+        let enum_ty = match &tuple.ty().elements[index as usize] {
+            Type::Enum(enum_ty) => enum_ty.clone(),
+            _ => unreachable!(),
+        };
+        EnumExpressionInner::Element(ElementExpression::new(tuple, index)).annotate(enum_ty)
+    }
+}
+
 pub trait Id<'ast, T>: Expr<'ast, T> {
     fn identifier(id: Identifier<'ast>) -> Self::Inner;
 }
@@ -2078,6 +2711,18 @@ impl<'ast, T: Field> Id<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> Id<'ast, T> for TupleExpression<'ast, T> {
+    fn identifier(id: Identifier<'ast>) -> Self::Inner {
+        TupleExpressionInner::Identifier(id)
+    }
+}
+
+impl<'ast, T: Field> Id<'ast, T> for EnumExpression<'ast, T> {
+    fn identifier(id: Identifier<'ast>) -> Self::Inner {
+        EnumExpressionInner::Identifier(id)
+    }
+}
+
 // `TypedExpressionList` does not have an Identifier variant
 // However implementing `From<TypedExpression>` is required for `TypedExpressionList` to be `Expr`, which makes generic treatment of function calls possible
 // This could maybe be avoided by splitting the `Expr` trait into many, but I did not find a way
@@ -2145,6 +2790,26 @@ impl<'ast, T: Field> FunctionCall<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> FunctionCall<'ast, T> for TupleExpression<'ast, T> {
+    fn function_call(
+        key: DeclarationFunctionKey<'ast, T>,
+        generics: Vec<Option<UExpression<'ast, T>>>,
+        arguments: Vec<TypedExpression<'ast, T>>,
+    ) -> Self::Inner {
+        TupleExpressionInner::FunctionCall(FunctionCallExpression::new(key, generics, arguments))
+    }
+}
+
+impl<'ast, T: Field> FunctionCall<'ast, T> for EnumExpression<'ast, T> {
+    fn function_call(
+        key: DeclarationFunctionKey<'ast, T>,
+        generics: Vec<Option<UExpression<'ast, T>>>,
+        arguments: Vec<TypedExpression<'ast, T>>,
+    ) -> Self::Inner {
+        EnumExpressionInner::FunctionCall(FunctionCallExpression::new(key, generics, arguments))
+    }
+}
+
 impl<'ast, T: Field> FunctionCall<'ast, T> for TypedExpressionList<'ast, T> {
     fn function_call(
         key: DeclarationFunctionKey<'ast, T>,
@@ -2196,6 +2861,37 @@ impl<'ast, T: Field> Block<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> Block<'ast, T> for TupleExpression<'ast, T> {
+    fn block(statements: Vec<TypedStatement<'ast, T>>, value: Self) -> Self {
+        let tuple_ty = value.ty().clone();
+
+        TupleExpressionInner::Block(BlockExpression::new(statements, value)).annotate(tuple_ty)
+    }
+}
+
+impl<'ast, T: Field> Block<'ast, T> for EnumExpression<'ast, T> {
+    fn block(statements: Vec<TypedStatement<'ast, T>>, value: Self) -> Self {
+        let enum_ty = value.ty().clone();
+
+        EnumExpressionInner::Block(BlockExpression::new(statements, value)).annotate(enum_ty)
+    }
+}
+
+impl<'ast, T: Field> Block<'ast, T> for TypedExpression<'ast, T> {
+    fn block(statements: Vec<TypedStatement<'ast, T>>, value: Self) -> Self {
+        match value {
+            TypedExpression::Boolean(e) => BooleanExpression::block(statements, e).into(),
+            TypedExpression::FieldElement(e) => FieldElementExpression::block(statements, e).into(),
+            TypedExpression::Uint(e) => UExpression::block(statements, e).into(),
+            TypedExpression::Array(e) => ArrayExpression::block(statements, e).into(),
+            TypedExpression::Struct(e) => StructExpression::block(statements, e).into(),
+            TypedExpression::Tuple(e) => TupleExpression::block(statements, e).into(),
+            TypedExpression::Enum(e) => EnumExpression::block(statements, e).into(),
+            TypedExpression::Int(_) => unreachable!("integer expressions cannot be blocks"),
+        }
+    }
+}
+
 pub trait Constant: Sized {
     // return whether this is constant
     fn is_constant(&self) -> bool;
@@ -2368,6 +3064,55 @@ impl<'ast, T: Field> Constant for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> Constant for TupleExpression<'ast, T> {
+    fn is_constant(&self) -> bool {
+        match self.as_inner() {
+            TupleExpressionInner::Value(v) => v.iter().all(|e| e.is_constant()),
+            _ => false,
+        }
+    }
+
+    fn into_canonical_constant(self) -> Self {
+        let tuple_ty = self.ty().clone();
+
+        match self.into_inner() {
+            TupleExpressionInner::Value(expressions) => TupleExpressionInner::Value(
+                expressions
+                    .into_iter()
+                    .map(|e| e.into_canonical_constant())
+                    .collect(),
+            )
+            .annotate(tuple_ty),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<'ast, T: Field> Constant for EnumExpression<'ast, T> {
+    fn is_constant(&self) -> bool {
+        match self.as_inner() {
+            EnumExpressionInner::Value(_, v) => v.iter().all(|e| e.is_constant()),
+            _ => false,
+        }
+    }
+
+    fn into_canonical_constant(self) -> Self {
+        let enum_ty = self.ty().clone();
+
+        match self.into_inner() {
+            EnumExpressionInner::Value(variant, expressions) => EnumExpressionInner::Value(
+                variant,
+                expressions
+                    .into_iter()
+                    .map(|e| e.into_canonical_constant())
+                    .collect(),
+            )
+            .annotate(enum_ty),
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl<'ast, T: Field> Constant for TypedExpression<'ast, T> {
     fn is_constant(&self) -> bool {
         match self {
@@ -2375,6 +3120,8 @@ impl<'ast, T: Field> Constant for TypedExpression<'ast, T> {
             TypedExpression::Boolean(e) => e.is_constant(),
             TypedExpression::Array(e) => e.is_constant(),
             TypedExpression::Struct(e) => e.is_constant(),
+            TypedExpression::Tuple(e) => e.is_constant(),
+            TypedExpression::Enum(e) => e.is_constant(),
             TypedExpression::Uint(e) => e.is_constant(),
             _ => unreachable!(),
         }
@@ -2386,6 +3133,8 @@ impl<'ast, T: Field> Constant for TypedExpression<'ast, T> {
             TypedExpression::Boolean(e) => e.into_canonical_constant().into(),
             TypedExpression::Array(e) => e.into_canonical_constant().into(),
             TypedExpression::Struct(e) => e.into_canonical_constant().into(),
+            TypedExpression::Tuple(e) => e.into_canonical_constant().into(),
+            TypedExpression::Enum(e) => e.into_canonical_constant().into(),
             TypedExpression::Uint(e) => e.into_canonical_constant().into(),
             _ => unreachable!(),
         }
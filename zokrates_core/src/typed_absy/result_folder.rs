@@ -38,6 +38,18 @@ impl<'ast, T: Field> ResultFold<'ast, T> for StructExpression<'ast, T> {
     }
 }
 
+impl<'ast, T: Field> ResultFold<'ast, T> for TupleExpression<'ast, T> {
+    fn fold<F: ResultFolder<'ast, T>>(self, f: &mut F) -> Result<Self, F::Error> {
+        f.fold_tuple_expression(self)
+    }
+}
+
+impl<'ast, T: Field> ResultFold<'ast, T> for EnumExpression<'ast, T> {
+    fn fold<F: ResultFolder<'ast, T>>(self, f: &mut F) -> Result<Self, F::Error> {
+        f.fold_enum_expression(self)
+    }
+}
+
 pub trait ResultFolder<'ast, T: Field>: Sized {
     type Error;
 
@@ -183,6 +195,8 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         match t {
             Array(array_type) => Ok(Array(self.fold_array_type(array_type)?)),
             Struct(struct_type) => Ok(Struct(self.fold_struct_type(struct_type)?)),
+            Tuple(tuple_type) => Ok(Tuple(self.fold_tuple_type(tuple_type)?)),
+            Enum(enum_type) => Ok(Enum(self.fold_enum_type(enum_type)?)),
             t => Ok(t),
         }
     }
@@ -218,6 +232,16 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         fold_member_expression(self, ty, e)
     }
 
+    fn fold_element_expression<
+        E: Expr<'ast, T> + Element<'ast, T> + From<TypedExpression<'ast, T>>,
+    >(
+        &mut self,
+        ty: &E::Ty,
+        e: ElementExpression<'ast, T, E>,
+    ) -> Result<ElementOrExpression<'ast, T, E>, Self::Error> {
+        fold_element_expression(self, ty, e)
+    }
+
     fn fold_select_expression<
         E: Expr<'ast, T>
             + Select<'ast, T>
@@ -274,6 +298,37 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         })
     }
 
+    fn fold_tuple_type(
+        &mut self,
+        t: TupleType<'ast, T>,
+    ) -> Result<TupleType<'ast, T>, Self::Error> {
+        Ok(TupleType {
+            elements: t
+                .elements
+                .into_iter()
+                .map(|e| self.fold_type(e))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn fold_enum_type(&mut self, t: EnumType<'ast, T>) -> Result<EnumType<'ast, T>, Self::Error> {
+        Ok(EnumType {
+            variants: t
+                .variants
+                .into_iter()
+                .map(|v| {
+                    let id = v.id;
+                    v.payload
+                        .into_iter()
+                        .map(|ty| self.fold_type(ty))
+                        .collect::<Result<_, _>>()
+                        .map(|payload| EnumVariant { id, payload })
+                })
+                .collect::<Result<_, _>>()?,
+            ..t
+        })
+    }
+
     fn fold_declaration_type(
         &mut self,
         t: DeclarationType<'ast, T>,
@@ -283,6 +338,8 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         match t {
             Array(array_type) => Ok(Array(self.fold_declaration_array_type(array_type)?)),
             Struct(struct_type) => Ok(Struct(self.fold_declaration_struct_type(struct_type)?)),
+            Tuple(tuple_type) => Ok(Tuple(self.fold_declaration_tuple_type(tuple_type)?)),
+            Enum(enum_type) => Ok(Enum(self.fold_declaration_enum_type(enum_type)?)),
             t => Ok(t),
         }
     }
@@ -320,6 +377,40 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         })
     }
 
+    fn fold_declaration_tuple_type(
+        &mut self,
+        t: DeclarationTupleType<'ast, T>,
+    ) -> Result<DeclarationTupleType<'ast, T>, Self::Error> {
+        Ok(DeclarationTupleType {
+            elements: t
+                .elements
+                .into_iter()
+                .map(|e| self.fold_declaration_type(e))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn fold_declaration_enum_type(
+        &mut self,
+        t: DeclarationEnumType<'ast, T>,
+    ) -> Result<DeclarationEnumType<'ast, T>, Self::Error> {
+        Ok(DeclarationEnumType {
+            variants: t
+                .variants
+                .into_iter()
+                .map(|v| {
+                    let id = v.id;
+                    v.payload
+                        .into_iter()
+                        .map(|ty| self.fold_declaration_type(ty))
+                        .collect::<Result<_, _>>()
+                        .map(|payload| DeclarationEnumVariant { id, payload })
+                })
+                .collect::<Result<_, _>>()?,
+            ..t
+        })
+    }
+
     fn fold_assignee(
         &mut self,
         a: TypedAssignee<'ast, T>,
@@ -367,6 +458,8 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
             TypedExpression::Uint(e) => Ok(self.fold_uint_expression(e)?.into()),
             TypedExpression::Array(e) => Ok(self.fold_array_expression(e)?.into()),
             TypedExpression::Struct(e) => Ok(self.fold_struct_expression(e)?.into()),
+            TypedExpression::Tuple(e) => Ok(self.fold_tuple_expression(e)?.into()),
+            TypedExpression::Enum(e) => Ok(self.fold_enum_expression(e)?.into()),
             TypedExpression::Int(e) => Ok(self.fold_int_expression(e)?.into()),
         }
     }
@@ -385,6 +478,20 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
         fold_struct_expression(self, e)
     }
 
+    fn fold_tuple_expression(
+        &mut self,
+        e: TupleExpression<'ast, T>,
+    ) -> Result<TupleExpression<'ast, T>, Self::Error> {
+        fold_tuple_expression(self, e)
+    }
+
+    fn fold_enum_expression(
+        &mut self,
+        e: EnumExpression<'ast, T>,
+    ) -> Result<EnumExpression<'ast, T>, Self::Error> {
+        fold_enum_expression(self, e)
+    }
+
     fn fold_expression_list_inner(
         &mut self,
         tys: &Types<'ast, T>,
@@ -448,6 +555,22 @@ pub trait ResultFolder<'ast, T: Field>: Sized {
     ) -> Result<StructExpressionInner<'ast, T>, Self::Error> {
         fold_struct_expression_inner(self, ty, e)
     }
+
+    fn fold_tuple_expression_inner(
+        &mut self,
+        ty: &TupleType<'ast, T>,
+        e: TupleExpressionInner<'ast, T>,
+    ) -> Result<TupleExpressionInner<'ast, T>, Self::Error> {
+        fold_tuple_expression_inner(self, ty, e)
+    }
+
+    fn fold_enum_expression_inner(
+        &mut self,
+        ty: &EnumType<'ast, T>,
+        e: EnumExpressionInner<'ast, T>,
+    ) -> Result<EnumExpressionInner<'ast, T>, Self::Error> {
+        fold_enum_expression_inner(self, ty, e)
+    }
 }
 
 pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
@@ -480,6 +603,23 @@ pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
                 .flatten()
                 .collect(),
         ),
+        TypedStatement::IfElse(condition, consequence, alternative) => TypedStatement::IfElse(
+            f.fold_boolean_expression(condition)?,
+            consequence
+                .into_iter()
+                .map(|s| f.fold_statement(s))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .flatten()
+                .collect(),
+            alternative
+                .into_iter()
+                .map(|s| f.fold_statement(s))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .flatten()
+                .collect(),
+        ),
         TypedStatement::MultipleDefinition(variables, elist) => TypedStatement::MultipleDefinition(
             variables
                 .into_iter()
@@ -487,6 +627,13 @@ pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
                 .collect::<Result<_, _>>()?,
             f.fold_expression_list(elist)?,
         ),
+        TypedStatement::Log(format_string, expressions) => TypedStatement::Log(
+            format_string,
+            expressions
+                .into_iter()
+                .map(|e| f.fold_expression(e))
+                .collect::<Result<_, _>>()?,
+        ),
         s => s,
     };
     Ok(vec![res])
@@ -522,6 +669,10 @@ pub fn fold_array_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
             MemberOrExpression::Member(m) => ArrayExpressionInner::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        ArrayExpressionInner::Element(m) => match f.fold_element_expression(ty, m)? {
+            ElementOrExpression::Element(m) => ArrayExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
         ArrayExpressionInner::Select(select) => match f.fold_select_expression(ty, select)? {
             SelectOrExpression::Select(m) => ArrayExpressionInner::Select(m),
             SelectOrExpression::Expression(u) => u,
@@ -552,6 +703,9 @@ pub fn fold_assignee<'ast, T: Field, F: ResultFolder<'ast, T>>(
             box f.fold_uint_expression(index)?,
         )),
         TypedAssignee::Member(box s, m) => Ok(TypedAssignee::Member(box f.fold_assignee(s)?, m)),
+        TypedAssignee::Element(box s, index) => {
+            Ok(TypedAssignee::Element(box f.fold_assignee(s)?, index))
+        }
     }
 }
 
@@ -591,6 +745,98 @@ pub fn fold_struct_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
             SelectOrExpression::Select(m) => StructExpressionInner::Select(m),
             SelectOrExpression::Expression(u) => u,
         },
+        StructExpressionInner::Element(m) => match f.fold_element_expression(ty, m)? {
+            ElementOrExpression::Element(m) => StructExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
+    };
+    Ok(e)
+}
+
+pub fn fold_tuple_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
+    f: &mut F,
+    ty: &TupleType<'ast, T>,
+    e: TupleExpressionInner<'ast, T>,
+) -> Result<TupleExpressionInner<'ast, T>, F::Error> {
+    let e = match e {
+        TupleExpressionInner::Block(block) => {
+            TupleExpressionInner::Block(f.fold_block_expression(block)?)
+        }
+        TupleExpressionInner::Identifier(id) => TupleExpressionInner::Identifier(f.fold_name(id)?),
+        TupleExpressionInner::Value(exprs) => TupleExpressionInner::Value(
+            exprs
+                .into_iter()
+                .map(|e| f.fold_expression(e))
+                .collect::<Result<_, _>>()?,
+        ),
+        TupleExpressionInner::FunctionCall(function_call) => {
+            match f.fold_function_call_expression(ty, function_call)? {
+                FunctionCallOrExpression::FunctionCall(c) => TupleExpressionInner::FunctionCall(c),
+                FunctionCallOrExpression::Expression(u) => u,
+            }
+        }
+        TupleExpressionInner::Conditional(c) => match f.fold_conditional_expression(ty, c)? {
+            ConditionalOrExpression::Conditional(c) => TupleExpressionInner::Conditional(c),
+            ConditionalOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Member(m) => match f.fold_member_expression(ty, m)? {
+            MemberOrExpression::Member(m) => TupleExpressionInner::Member(m),
+            MemberOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Select(select) => match f.fold_select_expression(ty, select)? {
+            SelectOrExpression::Select(m) => TupleExpressionInner::Select(m),
+            SelectOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Element(m) => match f.fold_element_expression(ty, m)? {
+            ElementOrExpression::Element(m) => TupleExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
+        TupleExpressionInner::Payload(box e, variant) => {
+            TupleExpressionInner::Payload(box f.fold_enum_expression(e)?, variant)
+        }
+    };
+    Ok(e)
+}
+
+pub fn fold_enum_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
+    f: &mut F,
+    ty: &EnumType<'ast, T>,
+    e: EnumExpressionInner<'ast, T>,
+) -> Result<EnumExpressionInner<'ast, T>, F::Error> {
+    let e = match e {
+        EnumExpressionInner::Block(block) => {
+            EnumExpressionInner::Block(f.fold_block_expression(block)?)
+        }
+        EnumExpressionInner::Identifier(id) => EnumExpressionInner::Identifier(f.fold_name(id)?),
+        EnumExpressionInner::Value(variant, exprs) => EnumExpressionInner::Value(
+            variant,
+            exprs
+                .into_iter()
+                .map(|e| f.fold_expression(e))
+                .collect::<Result<_, _>>()?,
+        ),
+        EnumExpressionInner::FunctionCall(function_call) => {
+            match f.fold_function_call_expression(ty, function_call)? {
+                FunctionCallOrExpression::FunctionCall(c) => EnumExpressionInner::FunctionCall(c),
+                FunctionCallOrExpression::Expression(u) => u,
+            }
+        }
+        EnumExpressionInner::Conditional(c) => match f.fold_conditional_expression(ty, c)? {
+            ConditionalOrExpression::Conditional(c) => EnumExpressionInner::Conditional(c),
+            ConditionalOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Member(m) => match f.fold_member_expression(ty, m)? {
+            MemberOrExpression::Member(m) => EnumExpressionInner::Member(m),
+            MemberOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Select(select) => match f.fold_select_expression(ty, select)? {
+            SelectOrExpression::Select(m) => EnumExpressionInner::Select(m),
+            SelectOrExpression::Expression(u) => u,
+        },
+        EnumExpressionInner::Element(m) => match f.fold_element_expression(ty, m)? {
+            ElementOrExpression::Element(m) => EnumExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     };
     Ok(e)
 }
@@ -662,6 +908,12 @@ pub fn fold_field_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
                 MemberOrExpression::Expression(u) => u,
             }
         }
+        FieldElementExpression::Element(m) => {
+            match f.fold_element_expression(&Type::FieldElement, m)? {
+                ElementOrExpression::Element(m) => FieldElementExpression::Element(m),
+                ElementOrExpression::Expression(u) => u,
+            }
+        }
         FieldElementExpression::Select(select) => {
             match f.fold_select_expression(&Type::FieldElement, select)? {
                 SelectOrExpression::Select(s) => FieldElementExpression::Select(s),
@@ -736,6 +988,22 @@ pub fn fold_member_expression<
     )))
 }
 
+pub fn fold_element_expression<
+    'ast,
+    T: Field,
+    E: Expr<'ast, T> + Element<'ast, T> + From<TypedExpression<'ast, T>>,
+    F: ResultFolder<'ast, T>,
+>(
+    f: &mut F,
+    _: &E::Ty,
+    e: ElementExpression<'ast, T, E>,
+) -> Result<ElementOrExpression<'ast, T, E>, F::Error> {
+    Ok(ElementOrExpression::Element(ElementExpression::new(
+        f.fold_tuple_expression(*e.tuple)?,
+        e.index,
+    )))
+}
+
 pub fn fold_select_expression<
     'ast,
     T: Field,
@@ -808,6 +1076,20 @@ pub fn fold_boolean_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
             let e2 = f.fold_struct_expression(e2)?;
             BooleanExpression::StructEq(box e1, box e2)
         }
+        BooleanExpression::TupleEq(box e1, box e2) => {
+            let e1 = f.fold_tuple_expression(e1)?;
+            let e2 = f.fold_tuple_expression(e2)?;
+            BooleanExpression::TupleEq(box e1, box e2)
+        }
+        BooleanExpression::EnumEq(box e1, box e2) => {
+            let e1 = f.fold_enum_expression(e1)?;
+            let e2 = f.fold_enum_expression(e2)?;
+            BooleanExpression::EnumEq(box e1, box e2)
+        }
+        BooleanExpression::IsVariant(box e, variant) => {
+            let e = f.fold_enum_expression(e)?;
+            BooleanExpression::IsVariant(box e, variant)
+        }
         BooleanExpression::UintEq(box e1, box e2) => {
             let e1 = f.fold_uint_expression(e1)?;
             let e2 = f.fold_uint_expression(e2)?;
@@ -889,6 +1171,10 @@ pub fn fold_boolean_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
             MemberOrExpression::Member(m) => BooleanExpression::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        BooleanExpression::Element(m) => match f.fold_element_expression(&Type::Boolean, m)? {
+            ElementOrExpression::Element(m) => BooleanExpression::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     };
     Ok(e)
 }
@@ -1011,6 +1297,10 @@ pub fn fold_uint_expression_inner<'ast, T: Field, F: ResultFolder<'ast, T>>(
             MemberOrExpression::Member(m) => UExpressionInner::Member(m),
             MemberOrExpression::Expression(u) => u,
         },
+        UExpressionInner::Element(m) => match f.fold_element_expression(&ty, m)? {
+            ElementOrExpression::Element(m) => UExpressionInner::Element(m),
+            ElementOrExpression::Expression(u) => u,
+        },
     };
     Ok(e)
 }
@@ -1161,6 +1451,28 @@ pub fn fold_struct_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
     })
 }
 
+pub fn fold_tuple_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
+    f: &mut F,
+    e: TupleExpression<'ast, T>,
+) -> Result<TupleExpression<'ast, T>, F::Error> {
+    let ty = f.fold_tuple_type(e.ty)?;
+    Ok(TupleExpression {
+        inner: f.fold_tuple_expression_inner(&ty, e.inner)?,
+        ty,
+    })
+}
+
+pub fn fold_enum_expression<'ast, T: Field, F: ResultFolder<'ast, T>>(
+    f: &mut F,
+    e: EnumExpression<'ast, T>,
+) -> Result<EnumExpression<'ast, T>, F::Error> {
+    let ty = f.fold_enum_type(e.ty)?;
+    Ok(EnumExpression {
+        inner: f.fold_enum_expression_inner(&ty, e.inner)?,
+        ty,
+    })
+}
+
 pub fn fold_constant<'ast, T: Field, F: ResultFolder<'ast, T>>(
     f: &mut F,
     c: TypedConstant<'ast, T>,
@@ -26,6 +26,22 @@ impl<'ast, T> IntoTypes<'ast, T> for StructType<'ast, T> {
     }
 }
 
+impl<'ast, T> IntoTypes<'ast, T> for TupleType<'ast, T> {
+    fn into_types(self) -> Types<'ast, T> {
+        Types {
+            inner: vec![Type::Tuple(self)],
+        }
+    }
+}
+
+impl<'ast, T> IntoTypes<'ast, T> for EnumType<'ast, T> {
+    fn into_types(self) -> Types<'ast, T> {
+        Types {
+            inner: vec![Type::Enum(self)],
+        }
+    }
+}
+
 impl<'ast, T> IntoTypes<'ast, T> for ArrayType<'ast, T> {
     fn into_types(self) -> Types<'ast, T> {
         Types {
@@ -530,21 +546,314 @@ impl<S> IntoIterator for GStructType<S> {
     }
 }
 
+#[derive(Debug, Clone, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct GTupleType<S> {
+    pub elements: Vec<GType<S>>,
+}
+
+pub type DeclarationTupleType<'ast, T> = GTupleType<DeclarationConstant<'ast, T>>;
+pub type ConcreteTupleType = GTupleType<u32>;
+pub type TupleType<'ast, T> = GTupleType<UExpression<'ast, T>>;
+
+impl<'ast, S, R: PartialEq<S>> PartialEq<GTupleType<S>> for GTupleType<R> {
+    fn eq(&self, other: &GTupleType<S>) -> bool {
+        self.elements.len() == other.elements.len()
+            && self
+                .elements
+                .iter()
+                .zip(other.elements.iter())
+                .all(|(a, b)| a == b)
+    }
+}
+
+impl<S> GTupleType<S> {
+    pub fn new(elements: Vec<GType<S>>) -> Self {
+        GTupleType { elements }
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for GTupleType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, e) in self.elements.iter().enumerate() {
+            write!(f, "{}", e)?;
+            // a tuple with a single element is displayed with a trailing comma, like in the source
+            if i < self.elements.len() - 1 || self.elements.len() == 1 {
+                write!(f, ",")?;
+            }
+            if i < self.elements.len() - 1 {
+                write!(f, " ")?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+fn try_from_g_tuple_type<T: TryInto<U>, U>(
+    t: GTupleType<T>,
+) -> Result<GTupleType<U>, SpecializationError> {
+    Ok(GTupleType {
+        elements: t
+            .elements
+            .into_iter()
+            .map(try_from_g_type)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+impl<'ast, T> TryFrom<TupleType<'ast, T>> for ConcreteTupleType {
+    type Error = SpecializationError;
+
+    fn try_from(t: TupleType<'ast, T>) -> Result<Self, Self::Error> {
+        try_from_g_tuple_type(t)
+    }
+}
+
+impl<'ast, T> From<ConcreteTupleType> for TupleType<'ast, T> {
+    fn from(t: ConcreteTupleType) -> Self {
+        try_from_g_tuple_type(t).unwrap()
+    }
+}
+
+impl<'ast, T> From<ConcreteTupleType> for DeclarationTupleType<'ast, T> {
+    fn from(t: ConcreteTupleType) -> Self {
+        try_from_g_tuple_type(t).unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct GEnumVariant<S> {
+    #[serde(rename = "name")]
+    pub id: String,
+    pub payload: Vec<GType<S>>,
+}
+
+pub type DeclarationEnumVariant<'ast, T> = GEnumVariant<DeclarationConstant<'ast, T>>;
+pub type ConcreteEnumVariant = GEnumVariant<u32>;
+pub type EnumVariant<'ast, T> = GEnumVariant<UExpression<'ast, T>>;
+
+impl<'ast, S, R: PartialEq<S>> PartialEq<GEnumVariant<S>> for GEnumVariant<R> {
+    fn eq(&self, other: &GEnumVariant<S>) -> bool {
+        self.id == other.id
+            && self.payload.len() == other.payload.len()
+            && self
+                .payload
+                .iter()
+                .zip(other.payload.iter())
+                .all(|(a, b)| a == b)
+    }
+}
+
+impl<S> GEnumVariant<S> {
+    pub fn new(id: String, payload: Vec<GType<S>>) -> Self {
+        GEnumVariant { id, payload }
+    }
+}
+
+fn try_from_g_enum_variant<T: TryInto<U>, U>(
+    t: GEnumVariant<T>,
+) -> Result<GEnumVariant<U>, SpecializationError> {
+    Ok(GEnumVariant {
+        id: t.id,
+        payload: t
+            .payload
+            .into_iter()
+            .map(try_from_g_type)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// An enum type. A value of this type is encoded as a `field` tag holding the index of its variant,
+/// followed by the payloads of all variants, where the payloads of inactive variants are zero.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct GEnumType<S> {
+    #[serde(flatten)]
+    pub canonical_location: StructLocation,
+    #[serde(skip)]
+    pub location: Option<StructLocation>,
+    pub variants: Vec<GEnumVariant<S>>,
+}
+
+pub type DeclarationEnumType<'ast, T> = GEnumType<DeclarationConstant<'ast, T>>;
+pub type ConcreteEnumType = GEnumType<u32>;
+pub type EnumType<'ast, T> = GEnumType<UExpression<'ast, T>>;
+
+impl<'ast, S, R: PartialEq<S>> PartialEq<GEnumType<S>> for GEnumType<R> {
+    fn eq(&self, other: &GEnumType<S>) -> bool {
+        self.canonical_location == other.canonical_location
+    }
+}
+
+impl<S: Hash> Hash for GEnumType<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_location.hash(state);
+    }
+}
+
+impl<S: Eq> Eq for GEnumType<S> {}
+
+impl<S> GEnumType<S> {
+    pub fn new(module: PathBuf, name: String, variants: Vec<GEnumVariant<S>>) -> Self {
+        GEnumType {
+            canonical_location: StructLocation { module, name },
+            location: None,
+            variants,
+        }
+    }
+
+    fn location(&self) -> &StructLocation {
+        self.location.as_ref().unwrap_or(&self.canonical_location)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.location().name
+    }
+
+    pub fn module(&self) -> &Path {
+        &self.location().module
+    }
+
+    /// The index of the variant called `id`, which is also its tag
+    pub fn variant_index(&self, id: &str) -> Option<usize> {
+        self.variants.iter().position(|v| v.id == id)
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for GEnumType<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+fn try_from_g_enum_type<T: TryInto<U>, U>(
+    t: GEnumType<T>,
+) -> Result<GEnumType<U>, SpecializationError> {
+    Ok(GEnumType {
+        location: t.location,
+        canonical_location: t.canonical_location,
+        variants: t
+            .variants
+            .into_iter()
+            .map(try_from_g_enum_variant)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+impl<'ast, T> TryFrom<EnumType<'ast, T>> for ConcreteEnumType {
+    type Error = SpecializationError;
+
+    fn try_from(t: EnumType<'ast, T>) -> Result<Self, Self::Error> {
+        try_from_g_enum_type(t)
+    }
+}
+
+impl<'ast, T> From<ConcreteEnumType> for EnumType<'ast, T> {
+    fn from(t: ConcreteEnumType) -> Self {
+        try_from_g_enum_type(t).unwrap()
+    }
+}
+
+impl<'ast, T> From<ConcreteEnumType> for DeclarationEnumType<'ast, T> {
+    fn from(t: ConcreteEnumType) -> Self {
+        try_from_g_enum_type(t).unwrap()
+    }
+}
+
+/// The bitwidth of an integer type. Signed integers share the representation of unsigned ones: a value of
+/// type `iN` is stored as its `N`-bit two's complement pattern, and only a few operations interpret it differently.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 pub enum UBitwidth {
     #[serde(rename = "8")]
-    B8 = 8,
+    B8,
     #[serde(rename = "16")]
-    B16 = 16,
+    B16,
     #[serde(rename = "32")]
-    B32 = 32,
+    B32,
     #[serde(rename = "64")]
-    B64 = 64,
+    B64,
+    #[serde(rename = "128")]
+    B128,
+    #[serde(rename = "i8")]
+    I8,
+    #[serde(rename = "i16")]
+    I16,
+    #[serde(rename = "i32")]
+    I32,
+    #[serde(rename = "i64")]
+    I64,
 }
 
 impl UBitwidth {
     pub fn to_usize(self) -> usize {
-        self as u32 as usize
+        match self {
+            UBitwidth::B8 | UBitwidth::I8 => 8,
+            UBitwidth::B16 | UBitwidth::I16 => 16,
+            UBitwidth::B32 | UBitwidth::I32 => 32,
+            UBitwidth::B64 | UBitwidth::I64 => 64,
+            UBitwidth::B128 => 128,
+        }
+    }
+
+    pub fn signed(b: usize) -> Self {
+        match b {
+            8 => UBitwidth::I8,
+            16 => UBitwidth::I16,
+            32 => UBitwidth::I32,
+            64 => UBitwidth::I64,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn is_signed(self) -> bool {
+        matches!(
+            self,
+            UBitwidth::I8 | UBitwidth::I16 | UBitwidth::I32 | UBitwidth::I64
+        )
+    }
+
+    /// The mask selecting the sign bit of a value of this bitwidth
+    pub fn sign_bit(self) -> u128 {
+        1 << (self.to_usize() - 1)
+    }
+
+    /// The bit pattern of this bitwidth with all bits set
+    pub fn mask(self) -> u128 {
+        u128::MAX >> (128 - self.to_usize())
+    }
+
+    /// The largest value of this bitwidth, as a bit pattern
+    pub fn max_value(self) -> u128 {
+        match self.is_signed() {
+            true => self.sign_bit() - 1,
+            false => self.mask(),
+        }
+    }
+
+    /// Interpret a bit pattern of this bitwidth as a signed number
+    pub fn to_signed(self, v: u128) -> i128 {
+        assert!(self.is_signed());
+        (v as i128) - (((v & self.sign_bit()) as i128) << 1)
+    }
+
+    /// Get the bit pattern of a signed number in this bitwidth
+    pub fn from_signed(self, v: i128) -> u128 {
+        (v as u128) & self.mask()
+    }
+
+    /// Map a bit pattern of this bitwidth to an unsigned value, such that the unsigned order of the results
+    /// matches the order of the inputs in this bitwidth
+    pub fn order_preserving(self, v: u128) -> u128 {
+        match self.is_signed() {
+            true => v ^ self.sign_bit(),
+            false => v,
+        }
+    }
+
+    fn prefix(self) -> char {
+        match self.is_signed() {
+            true => 'i',
+            false => 'u',
+        }
     }
 }
 
@@ -555,6 +864,7 @@ impl From<usize> for UBitwidth {
             16 => UBitwidth::B16,
             32 => UBitwidth::B32,
             64 => UBitwidth::B64,
+            128 => UBitwidth::B128,
             _ => unreachable!(),
         }
     }
@@ -572,6 +882,8 @@ pub enum GType<S> {
     Boolean,
     Array(GArrayType<S>),
     Struct(GStructType<S>),
+    Tuple(GTupleType<S>),
+    Enum(GEnumType<S>),
     Uint(UBitwidth),
     Int,
 }
@@ -598,11 +910,23 @@ impl<Z: Serialize> Serialize for GType<Z> {
                 map.serialize_entry("components", struct_type)?;
                 map.end()
             }
+            GType::Tuple(tuple_type) => {
+                let mut map = s.serialize_map(Some(2))?;
+                map.serialize_entry("type", "tuple")?;
+                map.serialize_entry("components", tuple_type)?;
+                map.end()
+            }
+            GType::Enum(enum_type) => {
+                let mut map = s.serialize_map(Some(2))?;
+                map.serialize_entry("type", "enum")?;
+                map.serialize_entry("components", enum_type)?;
+                map.end()
+            }
             GType::Uint(width) => s.serialize_newtype_variant(
                 "Type",
                 4,
                 "type",
-                format!("u{}", width.to_usize()).as_str(),
+                format!("{}{}", width.prefix(), width.to_usize()).as_str(),
             ),
             GType::Int => Err(S::Error::custom(
                 "Cannot serialize Int type as it's not allowed in function signatures".to_string(),
@@ -621,6 +945,8 @@ impl<'de, S: Deserialize<'de>> Deserialize<'de> for GType<S> {
         enum Components<S> {
             Array(GArrayType<S>),
             Struct(GStructType<S>),
+            Tuple(GTupleType<S>),
+            Enum(GEnumType<S>),
         }
 
         #[derive(Deserialize)]
@@ -663,10 +989,33 @@ impl<'de, S: Deserialize<'de>> Deserialize<'de> for GType<S> {
                     _ => Err(D::Error::custom("invalid `components` variant".to_string())),
                 }
             }
+            "tuple" => {
+                let components = mapping
+                    .components
+                    .ok_or_else(|| D::Error::custom("missing `components` field".to_string()))?;
+                match components {
+                    Components::Tuple(tuple_type) => Ok(GType::Tuple(tuple_type)),
+                    _ => Err(D::Error::custom("invalid `components` variant".to_string())),
+                }
+            }
+            "enum" => {
+                let components = mapping
+                    .components
+                    .ok_or_else(|| D::Error::custom("missing `components` field".to_string()))?;
+                match components {
+                    Components::Enum(enum_type) => Ok(GType::Enum(enum_type)),
+                    _ => Err(D::Error::custom("invalid `components` variant".to_string())),
+                }
+            }
             "u8" => strict_type(mapping, GType::Uint(UBitwidth::B8)),
             "u16" => strict_type(mapping, GType::Uint(UBitwidth::B16)),
             "u32" => strict_type(mapping, GType::Uint(UBitwidth::B32)),
             "u64" => strict_type(mapping, GType::Uint(UBitwidth::B64)),
+            "u128" => strict_type(mapping, GType::Uint(UBitwidth::B128)),
+            "i8" => strict_type(mapping, GType::Uint(UBitwidth::I8)),
+            "i16" => strict_type(mapping, GType::Uint(UBitwidth::I16)),
+            "i32" => strict_type(mapping, GType::Uint(UBitwidth::I32)),
+            "i64" => strict_type(mapping, GType::Uint(UBitwidth::I64)),
             t => Err(D::Error::custom(format!("invalid type `{}`", t))),
         }
     }
@@ -683,6 +1032,8 @@ impl<'ast, S, R: PartialEq<S>> PartialEq<GType<S>> for GType<R> {
         match (self, other) {
             (Array(l), Array(r)) => l == r,
             (Struct(l), Struct(r)) => l == r,
+            (Tuple(l), Tuple(r)) => l == r,
+            (Enum(l), Enum(r)) => l == r,
             (FieldElement, FieldElement) | (Boolean, Boolean) => true,
             (Uint(l), Uint(r)) => l == r,
             _ => false,
@@ -698,6 +1049,8 @@ pub fn try_from_g_type<T: TryInto<U>, U>(t: GType<T>) -> Result<GType<U>, Specia
         GType::Uint(bitwidth) => Ok(GType::Uint(bitwidth)),
         GType::Array(array_type) => Ok(GType::Array(try_from_g_array_type(array_type)?)),
         GType::Struct(struct_type) => Ok(GType::Struct(try_from_g_struct_type(struct_type)?)),
+        GType::Tuple(tuple_type) => Ok(GType::Tuple(try_from_g_tuple_type(tuple_type)?)),
+        GType::Enum(enum_type) => Ok(GType::Enum(try_from_g_enum_type(enum_type)?)),
     }
 }
 
@@ -753,10 +1106,12 @@ impl<S: fmt::Display> fmt::Display for GType<S> {
         match self {
             GType::FieldElement => write!(f, "field"),
             GType::Boolean => write!(f, "bool"),
-            GType::Uint(ref bitwidth) => write!(f, "u{}", bitwidth),
+            GType::Uint(ref bitwidth) => write!(f, "{}{}", bitwidth.prefix(), bitwidth),
             GType::Int => write!(f, "{{integer}}"),
             GType::Array(ref array_type) => write!(f, "{}", array_type),
             GType::Struct(ref struct_type) => write!(f, "{}", struct_type),
+            GType::Tuple(ref tuple_type) => write!(f, "{}", tuple_type),
+            GType::Enum(ref enum_type) => write!(f, "{}", enum_type),
         }
     }
 }
@@ -795,6 +1150,14 @@ impl<S> GType<S> {
         GType::Struct(struct_ty.into())
     }
 
+    pub fn tuple<U: Into<GTupleType<S>>>(tuple_ty: U) -> Self {
+        GType::Tuple(tuple_ty.into())
+    }
+
+    pub fn enumeration<U: Into<GEnumType<S>>>(enum_ty: U) -> Self {
+        GType::Enum(enum_ty.into())
+    }
+
     pub fn uint<W: Into<UBitwidth>>(b: W) -> Self {
         GType::Uint(b.into())
     }
@@ -829,6 +1192,14 @@ impl<'ast, T: fmt::Display + PartialEq + fmt::Debug> Type<'ast, T> {
                             .zip(r.members.iter())
                             .all(|(m, d_m)| m.ty.can_be_specialized_to(&*d_m.ty))
                 }
+                (Tuple(l), Tuple(r)) => {
+                    l.elements.len() == r.elements.len()
+                        && l.elements
+                            .iter()
+                            .zip(r.elements.iter())
+                            .all(|(e, d_e)| e.can_be_specialized_to(d_e))
+                }
+                (Enum(l), Enum(r)) => l.canonical_location == r.canonical_location,
                 _ => false,
             }
         }
@@ -841,7 +1212,7 @@ impl ConcreteType {
             GType::FieldElement => String::from("f"),
             GType::Int => unreachable!(),
             GType::Boolean => String::from("b"),
-            GType::Uint(bitwidth) => format!("u{}", bitwidth),
+            GType::Uint(bitwidth) => format!("{}{}", bitwidth.prefix(), bitwidth),
             GType::Array(array_type) => format!("{}[{}]", array_type.ty.to_slug(), array_type.size),
             GType::Struct(struct_type) => format!(
                 "{{{}}}",
@@ -851,6 +1222,32 @@ impl ConcreteType {
                     .collect::<Vec<_>>()
                     .join(",")
             ),
+            GType::Tuple(tuple_type) => format!(
+                "({})",
+                tuple_type
+                    .elements
+                    .iter()
+                    .map(|e| e.to_slug())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            GType::Enum(enum_type) => format!(
+                "<{}>",
+                enum_type
+                    .variants
+                    .iter()
+                    .map(|v| format!(
+                        "{}({})",
+                        v.id,
+                        v.payload
+                            .iter()
+                            .map(|ty| ty.to_slug())
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
         }
     }
 }
@@ -870,6 +1267,20 @@ impl ConcreteType {
                 .iter()
                 .map(|member| member.ty.get_primitive_count())
                 .sum(),
+            GType::Tuple(tuple_type) => tuple_type
+                .elements
+                .iter()
+                .map(|e| e.get_primitive_count())
+                .sum(),
+            // the tag, followed by the payloads of all variants
+            GType::Enum(enum_type) => {
+                1 + enum_type
+                    .variants
+                    .iter()
+                    .flat_map(|v| v.payload.iter())
+                    .map(|ty| ty.get_primitive_count())
+                    .sum::<usize>()
+            }
         }
     }
 }
@@ -1052,6 +1463,17 @@ pub fn check_type<'ast, T, S: Clone + PartialEq + PartialEq<u32>>(
                     .zip(s1.generics.iter())
                     .all(|(g0, g1)| check_generic(g0.as_ref().unwrap(), g1.as_ref(), constants))
         }
+        (DeclarationType::Tuple(t0), GType::Tuple(t1)) => {
+            t0.elements.len() == t1.elements.len()
+                && t0
+                    .elements
+                    .iter()
+                    .zip(t1.elements.iter())
+                    .all(|(e0, e1)| check_type(e0, e1, constants))
+        }
+        (DeclarationType::Enum(e0), GType::Enum(e1)) => {
+            e0.canonical_location == e1.canonical_location
+        }
         _ => false,
     }
 }
@@ -1128,6 +1550,29 @@ pub fn specialize_declaration_type<
                 location: s0.location,
             })
         }
+        DeclarationType::Tuple(t0) => GType::Tuple(GTupleType {
+            elements: t0
+                .elements
+                .into_iter()
+                .map(|e| specialize_declaration_type(e, generics))
+                .collect::<Result<_, _>>()?,
+        }),
+        DeclarationType::Enum(e0) => GType::Enum(GEnumType {
+            variants: e0
+                .variants
+                .into_iter()
+                .map(|v| {
+                    let id = v.id;
+                    v.payload
+                        .into_iter()
+                        .map(|ty| specialize_declaration_type(ty, generics))
+                        .collect::<Result<_, _>>()
+                        .map(|payload| GEnumVariant { id, payload })
+                })
+                .collect::<Result<_, _>>()?,
+            canonical_location: e0.canonical_location,
+            location: e0.location,
+        }),
     })
 }
 
@@ -1562,4 +2007,46 @@ mod tests {
         ));
         assert_eq!(format!("{}", t), "field[1][2]");
     }
+
+    #[test]
+    fn tuple() {
+        // (field, u32[3])
+        let t = ConcreteType::tuple(ConcreteTupleType::new(vec![
+            ConcreteType::FieldElement,
+            ConcreteType::array((ConcreteType::Uint(UBitwidth::B32), 3u32)),
+        ]));
+        assert_eq!(t.get_primitive_count(), 4);
+        assert_eq!(format!("{}", t), "(field, u32[3])");
+    }
+
+    #[test]
+    fn tuple_display() {
+        let t = ConcreteType::tuple(ConcreteTupleType::new(vec![ConcreteType::Boolean]));
+        assert_eq!(format!("{}", t), "(bool,)");
+
+        let t = ConcreteType::tuple(ConcreteTupleType::new(vec![]));
+        assert_eq!(format!("{}", t), "()");
+    }
+
+    #[test]
+    fn enumeration() {
+        // enum State { Idle, Active(field, u32[3]), Done(bool) }
+        let t = ConcreteType::enumeration(ConcreteEnumType::new(
+            "".into(),
+            "State".into(),
+            vec![
+                ConcreteEnumVariant::new("Idle".into(), vec![]),
+                ConcreteEnumVariant::new(
+                    "Active".into(),
+                    vec![
+                        ConcreteType::FieldElement,
+                        ConcreteType::array((ConcreteType::Uint(UBitwidth::B32), 3u32)),
+                    ],
+                ),
+                ConcreteEnumVariant::new("Done".into(), vec![ConcreteType::Boolean]),
+            ],
+        ));
+        assert_eq!(t.get_primitive_count(), 6);
+        assert_eq!(format!("{}", t), "State");
+    }
 }
@@ -104,7 +104,7 @@ pub fn fold_statement<'ast, T: Field, F: Folder<'ast, T>>(
         ZirStatement::Definition(a, e) => {
             ZirStatement::Definition(f.fold_assignee(a), f.fold_expression(e))
         }
-        ZirStatement::IfElse(condition, consequence, alternative) => ZirStatement::IfElse(
+        ZirStatement::IfElse(condition, consequence, alternative, kind) => ZirStatement::IfElse(
             f.fold_boolean_expression(condition),
             consequence
                 .into_iter()
@@ -114,6 +114,7 @@ pub fn fold_statement<'ast, T: Field, F: Folder<'ast, T>>(
                 .into_iter()
                 .flat_map(|e| f.fold_statement(e))
                 .collect(),
+            kind,
         ),
         ZirStatement::Assertion(e, error) => {
             ZirStatement::Assertion(f.fold_boolean_expression(e), error)
@@ -122,6 +123,14 @@ pub fn fold_statement<'ast, T: Field, F: Folder<'ast, T>>(
             variables.into_iter().map(|v| f.fold_variable(v)).collect(),
             f.fold_expression_list(elist),
         ),
+        ZirStatement::Log(format_string, expressions) => ZirStatement::Log(
+            format_string,
+            expressions
+                .into_iter()
+                .map(|(t, e)| (t, e.into_iter().map(|e| f.fold_expression(e)).collect()))
+                .collect(),
+        ),
+        ZirStatement::Span(span) => ZirStatement::Span(span),
     };
     vec![res]
 }
@@ -10,7 +10,7 @@ impl From<typed_absy::types::ConcreteSignature> for zir::types::Signature {
     }
 }
 
-fn from_type(t: typed_absy::types::ConcreteType) -> Vec<zir::types::Type> {
+pub fn from_type(t: typed_absy::types::ConcreteType) -> Vec<zir::types::Type> {
     match t {
         typed_absy::types::ConcreteType::Int => unreachable!(),
         typed_absy::types::ConcreteType::FieldElement => vec![zir::Type::FieldElement],
@@ -26,5 +26,20 @@ fn from_type(t: typed_absy::types::ConcreteType) -> Vec<zir::types::Type> {
             .into_iter()
             .flat_map(|struct_member| from_type(*struct_member.ty))
             .collect(),
+        typed_absy::types::ConcreteType::Tuple(tuple_type) => tuple_type
+            .elements
+            .into_iter()
+            .flat_map(from_type)
+            .collect(),
+        typed_absy::types::ConcreteType::Enum(enum_type) => {
+            std::iter::once(zir::Type::FieldElement)
+                .chain(
+                    enum_type
+                        .variants
+                        .into_iter()
+                        .flat_map(|v| v.payload.into_iter().flat_map(from_type)),
+                )
+                .collect()
+        }
     }
 }
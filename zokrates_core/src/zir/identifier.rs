@@ -6,6 +6,7 @@ use crate::typed_absy::Identifier as CoreIdentifier;
 #[derive(Debug, PartialEq, Clone, Hash, Eq)]
 pub enum Identifier<'ast> {
     Source(SourceIdentifier<'ast>),
+    Internal(&'static str, usize),
 }
 
 #[derive(Debug, PartialEq, Clone, Hash, Eq)]
@@ -13,6 +14,9 @@ pub enum SourceIdentifier<'ast> {
     Basic(CoreIdentifier<'ast>),
     Select(Box<SourceIdentifier<'ast>>, u32),
     Member(Box<SourceIdentifier<'ast>>, MemberId),
+    Element(Box<SourceIdentifier<'ast>>, u32),
+    Tag(Box<SourceIdentifier<'ast>>),
+    Variant(Box<SourceIdentifier<'ast>>, u32),
 }
 
 impl<'ast> fmt::Display for SourceIdentifier<'ast> {
@@ -21,6 +25,9 @@ impl<'ast> fmt::Display for SourceIdentifier<'ast> {
             SourceIdentifier::Basic(i) => write!(f, "{}", i),
             SourceIdentifier::Select(box i, index) => write!(f, "{}~{}", i, index),
             SourceIdentifier::Member(box i, m) => write!(f, "{}.{}", i, m),
+            SourceIdentifier::Element(box i, index) => write!(f, "{}.{}", i, index),
+            SourceIdentifier::Tag(box i) => write!(f, "{}.tag", i),
+            SourceIdentifier::Variant(box i, variant) => write!(f, "{}::{}", i, variant),
         }
     }
 }
@@ -29,6 +36,7 @@ impl<'ast> fmt::Display for Identifier<'ast> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Identifier::Source(s) => write!(f, "{}", s),
+            Identifier::Internal(name, index) => write!(f, "#{}_{}", name, index),
         }
     }
 }
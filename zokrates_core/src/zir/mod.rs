@@ -13,12 +13,15 @@ pub use self::variable::Variable;
 pub use crate::zir::uint::{ShouldReduce, UExpression, UExpressionInner, UMetadata};
 
 use crate::embed::FlatEmbed;
+use crate::flat_absy::SourceSpan;
+use crate::typed_absy::{ConcreteType, FormatString};
 use crate::zir::types::Signature;
 use std::convert::TryFrom;
 use std::fmt;
 use zokrates_field::Field;
 
 pub use self::folder::Folder;
+pub use self::from_typed::from_type;
 pub use self::identifier::{Identifier, SourceIdentifier};
 
 /// A typed program as a collection of modules, one of them being the main
@@ -89,6 +92,7 @@ pub type ZirAssignee<'ast> = Variable<'ast>;
 pub enum RuntimeError {
     SourceAssertion(String),
     SelectRangeCheck,
+    EnumTagRangeCheck,
 }
 
 impl fmt::Display for RuntimeError {
@@ -96,10 +100,21 @@ impl fmt::Display for RuntimeError {
         match self {
             RuntimeError::SourceAssertion(message) => write!(f, "{}", message),
             RuntimeError::SelectRangeCheck => write!(f, "Range check on array access"),
+            RuntimeError::EnumTagRangeCheck => write!(f, "Range check on enum tag"),
         }
     }
 }
 
+/// The origin of the statements of an `IfElse` statement
+#[derive(Clone, Copy, PartialEq, Hash, Eq, Debug)]
+pub enum IfElseKind {
+    /// The side statements of the branches of a conditional expression, which are only made
+    /// conditional if branches are isolated
+    Expression,
+    /// The branches of an if/else statement, which are always made conditional
+    Statement,
+}
+
 /// A statement in a `ZirFunction`
 #[derive(Clone, PartialEq, Hash, Eq, Debug)]
 pub enum ZirStatement<'ast, T> {
@@ -109,9 +124,15 @@ pub enum ZirStatement<'ast, T> {
         BooleanExpression<'ast, T>,
         Vec<ZirStatement<'ast, T>>,
         Vec<ZirStatement<'ast, T>>,
+        IfElseKind,
     ),
     Assertion(BooleanExpression<'ast, T>, RuntimeError),
     MultipleDefinition(Vec<ZirAssignee<'ast>>, ZirExpressionList<'ast, T>),
+    Log(
+        FormatString,
+        Vec<(ConcreteType, Vec<ZirExpression<'ast, T>>)>,
+    ),
+    Span(SourceSpan),
 }
 
 impl<'ast, T: fmt::Display> fmt::Display for ZirStatement<'ast, T> {
@@ -128,7 +149,7 @@ impl<'ast, T: fmt::Display> fmt::Display for ZirStatement<'ast, T> {
                 write!(f, "")
             }
             ZirStatement::Definition(ref lhs, ref rhs) => write!(f, "{} = {}", lhs, rhs),
-            ZirStatement::IfElse(ref condition, ref consequence, ref alternative) => {
+            ZirStatement::IfElse(ref condition, ref consequence, ref alternative, _) => {
                 write!(
                     f,
                     "if {} then {{{}}} else {{{}}} fi",
@@ -161,6 +182,21 @@ impl<'ast, T: fmt::Display> fmt::Display for ZirStatement<'ast, T> {
                 }
                 write!(f, " = {}", rhs)
             }
+            ZirStatement::Log(ref format_string, ref expressions) => {
+                write!(f, "log(\"{}\"", format_string)?;
+                for (_, e) in expressions {
+                    write!(
+                        f,
+                        ", [{}]",
+                        e.iter()
+                            .map(|e| e.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                }
+                write!(f, ")")
+            }
+            ZirStatement::Span(ref span) => write!(f, "// {}", span),
         }
     }
 }
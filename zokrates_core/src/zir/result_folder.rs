@@ -120,7 +120,7 @@ pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
         ZirStatement::Definition(a, e) => {
             ZirStatement::Definition(f.fold_assignee(a)?, f.fold_expression(e)?)
         }
-        ZirStatement::IfElse(condition, consequence, alternative) => ZirStatement::IfElse(
+        ZirStatement::IfElse(condition, consequence, alternative, kind) => ZirStatement::IfElse(
             f.fold_boolean_expression(condition)?,
             consequence
                 .into_iter()
@@ -136,6 +136,7 @@ pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
                 .into_iter()
                 .flatten()
                 .collect(),
+            kind,
         ),
         ZirStatement::Assertion(e, error) => {
             ZirStatement::Assertion(f.fold_boolean_expression(e)?, error)
@@ -147,6 +148,19 @@ pub fn fold_statement<'ast, T: Field, F: ResultFolder<'ast, T>>(
                 .collect::<Result<_, _>>()?,
             f.fold_expression_list(elist)?,
         ),
+        ZirStatement::Log(format_string, expressions) => ZirStatement::Log(
+            format_string,
+            expressions
+                .into_iter()
+                .map(|(t, e)| {
+                    e.into_iter()
+                        .map(|e| f.fold_expression(e))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map(|e| (t, e))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        ZirStatement::Span(span) => ZirStatement::Span(span),
     };
     Ok(vec![res])
 }
@@ -0,0 +1,132 @@
+extern crate zokrates_core;
+extern crate zokrates_field;
+
+use zokrates_core::compile::CompileConfig;
+use zokrates_core::{
+    compile::{compile, CompilationArtifacts},
+    ir::Interpreter,
+};
+use zokrates_field::Bn128Field;
+use zokrates_fs_resolver::FileSystemResolver;
+
+fn compile_with(source: &str, config: &CompileConfig) -> CompilationArtifacts<Bn128Field> {
+    let stdlib_path = std::fs::canonicalize(
+        std::env::current_dir()
+            .unwrap()
+            .join("../zokrates_stdlib/stdlib"),
+    )
+    .unwrap();
+
+    compile(
+        source.to_string(),
+        "./path/to/file".into(),
+        Some(&FileSystemResolver::with_stdlib_root(
+            stdlib_path.to_str().unwrap(),
+        )),
+        config,
+    )
+    .unwrap()
+}
+
+// compiles `source` with and without linear elimination, checking that both programs return the
+// same values on `inputs`, and returns the number of constraints before and after elimination
+fn check(source: &str, inputs: &[Bn128Field]) -> (usize, usize) {
+    let original = compile_with(source, &CompileConfig::default());
+    let optimized = compile_with(
+        source,
+        &CompileConfig::default().linear_elimination(Some(1000)),
+    );
+
+    let (before, after) = (
+        original.prog().constraint_count(),
+        optimized.prog().constraint_count(),
+    );
+
+    let interpreter = Interpreter::default();
+
+    assert_eq!(
+        interpreter
+            .execute(original.prog(), inputs)
+            .unwrap()
+            .return_values(),
+        interpreter
+            .execute(optimized.prog(), inputs)
+            .unwrap()
+            .return_values()
+    );
+
+    (before, after)
+}
+
+fn u32_inputs(count: u32) -> Vec<Bn128Field> {
+    (0..count)
+        .map(|i| Bn128Field::from(i.wrapping_mul(0x9e3779b9)))
+        .collect()
+}
+
+#[test]
+fn sha256() {
+    let source = r#"
+        import "hashes/sha256/512bit" as sha256
+
+        def main(u32[8] a, u32[8] b) -> u32[8]:
+            return sha256(a, b)
+    "#;
+
+    let (before, after) = check(source, &u32_inputs(16));
+    assert!(after < before);
+}
+
+#[test]
+fn blake2s() {
+    let source = r#"
+        import "hashes/blake2/blake2s" as blake2s
+
+        def main(u32[16] a) -> u32[8]:
+            return blake2s([a])
+    "#;
+
+    let (before, after) = check(source, &u32_inputs(16));
+    assert!(after < before);
+}
+
+#[test]
+fn pedersen() {
+    let source = r#"
+        import "hashes/pedersen/512bit" as pedersen
+
+        def main(u32[16] a) -> u32[8]:
+            return pedersen(a)
+    "#;
+
+    let (before, after) = check(source, &u32_inputs(16));
+    assert!(after < before);
+}
+
+#[test]
+fn mimc7() {
+    let source = r#"
+        import "hashes/mimc7/mimc7" as mimc7
+
+        def main(field x, field k) -> field:
+            return mimc7::<10>(x, k)
+    "#;
+
+    // only field arithmetic, whose linear combinations are already inlined: there is no linear
+    // check to eliminate
+    let (before, after) = check(source, &[Bn128Field::from(42), Bn128Field::from(1337)]);
+    assert_eq!(after, before);
+}
+
+#[test]
+fn poseidon() {
+    let source = r#"
+        import "hashes/poseidon/poseidon" as poseidon
+
+        def main(field a, field b) -> field:
+            return poseidon([a, b])
+    "#;
+
+    let (before, after) = check(source, &[Bn128Field::from(42), Bn128Field::from(1337)]);
+    assert_eq!(after, before);
+}
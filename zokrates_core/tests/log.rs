@@ -0,0 +1,87 @@
+extern crate zokrates_core;
+extern crate zokrates_field;
+
+use std::io;
+use zokrates_common::Resolver;
+use zokrates_core::compile::CompileConfig;
+use zokrates_core::{
+    compile::{compile, CompilationArtifacts},
+    ir::Interpreter,
+};
+use zokrates_field::Bn128Field;
+
+fn compile_source(source: &str) -> CompilationArtifacts<Bn128Field> {
+    compile(
+        source.to_string(),
+        "./path/to/file".into(),
+        None::<&dyn Resolver<io::Error>>,
+        &CompileConfig::default(),
+    )
+    .unwrap()
+}
+
+fn run(artifacts: &CompilationArtifacts<Bn128Field>, inputs: &[Bn128Field]) -> String {
+    let mut logs = vec![];
+    Interpreter::default()
+        .execute_with_log_stream(artifacts.prog(), inputs, &mut logs)
+        .unwrap();
+    String::from_utf8(logs).unwrap()
+}
+
+#[test]
+fn no_constraints() {
+    // logging values which need intermediate variables must not add constraints, even though
+    // they are computed from private inputs
+    let with_log = compile_source(
+        r#"
+        def main(private field a, private field b, u32 c) -> field:
+            log("{} {} {} {}", a * b, a * b * a, a < b, c + 1)
+            return a + b
+    "#,
+    );
+
+    let without_log = compile_source(
+        r#"
+        def main(private field a, private field b, u32 c) -> field:
+            return a + b
+    "#,
+    );
+
+    assert_eq!(
+        with_log.prog().constraint_count(),
+        without_log.prog().constraint_count()
+    );
+
+    assert_eq!(
+        run(
+            &with_log,
+            &[
+                Bn128Field::from(2),
+                Bn128Field::from(3),
+                Bn128Field::from(41)
+            ]
+        ),
+        "6 12 true 42\n"
+    );
+}
+
+#[test]
+fn branches() {
+    // logs are only printed in the branches which are taken
+    let artifacts = compile_source(
+        r#"
+        def main(field a) -> field:
+            field b = 0
+            if a == 1 {
+                log("one")
+                b = 1
+            } else {
+                log("not one {}", a)
+            }
+            return b
+    "#,
+    );
+
+    assert_eq!(run(&artifacts, &[Bn128Field::from(1)]), "one\n");
+    assert_eq!(run(&artifacts, &[Bn128Field::from(2)]), "not one 2\n");
+}
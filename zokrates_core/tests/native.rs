@@ -0,0 +1,106 @@
+extern crate zokrates_core;
+extern crate zokrates_field;
+
+use std::fs::{self, File};
+use std::path::Path;
+use std::process::Command;
+use zokrates_core::compile::CompileConfig;
+use zokrates_core::{
+    compile::{compile, CompilationArtifacts},
+    ir::native::{Dependency, NativeWitnessGenerator},
+    ir::{Interpreter, Witness},
+};
+use zokrates_field::{Bn128Field, Field};
+use zokrates_fs_resolver::FileSystemResolver;
+
+fn compile_source(source: &str) -> CompilationArtifacts<Bn128Field> {
+    let stdlib_path = std::fs::canonicalize(
+        std::env::current_dir()
+            .unwrap()
+            .join("../zokrates_stdlib/stdlib"),
+    )
+    .unwrap();
+
+    compile(
+        source.to_string(),
+        "./path/to/file".into(),
+        Some(&FileSystemResolver::with_stdlib_root(
+            stdlib_path.to_str().unwrap(),
+        )),
+        &CompileConfig::default(),
+    )
+    .unwrap()
+}
+
+// runs the generated witness generator in `dir` on `inputs`, returning the witness or the error
+// message printed on failure
+fn run(dir: &Path, inputs: &[Bn128Field]) -> Result<Witness<Bn128Field>, String> {
+    let witness_path = dir.join("witness");
+
+    let output = Command::new("cargo")
+        .current_dir(dir)
+        .args(&["run", "--release", "--quiet", "--"])
+        .arg(&witness_path)
+        .args(inputs.iter().map(|i| i.to_dec_string()))
+        .output()
+        .unwrap();
+
+    match output.status.success() {
+        true => Ok(Witness::read_text(File::open(&witness_path).unwrap()).unwrap()),
+        false => Err(String::from_utf8(output.stderr).unwrap().trim().to_string()),
+    }
+}
+
+// builds the generated crate, which needs the dependencies of the compiler to be available
+#[test]
+#[ignore]
+fn sha256() {
+    let artifacts = compile_source(
+        r#"
+        import "hashes/sha256/512bit" as sha256
+
+        def main(u32[8] a, u32[8] b) -> u32[8]:
+            assert(a[0] != b[0])
+            return sha256(a, b)
+    "#,
+    );
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    let generator = NativeWitnessGenerator {
+        prog: artifacts.prog(),
+        name: "sha256-witness".to_string(),
+        dependency: Dependency::Path(root.to_path_buf()),
+    };
+
+    let dir = std::env::temp_dir().join("zokrates_native_sha256");
+    for (path, content) in generator.files().unwrap() {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    // build with the toolchain of the compiler
+    fs::copy(
+        root.join("rust-toolchain.toml"),
+        dir.join("rust-toolchain.toml"),
+    )
+    .unwrap();
+
+    let interpreter = Interpreter::default();
+
+    let inputs: Vec<_> = (0..16u32).map(Bn128Field::from).collect();
+    assert_eq!(
+        run(&dir, &inputs).unwrap(),
+        interpreter.execute(artifacts.prog(), &inputs).unwrap()
+    );
+
+    // the assertion fails when the first elements are equal
+    let inputs: Vec<_> = (0..16u32).map(|i| Bn128Field::from(i % 8)).collect();
+    assert_eq!(
+        run(&dir, &inputs).unwrap_err(),
+        format!(
+            "Execution failed: {}",
+            interpreter.execute(artifacts.prog(), &inputs).unwrap_err()
+        )
+    );
+}
@@ -28,7 +28,7 @@ fn generate_proof() {
         .execute(&program, &[Bn128Field::from(42)])
         .unwrap();
 
-    let keypair = <Bellman as NonUniversalBackend<Bn128Field, G16>>::setup(program.clone());
+    let keypair = <Bellman as NonUniversalBackend<Bn128Field, G16>>::setup(program.clone(), None);
     let _proof =
-        <Bellman as Backend<Bn128Field, G16>>::generate_proof(program, witness, keypair.pk);
+        <Bellman as Backend<Bn128Field, G16>>::generate_proof(program, witness, keypair.pk, None);
 }
@@ -25,7 +25,8 @@ declare module 'zokrates-js' {
     beta: G2Affine,
     gamma: G2Affine,
     delta: G2Affine,
-    gamma_abc: G1Affine[]
+    gamma_abc: G1Affine[],
+    fingerprint?: string
   }
 
   export interface ProofPoints {
@@ -36,7 +37,8 @@ declare module 'zokrates-js' {
 
   export interface Proof {
     proof: ProofPoints,
-    inputs: string[]
+    inputs: string[],
+    fingerprint?: string
   }
 
   export interface ResolverResult {
@@ -13,7 +13,8 @@ use zokrates_core::ir;
 use zokrates_core::proof_system::bellman::Bellman;
 use zokrates_core::proof_system::groth16::G16;
 use zokrates_core::proof_system::{
-    Backend, NonUniversalBackend, Proof, Scheme, SolidityCompatibleScheme,
+    check_proof, check_proving_key, Backend, BoundVerificationKey, NonUniversalBackend, Proof,
+    Scheme, SolidityCompatibleScheme,
 };
 use zokrates_core::typed_absy::abi::Abi;
 use zokrates_core::typed_absy::types::ConcreteSignature as Signature;
@@ -39,7 +40,8 @@ pub struct ComputationResult {
 
 #[inline]
 fn deserialize_program(value: &[u8]) -> Result<ir::Prog<Bn128Field>, JsValue> {
-    let prog = ir::ProgEnum::deserialize(value).map_err(|err| JsValue::from_str(&err))?;
+    let prog =
+        ir::ProgEnum::deserialize(value).map_err(|err| JsValue::from_str(&err.to_string()))?;
     match prog {
         ir::ProgEnum::Bn128Program(p) => Ok(p),
         _ => Err(JsValue::from_str("Unsupported binary")),
@@ -47,9 +49,11 @@ fn deserialize_program(value: &[u8]) -> Result<ir::Prog<Bn128Field>, JsValue> {
 }
 
 #[inline]
-fn serialize_program(program: &ir::Prog<Bn128Field>) -> Vec<u8> {
+fn serialize_program(program: &ir::Prog<Bn128Field>, metadata: &ir::ProgMetadata) -> Vec<u8> {
     let mut buffer = Cursor::new(vec![]);
-    program.serialize(&mut buffer);
+    program
+        .serialize_with_metadata(&mut buffer, metadata)
+        .unwrap();
     buffer.into_inner()
 }
 
@@ -123,7 +127,7 @@ pub fn compile(
     })?;
 
     let result = CompilationResult {
-        program: serialize_program(artifacts.prog()),
+        program: serialize_program(artifacts.prog(), artifacts.metadata()),
         abi: to_string_pretty(artifacts.abi()).unwrap(),
     };
 
@@ -163,7 +167,9 @@ pub fn compute_witness(program: &[u8], abi: JsValue, args: JsValue) -> Result<Js
 #[wasm_bindgen]
 pub fn setup(program: &[u8]) -> Result<JsValue, JsValue> {
     let program_flattened = deserialize_program(program)?;
-    let keypair = <Bellman as NonUniversalBackend<Bn128Field, G16>>::setup(program_flattened);
+    let fingerprint = program_flattened.fingerprint();
+    let keypair = <Bellman as NonUniversalBackend<Bn128Field, G16>>::setup(program_flattened, None)
+        .bind(fingerprint);
     Ok(JsValue::from_serde(&keypair).unwrap())
 }
 
@@ -181,24 +187,32 @@ pub fn generate_proof(program: &[u8], witness: JsValue, pk: &[u8]) -> Result<JsV
     let program_flattened = deserialize_program(program)?;
     let str_witness = witness.as_string().unwrap();
 
-    let ir_witness: ir::Witness<Bn128Field> = ir::Witness::read(str_witness.as_bytes())
+    let ir_witness: ir::Witness<Bn128Field> = ir::Witness::read_text(str_witness.as_bytes())
         .map_err(|err| JsValue::from_str(&format!("Could not read witness: {}", err)))?;
 
-    let proof = <Bellman as Backend<Bn128Field, G16>>::generate_proof(
+    let fingerprint = program_flattened.fingerprint();
+    let pk = check_proving_key(pk.to_vec(), fingerprint).map_err(|e| JsValue::from_str(&e))?;
+
+    let mut proof = <Bellman as Backend<Bn128Field, G16>>::generate_proof(
         program_flattened,
         ir_witness,
-        pk.to_vec(),
+        pk,
+        None,
     );
+    proof.fingerprint = Some(fingerprint);
 
     Ok(JsValue::from_serde(&proof).unwrap())
 }
 
 #[wasm_bindgen]
 pub fn verify(vk: JsValue, proof: JsValue) -> Result<JsValue, JsValue> {
-    let vk: <G16 as Scheme<Bn128Field>>::VerificationKey = vk.into_serde().unwrap();
+    let vk: BoundVerificationKey<<G16 as Scheme<Bn128Field>>::VerificationKey> =
+        vk.into_serde().unwrap();
     let proof: Proof<<G16 as Scheme<Bn128Field>>::ProofPoints> = proof.into_serde().unwrap();
 
-    let ans = <Bellman as Backend<Bn128Field, G16>>::verify(vk, proof);
+    check_proof(&vk, &proof).map_err(|e| JsValue::from_str(&e))?;
+
+    let ans = <Bellman as Backend<Bn128Field, G16>>::verify(vk.vk, proof);
     Ok(JsValue::from_serde(&ans).unwrap())
 }
 
@@ -0,0 +1,117 @@
+use lsp_types::{CompletionItem, CompletionItemKind, Position};
+use std::fs;
+use std::path::Path;
+
+/// Returns the import paths which complete the one being typed at `position`, if any. Paths
+/// starting with `./` or `../` are relative to the current file, other paths are resolved in the
+/// standard library.
+pub fn completion(
+    source: &str,
+    path: &Path,
+    position: Position,
+    stdlib_path: &str,
+) -> Option<Vec<CompletionItem>> {
+    let line = source.lines().nth(position.line as usize)?;
+    let before = &line[..(position.character as usize).min(line.len())];
+
+    let prefix = import_prefix(before)?;
+
+    // split what is typed into the folder to list and the start of the entry
+    let (folder, start) = match prefix.rfind('/') {
+        Some(i) => (&prefix[..i + 1], &prefix[i + 1..]),
+        None => ("", prefix),
+    };
+
+    let root = match prefix.starts_with("./") || prefix.starts_with("../") {
+        true => path.parent()?.to_path_buf(),
+        false => Path::new(stdlib_path).to_path_buf(),
+    };
+
+    let mut items: Vec<_> = fs::read_dir(root.join(folder))
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let entry_path = entry.path();
+            let name = entry.file_name().into_string().ok()?;
+
+            if entry_path.is_dir() {
+                Some((name, CompletionItemKind::FOLDER))
+            } else if entry_path.extension().map(|e| e == "zok").unwrap_or(false) {
+                let stem = entry_path.file_stem()?.to_str()?.to_string();
+                Some((stem, CompletionItemKind::FILE))
+            } else {
+                None
+            }
+        })
+        .filter(|(name, _)| name.starts_with(start))
+        .map(|(name, kind)| CompletionItem {
+            label: name,
+            kind: Some(kind),
+            ..CompletionItem::default()
+        })
+        .collect();
+
+    items.sort_by(|a, b| a.label.cmp(&b.label));
+
+    Some(items)
+}
+
+/// Returns the part of the import path typed so far if `before` ends inside the path of an import
+fn import_prefix(before: &str) -> Option<&str> {
+    let trimmed = before.trim_start();
+
+    if !(trimmed.starts_with("from") || trimmed.starts_with("import")) {
+        return None;
+    }
+
+    // the path is the first string literal of the line, which must still be open
+    let (_, rest) = before.split_once('"')?;
+    match rest.contains('"') {
+        true => None,
+        false => Some(rest),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(items: Vec<CompletionItem>) -> Vec<String> {
+        items.into_iter().map(|i| i.label).collect()
+    }
+
+    #[test]
+    fn stdlib_paths() {
+        let stdlib = tempfile::tempdir().unwrap();
+        fs::create_dir_all(stdlib.path().join("hashes/sha256")).unwrap();
+        fs::write(stdlib.path().join("hashes/poseidon.zok"), "").unwrap();
+        fs::write(stdlib.path().join("hashes/README.md"), "").unwrap();
+        let stdlib_path = stdlib.path().to_str().unwrap();
+
+        let path = Path::new("main.zok");
+
+        let source = "from \"hashes/";
+        let res = completion(source, path, Position::new(0, 13), stdlib_path).unwrap();
+        assert_eq!(labels(res), vec!["poseidon", "sha256"]);
+
+        let source = "import \"hashes/s";
+        let res = completion(source, path, Position::new(0, 16), stdlib_path).unwrap();
+        assert_eq!(labels(res), vec!["sha256"]);
+
+        // outside of an import path
+        let source = "from \"hashes/poseidon\" import main";
+        assert!(completion(source, path, Position::new(0, 34), stdlib_path).is_none());
+    }
+
+    #[test]
+    fn relative_paths() {
+        let folder = tempfile::tempdir().unwrap();
+        fs::write(folder.path().join("lib.zok"), "").unwrap();
+
+        let path = folder.path().join("main.zok");
+
+        let source = "from \"./";
+        let res = completion(source, &path, Position::new(0, 8), "").unwrap();
+        assert_eq!(labels(res), vec!["lib"]);
+    }
+}
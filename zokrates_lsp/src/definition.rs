@@ -0,0 +1,132 @@
+use crate::source;
+use lsp_types::{Location, Position, Url};
+use std::path::{Path, PathBuf};
+use zokrates_common::Resolver;
+use zokrates_fs_resolver::FileSystemResolver;
+use zokrates_pest_ast::{self as pest, ImportDirective, Span, SymbolDeclaration};
+
+/// The maximum number of imports to follow, to protect against import cycles
+const MAX_IMPORT_DEPTH: usize = 32;
+
+/// Returns the location of the declaration of the function, struct, enum, type or constant at
+/// `position`, following imports
+pub fn definition(
+    source: &str,
+    path: &Path,
+    position: Position,
+    stdlib_path: &str,
+) -> Option<Location> {
+    let (id, _) = source::word_at(source, position)?;
+    let resolver = FileSystemResolver::with_stdlib_root(stdlib_path);
+
+    find_declaration(source, path, id, &resolver, 0)
+}
+
+fn location(path: &Path, span: &Span) -> Option<Location> {
+    Some(Location::new(
+        Url::from_file_path(path).ok()?,
+        source::span_range(span),
+    ))
+}
+
+/// Returns the location of the declaration of `id` in the module `source` at `path`
+fn find_declaration(
+    source: &str,
+    path: &Path,
+    id: &str,
+    resolver: &FileSystemResolver,
+    depth: usize,
+) -> Option<Location> {
+    if depth > MAX_IMPORT_DEPTH {
+        return None;
+    }
+
+    let ast = pest::generate_ast(source).ok()?;
+
+    // follow the import of `symbol` from `import_source`
+    let follow = |import_source: &str, symbol: &str| {
+        let (source, path) = resolver
+            .resolve(path.to_path_buf(), PathBuf::from(import_source))
+            .ok()?;
+        find_declaration(&source, &path, symbol, resolver, depth + 1)
+    };
+
+    for declaration in &ast.declarations {
+        let found = match declaration {
+            SymbolDeclaration::Function(f) if f.id.span.as_str() == id => {
+                location(path, &f.id.span)
+            }
+            SymbolDeclaration::Struct(s) if s.id.span.as_str() == id => location(path, &s.id.span),
+            SymbolDeclaration::Enum(e) if e.id.span.as_str() == id => location(path, &e.id.span),
+            SymbolDeclaration::Type(t) if t.id.span.as_str() == id => location(path, &t.id.span),
+            SymbolDeclaration::Constant(c) if c.id.span.as_str() == id => {
+                location(path, &c.id.span)
+            }
+            SymbolDeclaration::Import(ImportDirective::Main(i)) => {
+                // without an alias, the imported `main` is named after the file
+                let name = match &i.alias {
+                    Some(alias) => alias.span.as_str(),
+                    None => Path::new(i.source.span.as_str())
+                        .file_stem()
+                        .and_then(|s| s.to_str())
+                        .unwrap_or(""),
+                };
+                match name == id {
+                    true => follow(i.source.span.as_str(), "main"),
+                    false => continue,
+                }
+            }
+            SymbolDeclaration::Import(ImportDirective::From(i)) => {
+                match i
+                    .symbols
+                    .iter()
+                    .find(|s| s.alias.as_ref().unwrap_or(&s.id).span.as_str() == id)
+                {
+                    Some(s) => follow(i.source.span.as_str(), s.id.span.as_str()),
+                    None => continue,
+                }
+            }
+            _ => continue,
+        };
+
+        if found.is_some() {
+            return found;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn across_imports() {
+        let folder = tempfile::tempdir().unwrap();
+
+        let lib = "struct Point {\n  field x\n}\n\ndef foo() -> field:\n  return 1\n";
+        let lib_path = folder.path().join("lib.zok");
+        fs::write(&lib_path, lib).unwrap();
+
+        let main =
+            "from \"./lib\" import foo as bar, Point\n\ndef main() -> field:\n  return bar()\n";
+        let main_path = folder.path().join("main.zok");
+        fs::write(&main_path, main).unwrap();
+
+        // the call to `bar` leads to `foo` in `lib.zok`
+        let location = definition(main, &main_path, Position::new(3, 10), "").unwrap();
+        assert_eq!(location.uri, Url::from_file_path(&lib_path).unwrap());
+        assert_eq!(location.range.start, Position::new(4, 4));
+
+        // the imported struct leads to its declaration
+        let location = definition(main, &main_path, Position::new(0, 34), "").unwrap();
+        assert_eq!(location.range.start, Position::new(0, 7));
+
+        // `main` is declared in the file itself
+        let location = definition(main, &main_path, Position::new(2, 5), "").unwrap();
+        assert_eq!(location.uri, Url::from_file_path(&main_path).unwrap());
+        assert_eq!(location.range.start, Position::new(2, 4));
+    }
+}
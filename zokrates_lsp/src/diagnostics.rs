@@ -0,0 +1,83 @@
+use crate::source;
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Range};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use zokrates_core::compile::{check, CompileConfig, CompileErrorInner};
+use zokrates_core::flat_absy::Position;
+use zokrates_field::Field;
+use zokrates_fs_resolver::FileSystemResolver;
+
+/// Checks the program with entry point `path` and returns its errors and warnings, grouped by file
+pub fn diagnostics<T: Field>(
+    source: &str,
+    path: &Path,
+    stdlib_path: &str,
+) -> HashMap<PathBuf, Vec<Diagnostic>> {
+    let resolver = FileSystemResolver::with_stdlib_root(stdlib_path);
+
+    let (warnings, errors) = match check::<T, _>(
+        source.to_string(),
+        path.to_path_buf(),
+        Some(&resolver),
+        &CompileConfig::default(),
+    ) {
+        Ok(warnings) => (warnings.0, vec![]),
+        Err(e) => (vec![], e.0),
+    };
+
+    let mut res: HashMap<_, Vec<_>> = HashMap::new();
+    res.insert(path.to_path_buf(), vec![]);
+
+    for error in errors {
+        let (range, message) = match error.value() {
+            CompileErrorInner::SemanticError(e) => (error_range(e.pos()), e.message().to_string()),
+            CompileErrorInner::ImportError(e) => (error_range(e.pos()), e.message().to_string()),
+            CompileErrorInner::DeniedWarning(e) => (error_range(e.pos()), e.message().to_string()),
+            CompileErrorInner::ParserError(e) => {
+                let ((start_line, start_col), (end_line, end_col)) = e.line_col();
+                (
+                    Range::new(
+                        source::position(start_line, start_col),
+                        source::position(end_line, end_col),
+                    ),
+                    e.to_string(),
+                )
+            }
+            e => (Range::default(), e.to_string().trim().to_string()),
+        };
+
+        res.entry(error.file().clone())
+            .or_default()
+            .push(Diagnostic {
+                severity: Some(DiagnosticSeverity::ERROR),
+                source: Some("zokrates".into()),
+                ..Diagnostic::new_simple(range, message)
+            });
+    }
+
+    for warning in warnings {
+        let value = warning.value();
+
+        res.entry(warning.file().clone())
+            .or_default()
+            .push(Diagnostic {
+                severity: Some(DiagnosticSeverity::WARNING),
+                code: Some(NumberOrString::String(value.lint().to_string())),
+                source: Some("zokrates".into()),
+                ..Diagnostic::new_simple(error_range(value.pos()), value.message().to_string())
+            });
+    }
+
+    res
+}
+
+/// Converts the position of an error to a range, defaulting to the start of the file
+fn error_range(pos: &Option<(Position, Position)>) -> Range {
+    pos.map(|(start, end)| {
+        Range::new(
+            source::position(start.line, start.col),
+            source::position(end.line, end.col),
+        )
+    })
+    .unwrap_or_default()
+}
@@ -0,0 +1,270 @@
+use crate::source;
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position};
+use std::path::Path;
+use typed_arena::Arena;
+use zokrates_core::compile::check_types;
+use zokrates_core::flat_absy::SourceSpan;
+use zokrates_core::typed_absy::{
+    CoreIdentifier, TypedAssignee, TypedConstantSymbol, TypedFunction, TypedFunctionSymbol,
+    TypedModule, TypedProgram, TypedStatement, TypedSymbolDeclaration, Variable,
+};
+use zokrates_field::Field;
+use zokrates_fs_resolver::FileSystemResolver;
+use zokrates_pest_ast as pest;
+
+/// Returns the type of the symbol at `position`, along with the concrete generics it is called
+/// with if it is a generic function
+pub fn hover<T: Field>(
+    source: &str,
+    path: &Path,
+    position: Position,
+    stdlib_path: &str,
+) -> Option<Hover> {
+    let (id, range) = source::word_at(source, position)?;
+
+    let resolver = FileSystemResolver::with_stdlib_root(stdlib_path);
+    let arena = Arena::new();
+    let typed = check_types::<T, _>(source, path.to_path_buf(), Some(&resolver), &arena).ok()?;
+    let module = typed.modules.get(&typed.main)?;
+
+    let mut lines = vec![];
+
+    if let Some(ty) = local_type(source, module, id, position) {
+        lines.push(format!("{} {}", ty, id));
+    } else {
+        lines.extend(module_symbol(module, id));
+
+        let mut assignments = call_generics(&typed, path, id, position);
+        assignments.dedup();
+        lines.extend(assignments.into_iter().map(|a| format!("// with {}", a)));
+    }
+
+    match lines.is_empty() {
+        true => None,
+        false => Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```zokrates\n{}\n```", lines.join("\n")),
+            }),
+            range: Some(range),
+        }),
+    }
+}
+
+/// Returns the signatures of the functions and the types of the constants named `id` in `module`
+fn module_symbol<T: Field>(module: &TypedModule<T>, id: &str) -> Vec<String> {
+    module
+        .symbols
+        .iter()
+        .filter_map(|symbol| match symbol {
+            TypedSymbolDeclaration::Function(d) if d.key.id == id => {
+                Some(format!("def {}{}", id, d.key.signature))
+            }
+            TypedSymbolDeclaration::Constant(d) if d.id.id == id => match &d.symbol {
+                TypedConstantSymbol::Here(c) => Some(format!("const {} {}", c.ty, id)),
+                TypedConstantSymbol::There(c) => Some(format!("const {} // from {}", id, c)),
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the type of the local variable `id` visible at `position`
+fn local_type<T: Field>(
+    source: &str,
+    module: &TypedModule<T>,
+    id: &str,
+    position: Position,
+) -> Option<String> {
+    let ast = pest::generate_ast(source).ok()?;
+
+    // find the function the cursor is in
+    let definition = ast.declarations.iter().find_map(|d| match d {
+        pest::SymbolDeclaration::Function(f) if source::span_contains(&f.span, position) => Some(f),
+        _ => None,
+    })?;
+
+    let function = module.functions_iter().find_map(|d| match &d.symbol {
+        TypedFunctionSymbol::Here(f)
+            if d.key.id == definition.id.span.as_str()
+                && f.arguments.len() == definition.parameters.len() =>
+        {
+            Some(f)
+        }
+        _ => None,
+    })?;
+
+    let mut visitor = LocalVariables {
+        id,
+        position: source::line_col(position),
+        span: None,
+        ty: None,
+    };
+
+    visitor.visit_function(function);
+
+    visitor.ty
+}
+
+/// Finds the last declaration of a variable before a given position
+struct LocalVariables<'a> {
+    id: &'a str,
+    position: (usize, usize),
+    span: Option<(usize, usize)>,
+    ty: Option<String>,
+}
+
+impl<'a> LocalVariables<'a> {
+    fn visit_function<T: Field>(&mut self, f: &TypedFunction<T>) {
+        if let Some(p) = f
+            .arguments
+            .iter()
+            .find(|p| p.id.id.id == CoreIdentifier::Source(self.id))
+        {
+            self.ty = Some(p.id._type.to_string());
+        }
+
+        self.visit_statements(&f.statements);
+    }
+
+    fn visit_statements<T: Field>(&mut self, statements: &[TypedStatement<T>]) {
+        for s in statements {
+            match s {
+                TypedStatement::Span(span) => {
+                    self.span = Some((span.start.line, span.start.col));
+                }
+                TypedStatement::Declaration(v) => self.visit_variable(v),
+                TypedStatement::Definition(TypedAssignee::Identifier(v), _) => {
+                    self.visit_variable(v)
+                }
+                TypedStatement::MultipleDefinition(assignees, _) => {
+                    for a in assignees {
+                        if let TypedAssignee::Identifier(v) = a {
+                            self.visit_variable(v)
+                        }
+                    }
+                }
+                TypedStatement::For(v, _, _, statements) => {
+                    self.visit_variable(v);
+                    self.visit_statements(statements);
+                }
+                TypedStatement::IfElse(_, consequence, alternative) => {
+                    self.visit_statements(consequence);
+                    self.visit_statements(alternative);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_variable<T: Field>(&mut self, v: &Variable<T>) {
+        let before_cursor = self.span.map(|s| s <= self.position).unwrap_or(false);
+
+        if before_cursor && v.id.id == CoreIdentifier::Source(self.id) {
+            self.ty = Some(v._type.to_string());
+        }
+    }
+}
+
+/// Returns the concrete generics of the calls to `id` at `position`, by reducing the program to
+/// find out which values the generics take at each call site
+fn call_generics<T: Field>(
+    typed: &TypedProgram<T>,
+    path: &Path,
+    id: &str,
+    position: Position,
+) -> Vec<String> {
+    // only programs with a `main` function can be reduced
+    let has_main = typed
+        .modules
+        .get(&typed.main)
+        .map(|m| m.functions_iter().any(|d| d.key.id == "main"))
+        .unwrap_or(false);
+
+    if !has_main {
+        return vec![];
+    }
+
+    let reduced = match typed.clone().reduce() {
+        Ok(reduced) => reduced,
+        Err(_) => return vec![],
+    };
+
+    let file = path.display().to_string();
+    let position = source::line_col(position);
+
+    let contains = |span: &SourceSpan| {
+        span.file == file
+            && (span.start.line, span.start.col) <= position
+            && position <= (span.end.line, span.end.col)
+    };
+
+    let mut res = vec![];
+    let mut current: Option<&SourceSpan> = None;
+    // the call sites of the calls being inlined
+    let mut call_sites = vec![];
+
+    for module in reduced.modules.values() {
+        for function in module.functions_iter() {
+            if let TypedFunctionSymbol::Here(f) = &function.symbol {
+                for s in &f.statements {
+                    match s {
+                        TypedStatement::Span(span) => current = Some(span),
+                        TypedStatement::PushCallLog(key, assignment) => {
+                            if key.id == id
+                                && !assignment.0.is_empty()
+                                && current.map(contains).unwrap_or(false)
+                            {
+                                res.push(assignment.to_string());
+                            }
+                            call_sites.push(current);
+                        }
+                        TypedStatement::PopCallLog => {
+                            current = call_sites.pop().flatten();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zokrates_field::Bn128Field;
+
+    fn value(hover: Hover) -> String {
+        match hover.contents {
+            HoverContents::Markup(m) => m.value,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn local_variable() {
+        let source = "def main(field a) -> field:\n  u32 b = 1\n  field c = a\n  return c\n";
+        let path = Path::new("main.zok");
+
+        let res = hover::<Bn128Field>(source, path, Position::new(3, 10), "").unwrap();
+        assert_eq!(value(res), "```zokrates\nfield c\n```");
+
+        let res = hover::<Bn128Field>(source, path, Position::new(1, 6), "").unwrap();
+        assert_eq!(value(res), "```zokrates\nu32 b\n```");
+    }
+
+    #[test]
+    fn generic_call() {
+        let source = "def foo<N>(field[N] a) -> field:\n  return a[0]\n\ndef main() -> field:\n  return foo([1, 2, 3])\n";
+        let path = Path::new("main.zok");
+
+        let res = hover::<Bn128Field>(source, path, Position::new(4, 10), "").unwrap();
+        assert_eq!(
+            value(res),
+            "```zokrates\ndef foo<N>(field[N]) -> field\n// with N: 3\n```"
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! A language server for ZoKrates, providing diagnostics, go-to-definition, hover and import path
+//! completion over the language server protocol on stdio
+
+#[macro_use]
+mod source;
+mod completion;
+mod definition;
+mod diagnostics;
+mod hover;
+
+use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+    Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{Completion, GotoDefinition, HoverRequest, Request as _};
+use lsp_types::{
+    CompletionOptions, CompletionResponse, GotoDefinitionResponse, HoverProviderCapability,
+    InitializeParams, OneOf, PublishDiagnosticsParams, SaveOptions, ServerCapabilities,
+    TextDocumentPositionParams, TextDocumentSyncCapability, TextDocumentSyncKind,
+    TextDocumentSyncOptions, TextDocumentSyncSaveOptions, Url,
+};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::path::Path;
+
+type Result<T> = std::result::Result<T, Box<dyn Error + Sync + Send>>;
+
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Options(
+            TextDocumentSyncOptions {
+                open_close: Some(true),
+                change: Some(TextDocumentSyncKind::FULL),
+                save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions {
+                    include_text: Some(false),
+                })),
+                ..TextDocumentSyncOptions::default()
+            },
+        )),
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["\"".into(), "/".into()]),
+            ..CompletionOptions::default()
+        }),
+        ..ServerCapabilities::default()
+    };
+
+    let params = connection.initialize(serde_json::to_value(capabilities)?)?;
+    let params: InitializeParams = serde_json::from_value(params)?;
+
+    let mut server = Server::new(stdlib_path(&params));
+    server.run(&connection)?;
+
+    io_threads.join()?;
+    Ok(())
+}
+
+/// The path to the standard library, set by the client with the `stdlibPath` initialization
+/// option, otherwise taken from `ZOKRATES_STDLIB` and defaulting to `~/.zokrates/stdlib`
+fn stdlib_path(params: &InitializeParams) -> String {
+    params
+        .initialization_options
+        .as_ref()
+        .and_then(|o| o.get("stdlibPath"))
+        .and_then(|p| p.as_str())
+        .map(|p| p.to_string())
+        .or_else(|| std::env::var("ZOKRATES_STDLIB").ok())
+        .unwrap_or_else(|| {
+            dirs::home_dir()
+                .map(|p| p.join(".zokrates/stdlib"))
+                .unwrap_or_default()
+                .display()
+                .to_string()
+        })
+}
+
+struct Server {
+    stdlib_path: String,
+    /// The content of the open documents
+    documents: HashMap<Url, String>,
+    /// The files each document last published diagnostics for, so that they can be cleared
+    published: HashMap<Url, HashSet<Url>>,
+}
+
+impl Server {
+    fn new(stdlib_path: String) -> Self {
+        Server {
+            stdlib_path,
+            documents: HashMap::new(),
+            published: HashMap::new(),
+        }
+    }
+
+    fn run(&mut self, connection: &Connection) -> Result<()> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    let response = self.handle_request(request);
+                    connection.sender.send(Message::Response(response))?;
+                }
+                Message::Notification(notification) => {
+                    for notification in self.handle_notification(notification) {
+                        connection
+                            .sender
+                            .send(Message::Notification(notification))?;
+                    }
+                }
+                Message::Response(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        log::debug!("Request {}", request.method);
+
+        let id = request.id.clone();
+
+        let result = match request.method.as_str() {
+            GotoDefinition::METHOD => self.request::<GotoDefinition, _>(request, |p| {
+                self.with_document(
+                    &p.text_document_position_params,
+                    |source, path, position| {
+                        definition::definition(source, path, position, &self.stdlib_path)
+                            .map(GotoDefinitionResponse::Scalar)
+                    },
+                )
+            }),
+            HoverRequest::METHOD => self.request::<HoverRequest, _>(request, |p| {
+                self.with_document(
+                    &p.text_document_position_params,
+                    |source, path, position| {
+                        with_field!(
+                            source,
+                            hover::hover(source, path, position, &self.stdlib_path)
+                        )
+                    },
+                )
+            }),
+            Completion::METHOD => self.request::<Completion, _>(request, |p| {
+                self.with_document(&p.text_document_position, |source, path, position| {
+                    completion::completion(source, path, position, &self.stdlib_path)
+                        .map(CompletionResponse::Array)
+                })
+            }),
+            method => Err(format!("Unsupported request {}", method)),
+        };
+
+        match result {
+            Ok(value) => Response::new_ok(id, value),
+            Err(e) => Response::new_err(id, lsp_server::ErrorCode::MethodNotFound as i32, e),
+        }
+    }
+
+    /// Parses the parameters of `request` and serializes the result of `f` applied to them
+    fn request<R, F>(
+        &self,
+        request: Request,
+        f: F,
+    ) -> std::result::Result<serde_json::Value, String>
+    where
+        R: lsp_types::request::Request,
+        F: FnOnce(R::Params) -> R::Result,
+    {
+        let (_, params): (RequestId, R::Params) = request
+            .extract(R::METHOD)
+            .map_err(|r| format!("Invalid request {}", r.method))?;
+
+        serde_json::to_value(f(params)).map_err(|e| e.to_string())
+    }
+
+    /// Calls `f` with the content, path and cursor position of the document at `params`
+    fn with_document<F, U>(&self, params: &TextDocumentPositionParams, f: F) -> Option<U>
+    where
+        F: FnOnce(&str, &Path, lsp_types::Position) -> Option<U>,
+    {
+        let uri = &params.text_document.uri;
+        let source = self.documents.get(uri)?;
+        let path = uri.to_file_path().ok()?;
+
+        f(source, &path, params.position)
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Vec<Notification> {
+        log::debug!("Notification {}", notification.method);
+
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                if let Ok(p) = notification
+                    .extract::<lsp_types::DidOpenTextDocumentParams>(DidOpenTextDocument::METHOD)
+                {
+                    let uri = p.text_document.uri;
+                    self.documents.insert(uri.clone(), p.text_document.text);
+                    return self.publish_diagnostics(uri);
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                if let Ok(mut p) = notification.extract::<lsp_types::DidChangeTextDocumentParams>(
+                    DidChangeTextDocument::METHOD,
+                ) {
+                    // we only support full synchronisation, so the last change holds the content
+                    if let Some(change) = p.content_changes.pop() {
+                        self.documents.insert(p.text_document.uri, change.text);
+                    }
+                }
+            }
+            DidSaveTextDocument::METHOD => {
+                if let Ok(p) = notification
+                    .extract::<lsp_types::DidSaveTextDocumentParams>(DidSaveTextDocument::METHOD)
+                {
+                    return self.publish_diagnostics(p.text_document.uri);
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                if let Ok(p) = notification
+                    .extract::<lsp_types::DidCloseTextDocumentParams>(DidCloseTextDocument::METHOD)
+                {
+                    self.documents.remove(&p.text_document.uri);
+                }
+            }
+            _ => {}
+        }
+
+        vec![]
+    }
+
+    /// Checks the document at `uri` and publishes the diagnostics of every file involved, clearing
+    /// the files which no longer have any
+    fn publish_diagnostics(&mut self, uri: Url) -> Vec<Notification> {
+        let (source, path) = match (self.documents.get(&uri), uri.to_file_path()) {
+            (Some(source), Ok(path)) => (source, path),
+            _ => return vec![],
+        };
+
+        let diagnostics = with_field!(
+            source,
+            diagnostics::diagnostics(source, &path, &self.stdlib_path)
+        );
+
+        let mut diagnostics: HashMap<Url, _> = diagnostics
+            .into_iter()
+            .filter_map(|(file, d)| Some((Url::from_file_path(file).ok()?, d)))
+            .collect();
+
+        let published = self.published.entry(uri).or_default();
+        for stale in published.drain() {
+            diagnostics.entry(stale).or_default();
+        }
+        published.extend(
+            diagnostics
+                .iter()
+                .filter(|(_, d)| !d.is_empty())
+                .map(|(file, _)| file.clone()),
+        );
+
+        diagnostics
+            .into_iter()
+            .map(|(file, d)| {
+                Notification::new(
+                    PublishDiagnostics::METHOD.to_string(),
+                    PublishDiagnosticsParams::new(file, d, None),
+                )
+            })
+            .collect()
+    }
+}
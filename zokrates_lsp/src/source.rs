@@ -0,0 +1,110 @@
+//! Helpers to move between the positions of the language server protocol and those of ZoKrates
+
+use lsp_types::{Position, Range};
+use zokrates_pest_ast::Span;
+
+/// Calls the generic function `$f` with the field of the curve `$source` is written for, as
+/// declared with `#pragma curve`, defaulting to bn128
+macro_rules! with_field {
+    ($source:expr, $($f:ident)::+($($arg:expr),*)) => {{
+        use zokrates_field::{Bls12_377Field, Bls12_381Field, Bn128Field, Bw6_761Field};
+
+        match crate::source::curve($source) {
+            Some("bls12_381") => $($f)::+::<Bls12_381Field>($($arg),*),
+            Some("bls12_377") => $($f)::+::<Bls12_377Field>($($arg),*),
+            Some("bw6_761") => $($f)::+::<Bw6_761Field>($($arg),*),
+            _ => $($f)::+::<Bn128Field>($($arg),*),
+        }
+    }};
+}
+
+/// Returns the curve declared in `source` with `#pragma curve`, if any
+pub fn curve(source: &str) -> Option<&str> {
+    source.lines().find_map(|line| {
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next()) {
+            (Some("#pragma"), Some("curve")) => words.next(),
+            _ => None,
+        }
+    })
+}
+
+/// Converts a 1-based ZoKrates line and column to a protocol position
+pub fn position(line: usize, col: usize) -> Position {
+    Position::new(line.saturating_sub(1) as u32, col.saturating_sub(1) as u32)
+}
+
+/// Converts a protocol position to a 1-based ZoKrates line and column
+pub fn line_col(position: Position) -> (usize, usize) {
+    (position.line as usize + 1, position.character as usize + 1)
+}
+
+pub fn span_range(span: &Span) -> Range {
+    let (start_line, start_col) = span.start_pos().line_col();
+    let (end_line, end_col) = span.end_pos().line_col();
+    Range::new(position(start_line, start_col), position(end_line, end_col))
+}
+
+pub fn span_contains(span: &Span, position: Position) -> bool {
+    let range = span_range(span);
+    range.start <= position && position <= range.end
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Returns the identifier at `position` in `source` along with its range
+pub fn word_at(source: &str, position: Position) -> Option<(&str, Range)> {
+    let line = source.lines().nth(position.line as usize)?;
+    let index = (position.character as usize).min(line.len());
+
+    let start = line[..index]
+        .rfind(|c| !is_identifier_char(c))
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let end = line[index..]
+        .find(|c| !is_identifier_char(c))
+        .map(|i| index + i)
+        .unwrap_or_else(|| line.len());
+
+    match &line[start..end] {
+        "" => None,
+        word => Some((
+            word,
+            Range::new(
+                Position::new(position.line, start as u32),
+                Position::new(position.line, end as u32),
+            ),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word() {
+        let source = "def main():\n  return foo_bar(1)\n";
+
+        assert_eq!(
+            word_at(source, Position::new(1, 12)),
+            Some((
+                "foo_bar",
+                Range::new(Position::new(1, 9), Position::new(1, 16))
+            ))
+        );
+        assert_eq!(word_at(source, Position::new(1, 1)), None);
+        assert_eq!(word_at(source, Position::new(3, 0)), None);
+    }
+
+    #[test]
+    fn pragma() {
+        assert_eq!(
+            curve("#pragma curve bls12_377\ndef main():"),
+            Some("bls12_377")
+        );
+        assert_eq!(curve("def main():"), None);
+    }
+}
@@ -332,5 +332,107 @@ mod tests {
             let parse = ZoKratesParser::parse(Rule::iteration_statement, input);
             assert!(parse.is_ok());
         }
+
+        #[test]
+        fn parse_if_else_statement() {
+            for input in &[
+                "if a == b {\n c = c + 1\n}",
+                "if a {\n c = 1\n} else {\n c = 2\n}",
+                "if a {\n c = 1\n} else if b {\n c = 2\n} else {\n c = 3\n}",
+            ] {
+                let parse = ZoKratesParser::parse(Rule::if_else_statement, input);
+                assert_eq!(parse.unwrap().as_str(), *input);
+            }
+
+            // the `if ... then ... else ... fi` expression is not mistaken for a statement
+            let input = "c = if a then 1 else 2 fi\n";
+            let parse = ZoKratesParser::parse(Rule::statement, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_log_statement() {
+            for input in &["log(\"hello\")", "log(\"{} and {}\", a, [b, 1])"] {
+                let parse = ZoKratesParser::parse(Rule::log_statement, input);
+                assert_eq!(parse.unwrap().as_str(), *input);
+            }
+
+            // `log` is a keyword
+            assert!(ZoKratesParser::parse(Rule::identifier, "log").is_err());
+        }
+
+        #[test]
+        fn parse_tuples() {
+            for input in &[
+                "()",
+                "(field,)",
+                "(field, u32[2])",
+                "((bool, field), field)[3]",
+            ] {
+                let parse = ZoKratesParser::parse(Rule::ty, input);
+                assert_eq!(parse.unwrap().as_str(), *input);
+            }
+
+            for input in &["()", "(a,)", "(a, [1, 2], (b,))"] {
+                let parse = ZoKratesParser::parse(Rule::inline_tuple_expression, input);
+                assert_eq!(parse.unwrap().as_str(), *input);
+            }
+
+            let input = "(field, u32) t = (1, 2)";
+            let parse = ZoKratesParser::parse(Rule::definition_statement, input);
+            assert!(parse.is_ok());
+
+            let input = "t.0[1].1 = a.1.0";
+            let parse = ZoKratesParser::parse(Rule::definition_statement, input);
+            assert!(parse.is_ok());
+
+            // a single element between parentheses is a parenthesized expression, not a tuple
+            let input = "(a)";
+            let parse = ZoKratesParser::parse(Rule::inline_tuple_expression, input);
+            assert!(parse.is_err());
+        }
+
+        #[test]
+        fn parse_enums() {
+            let input = "enum State {\n Idle\n Active(field, u32[2])\n}\n";
+            let parse = ZoKratesParser::parse(Rule::ty_enum_definition, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+
+            for input in &["State::Idle", "State::Active(1, [2, 3])"] {
+                let parse = ZoKratesParser::parse(Rule::inline_enum_expression, input);
+                assert_eq!(parse.unwrap().as_str(), *input);
+            }
+
+            let input = "match s {\n State::Idle => 0,\n State::Active(a, _) => a,\n}";
+            let parse = ZoKratesParser::parse(Rule::match_expression, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+
+            let input = "match s { _ => 0 }";
+            let parse = ZoKratesParser::parse(Rule::match_expression, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+
+            // explicit generics are not mistaken for enum variants
+            let input = "foo::<2>(a)";
+            let parse = ZoKratesParser::parse(Rule::expression, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
+
+        #[test]
+        fn parse_attributes() {
+            let input = "#[test]
+#[should_fail]
+def foo():
+  assert(1 == 2)
+  return
+";
+            let parse = ZoKratesParser::parse(Rule::function_definition, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+
+            let input = "#[test] def foo():
+  return
+";
+            let parse = ZoKratesParser::parse(Rule::function_definition, input);
+            assert_eq!(parse.unwrap().as_str(), input);
+        }
     }
 }
@@ -1,5 +1,5 @@
 use from_pest::FromPest;
-use pest::error::Error as PestError;
+use pest::error::{Error as PestError, LineColLocation};
 use pest::iterators::Pairs;
 use std::fmt;
 use zokrates_parser::parse;
@@ -7,19 +7,25 @@ use zokrates_parser::Rule;
 #[macro_use]
 extern crate lazy_static;
 
+mod printer;
+
 pub use ast::{
     Access, Arguments, ArrayAccess, ArrayInitializerExpression, ArrayType, AssertionStatement,
-    Assignee, AssigneeAccess, BasicOrStructType, BasicType, BinaryExpression, BinaryOperator,
-    CallAccess, ConstantDefinition, ConstantGenericValue, DecimalLiteralExpression, DecimalNumber,
-    DecimalSuffix, DefinitionStatement, ExplicitGenerics, Expression, FieldType, File,
-    FromExpression, FunctionDefinition, HexLiteralExpression, HexNumberExpression,
-    IdentifierExpression, IfElseExpression, ImportDirective, ImportSymbol, InlineArrayExpression,
-    InlineStructExpression, InlineStructMember, IterationStatement, LiteralExpression, Parameter,
-    PostfixExpression, Range, RangeOrExpression, ReturnStatement, Span, Spread, SpreadOrExpression,
-    Statement, StructDefinition, StructField, SymbolDeclaration, TernaryExpression, ToExpression,
-    Type, TypeDefinition, TypedIdentifier, TypedIdentifierOrAssignee, UnaryExpression,
-    UnaryOperator, Underscore, Visibility,
+    Assignee, AssigneeAccess, Attribute, BasicOrStructOrTupleType, BasicType, BinaryExpression,
+    BinaryOperator, BindingPattern, Block, CallAccess, ConstantDefinition, ConstantGenericValue,
+    DecimalLiteralExpression, DecimalNumber, DecimalSuffix, DefinitionStatement, ElseClause,
+    EnumDefinition, EnumVariant, ExplicitGenerics, Expression, FieldType, File, FromExpression,
+    FunctionDefinition, HexLiteralExpression, HexNumberExpression, IdentifierExpression,
+    IfElseExpression, IfElseStatement, ImportDirective, ImportSymbol, InlineArrayExpression,
+    InlineEnumExpression, InlineStructExpression, InlineStructMember, InlineTupleExpression,
+    IterationStatement, LiteralExpression, LogStatement, MatchArm, MatchExpression, MatchPattern,
+    Parameter, PostfixExpression, Range, RangeOrExpression, ReturnStatement, Span, Spread,
+    SpreadOrExpression, Statement, StructDefinition, StructField, SymbolDeclaration,
+    TernaryExpression, ToExpression, TupleAccess, TupleType, Type, TypeDefinition, TypedIdentifier,
+    TypedIdentifierOrAssignee, UnaryExpression, UnaryOperator, Underscore, VariantPattern,
+    Visibility,
 };
+pub use printer::format;
 
 mod ast {
     use from_pest::ConversionError;
@@ -147,6 +153,7 @@ mod ast {
         Import(ImportDirective<'ast>),
         Constant(ConstantDefinition<'ast>),
         Struct(StructDefinition<'ast>),
+        Enum(EnumDefinition<'ast>),
         Type(TypeDefinition<'ast>),
         Function(FunctionDefinition<'ast>),
     }
@@ -170,9 +177,28 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_enum_definition))]
+    pub struct EnumDefinition<'ast> {
+        pub id: IdentifierExpression<'ast>,
+        pub variants: Vec<EnumVariant<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::enum_variant))]
+    pub struct EnumVariant<'ast> {
+        pub id: IdentifierExpression<'ast>,
+        pub payload: Vec<Type<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::function_definition))]
     pub struct FunctionDefinition<'ast> {
+        pub attributes: Vec<Attribute<'ast>>,
         pub id: IdentifierExpression<'ast>,
         pub generics: Vec<IdentifierExpression<'ast>>,
         pub parameters: Vec<Parameter<'ast>>,
@@ -182,6 +208,14 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::attribute))]
+    pub struct Attribute<'ast> {
+        pub id: IdentifierExpression<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::const_definition))]
     pub struct ConstantDefinition<'ast> {
@@ -242,6 +276,7 @@ mod ast {
         Basic(BasicType<'ast>),
         Array(ArrayType<'ast>),
         Struct(StructType<'ast>),
+        Tuple(TupleType<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -253,6 +288,40 @@ mod ast {
         U16(U16Type<'ast>),
         U32(U32Type<'ast>),
         U64(U64Type<'ast>),
+        U128(U128Type<'ast>),
+        I8(I8Type<'ast>),
+        I16(I16Type<'ast>),
+        I32(I32Type<'ast>),
+        I64(I64Type<'ast>),
+    }
+
+    impl<'ast> Type<'ast> {
+        pub fn span(&self) -> &Span<'ast> {
+            match self {
+                Type::Basic(t) => t.span(),
+                Type::Array(t) => &t.span,
+                Type::Struct(t) => &t.span,
+                Type::Tuple(t) => &t.span,
+            }
+        }
+    }
+
+    impl<'ast> BasicType<'ast> {
+        pub fn span(&self) -> &Span<'ast> {
+            match self {
+                BasicType::Field(t) => &t.span,
+                BasicType::Boolean(t) => &t.span,
+                BasicType::U8(t) => &t.span,
+                BasicType::U16(t) => &t.span,
+                BasicType::U32(t) => &t.span,
+                BasicType::U64(t) => &t.span,
+                BasicType::U128(t) => &t.span,
+                BasicType::I8(t) => &t.span,
+                BasicType::I16(t) => &t.span,
+                BasicType::I32(t) => &t.span,
+                BasicType::I64(t) => &t.span,
+            }
+        }
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -265,17 +334,18 @@ mod ast {
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::ty_array))]
     pub struct ArrayType<'ast> {
-        pub ty: BasicOrStructType<'ast>,
+        pub ty: BasicOrStructOrTupleType<'ast>,
         pub dimensions: Vec<Expression<'ast>>,
         #[pest_ast(outer())]
         pub span: Span<'ast>,
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
-    #[pest_ast(rule(Rule::ty_basic_or_struct))]
-    pub enum BasicOrStructType<'ast> {
+    #[pest_ast(rule(Rule::ty_basic_or_struct_or_tuple))]
+    pub enum BasicOrStructOrTupleType<'ast> {
         Struct(StructType<'ast>),
         Basic(BasicType<'ast>),
+        Tuple(TupleType<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -313,6 +383,41 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_u128))]
+    pub struct U128Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i8))]
+    pub struct I8Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i16))]
+    pub struct I16Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i32))]
+    pub struct I32Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_i64))]
+    pub struct I64Type<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::ty_struct))]
     pub struct StructType<'ast> {
@@ -322,6 +427,14 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::ty_tuple))]
+    pub struct TupleType<'ast> {
+        pub elements: Vec<Type<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::parameter))]
     pub struct Parameter<'ast> {
@@ -355,6 +468,8 @@ mod ast {
         Definition(DefinitionStatement<'ast>),
         Assertion(AssertionStatement<'ast>),
         Iteration(IterationStatement<'ast>),
+        IfElse(IfElseStatement<'ast>),
+        Log(LogStatement<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -384,6 +499,15 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::log_statement))]
+    pub struct LogStatement<'ast> {
+        pub format_string: AnyString<'ast>,
+        pub expressions: Vec<Expression<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::iteration_statement))]
     pub struct IterationStatement<'ast> {
@@ -396,6 +520,31 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::if_else_statement))]
+    pub struct IfElseStatement<'ast> {
+        pub condition: Expression<'ast>,
+        pub consequence: Block<'ast>,
+        pub alternative: Option<ElseClause<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::else_clause))]
+    pub enum ElseClause<'ast> {
+        IfElse(Box<IfElseStatement<'ast>>),
+        Block(Block<'ast>),
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::block))]
+    pub struct Block<'ast> {
+        pub statements: Vec<Statement<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::return_statement))]
     pub struct ReturnStatement<'ast> {
@@ -438,6 +587,9 @@ mod ast {
         Literal(LiteralExpression<'ast>),
         InlineArray(InlineArrayExpression<'ast>),
         InlineStruct(InlineStructExpression<'ast>),
+        InlineTuple(InlineTupleExpression<'ast>),
+        InlineEnum(InlineEnumExpression<'ast>),
+        Match(MatchExpression<'ast>),
         ArrayInitializer(ArrayInitializerExpression<'ast>),
     }
 
@@ -445,7 +597,10 @@ mod ast {
     #[pest_ast(rule(Rule::term))]
     pub enum Term<'ast> {
         Expression(Expression<'ast>),
+        InlineTuple(InlineTupleExpression<'ast>),
         InlineStruct(InlineStructExpression<'ast>),
+        InlineEnum(InlineEnumExpression<'ast>),
+        Match(MatchExpression<'ast>),
         IfElse(IfElseExpression<'ast>),
         Primary(PrimaryExpression<'ast>),
         InlineArray(InlineArrayExpression<'ast>),
@@ -566,6 +721,9 @@ mod ast {
                 Term::Primary(e) => e.into(),
                 Term::InlineArray(e) => Expression::InlineArray(e),
                 Term::InlineStruct(e) => Expression::InlineStruct(e),
+                Term::InlineTuple(e) => Expression::InlineTuple(e),
+                Term::InlineEnum(e) => Expression::InlineEnum(e),
+                Term::Match(e) => Expression::Match(e),
                 Term::ArrayInitializer(e) => Expression::ArrayInitializer(e),
             }
         }
@@ -668,6 +826,66 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::inline_tuple_expression))]
+    pub struct InlineTupleExpression<'ast> {
+        pub elements: Vec<Expression<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::inline_enum_expression))]
+    pub struct InlineEnumExpression<'ast> {
+        pub ty: IdentifierExpression<'ast>,
+        pub variant: IdentifierExpression<'ast>,
+        pub arguments: Vec<Expression<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::match_expression))]
+    pub struct MatchExpression<'ast> {
+        pub expression: Box<Expression<'ast>>,
+        pub arms: Vec<MatchArm<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::match_arm))]
+    pub struct MatchArm<'ast> {
+        pub pattern: MatchPattern<'ast>,
+        pub expression: Expression<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::match_pattern))]
+    pub enum MatchPattern<'ast> {
+        Wildcard(Underscore<'ast>),
+        Variant(VariantPattern<'ast>),
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::variant_pattern))]
+    pub struct VariantPattern<'ast> {
+        pub ty: IdentifierExpression<'ast>,
+        pub variant: IdentifierExpression<'ast>,
+        pub bindings: Vec<BindingPattern<'ast>>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::binding_pattern))]
+    pub enum BindingPattern<'ast> {
+        Wildcard(Underscore<'ast>),
+        Identifier(IdentifierExpression<'ast>),
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::array_initializer_expression))]
     pub struct ArrayInitializerExpression<'ast> {
@@ -700,6 +918,7 @@ mod ast {
         Call(CallAccess<'ast>),
         Select(ArrayAccess<'ast>),
         Member(MemberAccess<'ast>),
+        Element(TupleAccess<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -707,6 +926,7 @@ mod ast {
     pub enum AssigneeAccess<'ast> {
         Select(ArrayAccess<'ast>),
         Member(MemberAccess<'ast>),
+        Element(TupleAccess<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -765,6 +985,21 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::tuple_access))]
+    pub struct TupleAccess<'ast> {
+        pub index: TupleIndex<'ast>,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::tuple_index))]
+    pub struct TupleIndex<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, PartialEq, Clone)]
     pub struct BinaryExpression<'ast> {
         pub op: BinaryOperator,
@@ -851,6 +1086,9 @@ mod ast {
                 Expression::Postfix(p) => &p.span,
                 Expression::InlineArray(a) => &a.span,
                 Expression::InlineStruct(s) => &s.span,
+                Expression::InlineTuple(t) => &t.span,
+                Expression::InlineEnum(e) => &e.span,
+                Expression::Match(m) => &m.span,
                 Expression::ArrayInitializer(a) => &a.span,
                 Expression::Unary(u) => &u.span,
             }
@@ -905,6 +1143,11 @@ mod ast {
         U16(U16Suffix<'ast>),
         U32(U32Suffix<'ast>),
         U64(U64Suffix<'ast>),
+        U128(U128Suffix<'ast>),
+        I8(I8Suffix<'ast>),
+        I16(I16Suffix<'ast>),
+        I32(I32Suffix<'ast>),
+        I64(I64Suffix<'ast>),
         Field(FieldSuffix<'ast>),
     }
 
@@ -936,6 +1179,41 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::decimal_suffix_u128))]
+    pub struct U128Suffix<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::decimal_suffix_i8))]
+    pub struct I8Suffix<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::decimal_suffix_i16))]
+    pub struct I16Suffix<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::decimal_suffix_i32))]
+    pub struct I32Suffix<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::decimal_suffix_i64))]
+    pub struct I64Suffix<'ast> {
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::decimal_suffix_field))]
     pub struct FieldSuffix<'ast> {
@@ -983,6 +1261,7 @@ mod ast {
         U16(U16NumberExpression<'ast>),
         U32(U32NumberExpression<'ast>),
         U64(U64NumberExpression<'ast>),
+        U128(U128NumberExpression<'ast>),
     }
 
     #[derive(Debug, FromPest, PartialEq, Clone)]
@@ -1021,6 +1300,15 @@ mod ast {
         pub span: Span<'ast>,
     }
 
+    #[derive(Debug, FromPest, PartialEq, Clone)]
+    #[pest_ast(rule(Rule::hex_number_u128))]
+    pub struct U128NumberExpression<'ast> {
+        #[pest_ast(outer(with(span_into_str)))]
+        pub value: String,
+        #[pest_ast(outer())]
+        pub span: Span<'ast>,
+    }
+
     #[derive(Debug, FromPest, PartialEq, Clone)]
     #[pest_ast(rule(Rule::identifier))]
     pub struct IdentifierExpression<'ast> {
@@ -1060,6 +1348,16 @@ impl<'ast> From<Pairs<'ast, Rule>> for Prog<'ast> {
 #[derive(PartialEq, Clone, Debug)]
 pub struct Error(PestError<Rule>);
 
+impl Error {
+    /// Returns the start and end of the error as `(line, column)` pairs
+    pub fn line_col(&self) -> ((usize, usize), (usize, usize)) {
+        match self.0.line_col {
+            LineColLocation::Pos(pos) => (pos, pos),
+            LineColLocation::Span(start, end) => (start, end),
+        }
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -1134,6 +1432,7 @@ mod tests {
                         span: Span::new(source, 0, 29).unwrap()
                     })),
                     SymbolDeclaration::Function(FunctionDefinition {
+                        attributes: vec![],
                         generics: vec![],
                         id: IdentifierExpression {
                             value: String::from("main"),
@@ -1195,6 +1494,7 @@ mod tests {
                         span: Span::new(source, 0, 29).unwrap()
                     })),
                     SymbolDeclaration::Function(FunctionDefinition {
+                        attributes: vec![],
                         generics: vec![],
                         id: IdentifierExpression {
                             value: String::from("main"),
@@ -1280,6 +1580,7 @@ mod tests {
                         span: Span::new(source, 0, 29).unwrap()
                     })),
                     SymbolDeclaration::Function(FunctionDefinition {
+                        attributes: vec![],
                         generics: vec![],
                         id: IdentifierExpression {
                             value: String::from("main"),
@@ -1340,6 +1641,7 @@ mod tests {
             Ok(File {
                 pragma: None,
                 declarations: vec![SymbolDeclaration::Function(FunctionDefinition {
+                    attributes: vec![],
                     generics: vec![],
                     id: IdentifierExpression {
                         value: String::from("main"),
@@ -1378,6 +1680,7 @@ mod tests {
             Ok(File {
                 pragma: None,
                 declarations: vec![SymbolDeclaration::Function(FunctionDefinition {
+                    attributes: vec![],
                     generics: vec![],
                     id: IdentifierExpression {
                         value: String::from("main"),
@@ -1481,6 +1784,69 @@ mod tests {
         endfor
         assert(a.member == 1)
         return a
+"#;
+        let res = generate_ast(source);
+        println!("{:#?}", generate_ast(source));
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn tuples() {
+        let source = r#"def main((field, u32[2]) t) -> ((bool,)):
+        (field, u32[2]) u = (t.0, [t.1[0], 1])
+        u.1[0] = 2
+        field a, u32[2] b = u
+        return (a == 1,)
+"#;
+        let res = generate_ast(source);
+        println!("{:#?}", generate_ast(source));
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn enums() {
+        let source = r#"enum State {
+    Idle
+    Active(field, u32)
+}
+
+def main(State s) -> field:
+        State t = State::Active(1, 2)
+        return match s {
+            State::Idle => 0,
+            State::Active(a, _) => a,
+        }
+"#;
+        let res = generate_ast(source);
+        println!("{:#?}", generate_ast(source));
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn if_else_statements() {
+        let source = r#"def main(field a) -> field:
+        field b = 0
+        if a == 1 {
+            b = 1
+        } else if a == 2 {
+            b = 2
+            assert(b == a)
+        } else {
+            b = 3
+        }
+        return b
+"#;
+        let res = generate_ast(source);
+        println!("{:#?}", generate_ast(source));
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn log_statements() {
+        let source = r#"def main(field a, bool b) -> field:
+        log("a is {} and b is {}", a, b)
+        log("no placeholders")
+        return a
 "#;
         let res = generate_ast(source);
         println!("{:#?}", generate_ast(source));
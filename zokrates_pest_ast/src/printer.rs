@@ -0,0 +1,1057 @@
+//! A pretty-printer from the AST back to source code
+//!
+//! Comments are not part of the AST, so they are collected from the source and reinserted next
+//! to the items they were written next to. The output of the printer parses to the same AST as
+//! its input, modulo spans and redundant parentheses, and formatting it again leaves it unchanged.
+
+use crate::ast::*;
+use crate::{generate_ast, Error};
+
+/// Inline arrays and struct expressions wider than this are written over several lines
+const MAX_INLINE_WIDTH: usize = 80;
+
+const INDENT: &str = "\t";
+
+/// Formats the program `source`
+pub fn format(source: &str) -> Result<String, Error> {
+    let file = generate_ast(source)?;
+
+    let mut printer = Printer::new(source, comments(source));
+    printer.file(&file);
+
+    Ok(printer.out)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Comment<'a> {
+    start: usize,
+    end: usize,
+    text: &'a str,
+}
+
+/// Collects the comments of `source`, skipping the string literals
+fn comments(source: &str) -> Vec<Comment> {
+    let bytes = source.as_bytes();
+
+    let mut res = vec![];
+    let mut in_string = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let end = match (bytes[i], bytes.get(i + 1)) {
+            (b'"', _) => {
+                in_string = !in_string;
+                None
+            }
+            _ if in_string => None,
+            (b'/', Some(b'/')) => Some(
+                source[i..]
+                    .find(|c| c == '\n' || c == '\r')
+                    .map(|e| i + e)
+                    .unwrap_or_else(|| source.len()),
+            ),
+            (b'/', Some(b'*')) => Some(
+                source[i + 2..]
+                    .find("*/")
+                    .map(|e| i + 2 + e + 2)
+                    .unwrap_or_else(|| source.len()),
+            ),
+            _ => None,
+        };
+
+        match end {
+            Some(end) => {
+                res.push(Comment {
+                    start: i,
+                    end,
+                    text: &source[i..end],
+                });
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+
+    res
+}
+
+fn contains_newline(s: &str) -> bool {
+    s.contains(|c| c == '\n' || c == '\r')
+}
+
+// the precedence levels of expressions, following the precedence climber of the parser
+const TERNARY: u8 = 1;
+const UNARY: u8 = 11;
+const POWER: u8 = 12;
+const TERM: u8 = 13;
+
+fn precedence(e: &Expression) -> u8 {
+    match e {
+        Expression::Ternary(_) => TERNARY,
+        Expression::Binary(b) => match b.op {
+            BinaryOperator::Or => 2,
+            BinaryOperator::And => 3,
+            BinaryOperator::Lt
+            | BinaryOperator::Lte
+            | BinaryOperator::Gt
+            | BinaryOperator::Gte
+            | BinaryOperator::NotEq
+            | BinaryOperator::Eq => 4,
+            BinaryOperator::BitOr => 5,
+            BinaryOperator::BitXor => 6,
+            BinaryOperator::BitAnd => 7,
+            BinaryOperator::LeftShift | BinaryOperator::RightShift => 8,
+            BinaryOperator::Add | BinaryOperator::Sub => 9,
+            BinaryOperator::Mul | BinaryOperator::Div | BinaryOperator::Rem => 10,
+            BinaryOperator::Pow => POWER,
+        },
+        Expression::Unary(_) => UNARY,
+        _ => TERM,
+    }
+}
+
+fn binary_operator(op: &BinaryOperator) -> &'static str {
+    match op {
+        BinaryOperator::BitXor => "^",
+        BinaryOperator::BitAnd => "&",
+        BinaryOperator::BitOr => "|",
+        BinaryOperator::RightShift => ">>",
+        BinaryOperator::LeftShift => "<<",
+        BinaryOperator::Or => "||",
+        BinaryOperator::And => "&&",
+        BinaryOperator::Add => "+",
+        BinaryOperator::Sub => "-",
+        BinaryOperator::Mul => "*",
+        BinaryOperator::Div => "/",
+        BinaryOperator::Rem => "%",
+        BinaryOperator::Eq => "==",
+        BinaryOperator::NotEq => "!=",
+        BinaryOperator::Lt => "<",
+        BinaryOperator::Gt => ">",
+        BinaryOperator::Lte => "<=",
+        BinaryOperator::Gte => ">=",
+        BinaryOperator::Pow => "**",
+    }
+}
+
+fn unary_operator(op: &UnaryOperator) -> &'static str {
+    match op {
+        UnaryOperator::Pos(_) => "+",
+        UnaryOperator::Neg(_) => "-",
+        UnaryOperator::Not(_) => "!",
+    }
+}
+
+fn spread_or_expression_span<'ast>(e: &SpreadOrExpression<'ast>) -> Span<'ast> {
+    match e {
+        SpreadOrExpression::Spread(s) => s.span.clone(),
+        SpreadOrExpression::Expression(e) => e.span().clone(),
+    }
+}
+
+struct Printer<'a> {
+    source: &'a str,
+    /// The comments of the source, in order
+    comments: Vec<Comment<'a>>,
+    /// The index of the first comment which was not printed yet
+    next_comment: usize,
+    out: String,
+    indent: usize,
+    /// The end of the last item printed in the source, used to keep blank lines
+    last: usize,
+    /// Whether nothing was printed yet in the current block
+    first: bool,
+    /// Whether the next item must be preceded by a blank line
+    force_blank: bool,
+    /// Whether to write everything on a single line
+    flat: bool,
+}
+
+impl<'a> Printer<'a> {
+    fn new(source: &'a str, comments: Vec<Comment<'a>>) -> Self {
+        Printer {
+            source,
+            comments,
+            next_comment: 0,
+            out: String::new(),
+            indent: 0,
+            last: 0,
+            first: true,
+            force_blank: false,
+            flat: false,
+        }
+    }
+
+    /// Renders `e` on a single line, ignoring comments
+    fn render_flat(&self, e: &Expression) -> String {
+        let mut printer = Printer::new(self.source, vec![]);
+        printer.flat = true;
+        printer.expression(e);
+        printer.out
+    }
+
+    fn write(&mut self, s: &str) {
+        self.out.push_str(s);
+    }
+
+    fn write_indent(&mut self) {
+        self.out.push_str(&INDENT.repeat(self.indent));
+    }
+
+    fn peek_comment(&self) -> Option<Comment<'a>> {
+        self.comments.get(self.next_comment).copied()
+    }
+
+    /// Returns whether comments which were not printed yet start between `start` and `end`
+    fn has_comments_in(&self, start: usize, end: usize) -> bool {
+        self.comments[self.next_comment..]
+            .iter()
+            .take_while(|c| c.start < end)
+            .any(|c| c.start >= start)
+    }
+
+    fn has_blank_line(&self, from: usize, to: usize) -> bool {
+        if from >= to {
+            return false;
+        }
+
+        let lines: Vec<_> = self.source[from..to].split('\n').collect();
+        lines.len() > 2
+            && lines[1..lines.len() - 1]
+                .iter()
+                .any(|l| l.trim().is_empty())
+    }
+
+    /// Returns the position of the first `token` after `from` in the source, outside of comments
+    fn find(&self, token: char, from: usize) -> usize {
+        self.source[from..]
+            .match_indices(token)
+            .map(|(i, _)| from + i)
+            .find(|i| !self.comments.iter().any(|c| c.start <= *i && *i < c.end))
+            .unwrap()
+    }
+
+    /// Writes a blank line before the item starting at `start` if needed
+    fn separate(&mut self, start: usize) {
+        if !self.first && (self.force_blank || self.has_blank_line(self.last, start)) {
+            self.write("\n");
+        }
+        self.force_blank = false;
+        self.first = false;
+    }
+
+    fn comment_line(&mut self, c: Comment<'a>) {
+        self.separate(c.start);
+        self.write_indent();
+        self.write(c.text);
+        self.write("\n");
+        self.last = c.end;
+        self.next_comment += 1;
+    }
+
+    /// Starts a line for the item starting at `start`, after the comments written before it
+    fn item_start(&mut self, start: usize) {
+        while let Some(c) = self.peek_comment().filter(|c| c.start < start) {
+            self.comment_line(c);
+        }
+
+        self.separate(start);
+        self.write_indent();
+    }
+
+    /// Ends the line of the item ending at `end`, with the comments written in the item or after it
+    /// on the same line
+    fn item_end(&mut self, end: usize) {
+        self.last = end;
+
+        while let Some(c) = self.peek_comment() {
+            if c.start >= end && contains_newline(&self.source[self.last..c.start]) {
+                break;
+            }
+            self.write(" ");
+            self.write(c.text);
+            self.last = self.last.max(c.end);
+            self.next_comment += 1;
+        }
+
+        self.write("\n");
+        self.first = false;
+    }
+
+    /// Opens a block whose content starts after `start`
+    fn open_block(&mut self, start: usize) {
+        self.indent += 1;
+        self.first = true;
+        self.last = start;
+    }
+
+    /// Closes a block ending at `end`, writing the remaining comments it contains. If
+    /// `indented_only` is set, only the comments which are indented in the source are part of the
+    /// block.
+    fn close_block(&mut self, end: usize, indented_only: bool) {
+        self.comments_before(end, indented_only);
+        self.indent -= 1;
+        self.first = false;
+    }
+
+    fn comments_before(&mut self, end: usize, indented_only: bool) {
+        while let Some(c) = self.peek_comment().filter(|c| c.start < end) {
+            if indented_only {
+                let line_start = self.source[..c.start]
+                    .rfind(|c| c == '\n' || c == '\r')
+                    .map(|i| i + 1)
+                    .unwrap_or(0);
+                if line_start == c.start {
+                    break;
+                }
+            }
+            self.comment_line(c);
+        }
+    }
+
+    fn list<T, F: Fn(&mut Self, &T)>(&mut self, items: &[T], f: F) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.write(", ");
+            }
+            f(self, item);
+        }
+    }
+
+    fn file(&mut self, file: &File) {
+        if let Some(pragma) = &file.pragma {
+            self.item_start(pragma.span.start());
+            self.write("#pragma curve ");
+            self.write(&pragma.curve.name);
+            self.item_end(pragma.span.end());
+            self.force_blank = true;
+        }
+
+        for (i, declaration) in file.declarations.iter().enumerate() {
+            // consecutive imports are kept together
+            if i > 0 {
+                let imports = matches!(
+                    (&file.declarations[i - 1], declaration),
+                    (SymbolDeclaration::Import(_), SymbolDeclaration::Import(_))
+                );
+                self.force_blank |= !imports;
+            }
+
+            // the body of a function ends where the next declaration starts
+            let end = file
+                .declarations
+                .get(i + 1)
+                .map(|d| self.declaration_start(d))
+                .unwrap_or_else(|| self.source.len());
+
+            self.declaration(declaration, end);
+        }
+
+        // write the comments at the end of the file
+        self.comments_before(self.source.len(), false);
+    }
+
+    fn declaration_start(&self, declaration: &SymbolDeclaration) -> usize {
+        match declaration {
+            SymbolDeclaration::Import(ImportDirective::Main(i)) => i.span.start(),
+            SymbolDeclaration::Import(ImportDirective::From(i)) => i.span.start(),
+            SymbolDeclaration::Constant(c) => c.span.start(),
+            SymbolDeclaration::Struct(s) => s.span.start(),
+            SymbolDeclaration::Enum(e) => e.span.start(),
+            SymbolDeclaration::Type(t) => t.span.start(),
+            SymbolDeclaration::Function(f) => f.span.start(),
+        }
+    }
+
+    fn declaration(&mut self, declaration: &SymbolDeclaration, end: usize) {
+        self.item_start(self.declaration_start(declaration));
+
+        match declaration {
+            SymbolDeclaration::Import(ImportDirective::Main(i)) => {
+                self.write("import \"");
+                self.write(i.source.span.as_str());
+                self.write("\"");
+                match &i.alias {
+                    Some(alias) => {
+                        self.write(" as ");
+                        self.write(&alias.value);
+                        self.item_end(alias.span.end());
+                    }
+                    // include the closing quote
+                    None => self.item_end(i.source.span.end() + 1),
+                }
+            }
+            SymbolDeclaration::Import(ImportDirective::From(i)) => {
+                self.write("from \"");
+                self.write(i.source.span.as_str());
+                self.write("\" import ");
+                self.list(&i.symbols, |p, s| {
+                    p.write(&s.id.value);
+                    if let Some(alias) = &s.alias {
+                        p.write(" as ");
+                        p.write(&alias.value);
+                    }
+                });
+                self.item_end(i.symbols.last().unwrap().span.end());
+            }
+            SymbolDeclaration::Constant(c) => {
+                self.write("const ");
+                self.write(&ty(&c.ty));
+                self.write(" ");
+                self.write(&c.id.value);
+                self.write(" = ");
+                self.expression(&c.expression);
+                self.item_end(c.expression.span().end());
+            }
+            SymbolDeclaration::Type(t) => {
+                self.write("type ");
+                self.write(&t.id.value);
+                self.write(&generics(&t.generics));
+                self.write(" = ");
+                self.write(&ty(&t.ty));
+                self.item_end(t.ty.span().end());
+            }
+            SymbolDeclaration::Struct(s) => {
+                self.write("struct ");
+                self.write(&s.id.value);
+                self.write(&generics(&s.generics));
+                self.write(" {");
+                self.item_end(s.generics.last().unwrap_or(&s.id).span.end());
+
+                let close = self.find(
+                    '}',
+                    s.fields
+                        .last()
+                        .map(|f| f.span.end())
+                        .unwrap_or_else(|| s.id.span.end()),
+                );
+                self.open_block(close);
+                for field in &s.fields {
+                    self.item_start(field.span.start());
+                    self.write(&ty(&field.ty));
+                    self.write(" ");
+                    self.write(&field.id.value);
+                    self.item_end(field.span.end());
+                }
+                self.close_block(close, false);
+
+                self.write("}");
+                self.item_end(close + 1);
+            }
+            SymbolDeclaration::Enum(e) => {
+                self.write("enum ");
+                self.write(&e.id.value);
+                self.write(" {");
+                self.item_end(e.id.span.end());
+
+                let close = self.find(
+                    '}',
+                    e.variants
+                        .last()
+                        .map(|v| v.span.end())
+                        .unwrap_or_else(|| e.id.span.end()),
+                );
+                self.open_block(close);
+                for variant in &e.variants {
+                    self.item_start(variant.span.start());
+                    self.write(&variant.id.value);
+                    if !variant.payload.is_empty() {
+                        self.write("(");
+                        self.write(
+                            &variant
+                                .payload
+                                .iter()
+                                .map(ty)
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                        );
+                        self.write(")");
+                    }
+                    self.item_end(variant.span.end());
+                }
+                self.close_block(close, false);
+
+                self.write("}");
+                self.item_end(close + 1);
+            }
+            SymbolDeclaration::Function(f) => self.function(f, end),
+        }
+    }
+
+    fn function(&mut self, f: &FunctionDefinition, end: usize) {
+        for (i, attribute) in f.attributes.iter().enumerate() {
+            if i > 0 {
+                self.write_indent();
+            }
+            self.write("#[");
+            self.write(&attribute.id.value);
+            self.write("]");
+            self.item_end(attribute.id.span.end() + 1);
+        }
+        if !f.attributes.is_empty() {
+            self.write_indent();
+        }
+
+        self.write("def ");
+        self.write(&f.id.value);
+        self.write(&generics(&f.generics));
+        self.write("(");
+        self.list(&f.parameters, |p, parameter| {
+            match &parameter.visibility {
+                Some(Visibility::Public(_)) => p.write("public "),
+                Some(Visibility::Private(_)) => p.write("private "),
+                None => {}
+            }
+            p.write(&ty(&parameter.ty));
+            p.write(" ");
+            p.write(&parameter.id.value);
+        });
+        self.write(")");
+
+        match f.returns.as_slice() {
+            [] => {}
+            // a single tuple must be in parentheses, otherwise its elements would be read as
+            // several return types
+            [t @ Type::Tuple(_)] => {
+                self.write(" -> (");
+                self.write(&ty(t));
+                self.write(")");
+            }
+            [t] => {
+                self.write(" -> ");
+                self.write(&ty(t));
+            }
+            returns => {
+                self.write(" -> (");
+                self.write(&returns.iter().map(ty).collect::<Vec<_>>().join(", "));
+                self.write(")");
+            }
+        }
+        self.write(":");
+
+        let header_end = f
+            .returns
+            .last()
+            .map(|t| t.span().end())
+            .or_else(|| f.parameters.last().map(|p| p.span.end()))
+            .unwrap_or_else(|| f.id.span.end());
+        self.item_end(header_end);
+
+        self.open_block(header_end);
+        for statement in &f.statements {
+            self.statement(statement);
+        }
+        self.close_block(end, true);
+    }
+
+    fn block(&mut self, block: &Block) {
+        self.write(" {");
+        self.item_end(block.span.start() + 1);
+
+        let close = block.span.end() - 1;
+        self.open_block(block.span.start() + 1);
+        for statement in &block.statements {
+            self.statement(statement);
+        }
+        self.close_block(close, false);
+
+        self.write_indent();
+        self.write("}");
+    }
+
+    fn if_else_statement(&mut self, s: &IfElseStatement) {
+        self.write("if ");
+        // an identifier followed by an empty block would be read as an inline struct
+        if s.consequence.statements.is_empty() {
+            self.write("(");
+            self.expression(&s.condition);
+            self.write(")");
+        } else {
+            self.expression(&s.condition);
+        }
+        self.block(&s.consequence);
+
+        match &s.alternative {
+            Some(ElseClause::IfElse(s)) => {
+                self.write(" else ");
+                self.if_else_statement(s);
+            }
+            Some(ElseClause::Block(b)) => {
+                self.write(" else");
+                self.block(b);
+            }
+            None => {}
+        }
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        let span = match statement {
+            Statement::Return(s) => &s.span,
+            Statement::Definition(s) => &s.span,
+            Statement::Assertion(s) => &s.span,
+            Statement::Iteration(s) => &s.span,
+            Statement::IfElse(s) => &s.span,
+            Statement::Log(s) => &s.span,
+        };
+
+        self.item_start(span.start());
+
+        match statement {
+            Statement::Return(s) => {
+                self.write("return");
+                if !s.expressions.is_empty() {
+                    self.write(" ");
+                    self.list(&s.expressions, |p, e| p.expression(e));
+                }
+            }
+            Statement::Definition(s) => {
+                self.list(&s.lhs, |p, lhs| match lhs {
+                    TypedIdentifierOrAssignee::TypedIdentifier(i) => {
+                        p.write(&ty(&i.ty));
+                        p.write(" ");
+                        p.write(&i.identifier.value);
+                    }
+                    TypedIdentifierOrAssignee::Assignee(a) => {
+                        p.write(&a.id.value);
+                        for access in &a.accesses {
+                            match access {
+                                AssigneeAccess::Select(a) => p.array_access(a),
+                                AssigneeAccess::Member(m) => {
+                                    p.write(".");
+                                    p.write(&m.id.value);
+                                }
+                                AssigneeAccess::Element(e) => {
+                                    p.write(".");
+                                    p.write(e.index.span.as_str());
+                                }
+                            }
+                        }
+                    }
+                });
+                self.write(" = ");
+                self.expression(&s.expression);
+            }
+            Statement::Assertion(s) => {
+                self.write("assert(");
+                self.expression(&s.expression);
+                if let Some(message) = &s.message {
+                    self.write(", \"");
+                    self.write(&message.value);
+                    self.write("\"");
+                }
+                self.write(")");
+            }
+            Statement::Log(s) => {
+                self.write("log(\"");
+                self.write(&s.format_string.value);
+                self.write("\"");
+                for e in &s.expressions {
+                    self.write(", ");
+                    self.expression(e);
+                }
+                self.write(")");
+            }
+            Statement::Iteration(s) => {
+                self.write("for ");
+                self.write(&ty(&s.ty));
+                self.write(" ");
+                self.write(&s.index.value);
+                self.write(" in ");
+                self.expression(&s.from);
+                self.write("..");
+                self.expression(&s.to);
+                self.write(" do");
+                self.item_end(s.to.span().end());
+
+                let close = s.span.end() - "endfor".len();
+                self.open_block(s.to.span().end());
+                for statement in &s.statements {
+                    self.statement(statement);
+                }
+                self.close_block(close, false);
+
+                self.write_indent();
+                self.write("endfor");
+            }
+            Statement::IfElse(s) => self.if_else_statement(s),
+        }
+
+        self.item_end(span.end());
+    }
+
+    fn expression_with_parentheses(&mut self, e: &Expression, parentheses: bool) {
+        if parentheses {
+            self.write("(");
+        }
+        self.expression(e);
+        if parentheses {
+            self.write(")");
+        }
+    }
+
+    fn expression(&mut self, e: &Expression) {
+        match e {
+            Expression::Ternary(t) => {
+                self.expression_with_parentheses(&t.condition, precedence(&t.condition) <= TERNARY);
+                self.write(" ? ");
+                self.expression(&t.consequence);
+                self.write(" : ");
+                self.expression(&t.alternative);
+            }
+            Expression::IfElse(e) => {
+                self.write("if ");
+                self.expression(&e.condition);
+                self.write(" then ");
+                self.expression(&e.consequence);
+                self.write(" else ");
+                self.expression(&e.alternative);
+                self.write(" fi");
+            }
+            Expression::Binary(b) if b.op == BinaryOperator::Pow => {
+                self.expression_with_parentheses(&b.left, precedence(&b.left) < TERM);
+                self.write(" ** ");
+                let primary =
+                    matches!(*b.right, Expression::Identifier(_) | Expression::Literal(_));
+                self.expression_with_parentheses(&b.right, !primary);
+            }
+            Expression::Binary(b) => {
+                let p = precedence(e);
+                // operators are left associative
+                self.expression_with_parentheses(&b.left, precedence(&b.left) < p);
+                self.write(" ");
+                self.write(binary_operator(&b.op));
+                self.write(" ");
+                self.expression_with_parentheses(&b.right, precedence(&b.right) <= p);
+            }
+            Expression::Unary(u) => {
+                self.write(unary_operator(&u.op));
+                self.expression_with_parentheses(&u.expression, precedence(&u.expression) <= UNARY);
+            }
+            Expression::Postfix(p) => {
+                self.expression_with_parentheses(&p.base, precedence(&p.base) < TERM);
+                for access in &p.accesses {
+                    match access {
+                        Access::Call(c) => {
+                            if let Some(g) = &c.explicit_generics {
+                                self.write("::");
+                                self.write(&explicit_generics(g));
+                            }
+                            self.write("(");
+                            self.list(&c.arguments.expressions, |p, e| p.expression(e));
+                            self.write(")");
+                        }
+                        Access::Select(a) => self.array_access(a),
+                        Access::Member(m) => {
+                            self.write(".");
+                            self.write(&m.id.value);
+                        }
+                        Access::Element(e) => {
+                            self.write(".");
+                            self.write(e.index.span.as_str());
+                        }
+                    }
+                }
+            }
+            Expression::Identifier(i) => self.write(&i.value),
+            Expression::Literal(l) => self.write(l.span().as_str()),
+            Expression::InlineTuple(t) => {
+                self.write("(");
+                self.list(&t.elements, |p, e| p.expression(e));
+                if t.elements.len() == 1 {
+                    self.write(",");
+                }
+                self.write(")");
+            }
+            Expression::InlineEnum(e) => {
+                self.write(&e.ty.value);
+                self.write("::");
+                self.write(&e.variant.value);
+                if !e.arguments.is_empty() {
+                    self.write("(");
+                    self.list(&e.arguments, |p, e| p.expression(e));
+                    self.write(")");
+                }
+            }
+            Expression::ArrayInitializer(a) => {
+                self.write("[");
+                self.expression(&a.value);
+                self.write("; ");
+                self.expression(&a.count);
+                self.write("]");
+            }
+            Expression::InlineArray(a) => {
+                if self.is_multiline(e) {
+                    self.write("[");
+                    self.write("\n");
+                    self.open_block(a.span.start() + 1);
+                    for (i, element) in a.expressions.iter().enumerate() {
+                        let span = spread_or_expression_span(element);
+                        self.item_start(span.start());
+                        self.spread_or_expression(element);
+                        // arrays do not accept trailing commas
+                        if i < a.expressions.len() - 1 {
+                            self.write(",");
+                        }
+                        self.item_end(span.end());
+                    }
+                    self.close_block(a.span.end() - 1, false);
+                    self.write_indent();
+                    self.write("]");
+                } else {
+                    self.write("[");
+                    self.list(&a.expressions, |p, e| p.spread_or_expression(e));
+                    self.write("]");
+                }
+            }
+            Expression::InlineStruct(s) => {
+                self.write(&s.ty.value);
+                if s.members.is_empty() {
+                    self.write(" {}");
+                } else if self.is_multiline(e) {
+                    self.write(" {\n");
+                    self.open_block(s.ty.span.end());
+                    for member in &s.members {
+                        self.item_start(member.span.start());
+                        self.write(&member.id.value);
+                        self.write(": ");
+                        self.expression(&member.expression);
+                        self.write(",");
+                        self.item_end(member.span.end());
+                    }
+                    self.close_block(s.span.end() - 1, false);
+                    self.write_indent();
+                    self.write("}");
+                } else {
+                    self.write(" { ");
+                    self.list(&s.members, |p, m| {
+                        p.write(&m.id.value);
+                        p.write(": ");
+                        p.expression(&m.expression);
+                    });
+                    self.write(" }");
+                }
+            }
+            Expression::Match(m) => {
+                self.write("match ");
+                self.expression(&m.expression);
+                if self.flat {
+                    self.write(" { ");
+                    self.list(&m.arms, |p, arm| p.match_arm(arm));
+                    self.write(" }");
+                } else {
+                    self.write(" {\n");
+                    self.open_block(m.expression.span().end());
+                    for arm in &m.arms {
+                        self.item_start(arm.span.start());
+                        self.match_arm(arm);
+                        self.write(",");
+                        self.item_end(arm.span.end());
+                    }
+                    self.close_block(m.span.end() - 1, false);
+                    self.write_indent();
+                    self.write("}");
+                }
+            }
+        }
+    }
+
+    /// Returns whether the inline array or struct `e` should be written over several lines
+    fn is_multiline(&self, e: &Expression) -> bool {
+        !self.flat
+            && (self.render_flat(e).len() > MAX_INLINE_WIDTH
+                || self.has_comments_in(e.span().start(), e.span().end()))
+    }
+
+    fn match_arm(&mut self, arm: &MatchArm) {
+        match &arm.pattern {
+            MatchPattern::Wildcard(_) => self.write("_"),
+            MatchPattern::Variant(v) => {
+                self.write(&v.ty.value);
+                self.write("::");
+                self.write(&v.variant.value);
+                if !v.bindings.is_empty() {
+                    self.write("(");
+                    self.list(&v.bindings, |p, b| match b {
+                        BindingPattern::Wildcard(_) => p.write("_"),
+                        BindingPattern::Identifier(i) => p.write(&i.value),
+                    });
+                    self.write(")");
+                }
+            }
+        }
+        self.write(" => ");
+        self.expression(&arm.expression);
+    }
+
+    fn spread_or_expression(&mut self, e: &SpreadOrExpression) {
+        match e {
+            SpreadOrExpression::Spread(s) => {
+                self.write("...");
+                self.expression(&s.expression);
+            }
+            SpreadOrExpression::Expression(e) => self.expression(e),
+        }
+    }
+
+    fn array_access(&mut self, a: &ArrayAccess) {
+        self.write("[");
+        match &a.expression {
+            RangeOrExpression::Expression(e) => self.expression(e),
+            RangeOrExpression::Range(r) => {
+                if let Some(from) = &r.from {
+                    self.expression(&from.0);
+                }
+                self.write("..");
+                if let Some(to) = &r.to {
+                    self.expression(&to.0);
+                }
+            }
+        }
+        self.write("]");
+    }
+}
+
+fn generics(generics: &[IdentifierExpression]) -> String {
+    match generics.is_empty() {
+        true => String::new(),
+        false => format!(
+            "<{}>",
+            generics
+                .iter()
+                .map(|g| g.value.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn explicit_generics(g: &ExplicitGenerics) -> String {
+    format!(
+        "<{}>",
+        g.values
+            .iter()
+            .map(|v| match v {
+                ConstantGenericValue::Value(l) => l.span().as_str(),
+                ConstantGenericValue::Identifier(i) => i.value.as_str(),
+                ConstantGenericValue::Underscore(_) => "_",
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn basic_or_struct_or_tuple_type(t: &BasicOrStructOrTupleType) -> String {
+    match t {
+        BasicOrStructOrTupleType::Basic(t) => t.span().as_str().to_string(),
+        BasicOrStructOrTupleType::Struct(t) => struct_type(t),
+        BasicOrStructOrTupleType::Tuple(t) => tuple_type(t),
+    }
+}
+
+fn struct_type(t: &StructType) -> String {
+    match &t.explicit_generics {
+        Some(g) => format!("{}{}", t.id.value, explicit_generics(g)),
+        None => t.id.value.clone(),
+    }
+}
+
+fn tuple_type(t: &TupleType) -> String {
+    match t.elements.as_slice() {
+        [e] => format!("({},)", ty(e)),
+        elements => format!(
+            "({})",
+            elements.iter().map(ty).collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
+
+fn ty(t: &Type) -> String {
+    match t {
+        Type::Basic(t) => t.span().as_str().to_string(),
+        Type::Struct(t) => struct_type(t),
+        Type::Tuple(t) => tuple_type(t),
+        Type::Array(t) => {
+            let printer = Printer::new("", vec![]);
+            format!(
+                "{}{}",
+                basic_or_struct_or_tuple_type(&t.ty),
+                t.dimensions
+                    .iter()
+                    .map(|d| format!("[{}]", printer.render_flat(d)))
+                    .collect::<String>()
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_formats_to(source: &str, expected: &str) {
+        let formatted = format(source).unwrap();
+        assert_eq!(formatted, expected);
+        // formatting is idempotent
+        assert_eq!(format(&formatted).unwrap(), formatted);
+    }
+
+    #[test]
+    fn indentation_and_spacing() {
+        assert_formats_to(
+            "def main(private field a,field b)->field:\n    field c=if a==b then 1 else 2 fi\n  for u32 i in 0..3 do\n c=c+1\n      endfor\n    return c*(a+b)\n",
+            "def main(private field a, field b) -> field:\n\tfield c = if a == b then 1 else 2 fi\n\tfor u32 i in 0..3 do\n\t\tc = c + 1\n\tendfor\n\treturn c * (a + b)\n",
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert_formats_to(
+            "// header\nimport \"./foo\" // main import\n\n/* a\n   block */\ndef main():\n  // inside\n  assert(true) // trailing\n\n  return\n  // end of main\n",
+            "// header\nimport \"./foo\" // main import\n\n/* a\n   block */\ndef main():\n\t// inside\n\tassert(true) // trailing\n\n\treturn\n\t// end of main\n",
+        );
+    }
+
+    #[test]
+    fn parentheses() {
+        assert_formats_to(
+            "def main():\n\tfield a = (1 - (2 - 3)) - ((-4) ** 2)\n\tbool b = (true ? false : true) ? true : (false ? true : false)\n\treturn\n",
+            "def main():\n\tfield a = 1 - (2 - 3) - (-4) ** 2\n\tbool b = (true ? false : true) ? true : false ? true : false\n\treturn\n",
+        );
+    }
+
+    #[test]
+    fn struct_expressions() {
+        assert_formats_to(
+            "struct Foo {\n  field a\n  field b\n}\n\ndef main():\n\tFoo f = Foo {a:1,b:2}\n\tFoo g = Foo {\n\t\ta: 1, // one\n\t\tb: 2\n\t}\n\treturn\n",
+            "struct Foo {\n\tfield a\n\tfield b\n}\n\ndef main():\n\tFoo f = Foo { a: 1, b: 2 }\n\tFoo g = Foo {\n\t\ta: 1, // one\n\t\tb: 2,\n\t}\n\treturn\n",
+        );
+    }
+
+    #[test]
+    fn single_tuple_return() {
+        assert_formats_to(
+            "def main() -> ((field, bool)):\n\treturn (1, true)\n",
+            "def main() -> ((field, bool)):\n\treturn (1, true)\n",
+        );
+    }
+
+    #[test]
+    fn examples() {
+        use glob::glob;
+        use std::fs;
+
+        for path in glob("../zokrates_cli/examples/**/*.zok").unwrap() {
+            let path = path.unwrap();
+            let source = fs::read_to_string(&path).unwrap();
+
+            if let Ok(formatted) = format(&source) {
+                assert_eq!(
+                    format(&formatted).unwrap(),
+                    formatted,
+                    "formatting {} is not idempotent",
+                    path.display()
+                );
+            }
+        }
+    }
+}